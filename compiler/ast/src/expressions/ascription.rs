@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::Type;
+
+/// A type ascription expression, e.g. `(x : u8)`.
+/// Unlike a cast, an ascription performs no conversion; it only states the type of its expression.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AscriptionExpression {
+    /// The expression whose type is ascribed, e.g. `x` in `(x : u8)`.
+    pub expression: Box<Expression>,
+    /// The ascribed type, e.g. `u8` in `(x : u8)`.
+    pub type_: Type,
+    /// Span of the entire ascription `(x : u8)`.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for AscriptionExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({} : {})", self.expression, self.type_)
+    }
+}
+
+crate::simple_node_impl!(AscriptionExpression);
@@ -26,6 +26,9 @@ pub use access::*;
 mod array;
 pub use array::*;
 
+mod ascription;
+pub use ascription::*;
+
 mod binary;
 pub use binary::*;
 
@@ -41,6 +44,9 @@ pub use struct_init::*;
 mod err;
 pub use err::*;
 
+mod spread;
+pub use spread::*;
+
 mod ternary;
 pub use ternary::*;
 
@@ -63,6 +69,8 @@ pub enum Expression {
     Access(AccessExpression),
     /// An array expression, e.g., `[true, false, true, false]`.
     Array(ArrayExpression),
+    /// A type ascription expression, e.g., `(x : u8)`.
+    Ascription(AscriptionExpression),
     /// A binary expression, e.g., `42 + 24`.
     Binary(BinaryExpression),
     /// A call expression, e.g., `my_fun(args)`.
@@ -78,6 +86,8 @@ pub enum Expression {
     Identifier(Identifier),
     /// A literal expression.
     Literal(Literal),
+    /// A spread of a tuple into a tuple expression, e.g., `..foo` in `(bar, ..foo)`.
+    Spread(SpreadExpression),
     /// A ternary conditional expression `cond ? if_expr : else_expr`.
     Ternary(TernaryExpression),
     /// A tuple expression e.g., `(foo, 42, true)`.
@@ -94,6 +104,7 @@ impl Node for Expression {
         match self {
             Access(n) => n.span(),
             Array(n) => n.span(),
+            Ascription(n) => n.span(),
             Binary(n) => n.span(),
             Call(n) => n.span(),
             Cast(n) => n.span(),
@@ -101,6 +112,7 @@ impl Node for Expression {
             Err(n) => n.span(),
             Identifier(n) => n.span(),
             Literal(n) => n.span(),
+            Spread(n) => n.span(),
             Ternary(n) => n.span(),
             Tuple(n) => n.span(),
             Unary(n) => n.span(),
@@ -113,12 +125,14 @@ impl Node for Expression {
         match self {
             Access(n) => n.set_span(span),
             Array(n) => n.set_span(span),
+            Ascription(n) => n.set_span(span),
             Binary(n) => n.set_span(span),
             Call(n) => n.set_span(span),
             Cast(n) => n.set_span(span),
             Struct(n) => n.set_span(span),
             Identifier(n) => n.set_span(span),
             Literal(n) => n.set_span(span),
+            Spread(n) => n.set_span(span),
             Err(n) => n.set_span(span),
             Ternary(n) => n.set_span(span),
             Tuple(n) => n.set_span(span),
@@ -132,12 +146,14 @@ impl Node for Expression {
         match self {
             Access(n) => n.id(),
             Array(n) => n.id(),
+            Ascription(n) => n.id(),
             Binary(n) => n.id(),
             Call(n) => n.id(),
             Cast(n) => n.id(),
             Struct(n) => n.id(),
             Identifier(n) => n.id(),
             Literal(n) => n.id(),
+            Spread(n) => n.id(),
             Err(n) => n.id(),
             Ternary(n) => n.id(),
             Tuple(n) => n.id(),
@@ -151,12 +167,14 @@ impl Node for Expression {
         match self {
             Access(n) => n.set_id(id),
             Array(n) => n.set_id(id),
+            Ascription(n) => n.set_id(id),
             Binary(n) => n.set_id(id),
             Call(n) => n.set_id(id),
             Cast(n) => n.set_id(id),
             Struct(n) => n.set_id(id),
             Identifier(n) => n.set_id(id),
             Literal(n) => n.set_id(id),
+            Spread(n) => n.set_id(id),
             Err(n) => n.set_id(id),
             Ternary(n) => n.set_id(id),
             Tuple(n) => n.set_id(id),
@@ -172,6 +190,7 @@ impl fmt::Display for Expression {
         match &self {
             Access(n) => n.fmt(f),
             Array(n) => n.fmt(f),
+            Ascription(n) => n.fmt(f),
             Binary(n) => n.fmt(f),
             Call(n) => n.fmt(f),
             Cast(n) => n.fmt(f),
@@ -179,6 +198,7 @@ impl fmt::Display for Expression {
             Err(n) => n.fmt(f),
             Identifier(n) => n.fmt(f),
             Literal(n) => n.fmt(f),
+            Spread(n) => n.fmt(f),
             Ternary(n) => n.fmt(f),
             Tuple(n) => n.fmt(f),
             Unary(n) => n.fmt(f),
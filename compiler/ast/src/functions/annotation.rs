@@ -14,19 +14,51 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{simple_node_impl, Identifier, Node, NodeID};
+use crate::{simple_node_impl, Expression, Identifier, Node, NodeID};
 
 use leo_span::Span;
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-/// An annotation, e.g. @program.
+/// An argument to an annotation, either positional, e.g. `"use bar"` in `@deprecated("use bar")`,
+/// or named, e.g. `feature = "x"` in `@cfg(feature = "x")`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AnnotationArgument {
+    /// A positional argument.
+    Positional(Expression),
+    /// A named argument, `key = value`.
+    Named(Identifier, Expression),
+}
+
+impl AnnotationArgument {
+    /// Returns the span of the argument, including the name of a named argument.
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Positional(value) => value.span(),
+            Self::Named(key, value) => key.span + value.span(),
+        }
+    }
+}
+
+impl fmt::Display for AnnotationArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Positional(value) => write!(f, "{value}"),
+            Self::Named(key, value) => write!(f, "{key} = {value}"),
+        }
+    }
+}
+
+/// An annotation, e.g. @program or @deprecated("use bar").
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Annotation {
     // TODO: Consider using a symbol instead of an identifier.
     /// The name of the annotation.
     pub identifier: Identifier,
+    /// The arguments to the annotation, if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<AnnotationArgument>,
     /// A span locating where the annotation occurred in the source.
     pub span: Span,
     /// The ID of the node.
@@ -37,6 +69,14 @@ simple_node_impl!(Annotation);
 
 impl fmt::Display for Annotation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "@{}", self.identifier)
+        write!(f, "@{}", self.identifier)?;
+        if !self.arguments.is_empty() {
+            write!(
+                f,
+                "({})",
+                self.arguments.iter().map(|argument| argument.to_string()).collect::<Vec<_>>().join(", ")
+            )?;
+        }
+        Ok(())
     }
 }
@@ -38,7 +38,7 @@ pub use output::*;
 pub mod mode;
 pub use mode::*;
 
-use crate::{Block, Identifier, Node, NodeID, TupleType, Type};
+use crate::{Block, Expression, Identifier, Node, NodeID, TupleType, Type};
 use leo_span::{sym, Span, Symbol};
 
 use serde::{Deserialize, Serialize};
@@ -47,18 +47,27 @@ use std::fmt;
 /// A function definition.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Function {
+    /// The documentation comments on the function, e.g. `/// Adds two numbers.`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docs: Option<String>,
     /// Annotations on the function.
     pub annotations: Vec<Annotation>,
     /// Is this function a transition, inlined, or a regular function?.
     pub variant: Variant,
     /// The function identifier, e.g., `foo` in `function foo(...) { ... }`.
     pub identifier: Identifier,
+    /// The function's type parameters, e.g. `T` in `function foo<T>(...) { ... }`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<Identifier>,
     /// The function's input parameters.
     pub input: Vec<Input>,
     /// The function's output declarations.
     pub output: Vec<Output>,
     /// The function's output type.
     pub output_type: Type,
+    /// The compile-time predicates in the function's `where` clause, e.g. `N > 0u32`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub const_predicates: Vec<Expression>,
     /// The body of the function.
     pub block: Block,
     /// An optional finalize block
@@ -81,11 +90,14 @@ impl Function {
     /// Initialize a new function.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
+        docs: Option<String>,
         annotations: Vec<Annotation>,
         variant: Variant,
         identifier: Identifier,
+        type_params: Vec<Identifier>,
         input: Vec<Input>,
         output: Vec<Output>,
+        const_predicates: Vec<Expression>,
         block: Block,
         finalize: Option<Finalize>,
         span: Span,
@@ -103,7 +115,21 @@ impl Function {
             _ => Type::Tuple(TupleType::new(output.iter().map(get_output_type).collect())),
         };
 
-        Function { annotations, variant, identifier, input, output, output_type, block, finalize, span, id }
+        Function {
+            docs,
+            annotations,
+            variant,
+            identifier,
+            type_params,
+            input,
+            output,
+            output_type,
+            const_predicates,
+            block,
+            finalize,
+            span,
+            id,
+        }
     }
 
     /// Returns function name.
@@ -126,6 +152,10 @@ impl Function {
             Variant::Transition => write!(f, "transition ")?,
         }
         write!(f, "{}", self.identifier)?;
+        if !self.type_params.is_empty() {
+            let type_params = self.type_params.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+            write!(f, "<{type_params}>")?;
+        }
 
         let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
         let returns = match self.output.len() {
@@ -133,7 +163,12 @@ impl Function {
             1 => self.output[0].to_string(),
             _ => self.output.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
         };
-        write!(f, "({parameters}) -> {returns} {}", self.block)?;
+        write!(f, "({parameters}) -> {returns} ")?;
+        if !self.const_predicates.is_empty() {
+            let predicates = self.const_predicates.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+            write!(f, "where {predicates} ")?;
+        }
+        write!(f, "{}", self.block)?;
 
         if let Some(finalize) = &self.finalize {
             let parameters = finalize.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
@@ -27,6 +27,7 @@ pub trait ExpressionConsumer {
         match input {
             Expression::Access(access) => self.consume_access(access),
             Expression::Array(array) => self.consume_array(array),
+            Expression::Ascription(ascription) => self.consume_ascription(ascription),
             Expression::Binary(binary) => self.consume_binary(binary),
             Expression::Call(call) => self.consume_call(call),
             Expression::Cast(cast) => self.consume_cast(cast),
@@ -34,6 +35,7 @@ pub trait ExpressionConsumer {
             Expression::Err(err) => self.consume_err(err),
             Expression::Identifier(identifier) => self.consume_identifier(identifier),
             Expression::Literal(value) => self.consume_literal(value),
+            Expression::Spread(spread) => self.consume_spread(spread),
             Expression::Ternary(ternary) => self.consume_ternary(ternary),
             Expression::Tuple(tuple) => self.consume_tuple(tuple),
             Expression::Unary(unary) => self.consume_unary(unary),
@@ -45,6 +47,11 @@ pub trait ExpressionConsumer {
 
     fn consume_array(&mut self, _input: ArrayExpression) -> Self::Output;
 
+    /// Ascriptions perform no conversion, so by default they are consumed as their inner expression.
+    fn consume_ascription(&mut self, input: AscriptionExpression) -> Self::Output {
+        self.consume_expression(*input.expression)
+    }
+
     fn consume_binary(&mut self, _input: BinaryExpression) -> Self::Output;
 
     fn consume_call(&mut self, _input: CallExpression) -> Self::Output;
@@ -61,6 +68,10 @@ pub trait ExpressionConsumer {
 
     fn consume_literal(&mut self, _input: Literal) -> Self::Output;
 
+    fn consume_spread(&mut self, _input: SpreadExpression) -> Self::Output {
+        unreachable!("`SpreadExpression`s should be expanded before this phase of compilation.")
+    }
+
     fn consume_ternary(&mut self, _input: TernaryExpression) -> Self::Output;
 
     fn consume_tuple(&mut self, _input: TupleExpression) -> Self::Output;
@@ -76,6 +87,7 @@ pub trait StatementConsumer {
 
     fn consume_statement(&mut self, input: Statement) -> Self::Output {
         match input {
+            Statement::Annotated(stmt) => self.consume_annotated(stmt),
             Statement::Assert(assert) => self.consume_assert(assert),
             Statement::Assign(stmt) => self.consume_assign(*stmt),
             Statement::Block(stmt) => self.consume_block(stmt),
@@ -89,6 +101,8 @@ pub trait StatementConsumer {
         }
     }
 
+    fn consume_annotated(&mut self, input: AnnotatedStatement) -> Self::Output;
+
     fn consume_assert(&mut self, input: AssertStatement) -> Self::Output;
 
     fn consume_assign(&mut self, input: AssignStatement) -> Self::Output;
@@ -28,6 +28,7 @@ pub trait ExpressionReconstructor {
         match input {
             Expression::Access(access) => self.reconstruct_access(access),
             Expression::Array(array) => self.reconstruct_array(array),
+            Expression::Ascription(ascription) => self.reconstruct_ascription(ascription),
             Expression::Binary(binary) => self.reconstruct_binary(binary),
             Expression::Call(call) => self.reconstruct_call(call),
             Expression::Cast(cast) => self.reconstruct_cast(cast),
@@ -35,6 +36,7 @@ pub trait ExpressionReconstructor {
             Expression::Err(err) => self.reconstruct_err(err),
             Expression::Identifier(identifier) => self.reconstruct_identifier(identifier),
             Expression::Literal(value) => self.reconstruct_literal(value),
+            Expression::Spread(spread) => self.reconstruct_spread(spread),
             Expression::Ternary(ternary) => self.reconstruct_ternary(ternary),
             Expression::Tuple(tuple) => self.reconstruct_tuple(tuple),
             Expression::Unary(unary) => self.reconstruct_unary(unary),
@@ -124,6 +126,18 @@ pub trait ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_ascription(&mut self, input: AscriptionExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Ascription(AscriptionExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: input.type_,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
         (
             Expression::Binary(BinaryExpression {
@@ -198,6 +212,17 @@ pub trait ExpressionReconstructor {
         (Expression::Literal(input), Default::default())
     }
 
+    fn reconstruct_spread(&mut self, input: SpreadExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Spread(SpreadExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
         (
             Expression::Ternary(TernaryExpression {
@@ -243,6 +268,7 @@ pub trait ExpressionReconstructor {
 pub trait StatementReconstructor: ExpressionReconstructor {
     fn reconstruct_statement(&mut self, input: Statement) -> (Statement, Self::AdditionalOutput) {
         match input {
+            Statement::Annotated(stmt) => self.reconstruct_annotated(stmt),
             Statement::Assert(assert) => self.reconstruct_assert(assert),
             Statement::Assign(stmt) => self.reconstruct_assign(*stmt),
             Statement::Block(stmt) => {
@@ -259,6 +285,18 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         }
     }
 
+    fn reconstruct_annotated(&mut self, input: AnnotatedStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Annotated(AnnotatedStatement {
+                annotations: input.annotations,
+                statement: Box::new(self.reconstruct_statement(*input.statement).0),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_assert(&mut self, input: AssertStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Assert(AssertStatement {
@@ -386,6 +424,7 @@ pub trait StatementReconstructor: ExpressionReconstructor {
                 stop_value: input.stop_value,
                 block: self.reconstruct_block(input.block).0,
                 inclusive: input.inclusive,
+                step: input.step.map(|step| self.reconstruct_expression(step).0),
                 span: input.span,
                 id: input.id,
             })),
@@ -445,12 +484,23 @@ pub trait ProgramReconstructor: StatementReconstructor {
 
     fn reconstruct_function(&mut self, input: Function) -> Function {
         Function {
+            docs: self.reconstruct_docs(input.docs),
             annotations: input.annotations,
             variant: input.variant,
             identifier: input.identifier,
+            type_params: input
+                .type_params
+                .into_iter()
+                .map(|type_param| self.reconstruct_type_param(type_param))
+                .collect(),
             input: input.input,
             output: input.output,
             output_type: input.output_type,
+            const_predicates: input
+                .const_predicates
+                .into_iter()
+                .map(|predicate| self.reconstruct_function_predicate(predicate))
+                .collect(),
             block: self.reconstruct_block(input.block).0,
             finalize: input.finalize.map(|finalize| Finalize {
                 identifier: finalize.identifier,
@@ -466,7 +516,32 @@ pub trait ProgramReconstructor: StatementReconstructor {
         }
     }
 
+    fn reconstruct_function_predicate(&mut self, input: Expression) -> Expression {
+        self.reconstruct_expression(input).0
+    }
+
+    fn reconstruct_type_param(&mut self, input: Identifier) -> Identifier {
+        input
+    }
+
     fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            docs: self.reconstruct_docs(input.docs),
+            type_params: input
+                .type_params
+                .into_iter()
+                .map(|type_param| self.reconstruct_type_param(type_param))
+                .collect(),
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member { docs: self.reconstruct_docs(member.docs), ..member })
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_docs(&mut self, input: Option<String>) -> Option<String> {
         input
     }
 
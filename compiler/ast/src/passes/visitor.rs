@@ -29,6 +29,7 @@ pub trait ExpressionVisitor<'a> {
         match input {
             Expression::Access(access) => self.visit_access(access, additional),
             Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Ascription(ascription) => self.visit_ascription(ascription, additional),
             Expression::Binary(binary) => self.visit_binary(binary, additional),
             Expression::Call(call) => self.visit_call(call, additional),
             Expression::Cast(cast) => self.visit_cast(cast, additional),
@@ -36,6 +37,7 @@ pub trait ExpressionVisitor<'a> {
             Expression::Err(err) => self.visit_err(err, additional),
             Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
             Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Spread(spread) => self.visit_spread(spread, additional),
             Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
             Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
             Expression::Unary(unary) => self.visit_unary(unary, additional),
@@ -73,6 +75,15 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_ascription(
+        &mut self,
+        input: &'a AscriptionExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        self.visit_expression(&input.expression, additional);
+        Default::default()
+    }
+
     fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
         self.visit_expression(&input.left, additional);
         self.visit_expression(&input.right, additional);
@@ -107,6 +118,11 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_spread(&mut self, input: &'a SpreadExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, additional);
+        Default::default()
+    }
+
     fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
         self.visit_expression(&input.condition, additional);
         self.visit_expression(&input.if_true, additional);
@@ -135,6 +151,7 @@ pub trait ExpressionVisitor<'a> {
 pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
     fn visit_statement(&mut self, input: &'a Statement) {
         match input {
+            Statement::Annotated(stmt) => self.visit_annotated(stmt),
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
@@ -148,6 +165,10 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         }
     }
 
+    fn visit_annotated(&mut self, input: &'a AnnotatedStatement) {
+        self.visit_statement(&input.statement);
+    }
+
     fn visit_assert(&mut self, input: &'a AssertStatement) {
         match &input.variant {
             AssertVariant::Assert(expr) => self.visit_expression(expr, &Default::default()),
@@ -205,6 +226,9 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
     fn visit_iteration(&mut self, input: &'a IterationStatement) {
         self.visit_expression(&input.start, &Default::default());
         self.visit_expression(&input.stop, &Default::default());
+        if let Some(step) = &input.step {
+            self.visit_expression(step, &Default::default());
+        }
         self.visit_block(&input.block);
     }
 
@@ -240,14 +264,24 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
         self.visit_program(input)
     }
 
-    fn visit_struct(&mut self, _input: &'a Struct) {}
+    fn visit_struct(&mut self, input: &'a Struct) {
+        input.type_params.iter().for_each(|type_param| self.visit_type_param(type_param));
+    }
 
     fn visit_mapping(&mut self, _input: &'a Mapping) {}
 
     fn visit_function(&mut self, input: &'a Function) {
+        input.type_params.iter().for_each(|type_param| self.visit_type_param(type_param));
+        input.const_predicates.iter().for_each(|predicate| self.visit_function_predicate(predicate));
         self.visit_block(&input.block);
         if let Some(finalize) = &input.finalize {
             self.visit_block(&finalize.block);
         }
     }
+
+    fn visit_function_predicate(&mut self, input: &'a Expression) {
+        self.visit_expression(input, &Default::default());
+    }
+
+    fn visit_type_param(&mut self, _input: &'a Identifier) {}
 }
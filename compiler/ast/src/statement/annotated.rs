@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Annotation, Node, NodeID, Statement};
+use leo_span::Span;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A statement preceded by annotations, `@cfg(feature = "x") foo(a);`.
+/// Annotated statements are expanded before type checking.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct AnnotatedStatement {
+    /// The annotations of the statement.
+    pub annotations: Vec<Annotation>,
+    /// The statement being annotated.
+    pub statement: Box<Statement>,
+    /// The span, from the first annotation to the end of the statement.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for AnnotatedStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.annotations.iter().try_for_each(|annotation| write!(f, "{annotation} "))?;
+        write!(f, "{}", self.statement)
+    }
+}
+
+crate::simple_node_impl!(AnnotatedStatement);
@@ -21,7 +21,7 @@ use leo_span::Span;
 use serde::{Deserialize, Serialize};
 use std::{cell::RefCell, fmt};
 
-/// A bounded `for` loop statement `for variable in start .. =? stop block`.
+/// A bounded `for` loop statement `for variable in start .. =? stop (step step)? block`.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct IterationStatement {
     /// The binding / variable to introduce in the body `block`.
@@ -41,6 +41,11 @@ pub struct IterationStatement {
     /// Whether `stop` is inclusive or not.
     /// Signified with `=` when parsing.
     pub inclusive: bool,
+    /// The step of the iteration, if any.
+    /// Stepped loops must be lowered into unit-step loops by the step normalization pass, since the type checker
+    /// rejects them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step: Option<Expression>,
     /// The block to run on each iteration.
     pub block: Block,
     /// The span from `for` to `block`.
@@ -52,7 +57,11 @@ pub struct IterationStatement {
 impl fmt::Display for IterationStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let eq = if self.inclusive { "=" } else { "" };
-        write!(f, "for {} in {}..{eq}{} {}", self.variable, self.start, self.stop, self.block)
+        write!(f, "for {} in {}..{eq}{}", self.variable, self.start, self.stop)?;
+        if let Some(step) = &self.step {
+            write!(f, " step {step}")?;
+        }
+        write!(f, " {}", self.block)
     }
 }
 
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod annotated;
+pub use annotated::*;
+
 pub mod assert;
 pub use assert::*;
 
@@ -54,6 +57,8 @@ use std::fmt;
 /// Program statement that defines some action (or expression) to be carried out.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum Statement {
+    /// A statement preceded by annotations.
+    Annotated(AnnotatedStatement),
     /// An assert statement.
     Assert(AssertStatement),
     /// An assignment statement.
@@ -86,6 +91,7 @@ impl Statement {
 impl fmt::Display for Statement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Statement::Annotated(x) => x.fmt(f),
             Statement::Assert(x) => x.fmt(f),
             Statement::Assign(x) => x.fmt(f),
             Statement::Block(x) => x.fmt(f),
@@ -104,6 +110,7 @@ impl Node for Statement {
     fn span(&self) -> Span {
         use Statement::*;
         match self {
+            Annotated(n) => n.span(),
             Assert(n) => n.span(),
             Assign(n) => n.span(),
             Block(n) => n.span(),
@@ -120,6 +127,7 @@ impl Node for Statement {
     fn set_span(&mut self, span: Span) {
         use Statement::*;
         match self {
+            Annotated(n) => n.set_span(span),
             Assert(n) => n.set_span(span),
             Assign(n) => n.set_span(span),
             Block(n) => n.set_span(span),
@@ -136,6 +144,7 @@ impl Node for Statement {
     fn id(&self) -> NodeID {
         use Statement::*;
         match self {
+            Annotated(n) => n.id(),
             Assert(n) => n.id(),
             Assign(n) => n.id(),
             Block(n) => n.id(),
@@ -152,6 +161,7 @@ impl Node for Statement {
     fn set_id(&mut self, id: NodeID) {
         use Statement::*;
         match self {
+            Annotated(n) => n.set_id(id),
             Assert(n) => n.set_id(id),
             Assign(n) => n.set_id(id),
             Block(n) => n.set_id(id),
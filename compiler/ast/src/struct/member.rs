@@ -24,6 +24,9 @@ use std::fmt;
 /// A member of a structured data type, e.g `foobar: u8` or `private baz: bool` .
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Member {
+    /// The documentation comments on the member, e.g. `/// The owner of the token.`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docs: Option<String>,
     /// The mode of the member.
     pub mode: Mode,
     /// The identifier of the member.
@@ -17,7 +17,7 @@
 pub mod member;
 pub use member::*;
 
-use crate::{Identifier, Node, NodeID};
+use crate::{Annotation, Identifier, Node, NodeID};
 use leo_span::{Span, Symbol};
 
 use serde::{Deserialize, Serialize};
@@ -31,8 +31,17 @@ use std::fmt;
 /// The fields are named so `struct Foo(u8, u16)` is not allowed.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Struct {
+    /// The documentation comments on the struct, e.g. `/// A token owned by an address.`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docs: Option<String>,
+    /// Annotations on the struct, e.g. `@deprecated`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
     /// The name of the type in the type system in this module.
     pub identifier: Identifier,
+    /// The type parameters of the struct, e.g. `T` in `struct Foo<T> { ... }`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<Identifier>,
     /// The fields, constant variables, and functions of this structure.
     pub members: Vec<Member>,
     /// Was this a `record Foo { ... }`?
@@ -68,7 +77,12 @@ impl fmt::Debug for Struct {
 impl fmt::Display for Struct {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(if self.is_record { "record" } else { "struct" })?;
-        writeln!(f, " {} {{ ", self.identifier)?;
+        write!(f, " {}", self.identifier)?;
+        if !self.type_params.is_empty() {
+            let type_params = self.type_params.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+            write!(f, "<{type_params}>")?;
+        }
+        writeln!(f, " {{ ")?;
         for field in self.members.iter() {
             writeln!(f, "    {field}")?;
         }
@@ -186,6 +186,11 @@ impl<'a> Compiler<'a> {
         Ok((symbol_table, struct_graph, call_graph))
     }
 
+    /// Runs the predicate checking pass.
+    pub fn predicate_checking_pass(&self) -> Result<()> {
+        PredicateChecker::do_pass((&self.ast, self.handler))
+    }
+
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
         let (ast, symbol_table) = Unroller::do_pass((
@@ -304,6 +309,8 @@ impl<'a> Compiler<'a> {
         let st = self.symbol_table_pass()?;
         let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
 
+        self.predicate_checking_pass()?;
+
         // TODO: Make this pass optional.
         let st = self.loop_unrolling_pass(st)?;
 
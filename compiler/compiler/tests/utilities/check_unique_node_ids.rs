@@ -92,6 +92,13 @@ impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
         }
     }
 
+    fn visit_ascription(&mut self, input: &'a AscriptionExpression, _: &Self::AdditionalInput) -> Self::Output {
+        let AscriptionExpression { expression, type_, id, .. } = input;
+        self.visit_expression(expression, &Default::default());
+        self.check_ty(type_);
+        self.check(*id);
+    }
+
     fn visit_binary(&mut self, input: &'a BinaryExpression, _: &Self::AdditionalInput) -> Self::Output {
         let BinaryExpression { left, right, id, .. } = input;
         self.visit_expression(left, &Default::default());
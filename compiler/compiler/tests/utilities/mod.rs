@@ -230,6 +230,8 @@ pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, L
 
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
 
+    parsed.predicate_checking_pass()?;
+
     let st = parsed.loop_unrolling_pass(st)?;
 
     parsed.static_single_assignment_pass(&st)?;
@@ -18,8 +18,9 @@ use crate::{tokenizer::*, Token};
 
 use leo_ast::*;
 use leo_errors::{emitter::Handler, ParserError, ParserWarning, Result};
-use leo_span::{Span, Symbol};
+use leo_span::{span::BytePos, Span, Symbol};
 
+use indexmap::IndexMap;
 use std::{fmt::Display, mem};
 
 /// Stores a program in tokenized format plus additional context.
@@ -41,6 +42,8 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) disallow_struct_construction: bool,
     /// true if parsing an identifier inside an input file.
     pub(crate) allow_identifier_underscores: bool,
+    /// The documentation comments, keyed by the start of the token that follows them.
+    docs: IndexMap<BytePos, String>,
 }
 
 /// Dummy span used to appease borrow checker.
@@ -49,7 +52,8 @@ const DUMMY_EOF: SpannedToken = SpannedToken { token: Token::Eof, span: Span::du
 impl<'a> ParserContext<'a> {
     /// Returns a new [`ParserContext`] type given a vector of tokens.
     pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, mut tokens: Vec<SpannedToken>) -> Self {
-        // Strip out comments.
+        // Collect the documentation comments, then strip out comments.
+        let docs = Self::collect_docs(&tokens);
         tokens.retain(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)));
         // For performance we reverse so that we get cheap `.pop()`s.
         tokens.reverse();
@@ -63,11 +67,47 @@ impl<'a> ParserContext<'a> {
             prev_token: token.clone(),
             token,
             tokens,
+            docs,
         };
         p.bump();
         p
     }
 
+    /// Returns the text of the documentation comments, i.e. `/// ...` lines and `/** ... */` blocks,
+    /// keyed by the start of the token that follows them.
+    fn collect_docs(tokens: &[SpannedToken]) -> IndexMap<BytePos, String> {
+        let mut docs = IndexMap::new();
+        let mut lines = Vec::new();
+        for token in tokens {
+            match &token.token {
+                Token::CommentLine(comment) if comment.starts_with("///") && !comment.starts_with("////") => {
+                    let line = &comment[3..];
+                    lines.push(line.strip_prefix(' ').unwrap_or(line).trim_end().to_owned());
+                }
+                Token::CommentBlock(comment)
+                    if comment.starts_with("/**") && !comment.starts_with("/***") && comment.len() > 4 =>
+                {
+                    let block = &comment[3..comment.len() - 2];
+                    lines.extend(block.trim().lines().map(|line| {
+                        let line = line.trim();
+                        line.strip_prefix('*').unwrap_or(line).trim().to_owned()
+                    }));
+                }
+                Token::CommentLine(_) | Token::CommentBlock(_) => {}
+                _ if !lines.is_empty() => {
+                    docs.insert(token.span.lo, mem::take(&mut lines).join("\n"));
+                }
+                _ => {}
+            }
+        }
+        docs
+    }
+
+    /// Returns the documentation comments preceding the current token, if any.
+    pub(super) fn take_docs(&mut self) -> Option<String> {
+        self.docs.remove(&self.token.span.lo)
+    }
+
     /// Advances the parser cursor by one token.
     ///
     /// So e.g., if we had `previous = A`, `current = B`, and `tokens = [C, D, E]`,
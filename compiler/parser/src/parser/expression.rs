@@ -526,23 +526,60 @@ impl ParserContext<'_> {
             return Ok(Expression::Literal(Literal::Group(Box::new(GroupLiteral::Tuple(gt)))));
         }
 
-        let (mut elements, trailing, span) = self.parse_expr_tuple()?;
+        let (mut elements, trailing, span) = self.parse_paren_comma_list(|p| p.parse_tuple_element().map(Some))?;
 
         match elements.len() {
             // If the tuple expression is empty, return a `UnitExpression`.
             0 => Ok(Expression::Unit(UnitExpression { span, id: self.node_builder.next_id() })),
-            1 => match trailing {
+            // A single element is a parenthesized expression, unless it is a spread, e.g. `(..foo)`.
+            1 if !matches!(elements[0], Expression::Spread(_)) => match trailing {
                 // If there is one element in the tuple but no trailing comma, e.g `(foo)`, return the element.
-                false => Ok(elements.swap_remove(0)),
+                // An ascription, e.g. `(foo : u8)`, takes the span of the enclosing parentheses.
+                false => Ok(match elements.swap_remove(0) {
+                    Expression::Ascription(ascription) => {
+                        Expression::Ascription(AscriptionExpression { span, ..ascription })
+                    }
+                    element => element,
+                }),
                 // If there is one element in the tuple and a trailing comma, e.g `(foo,)`, emit an error since tuples must have at least two elements.
                 true => Err(ParserError::tuple_must_have_at_least_two_elements("expression", span).into()),
             },
+            // Ascriptions are only allowed in parenthesized expressions, e.g. `(foo : u8)`, not as tuple elements.
+            _ if elements.iter().any(|element| matches!(element, Expression::Ascription(_))) => {
+                Err(ParserError::unexpected(":", "',' or ')'", span).into())
+            }
             // Otherwise, return a tuple expression.
             // Note: This is the only place where `TupleExpression` is constructed in the parser.
             _ => Ok(Expression::Tuple(TupleExpression { elements, span, id: self.node_builder.next_id() })),
         }
     }
 
+    /// Returns an [`Expression`] AST node if the next tokens represent an element of a tuple expression,
+    /// which is either an expression, an expression with a type ascription, e.g. `foo : u8`,
+    /// or a spread of a tuple, e.g. `..foo`.
+    fn parse_tuple_element(&mut self) -> Result<Expression> {
+        if !self.eat(&Token::DotDot) {
+            let expression = self.parse_expression()?;
+            if !self.eat(&Token::Colon) {
+                return Ok(expression);
+            }
+            let (type_, end_span) = self.parse_type()?;
+            return Ok(Expression::Ascription(AscriptionExpression {
+                span: expression.span() + end_span,
+                expression: Box::new(expression),
+                type_,
+                id: self.node_builder.next_id(),
+            }));
+        }
+        let start_span = self.prev_token.span;
+        let expression = self.parse_expression()?;
+        Ok(Expression::Spread(SpreadExpression {
+            span: start_span + expression.span(),
+            expression: Box::new(expression),
+            id: self.node_builder.next_id(),
+        }))
+    }
+
     /// Returns an [`Expression`] AST node if the next tokens represent an array initialization expression.
     fn parse_array_expression(&mut self) -> Result<Expression> {
         let (elements, _, span) = self.parse_bracket_comma_list(|p| p.parse_expression().map(Some))?;
@@ -144,21 +144,33 @@ impl ParserContext<'_> {
         let mut mappings: Vec<(Symbol, Mapping)> = Vec::new();
 
         while self.has_next() {
+            let docs = self.take_docs();
             match &self.token.token {
                 Token::Const => {
                     let declaration = self.parse_const_declaration_statement()?;
                     consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
                 }
                 Token::Struct | Token::Record => {
-                    let (id, struct_) = self.parse_struct()?;
+                    let (id, struct_) = self.parse_struct(docs, Vec::new())?;
                     structs.push((id, struct_));
                 }
                 Token::Mapping => {
                     let (id, mapping) = self.parse_mapping()?;
                     mappings.push((id, mapping));
                 }
-                Token::At | Token::Function | Token::Transition | Token::Inline => {
-                    let (id, function) = self.parse_function()?;
+                Token::At => {
+                    // Annotations may decorate either a struct or a function.
+                    let annotations = self.parse_annotations()?;
+                    if matches!(&self.token.token, Token::Struct | Token::Record) {
+                        let (id, struct_) = self.parse_struct(docs, annotations)?;
+                        structs.push((id, struct_));
+                    } else {
+                        let (id, function) = self.parse_function(docs, annotations)?;
+                        functions.push((id, function));
+                    }
+                }
+                Token::Function | Token::Transition | Token::Inline => {
+                    let (id, function) = self.parse_function(docs, Vec::new())?;
                     functions.push((id, function));
                 }
                 Token::RightCurly => break,
@@ -224,24 +236,34 @@ impl ParserContext<'_> {
 
     /// Returns a [`Member`] AST node if the next tokens represent a struct member variable.
     fn parse_member_variable_declaration(&mut self) -> Result<Member> {
+        let docs = self.take_docs();
         let mode = self.parse_mode()?;
 
         let (identifier, type_, span) = self.parse_typed_ident()?;
 
-        Ok(Member { mode, identifier, type_, span, id: self.node_builder.next_id() })
+        Ok(Member { docs, mode, identifier, type_, span, id: self.node_builder.next_id() })
     }
 
-    /// Parses a struct or record definition, e.g., `struct Foo { ... }` or `record Foo { ... }`.
-    pub(super) fn parse_struct(&mut self) -> Result<(Symbol, Struct)> {
+    /// Parses a struct or record definition, e.g., `struct Foo { ... }` or `record Foo { ... }`,
+    /// given the documentation comments and annotations preceding it.
+    pub(super) fn parse_struct(
+        &mut self,
+        docs: Option<String>,
+        annotations: Vec<Annotation>,
+    ) -> Result<(Symbol, Struct)> {
         let is_record = matches!(&self.token.token, Token::Record);
         let start = self.expect_any(&[Token::Struct, Token::Record])?;
         let struct_name = self.expect_identifier()?;
+        let type_params = self.parse_type_params()?;
 
         self.expect(&Token::LeftCurly)?;
         let (members, end) = self.parse_struct_members()?;
 
         Ok((struct_name.name, Struct {
+            docs,
+            annotations,
             identifier: struct_name,
+            type_params,
             members,
             is_record,
             span: start + end,
@@ -249,6 +271,21 @@ impl ParserContext<'_> {
         }))
     }
 
+    /// Parses an optional list of type parameters, e.g. `<T, U>`.
+    fn parse_type_params(&mut self) -> Result<Vec<Identifier>> {
+        let mut type_params = Vec::new();
+        if self.eat(&Token::Lt) {
+            while !self.check(&Token::Gt) {
+                type_params.push(self.expect_identifier()?);
+                if !self.eat(&Token::Comma) {
+                    break;
+                }
+            }
+            self.expect(&Token::Gt)?;
+        }
+        Ok(type_params)
+    }
+
     /// Parses a mapping declaration, e.g. `mapping balances: address => u128`.
     pub(super) fn parse_mapping(&mut self) -> Result<(Symbol, Mapping)> {
         let start = self.expect(&Token::Mapping)?;
@@ -392,21 +429,47 @@ impl ParserContext<'_> {
 
         // TODO: Verify that this check is sound.
         // Check that there is no whitespace in between the `@` symbol and identifier.
-        match identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
-            true => Err(ParserError::space_in_annotation(span).into()),
-            false => Ok(Annotation { identifier, span, id: self.node_builder.next_id() }),
+        if identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
+            return Err(ParserError::space_in_annotation(span).into());
         }
+
+        // Parse the arguments, if they exist.
+        let (arguments, span) = match self.check(&Token::LeftParen) {
+            true => {
+                let (arguments, _, arguments_span) =
+                    self.parse_paren_comma_list(|p| p.parse_annotation_argument().map(Some))?;
+                (arguments, span + arguments_span)
+            }
+            false => (Vec::new(), span),
+        };
+
+        Ok(Annotation { identifier, arguments, span, id: self.node_builder.next_id() })
     }
 
-    /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
-    /// and function definition.
-    fn parse_function(&mut self) -> Result<(Symbol, Function)> {
+    /// Returns an [`AnnotationArgument`] AST node if the next tokens represent an annotation argument.
+    /// An argument is named if it starts with `key =`, e.g. `feature = "x"`, and positional otherwise.
+    fn parse_annotation_argument(&mut self) -> Result<AnnotationArgument> {
+        if matches!((&self.token.token, self.look_ahead(1, |t| &t.token)), (Token::Identifier(_), Token::Assign)) {
+            let key = self.expect_identifier()?;
+            self.expect(&Token::Assign)?;
+            return Ok(AnnotationArgument::Named(key, self.parse_expression()?));
+        }
+        Ok(AnnotationArgument::Positional(self.parse_expression()?))
+    }
+
+    /// Returns the [`Annotation`] AST nodes, if the next tokens represent a sequence of annotations.
+    pub(super) fn parse_annotations(&mut self) -> Result<Vec<Annotation>> {
         // TODO: Handle dangling annotations.
-        // Parse annotations, if they exist.
         let mut annotations = Vec::new();
         while self.look_ahead(0, |t| &t.token) == &Token::At {
             annotations.push(self.parse_annotation()?)
         }
+        Ok(annotations)
+    }
+
+    /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
+    /// and function definition, given the documentation comments and annotations preceding it.
+    fn parse_function(&mut self, docs: Option<String>, annotations: Vec<Annotation>) -> Result<(Symbol, Function)> {
         // Parse `<variant> IDENT`, where `<variant>` is `function`, `transition`, or `inline`.
         let (variant, start) = match self.token.token {
             Token::Inline => (Variant::Inline, self.expect(&Token::Inline)?),
@@ -416,6 +479,9 @@ impl ParserContext<'_> {
         };
         let name = self.expect_identifier()?;
 
+        // Parse the type parameters, if they exist.
+        let type_params = self.parse_type_params()?;
+
         // Parse parameters.
         let (inputs, ..) = self.parse_paren_comma_list(|p| p.parse_input().map(Some))?;
 
@@ -433,6 +499,21 @@ impl ParserContext<'_> {
             }
         };
 
+        // Parse the `where` clause, if it exists.
+        let const_predicates = match self.token.token {
+            Token::Identifier(sym::Where) => {
+                self.bump();
+                self.disallow_struct_construction = true;
+                let mut predicates = vec![self.parse_expression()?];
+                while self.eat(&Token::Comma) {
+                    predicates.push(self.parse_expression()?);
+                }
+                self.disallow_struct_construction = false;
+                predicates
+            }
+            _ => Vec::new(),
+        };
+
         // Parse the function body.
         let block = self.parse_block()?;
 
@@ -475,11 +556,14 @@ impl ParserContext<'_> {
         Ok((
             name.name,
             Function::new(
+                docs,
                 annotations,
                 variant,
                 name,
+                type_params,
                 inputs,
                 output,
+                const_predicates,
                 block,
                 finalize,
                 span,
@@ -40,6 +40,7 @@ impl ParserContext<'_> {
     /// Returns a [`Statement`] AST node if the next tokens represent a statement.
     pub(crate) fn parse_statement(&mut self) -> Result<Statement> {
         match &self.token.token {
+            Token::At => Ok(Statement::Annotated(self.parse_annotated_statement()?)),
             Token::Return => Ok(Statement::Return(self.parse_return_statement()?)),
             Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
             Token::For => Ok(Statement::Iteration(Box::new(self.parse_loop_statement()?))),
@@ -53,6 +54,14 @@ impl ParserContext<'_> {
         }
     }
 
+    /// Returns an [`AnnotatedStatement`] AST node if the next tokens represent an annotated statement.
+    fn parse_annotated_statement(&mut self) -> Result<AnnotatedStatement> {
+        let annotations = self.parse_annotations()?;
+        let statement = self.parse_statement()?;
+        let span = annotations[0].span + statement.span();
+        Ok(AnnotatedStatement { annotations, statement: Box::new(statement), span, id: self.node_builder.next_id() })
+    }
+
     /// Returns a [`AssertStatement`] AST node if the next tokens represent an assertion statement.
     fn parse_assert_statement(&mut self) -> Result<Statement> {
         // Check which variant of the assert statement is being used.
@@ -247,6 +256,14 @@ impl ParserContext<'_> {
         self.expect(&Token::DotDot)?;
         self.disallow_struct_construction = true;
         let stop = self.parse_conditional_expression()?;
+        // Parse the step, if it exists.
+        let step = match self.token.token {
+            Token::Identifier(sym::step) => {
+                self.bump();
+                Some(self.parse_conditional_expression()?)
+            }
+            _ => None,
+        };
         self.disallow_struct_construction = false;
 
         let block = self.parse_block()?;
@@ -260,6 +277,7 @@ impl ParserContext<'_> {
             stop,
             stop_value: Default::default(),
             inclusive: false,
+            step,
             block,
             id: self.node_builder.next_id(),
         })
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+/// A compiler pass that checks that chains of member, array, and tuple accesses do not exceed a maximum depth.
+pub struct AccessDepthChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The maximum number of chained accesses allowed in an expression.
+    limit: usize,
+}
+
+impl<'a> AccessDepthChecker<'a> {
+    /// Returns a new `AccessDepthChecker`.
+    pub fn new(handler: &'a Handler, limit: usize) -> Self {
+        Self { handler, limit }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for AccessDepthChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    /// Counts the accesses in the chain ending at `input`, and visits the indices and the base of the chain.
+    /// Only the outermost access of a chain is reported, since the accesses it contains are not visited on their own.
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        let mut depth = 0usize;
+        let mut access = input;
+        loop {
+            let inner = match access {
+                AccessExpression::Array(array) => {
+                    self.visit_expression(&array.index, additional);
+                    &array.array
+                }
+                AccessExpression::Member(member) => &member.inner,
+                AccessExpression::Tuple(tuple) => &tuple.tuple,
+                AccessExpression::AssociatedFunction(function) => {
+                    function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+                    break;
+                }
+                AccessExpression::AssociatedConstant(_) => break,
+            };
+            depth += 1;
+            match &**inner {
+                Expression::Access(
+                    inner @ (AccessExpression::Array(_) | AccessExpression::Member(_) | AccessExpression::Tuple(_)),
+                ) => access = inner,
+                inner => {
+                    self.visit_expression(inner, additional);
+                    break;
+                }
+            }
+        }
+
+        if depth > self.limit {
+            self.handler.emit_err(StaticAnalyzerError::access_depth_exceeded(input, depth, self.limit, input.span()));
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for AccessDepthChecker<'a> {
+    /// Checks both the place and the value of the assignment.
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &Default::default());
+        self.visit_expression(&input.value, &Default::default());
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for AccessDepthChecker<'a> {}
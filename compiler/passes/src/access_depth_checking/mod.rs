@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Access Depth Checking pass traverses the AST and checks that no chain of member, array, and tuple accesses,
+//! e.g. `a.b[0u32].1`, is deeper than a configurable limit, since backends with fixed memory cannot address
+//! arbitrarily nested data.
+//! The depth of a chain is the number of accesses in it, and an error is reported at the span of its outermost access.
+//! Chains in array indices are checked separately, and associated constants and functions are not counted.
+//!
+//! Consider the following Leo code, checked with a limit of 2.
+//! ```leo
+//! transition main(p: Pair) -> u8 {
+//!     return p.first.values[0u32] + p.second.0;
+//! }
+//! ```
+//!
+//! The access depth checking pass reports an error for `p.first.values[0u32]`, whose chain has depth 3.
+//! The chain `p.second.0` has depth 2 and is accepted.
+
+pub mod access_depth_checker;
+pub use access_depth_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for AccessDepthChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, usize);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, limit): Self::Input) -> Self::Output {
+        let mut visitor = AccessDepthChecker::new(handler, limit);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a transition returning the given access, with a limit of 4, returning the number of errors.
+    fn count_errors(access: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!("program test.aleo {{ transition main(s: S) -> u8 {{ return {access}; }} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let _ = AccessDepthChecker::do_pass((&ast, &handler, 4));
+        handler.err_count()
+    }
+
+    #[test]
+    fn test_rejects_access_over_limit() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(count_errors("s.a[0u32].b.0.c"), 1);
+        })
+    }
+
+    #[test]
+    fn test_accepts_access_within_limit() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(count_errors("s.a[0u32].b"), 0);
+        })
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::{sym, Symbol};
+
+use std::fmt;
+
+/// The literal types that a named annotation argument can be required to have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnnotationArgType {
+    /// A boolean literal, e.g. `true`.
+    Boolean,
+    /// An integer literal of any integer type, e.g. `5u32`.
+    Integer,
+    /// A string literal, e.g. `"x"`.
+    String,
+}
+
+impl AnnotationArgType {
+    /// Returns `true` if the literal has this type.
+    fn matches(&self, literal: &Literal) -> bool {
+        matches!(
+            (self, literal),
+            (Self::Boolean, Literal::Boolean(..))
+                | (Self::Integer, Literal::Integer(..))
+                | (Self::String, Literal::String(..))
+        )
+    }
+}
+
+impl fmt::Display for AnnotationArgType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Boolean => write!(f, "boolean"),
+            Self::Integer => write!(f, "integer"),
+            Self::String => write!(f, "string"),
+        }
+    }
+}
+
+/// The named arguments accepted by each annotation, and the literal type of each argument.
+/// Annotations that are not listed are not checked.
+pub const ANNOTATION_ARG_SCHEMA: &[(Symbol, &[(Symbol, AnnotationArgType)])] = &[
+    (sym::cfg, &[(sym::feature, AnnotationArgType::String)]),
+    (sym::test, &[(sym::repeat, AnnotationArgType::Integer)]),
+];
+
+/// A compiler pass that checks the named arguments of annotations against a schema.
+/// Positional arguments, e.g. the message in `@deprecated("use bar")`, are not checked.
+pub struct AnnotationArgTypeChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The named arguments accepted by each annotation.
+    schema: &'a [(Symbol, &'a [(Symbol, AnnotationArgType)])],
+}
+
+impl<'a> AnnotationArgTypeChecker<'a> {
+    /// Returns a new `AnnotationArgTypeChecker` with the given schema.
+    pub fn new(handler: &'a Handler, schema: &'a [(Symbol, &'a [(Symbol, AnnotationArgType)])]) -> Self {
+        Self { handler, schema }
+    }
+
+    /// Reports each named argument of a known annotation that is unknown or has a literal of the wrong type.
+    fn check_annotations(&self, annotations: &[Annotation]) {
+        for annotation in annotations {
+            let Some((_, parameters)) = self.schema.iter().find(|(name, _)| *name == annotation.identifier.name) else {
+                continue;
+            };
+            for argument in annotation.arguments.iter() {
+                let AnnotationArgument::Named(key, value) = argument else {
+                    continue;
+                };
+                match parameters.iter().find(|(name, _)| *name == key.name) {
+                    None => self.handler.emit_err(StaticAnalyzerError::unknown_annotation_argument(
+                        annotation.identifier,
+                        key,
+                        annotation.span,
+                    )),
+                    Some((_, expected)) => match value {
+                        Expression::Literal(literal) if expected.matches(literal) => {}
+                        _ => self.handler.emit_err(StaticAnalyzerError::annotation_argument_type_mismatch(
+                            annotation.identifier,
+                            key,
+                            expected,
+                            annotation.span,
+                        )),
+                    },
+                }
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for AnnotationArgTypeChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for AnnotationArgTypeChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for AnnotationArgTypeChecker<'a> {
+    fn visit_struct(&mut self, input: &'a Struct) {
+        self.check_annotations(&input.annotations);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.check_annotations(&input.annotations);
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Annotation Argument Type Checking pass traverses the AST and checks the named arguments of annotations,
+//! e.g. `repeat = 5u32` in `@test(repeat = 5u32)`, against a schema of the arguments each annotation accepts.
+//! A named argument whose value is not a literal of the expected type, or whose name the annotation does not accept,
+//! is reported at the span of the annotation.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     @test(repeat = "five")
+//!     transition main(a: u8) -> u8 {
+//!         return a;
+//!     }
+//! }
+//! ```
+//!
+//! The annotation argument type checking pass reports an error, since `repeat` must be an integer literal.
+
+pub mod annotation_arg_type_checker;
+pub use annotation_arg_type_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::Symbol;
+
+impl<'a> Pass for AnnotationArgTypeChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a [(Symbol, &'a [(Symbol, AnnotationArgType)])]);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, schema): Self::Input) -> Self::Output {
+        let mut visitor = AnnotationArgTypeChecker::new(handler, schema);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a transition with the given annotation against the default schema.
+    fn check_annotation(annotation: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!("program test.aleo {{ {annotation} transition main(a: u8) -> u8 {{ return a; }} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        AnnotationArgTypeChecker::do_pass((&ast, &handler, ANNOTATION_ARG_SCHEMA))
+    }
+
+    #[test]
+    fn test_accepts_correctly_typed_argument() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_annotation("@test(repeat = 5u32)").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_wrongly_typed_argument() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_annotation("@test(repeat = \"five\")").is_err());
+        })
+    }
+
+    #[test]
+    fn test_rejects_unknown_argument() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_annotation("@test(times = 5u32)").is_err());
+        })
+    }
+}
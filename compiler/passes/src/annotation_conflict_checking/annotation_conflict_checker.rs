@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::{sym, Symbol};
+
+/// The pairs of annotations that cannot decorate the same function.
+/// A test is run by the test framework, so it cannot also be the entry point of a program.
+pub const ANNOTATION_CONFLICTS: &[(Symbol, Symbol)] = &[(sym::test, sym::program)];
+
+/// A compiler pass that checks that no function is decorated with a pair of conflicting annotations.
+pub struct AnnotationConflictChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The pairs of annotations that cannot decorate the same function.
+    conflicts: &'a [(Symbol, Symbol)],
+}
+
+impl<'a> AnnotationConflictChecker<'a> {
+    /// Returns a new `AnnotationConflictChecker` with the given conflicting pairs.
+    pub fn new(handler: &'a Handler, conflicts: &'a [(Symbol, Symbol)]) -> Self {
+        Self { handler, conflicts }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for AnnotationConflictChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for AnnotationConflictChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for AnnotationConflictChecker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        let has = |name: &Symbol| input.annotations.iter().any(|annotation| annotation.identifier.name == *name);
+        self.conflicts.iter().filter(|(first, second)| has(first) && has(second)).for_each(|(first, second)| {
+            self.handler.emit_err(StaticAnalyzerError::conflicting_annotations(
+                input.identifier,
+                first,
+                second,
+                input.span,
+            ))
+        });
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Annotation Conflict Checking pass traverses the AST and checks that no function is decorated with a pair of
+//! annotations that cannot be combined, reporting each conflicting pair at the span of the function.
+//! The conflicting pairs are given as input to the pass; `ANNOTATION_CONFLICTS` lists the default ones.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! @test
+//! @program
+//! transition main(a: u8) -> u8 {
+//!     return a;
+//! }
+//! ```
+//!
+//! The annotation conflict checking pass reports an error for `main`, since a test cannot be a program entry point.
+
+pub mod annotation_conflict_checker;
+pub use annotation_conflict_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::Symbol;
+
+impl<'a> Pass for AnnotationConflictChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a [(Symbol, Symbol)]);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, conflicts): Self::Input) -> Self::Output {
+        let mut visitor = AnnotationConflictChecker::new(handler, conflicts);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a transition with the given annotations against the default conflicts.
+    fn check_annotations(annotations: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!("program test.aleo {{ {annotations} transition main(a: u8) -> u8 {{ return a; }} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        AnnotationConflictChecker::do_pass((&ast, &handler, ANNOTATION_CONFLICTS))
+    }
+
+    #[test]
+    fn test_rejects_conflicting_annotations() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_annotations("@test @program").is_err());
+        })
+    }
+
+    #[test]
+    fn test_accepts_compatible_annotations() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_annotations("@test @truncate").is_ok());
+        })
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::{sym, Symbol};
+
+use std::fmt;
+
+/// The kinds of items that can be annotated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnnotatedItem {
+    /// A function, transition, or inline function.
+    Function,
+    /// A struct definition.
+    Struct,
+    /// A record definition.
+    Record,
+}
+
+impl fmt::Display for AnnotatedItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Function => write!(f, "function"),
+            Self::Struct => write!(f, "struct"),
+            Self::Record => write!(f, "record"),
+        }
+    }
+}
+
+/// A compiler pass that checks that each annotation decorates an item it can be applied to.
+/// Annotations without placement rules, e.g. `@deprecated`, are accepted on any item.
+pub struct AnnotationPlacementChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> AnnotationPlacementChecker<'a> {
+    /// Returns a new `AnnotationPlacementChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler }
+    }
+
+    /// Returns `true` if the annotation `name` can be applied to `item`.
+    fn is_allowed(name: Symbol, item: AnnotatedItem) -> bool {
+        match name {
+            sym::program | sym::test | sym::truncate => item == AnnotatedItem::Function,
+            _ => true,
+        }
+    }
+
+    /// Reports each annotation in `annotations` that cannot be applied to `item`.
+    fn check_annotations(&self, annotations: &[Annotation], item: AnnotatedItem) {
+        annotations.iter().filter(|annotation| !Self::is_allowed(annotation.identifier.name, item)).for_each(
+            |annotation| {
+                self.handler.emit_err(StaticAnalyzerError::misplaced_annotation(annotation, item, annotation.span))
+            },
+        );
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for AnnotationPlacementChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for AnnotationPlacementChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for AnnotationPlacementChecker<'a> {
+    fn visit_struct(&mut self, input: &'a Struct) {
+        let item = if input.is_record { AnnotatedItem::Record } else { AnnotatedItem::Struct };
+        self.check_annotations(&input.annotations, item);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.check_annotations(&input.annotations, AnnotatedItem::Function);
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Annotation Placement Checking pass traverses the AST and checks that each annotation decorates an item
+//! it can be applied to, reporting misplaced annotations at the span of the annotation.
+//! The annotations `@program`, `@test`, and `@truncate` can only be applied to functions.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! @test
+//! struct Point {
+//!     x: u8,
+//!     y: u8,
+//! }
+//! ```
+//!
+//! The annotation placement checking pass reports an error for `@test`.
+
+pub mod annotation_placement_checker;
+pub use annotation_placement_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for AnnotationPlacementChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = AnnotationPlacementChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a program with the given items.
+    fn check_items(items: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!("program test.aleo {{ {items} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        AnnotationPlacementChecker::do_pass((&ast, &handler))
+    }
+
+    #[test]
+    fn test_accepts_test_on_function() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_items("@test transition main(a: u8) -> u8 { return a; }").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_test_on_struct() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_items("@test struct Point { x: u8, y: u8 }").is_err());
+        })
+    }
+}
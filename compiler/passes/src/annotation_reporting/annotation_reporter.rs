@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+/// An annotation, along with the item that it decorates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnnotationReport {
+    /// The name of the program that declares the item.
+    pub program: Symbol,
+    /// The name of the item that the annotation decorates.
+    pub item: Identifier,
+    /// The annotation, including its arguments and span.
+    pub annotation: Annotation,
+}
+
+impl AnnotationReport {
+    /// Returns the first argument of the annotation, if it is a string, e.g. `use bar` in `@deprecated("use bar")`.
+    pub fn message(&self) -> Option<&str> {
+        match self.annotation.arguments.first() {
+            Some(AnnotationArgument::Positional(Expression::Literal(Literal::String(message, ..)))) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+/// A compiler pass that collects the annotations with the given names, e.g. `@todo` and `@deprecated`,
+/// in the program and its imports.
+pub struct AnnotationReporter<'a> {
+    /// The names of the annotations to collect.
+    names: &'a IndexSet<Symbol>,
+    /// The name of the program that we are currently traversing.
+    program: Option<Symbol>,
+    /// The annotations collected so far, in the order in which they appear.
+    reports: Vec<AnnotationReport>,
+}
+
+impl<'a> AnnotationReporter<'a> {
+    /// Returns a new `AnnotationReporter`, which collects the annotations with the given names.
+    pub fn new(names: &'a IndexSet<Symbol>) -> Self {
+        Self { names, program: None, reports: Vec::new() }
+    }
+
+    /// Returns the collected annotations.
+    pub fn into_reports(self) -> Vec<AnnotationReport> {
+        self.reports
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for AnnotationReporter<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for AnnotationReporter<'a> {}
+
+impl<'a> ProgramVisitor<'a> for AnnotationReporter<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.program = Some(input.program_id.name.name);
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        // Note that this unwrap is safe since the program is set when entering a program scope.
+        let program = self.program.unwrap();
+        self.reports.extend(
+            input
+                .annotations
+                .iter()
+                .filter(|annotation| self.names.contains(&annotation.identifier.name))
+                .map(|annotation| AnnotationReport { program, item: input.identifier, annotation: annotation.clone() }),
+        );
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Annotation Reporting pass traverses the AST and collects the annotations with the given names,
+//! e.g. `@todo` and `@deprecated`, into a report.
+//! Each entry in the report contains the annotation, with its arguments and span, and the item that it decorates.
+//! Later passes may use the report, e.g. to warn on calls to deprecated functions.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! @deprecated("use bar")
+//! function foo() -> u8 {
+//!     return 1u8;
+//! }
+//! ```
+//!
+//! Collecting the `deprecated` annotations reports the annotation `@deprecated("use bar")` on the function `foo`.
+
+pub mod annotation_reporter;
+pub use annotation_reporter::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+impl<'a> Pass for AnnotationReporter<'a> {
+    type Input = (&'a Ast, &'a IndexSet<Symbol>);
+    type Output = Vec<AnnotationReport>;
+
+    fn do_pass((ast, names): Self::Input) -> Self::Output {
+        let mut visitor = AnnotationReporter::new(names);
+        visitor.visit_program(ast.as_repr());
+        visitor.into_reports()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_reports_deprecated_message() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let source = r#"program test.aleo {
+                @deprecated("use bar")
+                function foo() -> u8 {
+                    return 1u8;
+                }
+
+                @benchmark
+                function bar() -> u8 {
+                    return 2u8;
+                }
+            }"#;
+            let ast = parse_program(&handler, &NodeBuilder::default(), source);
+            let names = IndexSet::from([Symbol::intern("deprecated"), Symbol::intern("todo")]);
+            let reports = AnnotationReporter::do_pass((&ast, &names));
+
+            assert_eq!(reports.len(), 1);
+            assert_eq!(reports[0].program.to_string(), "test");
+            assert_eq!(reports[0].item.name.to_string(), "foo");
+            assert_eq!(reports[0].annotation.identifier.name.to_string(), "deprecated");
+            assert_eq!(reports[0].message(), Some("use bar"));
+        })
+    }
+}
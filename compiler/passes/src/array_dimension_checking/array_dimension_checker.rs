@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_span::Span;
+
+use snarkvm_console::network::{Network, Testnet3};
+
+/// A compiler pass that checks that the dimensions of all array types and array expressions
+/// are positive and do not exceed `Testnet3::MAX_ARRAY_ELEMENTS`.
+/// Note that negative dimensions are rejected by the parser, since array lengths are `NonNegativeNumber`s.
+pub struct ArrayDimensionChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// Whether or not arrays with no elements are allowed.
+    allow_empty: bool,
+}
+
+impl<'a> ArrayDimensionChecker<'a> {
+    /// Returns a new `ArrayDimensionChecker`.
+    pub fn new(handler: &'a Handler, allow_empty: bool) -> Self {
+        Self { handler, allow_empty }
+    }
+
+    /// Checks an array dimension, reporting an error at `span` if it is invalid.
+    fn check_dimension(&self, length: usize, span: Span) {
+        match length {
+            0 if self.allow_empty => {}
+            0 => self.handler.emit_err(TypeCheckerError::array_empty(span)),
+            1..=Testnet3::MAX_ARRAY_ELEMENTS => {}
+            length => {
+                self.handler.emit_err(TypeCheckerError::array_too_large(length, Testnet3::MAX_ARRAY_ELEMENTS, span))
+            }
+        }
+    }
+
+    /// Checks the dimensions of every array type in `type_`, reporting errors at `span`.
+    fn check_type(&self, type_: &Type, span: Span) {
+        match type_ {
+            Type::Array(array_type) => {
+                self.check_dimension(array_type.length(), span);
+                self.check_type(array_type.element_type(), span);
+            }
+            Type::Mapping(mapping_type) => {
+                self.check_type(&mapping_type.key, span);
+                self.check_type(&mapping_type.value, span);
+            }
+            Type::Tuple(tuple_type) => tuple_type.elements().iter().for_each(|type_| self.check_type(type_, span)),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ArrayDimensionChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_array(&mut self, input: &'a ArrayExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_dimension(input.elements.len(), input.span);
+        input.elements.iter().for_each(|element| self.visit_expression(element, additional));
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_type(&input.type_, input.span);
+        self.visit_expression(&input.expression, additional);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for ArrayDimensionChecker<'a> {
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.check_type(&input.type_, input.span);
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.check_type(&input.type_, input.span);
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.check_type(&input.type_, input.span);
+        self.visit_expression(&input.start, &Default::default());
+        self.visit_expression(&input.stop, &Default::default());
+        self.visit_block(&input.block);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ArrayDimensionChecker<'a> {
+    fn visit_struct(&mut self, input: &'a Struct) {
+        input.members.iter().for_each(|member| self.check_type(&member.type_, member.span));
+    }
+
+    fn visit_mapping(&mut self, input: &'a Mapping) {
+        self.check_type(&input.key_type, input.span);
+        self.check_type(&input.value_type, input.span);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        input.input.iter().for_each(|input| self.check_type(&input.type_(), input.span()));
+        input.output.iter().for_each(|output| self.check_type(&output.type_(), output.span()));
+        self.visit_block(&input.block);
+
+        if let Some(finalize) = &input.finalize {
+            finalize.input.iter().for_each(|input| self.check_type(&input.type_(), input.span()));
+            finalize.output.iter().for_each(|output| self.check_type(&output.type_(), output.span()));
+            self.visit_block(&finalize.block);
+        }
+    }
+}
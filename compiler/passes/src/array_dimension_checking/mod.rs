@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Array Dimension Checking pass traverses the AST and checks that the dimension of each array type,
+//! e.g. `[u8; 4]`, and of each array expression, e.g. `[1u8, 2u8]`, is positive and at most `MAX_ARRAY_ELEMENTS`.
+//! Arrays with no elements may be explicitly allowed.
+//! Errors are reported at the span of the node in which the array occurs.
+
+pub mod array_dimension_checker;
+pub use array_dimension_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ArrayDimensionChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, bool);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, allow_empty): Self::Input) -> Self::Output {
+        let mut visitor = ArrayDimensionChecker::new(handler, allow_empty);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+    /// Returns a program whose function takes an array with the given dimension.
+    fn program(dimension: &str) -> String {
+        format!(
+            "program test.aleo {{
+                transition main(a: [u8; {dimension}]) -> u8 {{
+                    return 0u8;
+                }}
+            }}"
+        )
+    }
+
+    #[test]
+    fn test_rejects_zero_dimension() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let ast = parse_program(&handler, &NodeBuilder::default(), &program("0"));
+
+            assert!(ArrayDimensionChecker::do_pass((&ast, &handler, false)).is_err());
+            assert_eq!(handler.err_count(), 1);
+        })
+    }
+
+    #[test]
+    fn test_allows_zero_dimension_when_enabled() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let ast = parse_program(&handler, &NodeBuilder::default(), &program("0"));
+
+            assert!(ArrayDimensionChecker::do_pass((&ast, &handler, true)).is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_negative_dimension() {
+        create_session_if_not_set_then(|s| {
+            let (handler, _) = Handler::new_with_buf();
+            let source_file = s.source_map.new_source(&program("-1"), FileName::Custom("test".into()));
+
+            assert!(leo_parser::parse_ast(&handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos)
+                .is_err());
+        })
+    }
+
+    #[test]
+    fn test_accepts_positive_dimension() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let ast = parse_program(&handler, &NodeBuilder::default(), &program("4"));
+
+            assert!(ArrayDimensionChecker::do_pass((&ast, &handler, false)).is_ok());
+            assert_eq!(handler.err_count(), 0);
+        })
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+/// A compiler pass that checks that the elements of each array expression have the same type.
+pub struct ArrayHomogeneityChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+}
+
+impl<'a> ArrayHomogeneityChecker<'a> {
+    /// Returns a new `ArrayHomogeneityChecker`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, type_table }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ArrayHomogeneityChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_array(&mut self, input: &'a ArrayExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Elements whose type could not be inferred have already been reported by the type checker.
+        let type_table = self.type_table;
+        let mut typed_elements =
+            input.elements.iter().filter_map(|element| Some((element, type_table.get(&element.id())?)));
+        if let Some((_, first_type)) = typed_elements.next() {
+            if let Some((element, element_type)) = typed_elements.find(|(_, type_)| !type_.eq_flat(&first_type)) {
+                self.handler.emit_err(StaticAnalyzerError::heterogeneous_array_element(
+                    element,
+                    element_type,
+                    first_type,
+                    element.span(),
+                ));
+            }
+        }
+        input.elements.iter().for_each(|element| self.visit_expression(element, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for ArrayHomogeneityChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for ArrayHomogeneityChecker<'a> {}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Array Homogeneity Checking pass traverses the AST and checks that the elements of each array expression have
+//! the same type as its first element, reporting the first element of a different type at its span.
+//! The pass runs after type checking and reads the types of the elements from the type table.
+//! Literals always carry their type, e.g. `1u8`, so a literal element is checked like any other element.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8) -> [u8; 2] {
+//!     return [a, 2u16];
+//! }
+//! ```
+//!
+//! The array homogeneity checking pass reports an error for `2u16`, since the first element has type `u8`.
+
+pub mod array_homogeneity_checker;
+pub use array_homogeneity_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ArrayHomogeneityChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut visitor = ArrayHomogeneityChecker::new(handler, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks a transition that returns the given array of two elements, then checks its elements.
+    /// The type checker may report errors of its own, which are ignored.
+    fn check_array(array: &str) -> Result<()> {
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8) -> [u8; 2] {{
+                    return {array};
+                }}
+            }}"
+        );
+        let (type_checker_handler, _) = Handler::new_with_buf();
+        let type_table = TypeTable::default();
+        let ast = parse_program(&type_checker_handler, &NodeBuilder::default(), &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &type_checker_handler)).unwrap();
+        let _ = TypeChecker::do_pass((&ast, &type_checker_handler, symbol_table, &type_table));
+
+        let (handler, _) = Handler::new_with_buf();
+        ArrayHomogeneityChecker::do_pass((&ast, &handler, &type_table))
+    }
+
+    #[test]
+    fn test_rejects_heterogeneous_array() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_array("[1u8, 2u16]").is_err());
+        })
+    }
+
+    #[test]
+    fn test_accepts_homogeneous_array() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_array("[1u8, 2u8]").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_accepts_literal_matching_variable() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_array("[a, 2u8]").is_ok());
+        })
+    }
+}
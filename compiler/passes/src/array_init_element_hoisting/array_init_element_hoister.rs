@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, ConditionalFuser, TypeTable};
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+pub struct ArrayInitElementHoister<'a> {
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+    /// The elements hoisted in the enclosing blocks, with the variables they are bound to and their types.
+    pub(crate) hoisted: Vec<(Expression, Symbol, Type)>,
+}
+
+impl<'a> ArrayInitElementHoister<'a> {
+    /// Initializes a new `ArrayInitElementHoister`.
+    pub fn new(type_table: &'a TypeTable, node_builder: &'a NodeBuilder, assigner: &'a Assigner) -> Self {
+        Self { type_table, node_builder, assigner, hoisted: Vec::new() }
+    }
+
+    /// Returns a new node ID of type `type_`.
+    fn next_id(&self, type_: Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        id
+    }
+
+    /// Returns `true` if the expression only depends on literals.
+    fn is_constant(expression: &Expression) -> bool {
+        match expression {
+            Expression::Literal(_) => true,
+            Expression::Binary(binary) => Self::is_constant(&binary.left) && Self::is_constant(&binary.right),
+            Expression::Cast(cast) => Self::is_constant(&cast.expression),
+            Expression::Ternary(ternary) => {
+                Self::is_constant(&ternary.condition)
+                    && Self::is_constant(&ternary.if_true)
+                    && Self::is_constant(&ternary.if_false)
+            }
+            Expression::Unary(unary) => Self::is_constant(&unary.receiver),
+            _ => false,
+        }
+    }
+
+    /// Returns the element of an array initialization that repeats a non-trivial constant expression,
+    /// e.g. `1u8 + 2u8` in `[1u8 + 2u8, 1u8 + 2u8, 1u8 + 2u8]`.
+    pub(crate) fn repeated_element(input: &ArrayExpression) -> Option<&Expression> {
+        let first = input.elements.first()?;
+        let repeated = !matches!(first, Expression::Literal(_))
+            && Self::is_constant(first)
+            && input.elements.iter().all(|element| ConditionalFuser::same_pure_expression(first, element));
+        repeated.then_some(first)
+    }
+
+    /// Returns the variable that `element` is bound to, and its type, if it was hoisted.
+    fn hoisted_variable(&self, element: &Expression) -> Option<(Symbol, Type)> {
+        self.hoisted.iter().find_map(|(hoisted, name, type_)| {
+            ConditionalFuser::same_pure_expression(hoisted, element).then(|| (*name, type_.clone()))
+        })
+    }
+
+    /// Binds `element` to a new variable, returning its definition.
+    fn hoist(&mut self, element: Expression, type_: Type) -> Statement {
+        let name = self.assigner.unique_symbol("$var", "$");
+        let span = element.span();
+        self.hoisted.push((element.clone(), name, type_.clone()));
+        Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(Identifier { name, span, id: self.next_id(type_.clone()) }),
+            type_,
+            value: element,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+}
+
+/// Collects the repeated elements of the array initializations in a statement.
+struct RepeatedElementCollector {
+    elements: Vec<Expression>,
+}
+
+impl RepeatedElementCollector {
+    /// Returns the repeated elements of the array initializations in `statement`, in order.
+    fn of_statement(statement: &Statement) -> Vec<Expression> {
+        let mut collector = Self { elements: Vec::new() };
+        collector.visit_statement(statement);
+        collector.elements
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for RepeatedElementCollector {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_array(&mut self, input: &'a ArrayExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        if let Some(element) = ArrayInitElementHoister::repeated_element(input) {
+            self.elements.push(element.clone());
+        }
+        input.elements.iter().for_each(|element| self.visit_expression(element, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for RepeatedElementCollector {}
+
+impl ExpressionReconstructor for ArrayInitElementHoister<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_array(&mut self, input: ArrayExpression) -> (Expression, Self::AdditionalOutput) {
+        let variable = Self::repeated_element(&input).and_then(|element| self.hoisted_variable(element));
+        let elements = match variable {
+            Some((name, type_)) => input
+                .elements
+                .iter()
+                .map(|element| {
+                    Expression::Identifier(Identifier { name, span: element.span(), id: self.next_id(type_.clone()) })
+                })
+                .collect(),
+            None => input.elements.into_iter().map(|element| self.reconstruct_expression(element).0).collect(),
+        };
+        (Expression::Array(ArrayExpression { elements, span: input.span, id: input.id }), Default::default())
+    }
+}
+
+impl StatementReconstructor for ArrayInitElementHoister<'_> {
+    /// Hoists each repeated element that initializes more than one array in the block, binding it before the
+    /// statement that first uses it. Elements hoisted in an enclosing block are reused.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        // The repeated elements that are not yet hoisted, with the index of the first statement that uses each
+        // and the number of array initializations it repeats in.
+        let mut candidates: Vec<(usize, Expression, usize)> = Vec::new();
+        for (index, statement) in input.statements.iter().enumerate() {
+            for element in RepeatedElementCollector::of_statement(statement) {
+                if self.hoisted_variable(&element).is_some() {
+                    continue;
+                }
+                match candidates
+                    .iter_mut()
+                    .find(|(_, other, _)| ConditionalFuser::same_pure_expression(other, &element))
+                {
+                    Some((_, _, count)) => *count += 1,
+                    None => candidates.push((index, element, 1)),
+                }
+            }
+        }
+
+        let outer = self.hoisted.len();
+        let mut candidates = candidates.into_iter().filter(|(_, _, count)| *count > 1).peekable();
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for (index, statement) in input.statements.into_iter().enumerate() {
+            while let Some((_, element, _)) = candidates.next_if(|(first, _, _)| *first == index) {
+                // Elements whose type is unknown have already been reported by the type checker.
+                if let Some(type_) = self.type_table.get(&element.id()) {
+                    let definition = self.hoist(element, type_);
+                    statements.push(definition);
+                }
+            }
+            statements.push(self.reconstruct_statement(statement).0);
+        }
+        self.hoisted.truncate(outer);
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+}
+
+impl ProgramReconstructor for ArrayInitElementHoister<'_> {}
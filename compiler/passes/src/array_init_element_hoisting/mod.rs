@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Array Init Element Hoisting pass traverses the AST and binds the elements of array initializations that
+//! repeat a non-trivial constant expression, e.g. `[1u8 + 2u8, 1u8 + 2u8]`, to a single variable, when the same
+//! element initializes more than one array in a block. The element is then computed once, before its first use.
+//! Literal elements are left as is, since they have no cost.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main() -> u8 {
+//!     let a: [u8; 3] = [1u8 + 2u8, 1u8 + 2u8, 1u8 + 2u8];
+//!     let b: [u8; 2] = [1u8 + 2u8, 1u8 + 2u8];
+//!     return a[0u32] + b[1u32];
+//! }
+//! ```
+//!
+//! The array init element hoisting pass produces the following code.
+//! ```leo
+//! function main() -> u8 {
+//!     let $var$0: u8 = 1u8 + 2u8;
+//!     let a: [u8; 3] = [$var$0, $var$0, $var$0];
+//!     let b: [u8; 2] = [$var$0, $var$0];
+//!     return a[0u32] + b[1u32];
+//! }
+//! ```
+
+pub mod array_init_element_hoister;
+pub use array_init_element_hoister::*;
+
+use crate::{Assigner, Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for ArrayInitElementHoister<'a> {
+    type Input = (Ast, &'a TypeTable, &'a NodeBuilder, &'a Assigner);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, type_table, node_builder, assigner): Self::Input) -> Self::Output {
+        let mut reconstructor = ArrayInitElementHoister::new(type_table, node_builder, assigner);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::Statement;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and hoists a function with the given body, returning its statements.
+    fn hoist(body: &str) -> Vec<Statement> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let type_table = TypeTable::default();
+        let source = format!(
+            "program test.aleo {{
+                function main() -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+
+        let ast = ArrayInitElementHoister::do_pass((ast, &type_table, &node_builder, &Assigner::default())).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1.block.statements
+    }
+
+    #[test]
+    fn test_hoists_shared_element_once() {
+        create_session_if_not_set_then(|_| {
+            let statements = hoist(
+                "let a: [u8; 3] = [1u8 + 2u8, 1u8 + 2u8, 1u8 + 2u8];
+                let b: [u8; 2] = [1u8 + 2u8, 1u8 + 2u8];
+                return a[0u32] + b[1u32];",
+            );
+
+            assert_eq!(statements.len(), 4);
+            assert_eq!(statements[0].to_string(), "let $var$0: u8 = 1u8 + 2u8;");
+            assert_eq!(statements[1].to_string(), "let a: [u8; 3] = [$var$0,$var$0,$var$0];");
+            assert_eq!(statements[2].to_string(), "let b: [u8; 2] = [$var$0,$var$0];");
+        })
+    }
+
+    #[test]
+    fn test_keeps_distinct_elements() {
+        create_session_if_not_set_then(|_| {
+            let statements = hoist(
+                "let a: [u8; 2] = [1u8 + 2u8, 1u8 + 2u8];
+                let b: [u8; 2] = [3u8 * 2u8, 3u8 * 2u8];
+                let c: [u8; 2] = [1u8, 1u8];
+                let d: [u8; 2] = [1u8, 1u8];
+                return a[0u32] + b[1u32] + c[0u32] + d[1u32];",
+            );
+
+            assert_eq!(statements.len(), 5);
+        })
+    }
+}
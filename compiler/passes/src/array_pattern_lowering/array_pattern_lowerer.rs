@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Assigner;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+pub struct ArrayPatternLowerer<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+}
+
+impl<'a> ArrayPatternLowerer<'a> {
+    /// Initializes a new `ArrayPatternLowerer`.
+    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, assigner: &'a Assigner) -> Self {
+        Self { handler, node_builder, assigner }
+    }
+
+    /// Lowers a definition whose place is an array pattern into one definition per element of the pattern.
+    /// A value other than a variable is first bound to a new variable, so that it is evaluated only once.
+    /// Elements of the pattern that are themselves array patterns are lowered in turn.
+    /// Any other definition is returned as is.
+    pub(crate) fn lower(&self, input: DefinitionStatement) -> Vec<DefinitionStatement> {
+        let Expression::Array(pattern) = &input.place else {
+            return vec![input];
+        };
+        let array_type = match &input.type_ {
+            Type::Array(array_type) if array_type.length() == pattern.elements.len() => array_type.clone(),
+            Type::Array(array_type) => {
+                self.handler.emit_err(StaticAnalyzerError::array_pattern_length_mismatch(
+                    pattern,
+                    array_type.length(),
+                    pattern.elements.len(),
+                    pattern.span,
+                ));
+                return vec![input];
+            }
+            type_ => {
+                self.handler.emit_err(StaticAnalyzerError::array_pattern_type_mismatch(pattern, type_, pattern.span));
+                return vec![input];
+            }
+        };
+
+        let mut definitions = Vec::with_capacity(array_type.length() + 1);
+        let array = match input.value {
+            Expression::Identifier(array) => array,
+            value => {
+                let array = Identifier {
+                    name: self.assigner.unique_symbol("$var", "$"),
+                    span: value.span(),
+                    id: self.node_builder.next_id(),
+                };
+                definitions.push(DefinitionStatement {
+                    declaration_type: DeclarationType::Let,
+                    place: Expression::Identifier(array),
+                    type_: input.type_.clone(),
+                    value,
+                    span: input.span,
+                    id: self.node_builder.next_id(),
+                });
+                array
+            }
+        };
+
+        let Expression::Array(pattern) = input.place else { unreachable!("The place is an array pattern.") };
+        for (index, element) in pattern.elements.into_iter().enumerate() {
+            let span = element.span();
+            let value = Expression::Access(AccessExpression::Array(ArrayAccess {
+                array: Box::new(Expression::Identifier(Identifier { id: self.node_builder.next_id(), ..array })),
+                index: Box::new(Expression::Literal(Literal::Integer(
+                    IntegerType::U32,
+                    index.to_string(),
+                    span,
+                    self.node_builder.next_id(),
+                ))),
+                span,
+                id: self.node_builder.next_id(),
+            }));
+            definitions.extend(self.lower(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: element,
+                type_: array_type.element_type().clone(),
+                value,
+                span: input.span,
+                id: self.node_builder.next_id(),
+            }));
+        }
+        definitions
+    }
+}
+
+impl ExpressionReconstructor for ArrayPatternLowerer<'_> {
+    type AdditionalOutput = Vec<Statement>;
+}
+
+impl StatementReconstructor for ArrayPatternLowerer<'_> {
+    /// Reconstructs the statements in the block, accumulating any additional statements.
+    fn reconstruct_block(&mut self, block: Block) -> (Block, Self::AdditionalOutput) {
+        let mut statements = Vec::with_capacity(block.statements.len());
+
+        for statement in block.statements {
+            let (reconstructed_statement, additional_statements) = self.reconstruct_statement(statement);
+            statements.extend(additional_statements);
+            statements.push(reconstructed_statement);
+        }
+
+        (Block { statements, span: block.span, id: block.id }, Default::default())
+    }
+
+    /// Lowers a definition with an array pattern into one definition per element.
+    /// The last definition is returned as the reconstructed statement, and the others as additional statements.
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        let mut statements: Vec<Statement> = self.lower(input).into_iter().map(Statement::Definition).collect();
+        // Note that this unwrap is safe since `lower` always returns at least one definition.
+        let last = statements.pop().unwrap();
+        (last, statements)
+    }
+}
+
+impl ProgramReconstructor for ArrayPatternLowerer<'_> {}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Array Pattern Lowering pass traverses the AST and lowers definitions that bind an array pattern, e.g.
+//! `let [a, b, c]: [u8; 3] = arr;`, into one definition per element of the pattern.
+//! The length of the array is given by the declared type of the definition, and must match the number of elements in
+//! the pattern; a mismatch, or a declared type that is not an array, is reported at the span of the pattern.
+//! A value other than a variable is first bound to a new variable, so that it is evaluated only once, and nested
+//! patterns, e.g. `let [[a, b], [c, d]]: [[u8; 2]; 2] = m;`, are lowered recursively.
+//! This pass runs before type checking, which only accepts variables and tuples of variables as places.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(arr: [u8; 3]) -> u8 {
+//!     let [a, b, c]: [u8; 3] = arr;
+//!     return a + b + c;
+//! }
+//! ```
+//!
+//! The array pattern lowering pass produces the following code.
+//! ```leo
+//! transition main(arr: [u8; 3]) -> u8 {
+//!     let a: u8 = arr[0u32];
+//!     let b: u8 = arr[1u32];
+//!     let c: u8 = arr[2u32];
+//!     return a + b + c;
+//! }
+//! ```
+
+pub mod array_pattern_lowerer;
+pub use array_pattern_lowerer::*;
+
+use crate::{Assigner, Pass};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ArrayPatternLowerer<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, &'a Assigner);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder, assigner): Self::Input) -> Self::Output {
+        let mut reconstructor = ArrayPatternLowerer::new(handler, node_builder, assigner);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{AccessExpression, Expression, Statement};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and lowers a transition with the input `arr: [u8; 3]` and the given definition,
+    /// returning the statements of its body and the number of errors.
+    fn lower(definition: &str) -> (Vec<Statement>, usize) {
+        let (handler, _) = Handler::new_with_buf();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(arr: [u8; 3]) -> u8 {{
+                    {definition}
+                    return arr[0u32];
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let statements = match ArrayPatternLowerer::do_pass((ast, &handler, &node_builder, &Assigner::default())) {
+            Ok(ast) => {
+                ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1.block.statements
+            }
+            Err(_) => Vec::new(),
+        };
+        (statements, handler.err_count())
+    }
+
+    #[test]
+    fn test_lowers_matching_pattern() {
+        create_session_if_not_set_then(|_| {
+            let (statements, errors) = lower("let [a, b, c]: [u8; 3] = arr;");
+
+            assert_eq!(errors, 0);
+            assert_eq!(statements.len(), 4);
+            for (index, (statement, name)) in statements.iter().zip(["a", "b", "c"]).enumerate() {
+                let Statement::Definition(definition) = statement else { panic!("expected a definition") };
+                assert_eq!(definition.place.to_string(), name);
+                assert_eq!(definition.type_.to_string(), "u8");
+                let Expression::Access(AccessExpression::Array(access)) = &definition.value else {
+                    panic!("expected an array access")
+                };
+                assert_eq!(access.array.to_string(), "arr");
+                assert_eq!(access.index.to_string(), format!("{index}u32"));
+            }
+        })
+    }
+
+    #[test]
+    fn test_rejects_length_mismatch() {
+        create_session_if_not_set_then(|_| assert_eq!(lower("let [a, b]: [u8; 3] = arr;").1, 1))
+    }
+}
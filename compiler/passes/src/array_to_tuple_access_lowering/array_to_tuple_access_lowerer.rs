@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NameCollector;
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+pub struct ArrayToTupleAccessLowerer {
+    /// The maximum length of an array that is lowered to a tuple.
+    pub(crate) max_length: usize,
+    /// The local arrays of the current function that are lowered to tuples.
+    pub(crate) lowered: IndexSet<Symbol>,
+}
+
+impl ArrayToTupleAccessLowerer {
+    /// Initializes a new `ArrayToTupleAccessLowerer`.
+    pub fn new(max_length: usize) -> Self {
+        Self { max_length, lowered: IndexSet::new() }
+    }
+
+    /// Returns the index of an array access if it is an integer literal.
+    fn constant_index(index: &Expression) -> Option<usize> {
+        match index {
+            Expression::Literal(Literal::Integer(_, value, ..)) => value.replace('_', "").parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl ExpressionReconstructor for ArrayToTupleAccessLowerer {
+    type AdditionalOutput = ();
+
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        match (&*input.array, Self::constant_index(&input.index)) {
+            (Expression::Identifier(array), Some(index)) if self.lowered.contains(&array.name) => (
+                Expression::Access(AccessExpression::Tuple(TupleAccess {
+                    tuple: input.array,
+                    index: NonNegativeNumber::from(index),
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            ),
+            _ => (
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(self.reconstruct_expression(*input.array).0),
+                    index: Box::new(self.reconstruct_expression(*input.index).0),
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            ),
+        }
+    }
+}
+
+impl StatementReconstructor for ArrayToTupleAccessLowerer {
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        // The definition of a lowered array binds a tuple of its elements instead.
+        let (type_, value) = match (&input.place, input.type_, input.value) {
+            (Expression::Identifier(place), Type::Array(array_type), Expression::Array(array))
+                if self.lowered.contains(&place.name) =>
+            {
+                (
+                    Type::Tuple(TupleType::new(vec![array_type.element_type().clone(); array_type.length()])),
+                    Expression::Tuple(TupleExpression {
+                        elements: array
+                            .elements
+                            .into_iter()
+                            .map(|element| self.reconstruct_expression(element).0)
+                            .collect(),
+                        span: array.span,
+                        id: array.id,
+                    }),
+                )
+            }
+            (_, type_, value) => (type_, self.reconstruct_expression(value).0),
+        };
+
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: input.place,
+                type_,
+                value,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for ArrayToTupleAccessLowerer {
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        let mut candidates = LoweringCandidates::new(self.max_length);
+        candidates.visit_function(&input);
+        self.lowered = candidates.lowered();
+
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            const_predicates: input
+                .const_predicates
+                .into_iter()
+                .map(|predicate| self.reconstruct_function_predicate(predicate))
+                .collect(),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: finalize.output,
+                output_type: finalize.output_type,
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }),
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
+
+/// Collects the local arrays of a function that can be lowered to tuples: those defined from an array literal, with
+/// a length between 2 and the maximum, that are only ever read through constant indices within bounds.
+struct LoweringCandidates {
+    /// The maximum length of an array that is lowered to a tuple.
+    max_length: usize,
+    /// The lengths of the arrays defined from an array literal.
+    lengths: IndexMap<Symbol, usize>,
+    /// The constant indices through which each array is read.
+    indices: IndexMap<Symbol, Vec<usize>>,
+    /// The arrays that are used other than through a constant index.
+    disqualified: IndexSet<Symbol>,
+}
+
+impl LoweringCandidates {
+    fn new(max_length: usize) -> Self {
+        Self { max_length, lengths: IndexMap::new(), indices: IndexMap::new(), disqualified: IndexSet::new() }
+    }
+
+    /// Returns the arrays that can be lowered to tuples.
+    fn lowered(self) -> IndexSet<Symbol> {
+        self.lengths
+            .into_iter()
+            .filter(|(name, length)| {
+                !self.disqualified.contains(name)
+                    && self.indices.get(name).map_or(true, |indices| indices.iter().all(|index| index < length))
+            })
+            .map(|(name, _)| name)
+            .collect()
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for LoweringCandidates {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(access) => {
+                match (&*access.array, ArrayToTupleAccessLowerer::constant_index(&access.index)) {
+                    (Expression::Identifier(array), Some(index)) => {
+                        self.indices.entry(array.name).or_default().push(index);
+                    }
+                    _ => self.visit_expression(&access.array, additional),
+                }
+                self.visit_expression(&access.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.disqualified.insert(input.name);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, additional),
+            None => self.visit_identifier(&member.identifier, additional),
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for LoweringCandidates {
+    // Arrays that are written to, in whole or in part, are not lowered.
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.disqualified.extend(NameCollector::of_expression(&input.place).names);
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        if let (Expression::Identifier(place), Type::Array(array_type), Expression::Array(_)) =
+            (&input.place, &input.type_, &input.value)
+        {
+            if (2..=self.max_length).contains(&array_type.length()) {
+                self.lengths.insert(place.name, array_type.length());
+            }
+        }
+        self.visit_expression(&input.value, &Default::default());
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for LoweringCandidates {}
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Array To Tuple Access Lowering pass traverses the AST and lowers small local arrays that are only read through
+//! constant indices to tuples, for backends that model fixed-size arrays as tuples.
+//! Each such array is defined from an array literal, which becomes a tuple literal of the corresponding tuple type,
+//! and each of its accesses `a[2u32]` becomes the tuple access `a.2`.
+//! The type of the array is lowered together with its accesses, so an array is only lowered if every use of it is a
+//! constant-index read within bounds.
+//! Arrays that are assigned to, passed around, or indexed dynamically are left as is.
+//!
+//! Consider the following Leo code, lowered with a maximum length of 4.
+//! ```leo
+//! transition main(i: u32) -> u8 {
+//!     let a: [u8; 3] = [1u8, 2u8, 3u8];
+//!     let b: [u8; 3] = [4u8, 5u8, 6u8];
+//!     return a[2u32] + b[i];
+//! }
+//! ```
+//!
+//! The array to tuple access lowering pass produces the following code.
+//! ```leo
+//! transition main(i: u32) -> u8 {
+//!     let a: (u8, u8, u8) = (1u8, 2u8, 3u8);
+//!     let b: [u8; 3] = [4u8, 5u8, 6u8];
+//!     return a.2 + b[i];
+//! }
+//! ```
+
+pub mod array_to_tuple_access_lowerer;
+pub use array_to_tuple_access_lowerer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for ArrayToTupleAccessLowerer {
+    type Input = (Ast, usize);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, max_length): Self::Input) -> Self::Output {
+        let mut reconstructor = ArrayToTupleAccessLowerer::new(max_length);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{AccessExpression, BinaryExpression, Expression, NodeBuilder, Statement, Type};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Lowers the transition in the module documentation with a maximum length of 4, returning its statements.
+    fn lower() -> Vec<Statement> {
+        let handler = Handler::default();
+        let source = "program test.aleo {
+            transition main(i: u32) -> u8 {
+                let a: [u8; 3] = [1u8, 2u8, 3u8];
+                let b: [u8; 3] = [4u8, 5u8, 6u8];
+                return a[2u32] + b[i];
+            }
+        }";
+        let ast = ArrayToTupleAccessLowerer::do_pass((parse_program(&handler, &NodeBuilder::default(), source), 4));
+        ast.unwrap()
+            .into_repr()
+            .program_scopes
+            .into_values()
+            .next()
+            .unwrap()
+            .functions
+            .pop()
+            .unwrap()
+            .1
+            .block
+            .statements
+    }
+
+    #[test]
+    fn test_lowers_constant_index_access() {
+        create_session_if_not_set_then(|_| {
+            let statements = lower();
+
+            let Statement::Definition(definition) = &statements[0] else { panic!("expected a definition") };
+            assert!(matches!(&definition.type_, Type::Tuple(tuple) if tuple.length() == 3));
+            assert!(matches!(definition.value, Expression::Tuple(_)));
+            let Statement::Return(statement) = &statements[2] else { panic!("expected a return statement") };
+            let Expression::Binary(BinaryExpression { left, .. }) = &statement.expression else {
+                panic!("expected a binary expression")
+            };
+            assert!(
+                matches!(**left, Expression::Access(AccessExpression::Tuple(ref access)) if access.index.value() == 2)
+            );
+        })
+    }
+
+    #[test]
+    fn test_keeps_dynamic_index_access() {
+        create_session_if_not_set_then(|_| {
+            let statements = lower();
+
+            let Statement::Definition(definition) = &statements[1] else { panic!("expected a definition") };
+            assert!(matches!(definition.type_, Type::Array(_)));
+            let Statement::Return(statement) = &statements[2] else { panic!("expected a return statement") };
+            let Expression::Binary(BinaryExpression { right, .. }) = &statement.expression else {
+                panic!("expected a binary expression")
+            };
+            assert!(matches!(**right, Expression::Access(AccessExpression::Array(_))));
+        })
+    }
+}
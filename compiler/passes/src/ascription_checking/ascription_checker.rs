@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+/// A compiler pass that checks that the type of each ascribed expression is compatible with the ascribed type.
+pub struct AscriptionChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+}
+
+impl<'a> AscriptionChecker<'a> {
+    /// Returns a new `AscriptionChecker`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, type_table }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for AscriptionChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_ascription(
+        &mut self,
+        input: &'a AscriptionExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        // Expressions whose type could not be inferred have already been reported by the type checker.
+        if let Some(actual) = self.type_table.get(&input.expression.id()) {
+            if !actual.eq_flat(&input.type_) {
+                self.handler.emit_err(StaticAnalyzerError::incompatible_ascription(
+                    &input.expression,
+                    actual,
+                    &input.type_,
+                    input.span,
+                ));
+            }
+        }
+        self.visit_expression(&input.expression, additional);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for AscriptionChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for AscriptionChecker<'a> {}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Ascription Checking pass traverses the AST and checks that the type of each ascribed expression is
+//! compatible with the ascribed type. An ascription, e.g. `(a : u8)`, states the type of its expression without
+//! converting it, so an incompatible ascription is reported at the span of the ascription.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u16) -> u8 {
+//!     return (a : u8);
+//! }
+//! ```
+//!
+//! The ascription checking pass reports an error for `(a : u8)`, since `a` is a `u16`.
+//! Use `a as u8` to convert `a` instead.
+
+pub mod ascription_checker;
+pub use ascription_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for AscriptionChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut visitor = AscriptionChecker::new(handler, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks a transition that returns `a` ascribed with `to`, then checks the ascription.
+    /// Note that the type checker also reports incompatible ascriptions, so its result is ignored.
+    fn check_ascription(from: &str, to: &str) -> Result<()> {
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: {from}) -> {to} {{
+                    return (a : {to});
+                }}
+            }}"
+        );
+        let (type_checker_handler, _) = Handler::new_with_buf();
+        let type_table = TypeTable::default();
+        let ast = parse_program(&type_checker_handler, &NodeBuilder::default(), &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &type_checker_handler)).unwrap();
+        let _ = TypeChecker::do_pass((&ast, &type_checker_handler, symbol_table, &type_table));
+
+        let (handler, _) = Handler::new_with_buf();
+        AscriptionChecker::do_pass((&ast, &handler, &type_table))
+    }
+
+    #[test]
+    fn test_accepts_compatible_ascription() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_ascription("u8", "u8").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_incompatible_ascription() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_ascription("u16", "u8").is_err());
+        })
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::Span;
+
+/// A compiler pass that checks that the conditions of conditional statements and ternary expressions
+/// do not contain assignments, e.g. `if (x = 5u8) { .. }`, which are almost certainly typos for `==`.
+/// Note that Leo does not currently have assignment expressions, so the parser rejects such conditions.
+/// This pass guards against their introduction: `find_assignment` matches exhaustively on the expression grammar,
+/// so any new expression must be classified here.
+pub struct AssignInConditionChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> AssignInConditionChecker<'a> {
+    /// Returns a new `AssignInConditionChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler }
+    }
+
+    /// Returns the span of the first assignment embedded in the expression, if any.
+    pub(crate) fn find_assignment(input: &Expression) -> Option<Span> {
+        match input {
+            Expression::Access(access) => match access {
+                AccessExpression::Array(access) => Self::find_first([&*access.array, &*access.index]),
+                AccessExpression::AssociatedConstant(_) => None,
+                AccessExpression::AssociatedFunction(function) => Self::find_first(function.arguments.iter()),
+                AccessExpression::Member(access) => Self::find_assignment(&access.inner),
+                AccessExpression::Tuple(access) => Self::find_assignment(&access.tuple),
+            },
+            Expression::Array(array) => Self::find_first(array.elements.iter()),
+            Expression::Ascription(ascription) => Self::find_assignment(&ascription.expression),
+            Expression::Binary(binary) => Self::find_first([&*binary.left, &*binary.right]),
+            Expression::Call(call) => Self::find_first(call.arguments.iter()),
+            Expression::Cast(cast) => Self::find_assignment(&cast.expression),
+            Expression::Struct(struct_) => {
+                Self::find_first(struct_.members.iter().filter_map(|member| member.expression.as_ref()))
+            }
+            Expression::Spread(spread) => Self::find_assignment(&spread.expression),
+            Expression::Ternary(ternary) => {
+                Self::find_first([&*ternary.condition, &*ternary.if_true, &*ternary.if_false])
+            }
+            Expression::Tuple(tuple) => Self::find_first(tuple.elements.iter()),
+            Expression::Unary(unary) => Self::find_assignment(&unary.receiver),
+            Expression::Err(_) | Expression::Identifier(_) | Expression::Literal(_) | Expression::Unit(_) => None,
+        }
+    }
+
+    /// Returns the span of the first assignment embedded in any of the expressions, if any.
+    fn find_first<'e>(expressions: impl IntoIterator<Item = &'e Expression>) -> Option<Span> {
+        expressions.into_iter().find_map(Self::find_assignment)
+    }
+
+    /// Reports each assignment embedded in the condition.
+    fn check_condition(&self, condition: &Expression) {
+        if let Some(span) = Self::find_assignment(condition) {
+            self.handler.emit_err(StaticAnalyzerError::assignment_in_condition(span));
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for AssignInConditionChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_condition(&input.condition);
+        self.visit_expression(&input.condition, additional);
+        self.visit_expression(&input.if_true, additional);
+        self.visit_expression(&input.if_false, additional);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for AssignInConditionChecker<'a> {
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        self.check_condition(&input.condition);
+        self.visit_expression(&input.condition, &Default::default());
+        self.visit_block(&input.then);
+        if let Some(otherwise) = input.otherwise.as_ref() {
+            self.visit_statement(otherwise);
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for AssignInConditionChecker<'a> {}
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Assign In Condition Checking pass traverses the AST and checks that the conditions of conditional statements
+//! and ternary expressions do not contain assignments, e.g. `if (x = 5u8) { .. }`.
+//! Errors are reported at the span of the offending assignment.
+//! Since Leo separates statements from expressions, this pass currently accepts every well-formed program.
+//! It guards against the future introduction of assignment expressions.
+
+pub mod assign_in_condition_checker;
+pub use assign_in_condition_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for AssignInConditionChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = AssignInConditionChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_accepts_comparison_conditions() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let source = "program test.aleo {
+                transition main(x: u8) -> u8 {
+                    let y: u8 = 0u8;
+                    if (x == 5u8) {
+                        y = x == 6u8 ? 1u8 : 2u8;
+                    }
+                    return y;
+                }
+            }";
+            let ast = parse_program(&handler, &NodeBuilder::default(), source);
+
+            assert!(AssignInConditionChecker::do_pass((&ast, &handler)).is_ok());
+        })
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// An assignment to a constant element of a tuple or array variable, e.g. `a.0 = x` or `a[1u32] = y`.
+struct ElementWrite {
+    /// The variable whose element is assigned.
+    base: Identifier,
+    /// The position of the assigned element.
+    position: usize,
+}
+
+/// Checks whether an expression mentions a variable.
+struct UsageFinder {
+    /// The name of the variable.
+    name: Symbol,
+    /// Whether the variable was found.
+    found: bool,
+}
+
+impl<'a> ExpressionVisitor<'a> for UsageFinder {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.found |= input.name == self.name;
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, additional),
+            None => self.visit_identifier(&member.identifier, additional),
+        });
+    }
+}
+
+pub struct AssignMerger<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The declared types of the variables in scope.
+    pub(crate) types: IndexMap<Symbol, Type>,
+}
+
+impl<'a> AssignMerger<'a> {
+    /// Initializes a new `AssignMerger` reducer.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder, types: IndexMap::new() }
+    }
+
+    /// Returns the element write performed by `statement`, if it assigns a constant element of a variable.
+    fn element_write(statement: &Statement) -> Option<ElementWrite> {
+        let Statement::Assign(assign) = statement else {
+            return None;
+        };
+        match &assign.place {
+            Expression::Access(AccessExpression::Tuple(access)) => match &*access.tuple {
+                Expression::Identifier(base) => Some(ElementWrite { base: *base, position: access.index.value() }),
+                _ => None,
+            },
+            Expression::Access(AccessExpression::Array(access)) => match (&*access.array, &*access.index) {
+                (Expression::Identifier(base), Expression::Literal(Literal::Integer(_, value, ..))) => {
+                    Some(ElementWrite { base: *base, position: value.replace('_', "").parse().ok()? })
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the number of elements of the variable `base`, if it is a tuple or an array.
+    fn length(&self, base: &Identifier) -> Option<usize> {
+        match self.types.get(&base.name) {
+            Some(Type::Tuple(tuple)) => Some(tuple.length()),
+            Some(Type::Array(array)) => Some(array.length()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `expression` mentions the variable `name`.
+    fn mentions(expression: &Expression, name: Symbol) -> bool {
+        let mut finder = UsageFinder { name, found: false };
+        finder.visit_expression(expression, &());
+        finder.found
+    }
+
+    /// Returns the expression reading the element at `position` of the variable `base`.
+    fn read_element(&self, base: &Identifier, position: usize, span: Span) -> Expression {
+        let variable = Box::new(Expression::Identifier(Identifier {
+            name: base.name,
+            span: base.span,
+            id: self.node_builder.next_id(),
+        }));
+        match self.types.get(&base.name) {
+            Some(Type::Array(_)) => Expression::Access(AccessExpression::Array(ArrayAccess {
+                array: variable,
+                index: Box::new(Expression::Literal(Literal::Integer(
+                    IntegerType::U32,
+                    position.to_string(),
+                    span,
+                    self.node_builder.next_id(),
+                ))),
+                span,
+                id: self.node_builder.next_id(),
+            })),
+            _ => Expression::Access(AccessExpression::Tuple(TupleAccess {
+                tuple: variable,
+                index: position.into(),
+                span,
+                id: self.node_builder.next_id(),
+            })),
+        }
+    }
+
+    /// Returns the statements of `run`, a sequence of writes to disjoint elements of the variable `base`.
+    /// The writes are merged into a single assignment of an aggregate if they assign at least half of the elements,
+    /// so that the aggregate does not mostly consist of reads of the unchanged elements.
+    fn merge(&self, base: Identifier, run: Vec<(usize, AssignStatement)>) -> Vec<Statement> {
+        let length = self.length(&base).unwrap_or_default();
+        if run.len() < 2 || run.len() * 2 < length {
+            return run.into_iter().map(|(_, assign)| Statement::Assign(Box::new(assign))).collect();
+        }
+
+        let span = run[0].1.span + run[run.len() - 1].1.span;
+        let mut values = run.into_iter().map(|(position, assign)| (position, assign.value)).collect::<IndexMap<_, _>>();
+        let elements = (0..length)
+            .map(|position| values.swap_remove(&position).unwrap_or_else(|| self.read_element(&base, position, span)))
+            .collect();
+        let value = match self.types.get(&base.name) {
+            Some(Type::Array(_)) => {
+                Expression::Array(ArrayExpression { elements, span, id: self.node_builder.next_id() })
+            }
+            _ => Expression::Tuple(TupleExpression { elements, span, id: self.node_builder.next_id() }),
+        };
+
+        vec![Statement::Assign(Box::new(AssignStatement {
+            place: Expression::Identifier(Identifier {
+                name: base.name,
+                span: base.span,
+                id: self.node_builder.next_id(),
+            }),
+            value,
+            span,
+            id: self.node_builder.next_id(),
+        }))]
+    }
+
+    /// Records the declared type of each internal function input.
+    fn declare_inputs(&mut self, inputs: &[Input]) {
+        self.types.extend(inputs.iter().filter_map(|input| match input {
+            Input::Internal(input) => Some((input.identifier.name, input.type_.clone())),
+            Input::External(_) => None,
+        }));
+    }
+}
+
+impl ExpressionReconstructor for AssignMerger<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for AssignMerger<'_> {
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        let mut statements = Vec::with_capacity(input.statements.len());
+        // The current run of writes to disjoint constant elements of the same variable.
+        let mut run: Option<(Identifier, Vec<(usize, AssignStatement)>)> = None;
+
+        for statement in input.statements {
+            let statement = self.reconstruct_statement(statement).0;
+            let write = Self::element_write(&statement)
+                .filter(|write| self.length(&write.base).map_or(false, |length| write.position < length));
+
+            match (write, statement) {
+                (Some(write), Statement::Assign(assign)) => {
+                    // A write extends the run if it targets a new element of the same variable and does not read
+                    // the variable, since the merged aggregate reads the variable before any of the writes.
+                    let extends = !Self::mentions(&assign.value, write.base.name)
+                        && matches!(&run, Some((base, writes))
+                            if base.name == write.base.name
+                                && writes.iter().all(|(position, _)| *position != write.position));
+                    if !extends {
+                        if let Some((base, writes)) = run.take() {
+                            statements.extend(self.merge(base, writes));
+                        }
+                    }
+                    run.get_or_insert_with(|| (write.base, Vec::new())).1.push((write.position, *assign));
+                }
+                (_, statement) => {
+                    if let Some((base, writes)) = run.take() {
+                        statements.extend(self.merge(base, writes));
+                    }
+                    if let Statement::Definition(definition) = &statement {
+                        if let Expression::Identifier(identifier) = &definition.place {
+                            self.types.insert(identifier.name, definition.type_.clone());
+                        }
+                    }
+                    statements.push(statement);
+                }
+            }
+        }
+        if let Some((base, writes)) = run.take() {
+            statements.extend(self.merge(base, writes));
+        }
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+}
+
+impl ProgramReconstructor for AssignMerger<'_> {
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        self.types.clear();
+        self.declare_inputs(&input.input);
+        let block = self.reconstruct_block(input.block).0;
+
+        let finalize = input.finalize.map(|finalize| {
+            self.types.clear();
+            self.declare_inputs(&finalize.input);
+            Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: finalize.output,
+                output_type: finalize.output_type,
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }
+        });
+
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            const_predicates: input.const_predicates,
+            block,
+            finalize,
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
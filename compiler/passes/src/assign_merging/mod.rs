@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Assign Merging pass traverses the AST and merges consecutive assignments to distinct constant elements of
+//! the same tuple or array variable into a single assignment of an aggregate.
+//! Elements that are not assigned are read from the variable, so the writes are only merged if they assign at least
+//! half of the elements, and a write whose value reads the variable ends the current sequence of writes.
+//! Since the type checker rejects assignments to elements, this pass runs on the parsed AST.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: (u8, u8, u8), x: u8, y: u8) -> u8 {
+//!     a.0 = x;
+//!     a.2 = y;
+//!     return a.1;
+//! }
+//! ```
+//!
+//! The assign merging pass produces the following code.
+//! ```leo
+//! transition main(a: (u8, u8, u8), x: u8, y: u8) -> u8 {
+//!     a = (x, a.1, y);
+//!     return a.1;
+//! }
+//! ```
+
+pub mod assign_merger;
+pub use assign_merger::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for AssignMerger<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = AssignMerger::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and merges a transition with the given body, returning the statements of the transition.
+    fn merge_body(body: &str) -> Vec<Statement> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: (u8, u8), b: [u8; 2], i: u32, x: u8, y: u8) -> u8 {{
+                    {body}
+                    return x;
+                }}
+            }}"
+        );
+        let ast = AssignMerger::do_pass((parse_program(&handler, &node_builder, &source), &node_builder)).unwrap();
+        let mut scope = ast.into_repr().program_scopes.into_values().next().unwrap();
+        scope.functions.pop().unwrap().1.block.statements
+    }
+
+    #[test]
+    fn test_merges_disjoint_element_writes() {
+        create_session_if_not_set_then(|_| {
+            let statements = merge_body("a.0 = x; a.1 = y;");
+
+            assert_eq!(statements.len(), 2);
+            let Statement::Assign(assign) = &statements[0] else {
+                panic!("expected an assignment, found `{}`", statements[0]);
+            };
+            assert_eq!(assign.place.to_string(), "a");
+            let Expression::Tuple(value) = &assign.value else {
+                panic!("expected a tuple, found `{}`", assign.value);
+            };
+            assert_eq!(value.elements.iter().map(|element| element.to_string()).collect::<Vec<_>>(), ["x", "y"]);
+        })
+    }
+
+    #[test]
+    fn test_keeps_overlapping_and_dynamic_writes() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(merge_body("a.0 = x; a.0 = y;").len(), 3);
+            assert_eq!(merge_body("b[i] = x; b[1u32] = y;").len(), 3);
+        })
+    }
+}
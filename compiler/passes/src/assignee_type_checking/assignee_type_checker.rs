@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// A compiler pass that checks that each access in the place of an assignment matches the type of the value it
+/// accesses, starting from the declared type of the variable at the root of the place.
+pub struct AssigneeTypeChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The structs of the current program scope.
+    structs: IndexMap<Symbol, &'a Struct>,
+    /// The global constants of the current program scope, mapped to their types.
+    globals: IndexMap<Symbol, Type>,
+    /// The variables of the current function, mapped to their declared types.
+    variables: IndexMap<Symbol, Type>,
+}
+
+impl<'a> AssigneeTypeChecker<'a> {
+    /// Returns a new `AssigneeTypeChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, structs: IndexMap::new(), globals: IndexMap::new(), variables: IndexMap::new() }
+    }
+
+    /// Records the declared type of each variable in `place`, which has the type `type_`.
+    fn declare(&mut self, place: &Expression, type_: &Type) {
+        match (place, type_) {
+            (Expression::Identifier(identifier), type_) => {
+                self.variables.insert(identifier.name, type_.clone());
+            }
+            (Expression::Tuple(tuple), Type::Tuple(tuple_type)) if tuple.elements.len() == tuple_type.length() => {
+                tuple.elements.iter().zip(tuple_type.elements()).for_each(|(place, type_)| self.declare(place, type_))
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the type of `place`, following its accesses from the type of its root variable.
+    /// Reports the first access that does not match the type of the value it accesses, and returns `None` if there is
+    /// such an access, or if a type is not known.
+    fn type_of_place(&self, place: &Expression) -> Option<Type> {
+        match place {
+            Expression::Identifier(identifier) => {
+                self.variables.get(&identifier.name).or_else(|| self.globals.get(&identifier.name)).cloned()
+            }
+            Expression::Access(AccessExpression::Member(access)) => match self.type_of_place(&access.inner)? {
+                Type::Identifier(struct_) => {
+                    let struct_definition = self.structs.get(&struct_.name)?;
+                    let member = struct_definition.members.iter().find(|member| member.name() == access.name.name);
+                    if member.is_none() {
+                        self.handler.emit_err(StaticAnalyzerError::unknown_assignee_member(
+                            access.name,
+                            struct_,
+                            access.span,
+                        ));
+                    }
+                    member.map(|member| member.type_.clone())
+                }
+                found => self.mismatch(place, "a struct", found),
+            },
+            Expression::Access(AccessExpression::Array(access)) => match self.type_of_place(&access.array)? {
+                Type::Array(array_type) => Some(array_type.element_type().clone()),
+                found => self.mismatch(place, "an array", found),
+            },
+            Expression::Access(AccessExpression::Tuple(access)) => match self.type_of_place(&access.tuple)? {
+                Type::Tuple(tuple_type) if access.index.value() < tuple_type.length() => {
+                    Some(tuple_type.elements()[access.index.value()].clone())
+                }
+                found => self.mismatch(place, format!("a tuple of more than {} elements", access.index.value()), found),
+            },
+            _ => None,
+        }
+    }
+
+    /// Reports that the access `access` expects `expected`, but accesses a value of type `found`.
+    fn mismatch(&self, access: &Expression, expected: impl std::fmt::Display, found: Type) -> Option<Type> {
+        self.handler.emit_err(StaticAnalyzerError::invalid_assignee_access(access, expected, found, access.span()));
+        None
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for AssigneeTypeChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for AssigneeTypeChecker<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.type_of_place(&input.place);
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.variables.insert(input.place.name, input.type_.clone());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.declare(&input.place, &input.type_);
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.variables.insert(input.variable.name, input.type_.clone());
+        self.visit_block(&input.block);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for AssigneeTypeChecker<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.structs = input.structs.iter().map(|(name, struct_)| (*name, struct_)).collect();
+        self.globals = input.consts.iter().map(|(name, declaration)| (*name, declaration.type_.clone())).collect();
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.variables = input.input.iter().map(|input| (input.identifier().name, input.type_())).collect();
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.variables = finalize.input.iter().map(|input| (input.identifier().name, input.type_())).collect();
+            self.visit_block(&finalize.block);
+        }
+    }
+}
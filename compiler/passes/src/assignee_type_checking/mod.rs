@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Assignee Type Checking pass traverses the AST and checks the accesses in the place of each assignment.
+//! Starting from the declared type of the variable at the root of the place, each access must match the type of the
+//! value it accesses: a member access needs a struct with that member, an index needs an array, and a tuple access
+//! needs a tuple with that element.
+//! The first access that does not match is reported at its span.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: [u8; 2]) -> u8 {
+//!     a.x = 1u8;
+//!     b[0u32] = 2u8;
+//!     return a;
+//! }
+//! ```
+//!
+//! The assignee type checking pass reports an error for `a.x`, since `a` is not a struct.
+
+pub mod assignee_type_checker;
+pub use assignee_type_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for AssigneeTypeChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = AssigneeTypeChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Checks a transition with the input `a: <type_>` that performs `assignment`, returning the number of errors.
+    fn check(type_: &str, assignment: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                struct Point {{
+                    x: u8,
+                    y: u8,
+                }}
+
+                transition main(a: {type_}) -> bool {{
+                    {assignment}
+                    return true;
+                }}
+            }}"
+        );
+        let _ = AssigneeTypeChecker::do_pass((&parse_program(&handler, &NodeBuilder::default(), &source), &handler));
+        handler.err_count()
+    }
+
+    #[test]
+    fn test_rejects_member_access_on_non_struct() {
+        create_session_if_not_set_then(|_| assert_eq!(check("u8", "a.x = 1u8;"), 1))
+    }
+
+    #[test]
+    fn test_rejects_index_on_non_array() {
+        create_session_if_not_set_then(|_| assert_eq!(check("u8", "a[0u32] = 1u8;"), 1))
+    }
+
+    #[test]
+    fn test_accepts_nested_path() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(check("[Point; 2]", "a[1u32].x = 1u8;"), 0);
+            assert_eq!(check("[Point; 2]", "a[1u32].z = 1u8;"), 1);
+        })
+    }
+}
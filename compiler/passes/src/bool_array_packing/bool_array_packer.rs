@@ -0,0 +1,394 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{PackingScope, PackingUsage};
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+pub struct BoolArrayPacker<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The largest length of a boolean array that is packed.
+    pub(crate) threshold: usize,
+    /// The variables that are packed, mapped to the integer types they are packed into and their lengths.
+    pub(crate) packed: IndexMap<(PackingScope, Symbol), (IntegerType, usize)>,
+    /// The scope of the variables being reconstructed.
+    pub(crate) scope: PackingScope,
+    /// The bounds checks that must be inserted before the statement currently being reconstructed.
+    pub(crate) statements: Vec<Statement>,
+}
+
+impl<'a> BoolArrayPacker<'a> {
+    /// Initializes a new `BoolArrayPacker` reducer, packing boolean arrays of at most `threshold` elements.
+    pub fn new(node_builder: &'a NodeBuilder, threshold: usize) -> Self {
+        Self { node_builder, threshold, packed: IndexMap::new(), scope: None, statements: Vec::new() }
+    }
+
+    /// Returns the integer type and the length of the packed variable referred to by `name` in the current scope, if
+    /// any.
+    fn packed(&self, name: Symbol) -> Option<(IntegerType, usize)> {
+        [(self.scope, name), (None, name)].into_iter().find_map(|variable| self.packed.get(&variable).copied())
+    }
+
+    /// Returns the integer type of the packed variable `name` declared in the current scope, if any.
+    fn declared(&self, name: Symbol) -> Option<IntegerType> {
+        self.packed.get(&(self.scope, name)).map(|(integer_type, _)| *integer_type)
+    }
+
+    /// Returns the integer literal `value` of type `type_`.
+    fn literal(&self, type_: IntegerType, value: u128, span: Span) -> Expression {
+        Expression::Literal(Literal::Integer(type_, value.to_string(), span, self.node_builder.next_id()))
+    }
+
+    /// Returns the binary expression `left op right`.
+    fn binary(&self, left: Expression, op: BinaryOperation, right: Expression, span: Span) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the ternary expression `condition ? if_true : if_false`.
+    fn ternary(&self, condition: Expression, if_true: Expression, if_false: Expression, span: Span) -> Expression {
+        Expression::Ternary(TernaryExpression {
+            condition: Box::new(condition),
+            if_true: Box::new(if_true),
+            if_false: Box::new(if_false),
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the number of bits of the unsigned integer type `type_`.
+    fn width(type_: IntegerType) -> u32 {
+        match type_ {
+            IntegerType::U8 | IntegerType::I8 => 8,
+            IntegerType::U16 | IntegerType::I16 => 16,
+            IntegerType::U32 | IntegerType::I32 => 32,
+            IntegerType::U64 | IntegerType::I64 => 64,
+            IntegerType::U128 | IntegerType::I128 => 128,
+        }
+    }
+
+    /// Returns the value of `index`, if it is an integer literal that is a valid bit position.
+    pub(crate) fn constant_index(index: &Expression) -> Option<u32> {
+        match index {
+            Expression::Literal(Literal::Integer(_, value, ..)) => {
+                value.replace('_', "").parse().ok().filter(|index| *index < 128)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the mask selecting the bit at `index`, `1 << index`, folded into a literal if `index` is constant.
+    fn mask(&self, type_: IntegerType, index: Expression, span: Span) -> Expression {
+        match Self::constant_index(&index) {
+            Some(index) => self.literal(type_, 1 << index, span),
+            None => self.binary(self.literal(type_, 1, span), BinaryOperation::Shl, index, span),
+        }
+    }
+
+    /// Returns `ternary` with `pack` applied to both of its branches.
+    fn map_branches(&self, ternary: TernaryExpression, pack: impl Fn(Expression) -> Expression) -> Expression {
+        Expression::Ternary(TernaryExpression {
+            condition: ternary.condition,
+            if_true: Box::new(pack(*ternary.if_true)),
+            if_false: Box::new(pack(*ternary.if_false)),
+            span: ternary.span,
+            id: ternary.id,
+        })
+    }
+
+    /// Packs an array literal of booleans into an integer of type `type_`, whose bit `i` is the element at index `i`.
+    /// Literals of boolean literals are folded into an integer literal, and the branches of a ternary are packed in
+    /// turn. Any other expression is returned unchanged.
+    pub(crate) fn pack(&self, type_: IntegerType, expression: Expression) -> Expression {
+        let array = match expression {
+            Expression::Array(array) => array,
+            Expression::Ternary(ternary) => return self.map_branches(ternary, |branch| self.pack(type_, branch)),
+            expression => return expression,
+        };
+        let bits: Option<Vec<bool>> = array
+            .elements
+            .iter()
+            .map(|element| match element {
+                Expression::Literal(Literal::Boolean(value, ..)) => Some(*value),
+                _ => None,
+            })
+            .collect();
+        if let Some(bits) = bits {
+            let value = bits.iter().enumerate().filter(|(_, bit)| **bit).fold(0u128, |value, (i, _)| value | 1 << i);
+            return self.literal(type_, value, array.span);
+        }
+
+        // Each element `e` at index `i` contributes `e ? (1 << i) : 0`.
+        array
+            .elements
+            .into_iter()
+            .enumerate()
+            .map(|(i, element)| {
+                self.ternary(
+                    element,
+                    self.literal(type_, 1 << i, array.span),
+                    self.literal(type_, 0, array.span),
+                    array.span,
+                )
+            })
+            .reduce(|packed, bit| self.binary(packed, BinaryOperation::BitwiseOr, bit, array.span))
+            .unwrap_or_else(|| self.literal(type_, 0, array.span))
+    }
+
+    /// Checks that the index of an element access of a packed array of `length` elements is in bounds, since the
+    /// packed integer may have more bits than the array has elements. Constant indices are checked by the packing
+    /// usage, so only the other indices are checked, with `assert(i as u32 < length)`.
+    fn check_index(&mut self, index: &Expression, length: usize, span: Span) {
+        if Self::constant_index(index).is_some() {
+            return;
+        }
+        let index = Expression::Cast(CastExpression {
+            expression: Box::new(index.clone()),
+            type_: Type::Integer(IntegerType::U32),
+            span,
+            id: self.node_builder.next_id(),
+        });
+        let length = self.literal(IntegerType::U32, length as u128, span);
+        self.statements.push(Statement::Assert(AssertStatement {
+            variant: AssertVariant::Assert(self.binary(index, BinaryOperation::Lt, length, span)),
+            span,
+            id: self.node_builder.next_id(),
+        }));
+    }
+}
+
+impl ExpressionReconstructor for BoolArrayPacker<'_> {
+    type AdditionalOutput = ();
+
+    /// Rewrites `a[i]` to `(a >> i) & 1 == 1`, if `a` is a packed variable.
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        let index = self.reconstruct_expression(*input.index).0;
+        match *input.array {
+            Expression::Identifier(array) if self.packed(array.name).is_some() => {
+                let (type_, length) = self.packed(array.name).unwrap();
+                self.check_index(&index, length, input.span);
+                let shifted = self.binary(Expression::Identifier(array), BinaryOperation::Shr, index, input.span);
+                let bit =
+                    self.binary(shifted, BinaryOperation::BitwiseAnd, self.literal(type_, 1, input.span), input.span);
+                (
+                    self.binary(bit, BinaryOperation::Eq, self.literal(type_, 1, input.span), input.span),
+                    Default::default(),
+                )
+            }
+            array => (
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(self.reconstruct_expression(array).0),
+                    index: Box::new(index),
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            ),
+        }
+    }
+}
+
+impl StatementReconstructor for BoolArrayPacker<'_> {
+    /// Rewrites `a[i] = v` to `a = v ? a | (1 << i) : a & !(1 << i)`, and packs array literals assigned to `a`,
+    /// if `a` is a packed variable.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+        let (place, value) = match input.place {
+            Expression::Identifier(place) if self.packed(place.name).is_some() => {
+                let (type_, _) = self.packed(place.name).unwrap();
+                (Expression::Identifier(place), self.pack(type_, value))
+            }
+            Expression::Access(AccessExpression::Array(access)) if matches!(&*access.array, Expression::Identifier(array) if self.packed(array.name).is_some()) =>
+            {
+                let Expression::Identifier(array) = *access.array else { unreachable!("the array is an identifier") };
+                let (type_, length) = self.packed(array.name).unwrap();
+                let index = self.reconstruct_expression(*access.index).0;
+                let span = input.span;
+                self.check_index(&index, length, span);
+                let set = self.binary(
+                    Expression::Identifier(array),
+                    BinaryOperation::BitwiseOr,
+                    self.mask(type_, index.clone(), span),
+                    span,
+                );
+                let cleared_mask = match Self::constant_index(&index) {
+                    Some(index) => {
+                        self.literal(type_, !(1u128 << index) & (u128::MAX >> (128 - Self::width(type_))), span)
+                    }
+                    None => Expression::Unary(UnaryExpression {
+                        receiver: Box::new(self.mask(type_, index, span)),
+                        op: UnaryOperation::Not,
+                        span,
+                        id: self.node_builder.next_id(),
+                    }),
+                };
+                let cleared =
+                    self.binary(Expression::Identifier(array), BinaryOperation::BitwiseAnd, cleared_mask, span);
+                (Expression::Identifier(array), self.ternary(value, set, cleared, span))
+            }
+            place => (place, value),
+        };
+        (
+            Statement::Assign(Box::new(AssignStatement { place, value, span: input.span, id: input.id })),
+            Default::default(),
+        )
+    }
+
+    /// Reconstructs the statements of a block, inserting the bounds checks of each statement before it.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        let outer = std::mem::take(&mut self.statements);
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let statement = self.reconstruct_statement(statement).0;
+            statements.append(&mut self.statements);
+            statements.push(statement);
+        }
+        self.statements = outer;
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    /// Reconstructs the conditional statement, keeping the bounds checks of an `else if` condition inside the `else`
+    /// branch.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let condition = self.reconstruct_expression(input.condition).0;
+        let then = self.reconstruct_block(input.then).0;
+        let otherwise = input.otherwise.map(|otherwise| match *otherwise {
+            Statement::Block(block) => Box::new(Statement::Block(self.reconstruct_block(block).0)),
+            otherwise => {
+                let span = otherwise.span();
+                let mut block = self
+                    .reconstruct_block(Block { statements: vec![otherwise], span, id: self.node_builder.next_id() })
+                    .0;
+                match block.statements.len() {
+                    1 => Box::new(block.statements.pop().unwrap()),
+                    _ => Box::new(Statement::Block(block)),
+                }
+            }
+        });
+
+        (
+            Statement::Conditional(ConditionalStatement { condition, then, otherwise, span: input.span, id: input.id }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+        let (type_, value) = match self.declared(input.place.name) {
+            Some(integer_type) => (Type::Integer(integer_type), self.pack(integer_type, value)),
+            None => (input.type_, value),
+        };
+        (
+            Statement::Const(ConstDeclaration { place: input.place, type_, value, span: input.span, id: input.id }),
+            Default::default(),
+        )
+    }
+
+    /// Rewrites the declared type of a packed variable and packs its initializer.
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+        let packed = match &input.place {
+            Expression::Identifier(place) => self.declared(place.name),
+            _ => None,
+        };
+        let (type_, value) = match packed {
+            Some(integer_type) => (Type::Integer(integer_type), self.pack(integer_type, value)),
+            None => (input.type_, value),
+        };
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: input.place,
+                type_,
+                value,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for BoolArrayPacker<'_> {
+    /// Decides which variables are packed before reconstructing the functions and constants that declare them.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        self.packed = PackingUsage::of_program_scope(&input, self.threshold);
+
+        let functions = input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect();
+        self.scope = None;
+        ProgramScope {
+            program_id: input.program_id,
+            structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions,
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+
+    /// Reconstructs the bodies of the function, leaving its inputs and outputs intact.
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        let name = input.name();
+        self.scope = Some((name, false));
+        let block = self.reconstruct_block(input.block).0;
+
+        let finalize = input.finalize.map(|finalize| {
+            self.scope = Some((name, true));
+            Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: finalize.output,
+                output_type: finalize.output_type,
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }
+        });
+
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            const_predicates: input.const_predicates,
+            block,
+            finalize,
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
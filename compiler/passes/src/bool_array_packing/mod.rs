@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Bool Array Packing pass traverses the AST and packs boolean arrays of at most a threshold number of elements
+//! into the smallest unsigned integer type with at least as many bits, so that bit `i` holds the element at index `i`.
+//! Only definitions and constants are packed, and only if they do not escape: a variable that is passed to a call,
+//! stored in a struct, a record, a tuple, or a mapping, returned, or assigned from such a value is left intact, and so
+//! are function inputs, so the signatures of functions, structs, and mappings never change.
+//! The types of the packed variables are rewritten, element accesses `a[i]` are rewritten to `(a >> i) & 1 == 1`,
+//! element assignments `a[i] = v` are rewritten to bit operations setting or clearing bit `i`,
+//! and array literals initializing or assigned to packed variables are packed, along with those in the branches of
+//! ternaries. Since the packed integer may have more bits than the array has elements, each access with a non-constant
+//! index `i` into an array of `N` elements is preceded by `assert((i as u32) < N);`.
+//!
+//! Consider the following Leo code, with a threshold of 8.
+//! ```leo
+//! transition main(i: u32) -> bool {
+//!     let a: [bool; 4] = [false, true, false, false];
+//!     let b: [bool; 4] = [true, false, true, false];
+//!     b[1u32] = a[i];
+//!     return b[1u32];
+//! }
+//! ```
+//!
+//! The bool array packing pass produces the following code.
+//! ```leo
+//! transition main(i: u32) -> bool {
+//!     let a: u8 = 2u8;
+//!     let b: u8 = 5u8;
+//!     assert((i as u32) < 4u32);
+//!     b = (a >> i) & 1u8 == 1u8 ? b | 2u8 : b & 253u8;
+//!     return (b >> 1u32) & 1u8 == 1u8;
+//! }
+//! ```
+
+pub mod bool_array_packer;
+pub use bool_array_packer::*;
+
+pub mod packing_usage;
+pub use packing_usage::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for BoolArrayPacker<'a> {
+    type Input = (Ast, &'a NodeBuilder, usize);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder, threshold): Self::Input) -> Self::Output {
+        let mut reconstructor = BoolArrayPacker::new(node_builder, threshold);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, Function, Input, Statement, UnaryOperation};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and packs a program with the given functions with a threshold of 16, returning its functions.
+    fn pack(functions: &str) -> Vec<Function> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                {functions}
+            }}"
+        );
+        let ast =
+            BoolArrayPacker::do_pass((parse_program(&handler, &node_builder, &source), &node_builder, 16)).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap().functions.into_iter().map(|(_, f)| f).collect()
+    }
+
+    /// Parses and packs a program with a single transition with a threshold of 16, returning the transition.
+    fn pack_transition(signature: &str, body: &str) -> Function {
+        pack(&format!("transition {signature} {{ {body} }}")).pop().unwrap()
+    }
+
+    /// Returns the types of the inputs of `function`, as strings.
+    fn input_types(function: &Function) -> Vec<String> {
+        function
+            .input
+            .iter()
+            .map(|input| match input {
+                Input::Internal(input) => input.type_.to_string(),
+                input => panic!("expected an internal input, found `{input}`"),
+            })
+            .collect()
+    }
+
+    /// Returns the declared type and the value of the definition at `index` in the body of `function`, as strings.
+    fn definition(function: &Function, index: usize) -> (String, String) {
+        match &function.block.statements[index] {
+            Statement::Definition(definition) => (definition.type_.to_string(), definition.value.to_string()),
+            statement => panic!("expected a definition, found `{statement}`"),
+        }
+    }
+
+    #[test]
+    fn test_rewrites_bool_array_types() {
+        create_session_if_not_set_then(|_| {
+            let function = pack_transition(
+                "main(a: [bool; 8]) -> bool",
+                "let b: [bool; 8] = [true, false, true, false, false, false, false, false];
+                let c: [bool; 9] = [false, false, false, false, false, false, false, false, true];
+                let d: [bool; 17] = [false, false, false, false, false, false, false, false, false,
+                    false, false, false, false, false, false, false, false];
+                let e: [u8; 1] = [1u8];
+                return a[0u32] && b[1u32] && c[8u32] && d[0u32] && e[0u32] == 1u8;",
+            );
+
+            assert_eq!(input_types(&function), vec!["[bool; 8]"]);
+            assert_eq!(definition(&function, 0), ("u8".to_string(), "5u8".to_string()));
+            assert_eq!(definition(&function, 1), ("u16".to_string(), "256u16".to_string()));
+            assert_eq!(definition(&function, 2).0, "[bool; 17]");
+            assert_eq!(definition(&function, 3).0, "[u8; 1]");
+        })
+    }
+
+    #[test]
+    fn test_lowers_constant_index_access_and_assignment() {
+        create_session_if_not_set_then(|_| {
+            let function = pack_transition(
+                "main(v: bool) -> bool",
+                "let a: [bool; 4] = [false, false, false, false]; a[2u32] = v; return a[2u32];",
+            );
+
+            let Statement::Assign(assign) = &function.block.statements[1] else {
+                panic!("expected an assignment, found `{}`", function.block.statements[1]);
+            };
+            assert_eq!(assign.place.to_string(), "a");
+            let Expression::Ternary(value) = &assign.value else {
+                panic!("expected a ternary, found `{}`", assign.value);
+            };
+            assert_eq!(value.condition.to_string(), "v");
+            assert_eq!(value.if_true.to_string(), "a | 4u8");
+            assert_eq!(value.if_false.to_string(), "a & 251u8");
+
+            let Statement::Return(output) = &function.block.statements[2] else {
+                panic!("expected a return, found `{}`", function.block.statements[2]);
+            };
+            let Expression::Binary(equality) = &output.expression else {
+                panic!("expected a comparison, found `{}`", output.expression);
+            };
+            assert_eq!(equality.left.to_string(), "a >> 2u32 & 1u8");
+            assert_eq!(equality.right.to_string(), "1u8");
+        })
+    }
+
+    #[test]
+    fn test_lowers_dynamic_index_access_and_assignment_to_checked_shifts() {
+        create_session_if_not_set_then(|_| {
+            let function = pack_transition(
+                "main(i: u32, v: bool) -> bool",
+                "let a: [bool; 4] = [false, false, false, false]; a[i] = v; return a[i];",
+            );
+
+            assert_eq!(function.block.statements[1].to_string(), "assert((i as u32) < 4u32);");
+            let Statement::Assign(assign) = &function.block.statements[2] else {
+                panic!("expected an assignment, found `{}`", function.block.statements[2]);
+            };
+            assert_eq!(assign.place.to_string(), "a");
+            let Expression::Ternary(value) = &assign.value else {
+                panic!("expected a ternary, found `{}`", assign.value);
+            };
+            assert_eq!(value.condition.to_string(), "v");
+            assert_eq!(value.if_true.to_string(), "a | 1u8 << i");
+            let Expression::Binary(cleared) = &*value.if_false else {
+                panic!("expected a binary expression, found `{}`", value.if_false);
+            };
+            assert_eq!(cleared.left.to_string(), "a");
+            let Expression::Unary(mask) = &*cleared.right else {
+                panic!("expected a negated mask, found `{}`", cleared.right);
+            };
+            assert_eq!(mask.op, UnaryOperation::Not);
+            assert_eq!(mask.receiver.to_string(), "1u8 << i");
+
+            assert_eq!(function.block.statements[3].to_string(), "assert((i as u32) < 4u32);");
+            let Statement::Return(output) = &function.block.statements[4] else {
+                panic!("expected a return, found `{}`", function.block.statements[4]);
+            };
+            assert_eq!(output.expression.to_string(), "a >> i & 1u8 == 1u8");
+        })
+    }
+
+    #[test]
+    fn test_packs_ternary_branches() {
+        create_session_if_not_set_then(|_| {
+            let function = pack_transition(
+                "main(c: bool) -> bool",
+                "let x: [bool; 2] = [false, false]; let b: [bool; 2] = c ? [true, false] : x; return b[0u32];",
+            );
+
+            assert_eq!(definition(&function, 1), ("u8".to_string(), "(c ? 1u8 : x)".to_string()));
+        })
+    }
+
+    #[test]
+    fn test_preserves_call_arguments() {
+        create_session_if_not_set_then(|_| {
+            let functions = pack(
+                "function first(a: [bool; 2]) -> bool { return a[0u32]; }
+                transition main() -> bool { let b: [bool; 2] = [false, true]; return first(b); }",
+            );
+
+            assert_eq!(input_types(&functions[0]), vec!["[bool; 2]"]);
+            assert_eq!(definition(&functions[1], 0), ("[bool; 2]".to_string(), "[false, true]".to_string()));
+        })
+    }
+
+    #[test]
+    fn test_preserves_tuple_elements() {
+        create_session_if_not_set_then(|_| {
+            let function = pack_transition(
+                "main() -> bool",
+                "let b: [bool; 2] = [true, true]; let t: ([bool; 2], u8) = (b, 1u8); return t.0[0u32];",
+            );
+
+            assert_eq!(definition(&function, 0).0, "[bool; 2]");
+            assert_eq!(definition(&function, 1).0, "([bool; 2],u8)");
+        })
+    }
+
+    #[test]
+    fn test_preserves_struct_fields() {
+        create_session_if_not_set_then(|_| {
+            let functions = pack(
+                "struct Flags { bits: [bool; 2] }
+                transition main() -> Flags { let b: [bool; 2] = [true, false]; return Flags { bits: b }; }",
+            );
+
+            assert_eq!(definition(&functions[0], 0).0, "[bool; 2]");
+        })
+    }
+
+    #[test]
+    fn test_preserves_mapping_values() {
+        create_session_if_not_set_then(|_| {
+            let functions = pack(
+                "mapping m: u8 => [bool; 2];
+                transition main() { return then finalize(); }
+                finalize main() { let b: [bool; 2] = [true, false]; Mapping::set(m, 0u8, b); }",
+            );
+
+            let Statement::Definition(definition) = &functions[0].finalize.as_ref().unwrap().block.statements[0] else {
+                panic!("expected a definition in the finalize block");
+            };
+            assert_eq!(definition.type_.to_string(), "[bool; 2]");
+        })
+    }
+
+    #[test]
+    fn test_preserves_out_of_range_constant_index() {
+        create_session_if_not_set_then(|_| {
+            let function = pack_transition("main() -> bool", "let b: [bool; 2] = [true, false]; return b[5u32];");
+
+            assert_eq!(definition(&function, 0).0, "[bool; 2]");
+        })
+    }
+}
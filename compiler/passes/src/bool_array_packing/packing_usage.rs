@@ -0,0 +1,265 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BoolArrayPacker, NameCollector};
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The scope of a variable: `None` for the program-scope constants, and otherwise the name of the enclosing function
+/// and whether the variable is declared in its finalize block.
+pub(crate) type PackingScope = Option<(Symbol, bool)>;
+
+/// Decides which boolean arrays in a program scope can be packed, mapping each of them to the integer type it is
+/// packed into and its length.
+/// A variable can be packed if it is a definition or a constant of a boolean array of at most the threshold number of
+/// elements, and every use of it is supported by the packing:
+/// - an element read `a[i]` or an element write `a[i] = v;`, whose index is a constant less than the length of the
+///   array, or is free of calls, since it is also evaluated by the bounds check;
+/// - a definition or an assignment of the whole variable, whose value is an array literal, a ternary of such values,
+///   or another variable that is packed as well.
+///
+/// Any other use, for example passing the variable to a call, storing it in a struct, a tuple, or a mapping, or
+/// returning it, lets the array escape, so it is left intact. Function inputs are left intact as well.
+#[derive(Default)]
+pub(crate) struct PackingUsage {
+    /// The largest length of a boolean array that is packed.
+    threshold: usize,
+    /// The scope of the variables being declared and used.
+    scope: PackingScope,
+    /// The boolean arrays that may be packed, mapped to the integer type they are packed into and their lengths.
+    candidates: IndexMap<(PackingScope, Symbol), (IntegerType, usize)>,
+    /// The variables that are used in a way the packing does not support.
+    rejected: IndexSet<(PackingScope, Symbol)>,
+    /// The pairs of variables where the whole value of one is assigned to the other, so that both or neither must be
+    /// packed.
+    links: Vec<((PackingScope, Symbol), (PackingScope, Symbol))>,
+}
+
+impl PackingUsage {
+    /// Returns the variables of `input` that can be packed, mapped to the integer type they are packed into and their
+    /// lengths.
+    pub(crate) fn of_program_scope(
+        input: &ProgramScope,
+        threshold: usize,
+    ) -> IndexMap<(PackingScope, Symbol), (IntegerType, usize)> {
+        let mut usage = Self { threshold, ..Default::default() };
+        for (_, declaration) in input.consts.iter() {
+            usage.declare(declaration.place, &declaration.type_);
+        }
+        input.consts.iter().for_each(|(_, declaration)| usage.assign(declaration.place.name, &declaration.value));
+        for (_, function) in input.functions.iter() {
+            usage.scope = Some((function.name(), false));
+            usage.declare_inputs(&function.input);
+            usage.visit_block(&function.block);
+            if let Some(finalize) = &function.finalize {
+                usage.scope = Some((function.name(), true));
+                usage.declare_inputs(&finalize.input);
+                usage.visit_block(&finalize.block);
+            }
+        }
+
+        // A variable whose whole value flows to or from a rejected variable cannot be packed either.
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (left, right) in usage.links.iter() {
+                if usage.rejected.contains(left) != usage.rejected.contains(right) {
+                    usage.rejected.insert(*left);
+                    usage.rejected.insert(*right);
+                    changed = true;
+                }
+            }
+        }
+
+        let rejected = usage.rejected;
+        usage.candidates.into_iter().filter(|(variable, _)| !rejected.contains(variable)).collect()
+    }
+
+    /// Returns the smallest unsigned integer type covering `type_` and the length of `type_`, if `type_` is a boolean
+    /// array of at most `threshold` elements.
+    pub(crate) fn packed_type(type_: &Type, threshold: usize) -> Option<(IntegerType, usize)> {
+        match type_ {
+            Type::Array(array) if matches!(array.element_type(), Type::Boolean) && array.length() <= threshold => {
+                let integer_type = match array.length() {
+                    1..=8 => IntegerType::U8,
+                    9..=16 => IntegerType::U16,
+                    17..=32 => IntegerType::U32,
+                    33..=64 => IntegerType::U64,
+                    65..=128 => IntegerType::U128,
+                    _ => return None,
+                };
+                Some((integer_type, array.length()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Records `place` as a candidate if `type_` is a boolean array that may be packed.
+    fn declare(&mut self, place: Identifier, type_: &Type) {
+        if let Some(packed) = Self::packed_type(type_, self.threshold) {
+            self.candidates.insert((self.scope, place.name), packed);
+        }
+    }
+
+    /// Records the inputs of a function, which are never packed, since packing them would change its signature.
+    fn declare_inputs(&mut self, inputs: &[Input]) {
+        for input in inputs.iter() {
+            if let Input::Internal(input) = input {
+                self.declare(input.identifier, &input.type_);
+                self.rejected.insert((self.scope, input.identifier.name));
+            }
+        }
+    }
+
+    /// Returns the candidate referred to by `name` in the current scope, if any.
+    fn candidate(&self, name: Symbol) -> Option<(PackingScope, Symbol)> {
+        [(self.scope, name), (None, name)].into_iter().find(|variable| self.candidates.contains_key(variable))
+    }
+
+    /// Checks the index of an element access of the candidate `variable`, rejecting the candidate if the index is a
+    /// constant out of bounds, or if the index cannot be evaluated again by a bounds check.
+    fn index(&mut self, variable: (PackingScope, Symbol), index: &Expression) {
+        let length = self.candidates[&variable].1;
+        let supported = match BoolArrayPacker::constant_index(index) {
+            Some(index) => (index as usize) < length,
+            // The bounds check is a statement, so it cannot be placed in a program-scope constant.
+            None => self.scope.is_some() && !NameCollector::of_expression(index).has_calls,
+        };
+        if !supported {
+            self.rejected.insert(variable);
+        }
+        self.visit_expression(index, &());
+    }
+
+    /// Returns the candidate `a`, if `expression` is the identifier `a`.
+    fn candidate_of(&self, expression: &Expression) -> Option<(PackingScope, Symbol)> {
+        match expression {
+            Expression::Identifier(identifier) => self.candidate(identifier.name),
+            _ => None,
+        }
+    }
+
+    /// Returns the candidate accessed by `a[i]`, if `expression` is such an access of a candidate `a`.
+    fn accessed_candidate<'b>(&self, expression: &'b Expression) -> Option<((PackingScope, Symbol), &'b ArrayAccess)> {
+        match expression {
+            Expression::Access(AccessExpression::Array(access)) => match &*access.array {
+                Expression::Identifier(array) => self.candidate(array.name).map(|variable| (variable, access)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Checks a value assigned as a whole to the variable `name`, rejecting it if the value cannot be packed.
+    fn assign(&mut self, name: Symbol, value: &Expression) {
+        let Some(place) = self.candidate(name) else {
+            self.visit_expression(value, &());
+            return;
+        };
+        match value {
+            Expression::Identifier(other) if self.candidate(other.name).is_some() => {
+                self.links.push((place, self.candidate(other.name).unwrap()));
+            }
+            Expression::Array(_) => self.visit_expression(value, &()),
+            Expression::Ternary(ternary) => {
+                self.visit_expression(&ternary.condition, &());
+                self.assign(name, &ternary.if_true);
+                self.assign(name, &ternary.if_false);
+            }
+            _ => {
+                self.rejected.insert(place);
+                self.visit_expression(value, &());
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for PackingUsage {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            // An element read `a[i]` is rewritten to bit operations.
+            AccessExpression::Array(access) => match self.candidate_of(&access.array) {
+                Some(variable) => self.index(variable, &access.index),
+                None => {
+                    self.visit_expression(&access.array, additional);
+                    self.visit_expression(&access.index, additional);
+                }
+            },
+            AccessExpression::AssociatedFunction(function) => {
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    /// Any other use of a candidate lets it escape.
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        if let Some(variable) = self.candidate(input.name) {
+            self.rejected.insert(variable);
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for member in input.members.iter() {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                None => self.visit_identifier(&member.identifier, additional),
+            }
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for PackingUsage {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        match &input.place {
+            Expression::Identifier(place) => self.assign(place.name, &input.value),
+            // An element write `a[i] = v;` is rewritten to bit operations setting or clearing a bit of `a`.
+            place if self.accessed_candidate(place).is_some() => {
+                let (variable, access) = self.accessed_candidate(place).unwrap();
+                self.index(variable, &access.index);
+                self.visit_expression(&input.value, &());
+            }
+            place => {
+                self.visit_expression(place, &());
+                self.visit_expression(&input.value, &());
+            }
+        }
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.declare(input.place, &input.type_);
+        self.assign(input.place.name, &input.value);
+    }
+
+    /// Only definitions of a single variable are packed; the places of a tuple definition are left intact.
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        match &input.place {
+            Expression::Identifier(place) => {
+                self.declare(*place, &input.type_);
+                self.assign(place.name, &input.value);
+            }
+            _ => self.visit_expression(&input.value, &()),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// A compiler pass that checks that each call passes as many arguments as the callee declares inputs.
+/// This includes calls to functions in the same program, calls to functions in imported programs,
+/// calls to core functions, where the receiver of a method call like `m.get(k)` is counted as an argument,
+/// and invocations of finalize blocks.
+pub struct CallArityChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping from each program name and function name to the number of inputs the function declares.
+    arities: IndexMap<(Symbol, Symbol), usize>,
+    /// The name of the program that we are currently traversing.
+    program: Option<Symbol>,
+    /// The function that we are currently traversing.
+    function: Option<&'a Function>,
+}
+
+impl<'a> CallArityChecker<'a> {
+    /// Returns a new `CallArityChecker`, given the program whose calls are checked.
+    pub fn new(handler: &'a Handler, program: &Program) -> Self {
+        let mut arities = IndexMap::new();
+        Self::collect_arities(program, &mut arities);
+        Self { handler, arities, program: None, function: None }
+    }
+
+    /// Records the number of inputs of each function in `program` and its imports.
+    fn collect_arities(program: &Program, arities: &mut IndexMap<(Symbol, Symbol), usize>) {
+        program.imports.values().for_each(|(import, _)| Self::collect_arities(import, arities));
+        for (program_name, scope) in program.program_scopes.iter() {
+            for (function_name, function) in scope.functions.iter() {
+                arities.insert((*program_name, *function_name), function.input.len());
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for CallArityChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                if let Type::Identifier(type_) = &function.ty {
+                    if let Some(core_function) = CoreFunction::from_symbols(type_.name, function.name.name) {
+                        if core_function.num_args() != function.arguments.len() {
+                            self.handler.emit_err(TypeCheckerError::incorrect_num_args_to_call(
+                                core_function.num_args(),
+                                function.arguments.len(),
+                                function.span,
+                            ));
+                        }
+                    }
+                }
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        let program = match input.external.as_deref() {
+            Some(Expression::Identifier(program)) => Some(program.name),
+            Some(_) => None,
+            None => self.program,
+        };
+        if let (Some(program), Expression::Identifier(function)) = (program, &*input.function) {
+            // Unknown functions are reported by the type checker.
+            if let Some(arity) = self.arities.get(&(program, function.name)) {
+                if *arity != input.arguments.len() {
+                    self.handler.emit_err(TypeCheckerError::incorrect_num_args_to_call(
+                        arity,
+                        input.arguments.len(),
+                        input.span,
+                    ));
+                }
+            }
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for CallArityChecker<'a> {
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        if let (Some(arguments), Some(finalize)) =
+            (&input.finalize_arguments, self.function.and_then(|function| function.finalize.as_ref()))
+        {
+            if finalize.input.len() != arguments.len() {
+                self.handler.emit_err(TypeCheckerError::incorrect_num_args_to_finalize(
+                    finalize.input.len(),
+                    arguments.len(),
+                    input.span,
+                ));
+            }
+        }
+
+        self.visit_expression(&input.expression, &Default::default());
+        if let Some(arguments) = &input.finalize_arguments {
+            arguments.iter().for_each(|argument| self.visit_expression(argument, &Default::default()));
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for CallArityChecker<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.program = Some(input.program_id.name.name);
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+        input.consts.iter().for_each(|(_, declaration)| self.visit_const(declaration));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.function = Some(input);
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_block(&finalize.block);
+        }
+        self.function = None;
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Call Arity Checking pass traverses the AST and checks that each call passes exactly as many arguments
+//! as the callee declares inputs, reporting an error at the span of each offending call.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function add(a: u8, b: u8) -> u8 {
+//!     return a + b;
+//! }
+//!
+//! transition main(a: u8) -> u8 {
+//!     return add(a);
+//! }
+//! ```
+//!
+//! The call arity checking pass reports that `add(a)` expects `2` arguments, but got `1`.
+
+pub mod call_arity_checker;
+pub use call_arity_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for CallArityChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = CallArityChecker::new(handler, ast.as_repr());
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Checks a program calling `add(a: u8, b: u8)` with the given arguments, returning the number of errors.
+    fn check_call(arguments: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                function add(a: u8, b: u8) -> u8 {{
+                    return a + b;
+                }}
+
+                transition main(a: u8) -> u8 {{
+                    return add({arguments});
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let _ = CallArityChecker::do_pass((&ast, &handler));
+        handler.err_count()
+    }
+
+    #[test]
+    fn test_rejects_too_few_arguments() {
+        create_session_if_not_set_then(|_| assert_eq!(check_call("a"), 1))
+    }
+
+    #[test]
+    fn test_rejects_too_many_arguments() {
+        create_session_if_not_set_then(|_| assert_eq!(check_call("a, a, a"), 1))
+    }
+
+    #[test]
+    fn test_accepts_exact_arguments() {
+        create_session_if_not_set_then(|_| assert_eq!(check_call("a, 1u8"), 0))
+    }
+}
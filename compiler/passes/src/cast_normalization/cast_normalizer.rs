@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{RangeAnalyzer, TypeTable};
+
+use leo_ast::*;
+
+pub struct CastNormalizer<'a> {
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> CastNormalizer<'a> {
+    /// Initializes a new `CastNormalizer`.
+    pub fn new(type_table: &'a TypeTable, node_builder: &'a NodeBuilder) -> Self {
+        Self { type_table, node_builder }
+    }
+
+    /// Returns the integer type of the node `id`, if any.
+    fn integer_type(&self, id: &NodeID) -> Option<IntegerType> {
+        match self.type_table.get(id) {
+            Some(Type::Integer(integer_type)) => Some(integer_type),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if every value of type `from` is also a value of type `to`, so that casting cannot fail.
+    pub(crate) fn is_lossless(from: IntegerType, to: IntegerType) -> bool {
+        let bits = |integer_type| match integer_type {
+            IntegerType::U8 | IntegerType::I8 => 8,
+            IntegerType::U16 | IntegerType::I16 => 16,
+            IntegerType::U32 | IntegerType::I32 => 32,
+            IntegerType::U64 | IntegerType::I64 => 64,
+            IntegerType::U128 | IntegerType::I128 => 128,
+        };
+        match (from.is_signed(), to.is_signed()) {
+            (false, true) => bits(to) > bits(from),
+            (true, false) => false,
+            _ => bits(to) >= bits(from),
+        }
+    }
+
+    /// Returns the operand and the source type of a lossless integer cast to `target`.
+    fn lossless_cast(&self, input: &Expression, target: IntegerType) -> Option<(Expression, IntegerType)> {
+        match input {
+            Expression::Cast(cast) if cast.type_ == Type::Integer(target) => {
+                let source = self.integer_type(&cast.expression.id())?;
+                Self::is_lossless(source, target).then(|| (*cast.expression.clone(), source))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a new node ID, recording `type_` as the type of the node.
+    fn next_id(&self, type_: Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        id
+    }
+}
+
+impl ExpressionReconstructor for CastNormalizer<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let left = self.reconstruct_expression(*input.left).0;
+        let right = self.reconstruct_expression(*input.right).0;
+
+        // `(a as U) op (b as U)` becomes `(a op b) as U`, saving a cast, if `a op b` provably does not overflow the
+        // common type of `a` and `b`. Since both casts are lossless, the result is the same.
+        if let (BinaryOperation::Add | BinaryOperation::Sub | BinaryOperation::Mul, Some(target)) =
+            (input.op, self.integer_type(&input.id))
+        {
+            if let (Some((left, source)), Some((right, right_source))) =
+                (self.lossless_cast(&left, target), self.lossless_cast(&right, target))
+            {
+                if source == right_source
+                    && RangeAnalyzer::new(self.type_table).is_safe(input.op, &left, &right, &source)
+                {
+                    let operation = Expression::Binary(BinaryExpression {
+                        left: Box::new(left),
+                        right: Box::new(right),
+                        op: input.op,
+                        span: input.span,
+                        id: self.next_id(Type::Integer(source)),
+                    });
+                    return (
+                        Expression::Cast(CastExpression {
+                            expression: Box::new(operation),
+                            type_: Type::Integer(target),
+                            span: input.span,
+                            id: input.id,
+                        }),
+                        Default::default(),
+                    );
+                }
+            }
+        }
+
+        (
+            Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op: input.op,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        let expression = self.reconstruct_expression(*input.expression).0;
+
+        // `(x as U) as V` becomes `x as V` if the inner cast is lossless, since `x` then reaches the outer cast intact.
+        let expression = match (expression, self.integer_type(&input.id)) {
+            (Expression::Cast(inner), Some(_)) => match (&inner.type_, self.integer_type(&inner.expression.id())) {
+                (Type::Integer(middle), Some(source)) if Self::is_lossless(source, *middle) => *inner.expression,
+                _ => Expression::Cast(inner),
+            },
+            (expression, _) => expression,
+        };
+
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(expression),
+                type_: input.type_,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for CastNormalizer<'_> {}
+
+impl ProgramReconstructor for CastNormalizer<'_> {}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Cast Normalization pass traverses the AST and removes redundant integer casts without changing any result.
+//! A cast of a lossless cast, e.g. `(x as u32) as u64` where `x: u16`, is collapsed into a single cast `x as u64`.
+//! An addition, subtraction, or multiplication of two lossless casts of the same type, e.g. `(a as u32) * (b as u32)`
+//! where `a: u16` and `b: u16`, is computed in the narrower type and cast once, e.g. `(a * b) as u32`, but only if
+//! the range analysis proves that the narrower operation cannot overflow. Casts that may fail are never removed.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u16, b: u16) -> (u64, u32) {
+//!     return ((a as u32) as u64, (a as u32) + (b as u32));
+//! }
+//! ```
+//!
+//! The cast normalization pass produces the following code, leaving the addition as is since `a + b` may overflow.
+//! ```leo
+//! transition main(a: u16, b: u16) -> (u64, u32) {
+//!     return (a as u64, (a as u32) + (b as u32));
+//! }
+//! ```
+
+pub mod cast_normalizer;
+pub use cast_normalizer::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for CastNormalizer<'a> {
+    type Input = (Ast, &'a TypeTable, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, type_table, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = CastNormalizer::new(type_table, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::Statement;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and normalizes a transition with the given signature returning `expression`,
+    /// returning the normalized expression as a string.
+    fn normalize_return(signature: &str, expression: &str) -> String {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let type_table = TypeTable::default();
+        let source = format!(
+            "program test.aleo {{
+                transition {signature} {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+
+        let ast = CastNormalizer::do_pass((ast, &type_table, &node_builder)).unwrap();
+        let mut function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        match function.block.statements.pop() {
+            Some(Statement::Return(statement)) => statement.expression.to_string(),
+            statement => panic!("expected a return statement, found `{statement:?}`"),
+        }
+    }
+
+    #[test]
+    fn test_collapses_lossless_nested_cast() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(normalize_return("main(x: u16) -> u64", "(x as u32) as u64"), "(x as u64)");
+            assert_eq!(normalize_return("main(x: u32) -> u64", "(x as u16) as u64"), "((x as u16) as u64)");
+        })
+    }
+
+    #[test]
+    fn test_hoists_cast_only_without_overflow() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(
+                normalize_return("main(a: u16, b: u16) -> u32", "(a as u32) + (b as u32)"),
+                "(a as u32) + (b as u32)"
+            );
+            assert_eq!(normalize_return("main(a: u16) -> u32", "(a as u32) * (1u16 as u32)"), "(a * 1u16 as u32)");
+        })
+    }
+}
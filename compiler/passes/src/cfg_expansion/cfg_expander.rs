@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::sym;
+
+use indexmap::IndexSet;
+
+/// A compiler pass that removes the functions, structs, and statements whose `@cfg` annotations do not hold for the
+/// active features, and strips the `@cfg` annotations from the items and statements that are kept.
+pub struct CfgExpander<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The names of the active features.
+    features: &'a IndexSet<String>,
+}
+
+impl<'a> CfgExpander<'a> {
+    /// Returns a new `CfgExpander` for the given active features.
+    pub fn new(handler: &'a Handler, features: &'a IndexSet<String>) -> Self {
+        Self { handler, features }
+    }
+
+    /// Returns the annotations without their `@cfg` annotations, or `None` if any `@cfg` annotation does not hold.
+    /// An item whose `@cfg` annotation cannot be evaluated is kept, after reporting the error.
+    fn expand(&self, annotations: Vec<Annotation>) -> Option<Vec<Annotation>> {
+        let mut enabled = true;
+        let mut kept = Vec::with_capacity(annotations.len());
+        for annotation in annotations {
+            if annotation.identifier.name != sym::cfg {
+                kept.push(annotation);
+                continue;
+            }
+            if annotation.arguments.is_empty() {
+                self.handler.emit_err(StaticAnalyzerError::cfg_without_predicate(annotation.span));
+            }
+            for predicate in annotation.arguments.iter() {
+                match self.evaluate_argument(predicate) {
+                    Some(holds) => enabled &= holds,
+                    None => self
+                        .handler
+                        .emit_err(StaticAnalyzerError::unsupported_cfg_predicate(predicate, predicate.span())),
+                }
+            }
+        }
+        enabled.then_some(kept)
+    }
+
+    /// Evaluates an argument of a `@cfg` annotation, returning `None` if it is neither the named argument
+    /// `feature = "name"` nor a predicate.
+    fn evaluate_argument(&self, argument: &AnnotationArgument) -> Option<bool> {
+        match argument {
+            AnnotationArgument::Named(key, Expression::Literal(Literal::String(name, ..)))
+                if key.name == sym::feature =>
+            {
+                Some(self.features.contains(name))
+            }
+            AnnotationArgument::Named(..) => None,
+            AnnotationArgument::Positional(predicate) => self.evaluate(predicate),
+        }
+    }
+
+    /// Evaluates a `@cfg` predicate, returning `None` if it is not of the form `feature == "name"`,
+    /// possibly combined with `!`, `&&`, and `||`.
+    fn evaluate(&self, predicate: &Expression) -> Option<bool> {
+        match predicate {
+            Expression::Binary(binary) => match (binary.op, &*binary.left, &*binary.right) {
+                (BinaryOperation::Eq, Expression::Identifier(key), Expression::Literal(Literal::String(name, ..)))
+                    if key.name == sym::feature =>
+                {
+                    Some(self.features.contains(name))
+                }
+                (BinaryOperation::And, left, right) => Some(self.evaluate(left)? & self.evaluate(right)?),
+                (BinaryOperation::Or, left, right) => Some(self.evaluate(left)? | self.evaluate(right)?),
+                _ => None,
+            },
+            Expression::Unary(unary) if unary.op == UnaryOperation::Not => {
+                self.evaluate(&unary.receiver).map(|holds| !holds)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ExpressionReconstructor for CfgExpander<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for CfgExpander<'_> {
+    /// Removes the statements whose `@cfg` annotations do not hold from the block, and unwraps the kept statements
+    /// that have no other annotations.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        let statements = input
+            .statements
+            .into_iter()
+            .filter_map(|statement| match statement {
+                Statement::Annotated(annotated) => {
+                    let annotations = self.expand(annotated.annotations)?;
+                    let statement = self.reconstruct_statement(*annotated.statement).0;
+                    if annotations.is_empty() {
+                        Some(statement)
+                    } else {
+                        Some(Statement::Annotated(AnnotatedStatement {
+                            annotations,
+                            statement: Box::new(statement),
+                            span: annotated.span,
+                            id: annotated.id,
+                        }))
+                    }
+                }
+                statement => Some(self.reconstruct_statement(statement).0),
+            })
+            .collect();
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+}
+
+impl ProgramReconstructor for CfgExpander<'_> {
+    /// Removes the structs and functions whose `@cfg` annotations do not hold, before reconstructing the scope.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        ProgramScope {
+            program_id: input.program_id,
+            structs: input
+                .structs
+                .into_iter()
+                .filter_map(|(i, mut struct_)| {
+                    struct_.annotations = self.expand(std::mem::take(&mut struct_.annotations))?;
+                    Some((i, self.reconstruct_struct(struct_)))
+                })
+                .collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input
+                .functions
+                .into_iter()
+                .filter_map(|(i, mut function)| {
+                    function.annotations = self.expand(std::mem::take(&mut function.annotations))?;
+                    Some((i, self.reconstruct_function(function)))
+                })
+                .collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+}
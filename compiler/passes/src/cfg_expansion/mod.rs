@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Cfg Expansion pass traverses the AST and evaluates the `@cfg` annotations on functions, structs, and statements
+//! against a set of active features, e.g. `@cfg(feature = "x")`.
+//! An item whose `@cfg` annotations do not all hold is removed, as is a statement from its enclosing block, and the
+//! `@cfg` annotations of the kept items and statements are stripped, so that later passes do not see them.
+//! Each argument must hold: either the named argument `feature = "name"`, which holds if `name` is an active feature,
+//! or a predicate, which is either `feature == "name"` or a combination of predicates with `!`, `&&`, and `||`.
+//! Any other argument is reported as an error.
+//!
+//! Consider the following Leo code, compiled without the feature `debug`.
+//! ```leo
+//! @cfg(feature = "debug")
+//! function dump(a: u8) -> u8 {
+//!     return a;
+//! }
+//!
+//! transition main(a: u8) -> u8 {
+//!     @cfg(feature = "debug")
+//!     a = dump(a);
+//!     return a;
+//! }
+//! ```
+//!
+//! The cfg expansion pass removes the function `dump` and the assignment in `main`.
+
+pub mod cfg_expander;
+pub use cfg_expander::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+use indexmap::IndexSet;
+
+impl<'a> Pass for CfgExpander<'a> {
+    type Input = (Ast, &'a Handler, &'a IndexSet<String>);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, features): Self::Input) -> Self::Output {
+        let mut reconstructor = CfgExpander::new(handler, features);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{NodeBuilder, ProgramScope, Statement};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses a program with the helper `dump` annotated with the given annotations,
+    /// and expands it with the feature `debug` active.
+    fn expand(annotations: &str) -> Result<ProgramScope> {
+        let handler = Handler::default();
+        let source = format!(
+            "program test.aleo {{
+                {annotations}
+                function dump(a: u8) -> u8 {{
+                    return a;
+                }}
+
+                transition main(a: u8) -> u8 {{
+                    return a;
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let features = IndexSet::from(["debug".to_string()]);
+        let ast = CfgExpander::do_pass((ast, &handler, &features))?;
+        Ok(ast.into_repr().program_scopes.into_values().next().unwrap())
+    }
+
+    #[test]
+    fn test_removes_disabled_item() {
+        create_session_if_not_set_then(|_| {
+            let scope = expand("@cfg(feature = \"release\")").unwrap();
+
+            assert_eq!(scope.functions.len(), 1);
+            assert_eq!(scope.functions[0].1.identifier.to_string(), "main");
+        })
+    }
+
+    #[test]
+    fn test_keeps_enabled_item_without_cfg() {
+        create_session_if_not_set_then(|_| {
+            let scope = expand("@cfg(feature == \"debug\" && !(feature == \"release\")) @deprecated").unwrap();
+
+            assert_eq!(scope.functions.len(), 2);
+            let annotations = &scope.functions[0].1.annotations;
+            assert_eq!(annotations.len(), 1);
+            assert_eq!(annotations[0].to_string(), "@deprecated");
+        })
+    }
+
+    #[test]
+    fn test_rejects_unsupported_predicate() {
+        create_session_if_not_set_then(|_| {
+            assert!(expand("@cfg(debug)").is_err());
+            assert!(expand("@cfg(target = \"debug\")").is_err());
+        })
+    }
+
+    #[test]
+    fn test_filters_annotated_statements() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let source = "program test.aleo {
+                transition main(a: u8) -> u8 {
+                    @cfg(feature = \"debug\")
+                    a = a + 1u8;
+                    if a > 1u8 {
+                        @cfg(feature = \"release\")
+                        a = a + 2u8;
+                    }
+                    return a;
+                }
+            }";
+            let ast = parse_program(&handler, &NodeBuilder::default(), source);
+            let features = IndexSet::from(["debug".to_string()]);
+            let ast = CfgExpander::do_pass((ast, &handler, &features)).unwrap();
+            let scope = ast.into_repr().program_scopes.into_values().next().unwrap();
+
+            let statements = &scope.functions[0].1.block.statements;
+            assert_eq!(statements.len(), 3);
+            assert!(matches!(&statements[0], Statement::Assign(assign) if assign.value.to_string() == "a + 1u8"));
+            let Statement::Conditional(conditional) = &statements[1] else {
+                panic!("expected a conditional, found `{}`", statements[1]);
+            };
+            assert!(conditional.then.statements.is_empty());
+        })
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Assigner;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+pub struct ChainedComparisonDesugarer<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+    /// A mapping from each function name to its output type.
+    pub(crate) outputs: IndexMap<Symbol, Type>,
+    /// A mapping from each program-scope constant to its declared type.
+    pub(crate) globals: IndexMap<Symbol, Type>,
+    /// A mapping from each variable in scope to its declared type.
+    pub(crate) variables: IndexMap<Symbol, Type>,
+    /// The statements that must be inserted before the statement currently being reconstructed.
+    pub(crate) statements: Vec<Statement>,
+}
+
+impl<'a> ChainedComparisonDesugarer<'a> {
+    /// Initializes a new `ChainedComparisonDesugarer`.
+    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, assigner: &'a Assigner) -> Self {
+        Self {
+            handler,
+            node_builder,
+            assigner,
+            outputs: Default::default(),
+            globals: Default::default(),
+            variables: Default::default(),
+            statements: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the operation is `<`, `<=`, `>`, or `>=`.
+    fn is_ordering(op: BinaryOperation) -> bool {
+        matches!(op, BinaryOperation::Lt | BinaryOperation::Lte | BinaryOperation::Gt | BinaryOperation::Gte)
+    }
+
+    /// Returns `true` if the expression is a comparison whose left operand is itself a comparison, e.g. `(a < b) < c`.
+    fn is_chain(input: &BinaryExpression) -> bool {
+        Self::is_ordering(input.op) && matches!(&*input.left, Expression::Binary(left) if Self::is_ordering(left.op))
+    }
+
+    /// Splits the chain `a op1 b op2 c ..` into its operands `[a, b, c, ..]` and its operations `[op1, op2, ..]`.
+    fn flatten(input: Expression, operands: &mut Vec<Expression>, operations: &mut Vec<(BinaryOperation, Span)>) {
+        match input {
+            Expression::Binary(binary) if Self::is_ordering(binary.op) => {
+                Self::flatten(*binary.left, operands, operations);
+                operations.push((binary.op, binary.span));
+                operands.push(*binary.right);
+            }
+            operand => operands.push(operand),
+        }
+    }
+
+    /// Records the declared type of each variable in `place`, which has the type `type_`.
+    fn declare(&mut self, place: &Expression, type_: &Type) {
+        match (place, type_) {
+            (Expression::Identifier(identifier), type_) => {
+                self.variables.insert(identifier.name, type_.clone());
+            }
+            (Expression::Tuple(tuple), Type::Tuple(tuple_type)) if tuple.elements.len() == tuple_type.length() => {
+                tuple.elements.iter().zip(tuple_type.elements()).for_each(|(place, type_)| self.declare(place, type_))
+            }
+            _ => {}
+        }
+    }
+
+    /// Infers the type of an operand from its syntax and the declared types of variables and functions,
+    /// returning `None` if it cannot be inferred.
+    fn type_of(&self, input: &Expression) -> Option<Type> {
+        match input {
+            Expression::Ascription(ascription) => Some(ascription.type_.clone()),
+            Expression::Binary(binary) => match binary.op {
+                BinaryOperation::And
+                | BinaryOperation::Eq
+                | BinaryOperation::Gt
+                | BinaryOperation::Gte
+                | BinaryOperation::Lt
+                | BinaryOperation::Lte
+                | BinaryOperation::Nand
+                | BinaryOperation::Neq
+                | BinaryOperation::Nor
+                | BinaryOperation::Or => Some(Type::Boolean),
+                // The right operand of a power or a shift may have a different type, e.g. `a ** 2u32` for `a: u8`.
+                BinaryOperation::Pow
+                | BinaryOperation::PowWrapped
+                | BinaryOperation::Shl
+                | BinaryOperation::ShlWrapped
+                | BinaryOperation::Shr
+                | BinaryOperation::ShrWrapped => self.type_of(&binary.left),
+                _ => self.type_of(&binary.left).or_else(|| self.type_of(&binary.right)),
+            },
+            Expression::Call(call) => match (&*call.function, &call.external) {
+                (Expression::Identifier(function), None) => self.outputs.get(&function.name).cloned(),
+                _ => None,
+            },
+            Expression::Cast(cast) => Some(cast.type_.clone()),
+            Expression::Identifier(identifier) => self.variables.get(&identifier.name).cloned(),
+            Expression::Literal(literal) => Some(match literal {
+                Literal::Address(..) => Type::Address,
+                Literal::Boolean(..) => Type::Boolean,
+                Literal::Field(..) => Type::Field,
+                Literal::Group(..) => Type::Group,
+                Literal::Integer(integer_type, ..) => Type::Integer(*integer_type),
+                Literal::Scalar(..) => Type::Scalar,
+                Literal::String(..) => Type::String,
+            }),
+            Expression::Ternary(ternary) => self.type_of(&ternary.if_true).or_else(|| self.type_of(&ternary.if_false)),
+            Expression::Unary(unary) => match unary.op {
+                UnaryOperation::ToXCoordinate | UnaryOperation::ToYCoordinate => Some(Type::Field),
+                _ => self.type_of(&unary.receiver),
+            },
+            _ => None,
+        }
+    }
+
+    /// Binds an operand to a new variable, so that it is evaluated only once and before the operands that follow it.
+    /// Variables and literals are returned as is, since evaluating them has no effect.
+    fn bind(&mut self, operand: Expression) -> Expression {
+        if matches!(operand, Expression::Identifier(_) | Expression::Literal(_)) {
+            return operand;
+        }
+        match self.type_of(&operand) {
+            Some(type_) => {
+                let name = self.assigner.unique_symbol("$var", "$");
+                let span = operand.span();
+                self.statements.push(Statement::Definition(DefinitionStatement {
+                    declaration_type: DeclarationType::Let,
+                    place: Expression::Identifier(Identifier { name, span, id: self.node_builder.next_id() }),
+                    type_: type_.clone(),
+                    value: operand,
+                    span,
+                    id: self.node_builder.next_id(),
+                }));
+                self.variables.insert(name, type_);
+                Expression::Identifier(Identifier { name, span, id: self.node_builder.next_id() })
+            }
+            None => {
+                self.handler
+                    .emit_err(StaticAnalyzerError::chained_comparison_operand_type_unknown(&operand, operand.span()));
+                operand
+            }
+        }
+    }
+
+    /// Returns a copy of an operand shared by two comparisons, with a fresh node ID.
+    fn copy(&self, operand: &Expression) -> Expression {
+        let mut copy = operand.clone();
+        copy.set_id(self.node_builder.next_id());
+        copy
+    }
+}
+
+impl ExpressionReconstructor for ChainedComparisonDesugarer<'_> {
+    type AdditionalOutput = ();
+
+    /// Desugars the chain `a op1 b op2 c` into `a op1 b && b op2 c`.
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        if !Self::is_chain(&input) {
+            return (
+                Expression::Binary(BinaryExpression {
+                    left: Box::new(self.reconstruct_expression(*input.left).0),
+                    right: Box::new(self.reconstruct_expression(*input.right).0),
+                    op: input.op,
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            );
+        }
+
+        let (span, id) = (input.span, input.id);
+        let mut operands = Vec::new();
+        let mut operations = Vec::new();
+        Self::flatten(Expression::Binary(input), &mut operands, &mut operations);
+
+        // Each operand other than the first and the last is shared by two comparisons, so it is bound to a variable
+        // unless it is a variable or a literal. The operands before a bound operand are bound too, so that the
+        // operands are still evaluated in source order.
+        let last = operands.len() - 1;
+        let bound = operands[1..last]
+            .iter()
+            .rposition(|operand| !matches!(operand, Expression::Identifier(_) | Expression::Literal(_)))
+            .map_or(0, |index| index + 2);
+        let mut operands = operands
+            .into_iter()
+            .enumerate()
+            .map(|(index, operand)| {
+                let operand = self.reconstruct_expression(operand).0;
+                if index < bound {
+                    self.bind(operand)
+                } else {
+                    operand
+                }
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+        let mut left = operands.next();
+        let mut comparisons = Vec::with_capacity(operations.len());
+        for (index, ((op, span), right)) in operations.into_iter().zip(operands).enumerate() {
+            let next = (index + 1 < last).then(|| self.copy(&right));
+            comparisons.push(Expression::Binary(BinaryExpression {
+                left: Box::new(left.take().expect("Each comparison has a left operand.")),
+                right: Box::new(right),
+                op,
+                span,
+                id: self.node_builder.next_id(),
+            }));
+            left = next;
+        }
+
+        let mut conjunction = comparisons
+            .into_iter()
+            .reduce(|left, right| {
+                Expression::Binary(BinaryExpression {
+                    span: left.span() + right.span(),
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    op: BinaryOperation::And,
+                    id: self.node_builder.next_id(),
+                })
+            })
+            .expect("A chain has at least two comparisons.");
+        conjunction.set_span(span);
+        conjunction.set_id(id);
+        (conjunction, Default::default())
+    }
+}
+
+impl StatementReconstructor for ChainedComparisonDesugarer<'_> {
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        let outer = std::mem::take(&mut self.statements);
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            match &statement {
+                Statement::Const(declaration) => {
+                    self.variables.insert(declaration.place.name, declaration.type_.clone());
+                }
+                Statement::Definition(definition) => self.declare(&definition.place, &definition.type_),
+                Statement::Iteration(iteration) => {
+                    self.variables.insert(iteration.variable.name, iteration.type_.clone());
+                }
+                _ => {}
+            }
+            let statement = self.reconstruct_statement(statement).0;
+            statements.append(&mut self.statements);
+            statements.push(statement);
+        }
+        self.statements = outer;
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    /// Reconstructs the conditional statement, keeping the definitions of an `else if` condition inside the `else`
+    /// branch.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let condition = self.reconstruct_expression(input.condition).0;
+        let then = self.reconstruct_block(input.then).0;
+        let otherwise = input.otherwise.map(|otherwise| match *otherwise {
+            Statement::Block(block) => Box::new(Statement::Block(self.reconstruct_block(block).0)),
+            otherwise => {
+                let span = otherwise.span();
+                let mut block = self
+                    .reconstruct_block(Block { statements: vec![otherwise], span, id: self.node_builder.next_id() })
+                    .0;
+                match block.statements.len() {
+                    1 => Box::new(block.statements.pop().unwrap()),
+                    _ => Box::new(Statement::Block(block)),
+                }
+            }
+        });
+
+        (
+            Statement::Conditional(ConditionalStatement { condition, then, otherwise, span: input.span, id: input.id }),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for ChainedComparisonDesugarer<'_> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        self.outputs = input.functions.iter().map(|(name, function)| (*name, function.output_type.clone())).collect();
+        self.globals = input.consts.iter().map(|(name, declaration)| (*name, declaration.type_.clone())).collect();
+
+        ProgramScope {
+            program_id: input.program_id,
+            structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            consts: input.consts,
+            span: input.span,
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        self.variables = self.globals.clone();
+        self.variables.extend(input.input.iter().map(|input| (input.identifier().name, input.type_())));
+        let block = self.reconstruct_block(input.block).0;
+
+        let finalize = input.finalize.map(|finalize| {
+            self.variables = self.globals.clone();
+            self.variables.extend(finalize.input.iter().map(|input| (input.identifier().name, input.type_())));
+            Finalize { block: self.reconstruct_block(finalize.block).0, ..finalize }
+        });
+
+        Function { block, finalize, ..input }
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Chained Comparison Desugaring pass traverses the AST and rewrites chained comparisons into conjunctions,
+//! e.g. `a < b < c` into `a < b && b < c`.
+//! A chain is a comparison whose left operand is itself a comparison, which is how a chain like `a < b < c` is
+//! represented in the AST, i.e. `(a < b) < c`.
+//! Since booleans cannot be ordered, such a comparison is otherwise invalid.
+//! An operand shared by two comparisons is bound to a new variable, unless it is a variable or a literal, so that it
+//! is evaluated only once. The operands before it are bound as well, so that the operands are evaluated in source
+//! order. The type of a bound operand is inferred from its syntax, and an error is reported if it cannot be inferred.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8, c: u8) -> bool {
+//!     return (a < f(b)) <= c;
+//! }
+//! ```
+//!
+//! The chained comparison desugaring pass produces the following code.
+//! ```leo
+//! transition main(a: u8, b: u8, c: u8) -> bool {
+//!     let $var$0: u8 = f(b);
+//!     return a < $var$0 && $var$0 <= c;
+//! }
+//! ```
+
+pub mod chained_comparison_desugarer;
+pub use chained_comparison_desugarer::*;
+
+use crate::{Assigner, Pass};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ChainedComparisonDesugarer<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, &'a Assigner);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder, assigner): Self::Input) -> Self::Output {
+        let mut reconstructor = ChainedComparisonDesugarer::new(handler, node_builder, assigner);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and desugars a transition that returns `expression`, returning its statements as strings.
+    fn desugar(expression: &str) -> Vec<String> {
+        let handler = Handler::default();
+        let ast = desugar_with(&handler, expression).unwrap();
+        let function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        function.block.statements.iter().map(|statement| statement.to_string()).collect()
+    }
+
+    /// Parses and desugars a transition that returns `expression`, returning the number of errors.
+    fn desugar_errors(expression: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let _ = desugar_with(&handler, expression);
+        handler.err_count()
+    }
+
+    /// Parses and desugars a transition that returns `expression` with the given handler.
+    fn desugar_with(handler: &Handler, expression: &str) -> Result<Ast> {
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                function f(a: u8) -> u8 {{
+                    return a;
+                }}
+
+                transition main(a: u8, b: u8, c: u8) -> bool {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = parse_program(handler, &node_builder, &source);
+        ChainedComparisonDesugarer::do_pass((ast, handler, &node_builder, &Assigner::default()))
+    }
+
+    #[test]
+    fn test_desugars_chained_comparison() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(desugar("(a < b) < c"), vec!["return a < b && b < c"]);
+        })
+    }
+
+    #[test]
+    fn test_binds_side_effecting_operand() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(desugar("(a < f(b)) <= c"), vec!["let $var$0: u8 = f(b);", "return a < $var$0 && $var$0 <= c"]);
+        })
+    }
+
+    #[test]
+    fn test_binds_operands_in_source_order() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(desugar("((f(a) < f(b)) < c) < f(c)"), vec![
+                "let $var$0: u8 = f(a);",
+                "let $var$1: u8 = f(b);",
+                "return $var$0 < $var$1 && $var$1 < c && c < f(c)"
+            ]);
+        })
+    }
+
+    #[test]
+    fn test_types_shift_by_its_left_operand() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(desugar("(a < (f(b) << 1u32)) < c"), vec![
+                "let $var$0: u8 = f(b) << 1u32;",
+                "return a < $var$0 && $var$0 < c"
+            ]);
+        })
+    }
+
+    #[test]
+    fn test_rejects_power_of_unknown_type() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(desugar_errors("(a < ((f(b), c).0 ** 2u8)) < c"), 1);
+        })
+    }
+
+    #[test]
+    fn test_preserves_single_comparison() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(desugar("a < b"), vec!["return a < b"]);
+        })
+    }
+}
@@ -0,0 +1,283 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+#[derive(Default)]
+pub struct CircuitDeduplicator {
+    /// A mapping from each merged circuit to the circuit it was merged into.
+    pub(crate) merged: IndexMap<Symbol, Symbol>,
+}
+
+impl CircuitDeduplicator {
+    /// Returns `true` if the circuit was synthesized by the compiler, i.e. if its name cannot be written by the user.
+    pub(crate) fn is_synthesized(name: Symbol) -> bool {
+        name.to_string().contains('$')
+    }
+
+    /// Returns the circuit that `name` was merged into, or `name` if it was not merged.
+    fn canonical(&self, mut name: Symbol) -> Symbol {
+        while let Some(canonical) = self.merged.get(&name) {
+            name = *canonical;
+        }
+        name
+    }
+
+    /// Returns the identifier of the circuit that `identifier` was merged into.
+    fn identifier(&self, identifier: Identifier) -> Identifier {
+        Identifier { name: self.canonical(identifier.name), ..identifier }
+    }
+
+    /// Returns the type with each merged circuit replaced by the circuit it was merged into.
+    fn type_(&self, type_: Type) -> Type {
+        match type_ {
+            Type::Array(array_type) => Type::Array(ArrayType::new(
+                self.type_(array_type.element_type().clone()),
+                NonNegativeNumber::from(array_type.length()),
+            )),
+            Type::Identifier(identifier) => Type::Identifier(self.identifier(identifier)),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.type_(*mapping_type.key)),
+                value: Box::new(self.type_(*mapping_type.value)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| self.type_(type_.clone())).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+
+    /// Merges the synthesized circuits of `structs` that have the same members, modes, and member types, into the
+    /// first of them. Merging circuits may make the circuits that contain them identical, so this is repeated until
+    /// no more circuits are merged.
+    fn merge(&mut self, structs: &[(Symbol, Struct)]) {
+        loop {
+            let mut shapes: Vec<(Vec<(Mode, Symbol, Type)>, Symbol)> = Vec::new();
+            let merged = self.merged.len();
+            for (name, circuit) in structs {
+                if circuit.is_record || !Self::is_synthesized(*name) || self.merged.contains_key(name) {
+                    continue;
+                }
+                let shape = circuit
+                    .members
+                    .iter()
+                    .map(|member| (member.mode, member.name(), self.type_(member.type_.clone())))
+                    .collect::<Vec<_>>();
+                match shapes.iter().find(|(other, _)| *other == shape) {
+                    Some((_, canonical)) => {
+                        self.merged.insert(*name, *canonical);
+                    }
+                    None => shapes.push((shape, *name)),
+                }
+            }
+            if self.merged.len() == merged {
+                break;
+            }
+        }
+    }
+
+    /// Returns the function input with each merged circuit in its type replaced.
+    fn input(&self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput { type_: self.type_(input.type_), ..input }),
+            input => input,
+        }
+    }
+
+    /// Returns the function output with each merged circuit in its type replaced.
+    fn output(&self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => Output::Internal(FunctionOutput { type_: self.type_(output.type_), ..output }),
+            output => output,
+        }
+    }
+}
+
+impl ExpressionReconstructor for CircuitDeduplicator {
+    type AdditionalOutput = ();
+
+    fn reconstruct_ascription(&mut self, input: AscriptionExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Ascription(AscriptionExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.type_(input.type_),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.type_(input.type_),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: self.identifier(input.name),
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: match member.expression {
+                            Some(expression) => Some(self.reconstruct_expression(expression).0),
+                            None => Some(self.reconstruct_expression(Expression::Identifier(member.identifier)).0),
+                        },
+                        span: member.span,
+                        id: member.id,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for CircuitDeduplicator {
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Const(ConstDeclaration {
+                place: input.place,
+                type_: self.type_(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: input.place,
+                type_: self.type_(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Iteration(Box::new(IterationStatement {
+                variable: input.variable,
+                type_: self.type_(input.type_),
+                start: self.reconstruct_expression(input.start).0,
+                start_value: input.start_value,
+                stop: self.reconstruct_expression(input.stop).0,
+                stop_value: input.stop_value,
+                block: self.reconstruct_block(input.block).0,
+                inclusive: input.inclusive,
+                step: input.step.map(|step| self.reconstruct_expression(step).0),
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for CircuitDeduplicator {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        self.merged.clear();
+        self.merge(&input.structs);
+
+        ProgramScope {
+            program_id: input.program_id,
+            structs: input
+                .structs
+                .into_iter()
+                .filter(|(name, _)| !self.merged.contains_key(name))
+                .map(|(i, c)| (i, self.reconstruct_struct(c)))
+                .collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input.into_iter().map(|input| self.input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.output(output)).collect(),
+            output_type: self.type_(input.output_type),
+            const_predicates: input
+                .const_predicates
+                .into_iter()
+                .map(|predicate| self.reconstruct_function_predicate(predicate))
+                .collect(),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input.into_iter().map(|input| self.input(input)).collect(),
+                output: finalize.output.into_iter().map(|output| self.output(output)).collect(),
+                output_type: self.type_(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }),
+            span: input.span,
+            id: input.id,
+        }
+    }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member { type_: self.type_(member.type_), ..member })
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping { key_type: self.type_(input.key_type), value_type: self.type_(input.value_type), ..input }
+    }
+}
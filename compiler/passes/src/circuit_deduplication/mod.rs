@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Circuit Deduplication pass traverses the AST and merges the circuits synthesized by the compiler that have the
+//! same structure, i.e. the same members, in the same order, with the same modes and types.
+//! Synthesized circuits are recognized by their names, which contain a `$` and so cannot be written by the user.
+//! Each group of identical synthesized circuits is merged into its first circuit, and every reference to the others,
+//! in types and in circuit initializers, is rewired to it.
+//! Since merging circuits can make the circuits that contain them identical, merging is repeated until nothing changes.
+//! Circuits named by the user, and records, are never merged, even if they are identical to another circuit.
+//!
+//! Consider the following circuits, where `$tuple$0` and `$tuple$1` were synthesized by an earlier pass.
+//! ```leo
+//! struct Pair { a: u8, b: u8 }
+//! struct $tuple$0 { a: u8, b: u8 }
+//! struct $tuple$1 { a: u8, b: u8 }
+//!
+//! transition main(x: $tuple$0, y: $tuple$1, z: Pair) -> u8 {
+//!     return x.a + y.b + z.a;
+//! }
+//! ```
+//!
+//! The circuit deduplication pass removes `$tuple$1`, and gives `y` the type `$tuple$0`; `Pair` is left as is.
+
+pub mod circuit_deduplicator;
+pub use circuit_deduplicator::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for CircuitDeduplicator {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let program = CircuitDeduplicator::default().reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Input, NodeBuilder, Type};
+    use leo_errors::emitter::Handler;
+    use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+    #[test]
+    fn test_merges_identical_synthesized_circuits() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let source = "program test.aleo {
+                struct Pair { a: u8, b: u8 }
+                struct T0 { a: u8, b: u8 }
+                struct T1 { a: u8, b: u8 }
+
+                transition main(x: T0, y: T1, z: Pair) -> u8 {
+                    return x.a + y.b + z.a;
+                }
+            }";
+            let mut program = parse_program(&handler, &NodeBuilder::default(), source).into_repr();
+
+            // Users cannot name circuits with a `$`, so the synthesized circuits are renamed after parsing.
+            let synthesized = |name: Symbol| match name.to_string().as_str() {
+                "T0" => Symbol::intern("$tuple$0"),
+                "T1" => Symbol::intern("$tuple$1"),
+                _ => name,
+            };
+            let scope = program.program_scopes.values_mut().next().unwrap();
+            for (name, circuit) in scope.structs.iter_mut() {
+                *name = synthesized(*name);
+                circuit.identifier.name = synthesized(circuit.identifier.name);
+            }
+            for input in scope.functions[0].1.input.iter_mut() {
+                if let Input::Internal(input) = input {
+                    if let Type::Identifier(identifier) = &mut input.type_ {
+                        identifier.name = synthesized(identifier.name);
+                    }
+                }
+            }
+
+            let program = CircuitDeduplicator::do_pass(Ast::new(program)).unwrap().into_repr();
+            let scope = program.program_scopes.into_values().next().unwrap();
+            let structs = scope.structs.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>();
+            assert_eq!(structs, vec!["Pair", "$tuple$0"]);
+            let inputs = scope.functions[0].1.input.iter().map(|input| input.type_().to_string()).collect::<Vec<_>>();
+            assert_eq!(inputs, vec!["$tuple$0", "$tuple$0", "Pair"]);
+        })
+    }
+}
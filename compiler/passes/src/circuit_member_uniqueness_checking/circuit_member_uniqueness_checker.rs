@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// A compiler pass that checks that the members of each struct and record have distinct names.
+pub struct CircuitMemberUniquenessChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> CircuitMemberUniquenessChecker<'a> {
+    /// Returns a new `CircuitMemberUniquenessChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for CircuitMemberUniquenessChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for CircuitMemberUniquenessChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for CircuitMemberUniquenessChecker<'a> {
+    fn visit_struct(&mut self, input: &'a Struct) {
+        // Maps each member name to the span of its first declaration.
+        let mut declared: IndexMap<Symbol, Span> = IndexMap::new();
+        input.members.iter().for_each(|member| match declared.get(&member.name()) {
+            Some(previous) => self.handler.emit_err(StaticAnalyzerError::duplicate_member(
+                input.identifier,
+                member.identifier,
+                previous,
+                member.span,
+            )),
+            None => {
+                declared.insert(member.name(), member.span);
+            }
+        });
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Circuit Member Uniqueness Checking pass traverses the AST and checks that no struct or record declares
+//! two members with the same name. Each duplicate is reported at its own span, and the error message points to
+//! the span of the first declaration. Structs in Leo have no methods, so only fields can collide.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! struct Point {
+//!     x: u8,
+//!     x: u16,
+//! }
+//! ```
+//!
+//! The circuit member uniqueness checking pass reports an error for the second `x`.
+
+pub mod circuit_member_uniqueness_checker;
+pub use circuit_member_uniqueness_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for CircuitMemberUniquenessChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = CircuitMemberUniquenessChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a program with the given items.
+    fn check_items(items: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!("program test.aleo {{ {items} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        CircuitMemberUniquenessChecker::do_pass((&ast, &handler))
+    }
+
+    #[test]
+    fn test_rejects_duplicate_fields() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_items("struct Point { x: u8, x: u8 }").is_err());
+        })
+    }
+
+    #[test]
+    fn test_rejects_duplicate_fields_of_different_types() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_items("record Token { owner: address, amount: u64, amount: u32 }").is_err());
+        })
+    }
+
+    #[test]
+    fn test_accepts_distinct_members() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_items("struct Point { x: u8, y: u8, z: u8 }").is_ok());
+        })
+    }
+}
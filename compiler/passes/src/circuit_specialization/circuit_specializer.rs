@@ -0,0 +1,421 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::InstantiationCollector;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+pub struct CircuitSpecializer<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A mapping from the name of each generic circuit to its definition.
+    pub(crate) generics: IndexMap<Symbol, Struct>,
+    /// A mapping from the name of each generic circuit to the concrete types it is instantiated with.
+    pub(crate) instantiations: IndexMap<Symbol, IndexSet<Vec<Type>>>,
+}
+
+impl<'a> CircuitSpecializer<'a> {
+    /// Initializes a new `CircuitSpecializer` reducer.
+    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder) -> Self {
+        Self { handler, node_builder, generics: IndexMap::new(), instantiations: IndexMap::new() }
+    }
+
+    /// Returns the mangling of the type argument `type_`, which consists of identifier characters only.
+    fn mangle(type_: &Type) -> String {
+        match type_ {
+            Type::Array(array_type) => {
+                format!("array{}_{}", array_type.length(), Self::mangle(array_type.element_type()))
+            }
+            Type::Tuple(tuple_type) => format!(
+                "tuple{}_{}",
+                tuple_type.length(),
+                tuple_type.elements().iter().map(Self::mangle).collect::<Vec<_>>().join("_")
+            ),
+            type_ => type_.to_string(),
+        }
+    }
+
+    /// Returns the name of the specialization of the circuit `name` for the concrete types `concrete`, e.g.
+    /// `Pair$u8$field`. Since `$` cannot appear in user-written names, the name never clashes with a declared struct,
+    /// and it is recognized as synthesized by the circuit deduplication pass.
+    fn specialized_name(name: Symbol, concrete: &[Type]) -> Symbol {
+        let concrete = concrete.iter().map(Self::mangle).collect::<Vec<_>>().join("$");
+        Symbol::intern(&format!("{name}${concrete}"))
+    }
+
+    /// Returns the name of the only specialization of the generic circuit `name`, if it is instantiated exactly once.
+    fn only_specialization(&self, name: Symbol) -> Option<Symbol> {
+        match self.instantiations.get(&name) {
+            Some(instantiations) if instantiations.len() == 1 => {
+                instantiations.first().map(|concrete| Self::specialized_name(name, concrete))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `identifier` renamed to the only specialization of the generic circuit it refers to.
+    /// Since every generic circuit is removed, a reference to one that is not instantiated exactly once is reported.
+    fn resolve(&self, identifier: Identifier) -> Identifier {
+        if !self.generics.contains_key(&identifier.name) {
+            return identifier;
+        }
+        match self.only_specialization(identifier.name) {
+            Some(name) => Identifier { name, ..identifier },
+            None => {
+                let count = self.instantiations.get(&identifier.name).map_or(0, |instantiations| instantiations.len());
+                self.handler.emit_err(StaticAnalyzerError::unresolved_generic_circuit(
+                    identifier.name,
+                    count,
+                    identifier.span,
+                ));
+                identifier
+            }
+        }
+    }
+
+    /// Returns `type_` with each type parameter substituted by its concrete type in `substitution`.
+    fn substitute(type_: &Type, substitution: &IndexMap<Symbol, Type>) -> Type {
+        match type_ {
+            Type::Identifier(identifier) if substitution.contains_key(&identifier.name) => {
+                substitution[&identifier.name].clone()
+            }
+            Type::Array(array) => {
+                Type::Array(ArrayType::new(Self::substitute(array.element_type(), substitution), array.length().into()))
+            }
+            Type::Tuple(tuple) => Type::Tuple(TupleType::new(
+                tuple.elements().iter().map(|type_| Self::substitute(type_, substitution)).collect(),
+            )),
+            _ => type_.clone(),
+        }
+    }
+
+    /// Returns the type with each generic circuit replaced by its only specialization.
+    /// A generic circuit that is not instantiated exactly once cannot be resolved without type arguments, so it is
+    /// reported.
+    fn type_(&self, type_: Type) -> Type {
+        match type_ {
+            Type::Array(array_type) => Type::Array(ArrayType::new(
+                self.type_(array_type.element_type().clone()),
+                NonNegativeNumber::from(array_type.length()),
+            )),
+            Type::Identifier(identifier) => Type::Identifier(self.resolve(identifier)),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.type_(*mapping_type.key)),
+                value: Box::new(self.type_(*mapping_type.value)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| self.type_(type_.clone())).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+
+    /// Returns the function input with each generic circuit in its type replaced.
+    fn input(&self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput { type_: self.type_(input.type_), ..input }),
+            input => input,
+        }
+    }
+
+    /// Returns the function output with each generic circuit in its type replaced.
+    fn output(&self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => Output::Internal(FunctionOutput { type_: self.type_(output.type_), ..output }),
+            output => output,
+        }
+    }
+
+    /// Returns the concrete type of `expression`, if it can be determined syntactically.
+    /// Only literals, casts, and ascriptions of primitive types are supported.
+    fn concrete_type(expression: &Expression) -> Option<Type> {
+        let type_ = match expression {
+            Expression::Literal(Literal::Address(..)) => Type::Address,
+            Expression::Literal(Literal::Boolean(..)) => Type::Boolean,
+            Expression::Literal(Literal::Field(..)) => Type::Field,
+            Expression::Literal(Literal::Group(..)) => Type::Group,
+            Expression::Literal(Literal::Integer(type_, ..)) => Type::Integer(*type_),
+            Expression::Literal(Literal::Scalar(..)) => Type::Scalar,
+            Expression::Ascription(ascription) => ascription.type_.clone(),
+            Expression::Cast(cast) => cast.type_.clone(),
+            _ => return None,
+        };
+        match type_ {
+            Type::Address | Type::Boolean | Type::Field | Type::Group | Type::Integer(_) | Type::Scalar => Some(type_),
+            _ => None,
+        }
+    }
+
+    /// Returns the concrete types that the initializer `input` instantiates its generic circuit with, if any.
+    /// Each type parameter takes the type of the value of the first member declared with it, and every type parameter
+    /// must be determined.
+    pub(crate) fn instantiation(generics: &IndexMap<Symbol, Struct>, input: &StructExpression) -> Option<Vec<Type>> {
+        let circuit = generics.get(&input.name.name)?;
+        circuit
+            .type_params
+            .iter()
+            .map(|type_param| {
+                circuit
+                    .members
+                    .iter()
+                    .filter(|member| {
+                        matches!(&member.type_, Type::Identifier(identifier) if identifier.name == type_param.name)
+                    })
+                    .find_map(|member| {
+                        input
+                            .members
+                            .iter()
+                            .find(|initializer| initializer.identifier.name == member.name())
+                            .and_then(|initializer| initializer.expression.as_ref())
+                            .and_then(Self::concrete_type)
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the specialization of the generic circuit `circuit` for the concrete types `concrete`.
+    fn specialize(&self, circuit: &Struct, concrete: &[Type]) -> Struct {
+        let substitution = circuit
+            .type_params
+            .iter()
+            .map(|type_param| type_param.name)
+            .zip(concrete.iter().cloned())
+            .collect::<IndexMap<_, _>>();
+        Struct {
+            docs: circuit.docs.clone(),
+            annotations: circuit.annotations.clone(),
+            identifier: Identifier {
+                name: Self::specialized_name(circuit.identifier.name, concrete),
+                span: circuit.identifier.span,
+                id: self.node_builder.next_id(),
+            },
+            type_params: Vec::new(),
+            members: circuit
+                .members
+                .iter()
+                .map(|member| Member {
+                    docs: member.docs.clone(),
+                    mode: member.mode,
+                    identifier: member.identifier,
+                    type_: self.type_(Self::substitute(&member.type_, &substitution)),
+                    span: member.span,
+                    id: self.node_builder.next_id(),
+                })
+                .collect(),
+            is_record: circuit.is_record,
+            span: circuit.span,
+            id: self.node_builder.next_id(),
+        }
+    }
+}
+
+impl ExpressionReconstructor for CircuitSpecializer<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_ascription(&mut self, input: AscriptionExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Ascription(AscriptionExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.type_(input.type_),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.type_(input.type_),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Renames an initializer of a generic circuit to the specialization it instantiates, or to the only
+    /// specialization of the circuit if its concrete types cannot be determined.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        let name = match Self::instantiation(&self.generics, &input) {
+            Some(concrete) => Identifier { name: Self::specialized_name(input.name.name, &concrete), ..input.name },
+            None => self.resolve(input.name),
+        };
+        (
+            Expression::Struct(StructExpression {
+                name,
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: match member.expression {
+                            Some(expression) => Some(self.reconstruct_expression(expression).0),
+                            None => Some(self.reconstruct_expression(Expression::Identifier(member.identifier)).0),
+                        },
+                        span: member.span,
+                        id: member.id,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for CircuitSpecializer<'_> {
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Const(ConstDeclaration {
+                place: input.place,
+                type_: self.type_(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+        // A definition annotated with a generic circuit takes the type of the specialization it is initialized with.
+        let type_ = match (&input.type_, &value) {
+            (Type::Identifier(identifier), Expression::Struct(circuit))
+                if self.generics.contains_key(&identifier.name) && circuit.name.name != identifier.name =>
+            {
+                Type::Identifier(circuit.name)
+            }
+            _ => self.type_(input.type_),
+        };
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: input.place,
+                type_,
+                value,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for CircuitSpecializer<'_> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        self.generics = input
+            .structs
+            .iter()
+            .filter(|(_, circuit)| !circuit.type_params.is_empty())
+            .map(|(name, circuit)| (*name, circuit.clone()))
+            .collect();
+        // The instantiations are collected first, so that every reference to a generic circuit can be resolved.
+        self.instantiations = InstantiationCollector::of_program_scope(&self.generics, &input);
+
+        // Each generic circuit is replaced by its specializations, in the order they were first instantiated.
+        let structs = input
+            .structs
+            .into_iter()
+            .flat_map(|(name, circuit)| {
+                if self.generics.contains_key(&name) {
+                    self.instantiations
+                        .get(&name)
+                        .into_iter()
+                        .flatten()
+                        .map(|concrete| {
+                            let specialization = self.specialize(&circuit, concrete);
+                            (specialization.name(), specialization)
+                        })
+                        .collect::<Vec<_>>()
+                } else {
+                    vec![(name, self.reconstruct_struct(circuit))]
+                }
+            })
+            .collect();
+
+        ProgramScope {
+            program_id: input.program_id,
+            structs,
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input.into_iter().map(|input| self.input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.output(output)).collect(),
+            output_type: self.type_(input.output_type),
+            const_predicates: input
+                .const_predicates
+                .into_iter()
+                .map(|predicate| self.reconstruct_function_predicate(predicate))
+                .collect(),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input.into_iter().map(|input| self.input(input)).collect(),
+                output: finalize.output.into_iter().map(|output| self.output(output)).collect(),
+                output_type: self.type_(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }),
+            span: input.span,
+            id: input.id,
+        }
+    }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member { type_: self.type_(member.type_), ..member })
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping { key_type: self.type_(input.key_type), value_type: self.type_(input.value_type), ..input }
+    }
+}
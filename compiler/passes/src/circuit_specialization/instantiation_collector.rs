@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CircuitSpecializer;
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// Collects the concrete types that each generic circuit of a program scope is instantiated with.
+pub(crate) struct InstantiationCollector<'a> {
+    /// A mapping from the name of each generic circuit to its definition.
+    pub(crate) generics: &'a IndexMap<Symbol, Struct>,
+    /// A mapping from the name of each generic circuit to the concrete types it is instantiated with.
+    pub(crate) instantiations: IndexMap<Symbol, IndexSet<Vec<Type>>>,
+}
+
+impl<'a> InstantiationCollector<'a> {
+    /// Returns the instantiations of the generic circuits `generics` in the program scope `input`.
+    pub(crate) fn of_program_scope(
+        generics: &'a IndexMap<Symbol, Struct>,
+        input: &'a ProgramScope,
+    ) -> IndexMap<Symbol, IndexSet<Vec<Type>>> {
+        let mut collector = Self { generics, instantiations: IndexMap::new() };
+        collector.visit_program_scope(input);
+        collector.instantiations
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for InstantiationCollector<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        if let Some(concrete) = CircuitSpecializer::instantiation(self.generics, input) {
+            self.instantiations.entry(input.name.name).or_default().insert(concrete);
+        }
+        input
+            .members
+            .iter()
+            .filter_map(|member| member.expression.as_ref())
+            .for_each(|expression| self.visit_expression(expression, additional));
+    }
+}
+
+impl<'a> StatementVisitor<'a> for InstantiationCollector<'a> {}
+
+impl<'a> ProgramVisitor<'a> for InstantiationCollector<'a> {}
@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Circuit Specialization pass traverses the AST and monomorphizes circuits declared with type parameters.
+//! A circuit initializer instantiates a generic circuit with one concrete type per type parameter, namely the type of
+//! the value of the first member declared with that type parameter, if it can be determined from a literal or a cast.
+//! Each generic circuit is replaced by one specialization per instantiation, named after the circuit and the mangled
+//! concrete types joined by `$`, e.g. `Pair$u8$field`, and the initializers and the definitions annotated with the
+//! generic circuit are rewritten to the specialization.
+//! The other references to a generic circuit, e.g. in function signatures, casts, constants, and member types, are
+//! rewritten to its specialization if it is instantiated exactly once, and are reported otherwise, since the generic
+//! circuit itself is removed.
+//! Since the type checker rejects type parameters, this pass runs on the parsed AST.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! struct Container<T> {
+//!     value: T,
+//! }
+//!
+//! transition main() -> u8 {
+//!     let a: Container = Container { value: 1u8 };
+//!     return a.value;
+//! }
+//! ```
+//!
+//! The circuit specialization pass produces the following code.
+//! ```leo
+//! struct Container$u8 {
+//!     value: u8,
+//! }
+//!
+//! transition main() -> u8 {
+//!     let a: Container$u8 = Container$u8 { value: 1u8 };
+//!     return a.value;
+//! }
+//! ```
+
+pub mod circuit_specializer;
+pub use circuit_specializer::*;
+
+pub mod instantiation_collector;
+pub use instantiation_collector::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for CircuitSpecializer<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = CircuitSpecializer::new(handler, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, ProgramScope, Statement};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and specializes a program with the given body, returning its program scope.
+    fn specialize(body: &str) -> ProgramScope {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!("program test.aleo {{ {body} }}");
+        let ast = parse_program(&handler, &node_builder, &source);
+        let ast = CircuitSpecializer::do_pass((ast, &handler, &node_builder)).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap()
+    }
+
+    /// Parses and specializes a program with the given body, returning the number of errors.
+    fn specialization_errors(body: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let node_builder = NodeBuilder::default();
+        let source = format!("program test.aleo {{ {body} }}");
+        let ast = parse_program(&handler, &node_builder, &source);
+        let _ = CircuitSpecializer::do_pass((ast, &handler, &node_builder));
+        handler.err_count()
+    }
+
+    #[test]
+    fn test_specializes_container_for_each_instantiation() {
+        create_session_if_not_set_then(|_| {
+            let scope = specialize(
+                "struct Container<T> { value: T, count: u32 }
+                struct Pair<T> { left: T, right: T }
+                transition main() -> field {
+                    let a: Container = Container { value: 1u8, count: 0u32 };
+                    let b: Container = Container { value: 2field, count: 1u32 };
+                    return b.value;
+                }",
+            );
+
+            // `Pair` is never instantiated, so it is not specialized.
+            let structs = scope.structs.iter().map(|(_, circuit)| circuit.to_string()).collect::<Vec<_>>();
+            assert_eq!(structs, [
+                "struct Container$u8 { \n    value: u8\n    count: u32\n}",
+                "struct Container$field { \n    value: field\n    count: u32\n}",
+            ]);
+
+            let statements = &scope.functions[0].1.block.statements;
+            for (statement, expected) in statements.iter().zip(["Container$u8", "Container$field"]) {
+                let Statement::Definition(definition) = statement else {
+                    panic!("expected a definition, found `{statement}`");
+                };
+                assert_eq!(definition.type_.to_string(), expected);
+                let Expression::Struct(value) = &definition.value else {
+                    panic!("expected a struct initializer, found `{}`", definition.value);
+                };
+                assert_eq!(value.name.to_string(), expected);
+            }
+        })
+    }
+
+    #[test]
+    fn test_rewrites_references_to_the_only_specialization() {
+        create_session_if_not_set_then(|_| {
+            let scope = specialize(
+                "struct Pair<K, V> { key: K, value: V }
+                struct Entry { pair: Pair, present: bool }
+                const EMPTY: Pair = Pair { key: 0u8, value: 0field };
+                function first(p: Pair) -> Pair {
+                    return p;
+                }
+                transition main(a: u8) -> u8 {
+                    let e: Entry = Entry { pair: first(Pair { key: a, value: 1field }), present: true };
+                    return e.pair.key;
+                }",
+            );
+
+            let structs = scope.structs.iter().map(|(_, circuit)| circuit.to_string()).collect::<Vec<_>>();
+            assert_eq!(structs, [
+                "struct Pair$u8$field { \n    key: u8\n    value: field\n}",
+                "struct Entry { \n    pair: Pair$u8$field\n    present: bool\n}",
+            ]);
+            assert_eq!(scope.consts[0].1.type_.to_string(), "Pair$u8$field");
+
+            let function = &scope.functions[0].1;
+            assert_eq!(function.input[0].type_().to_string(), "Pair$u8$field");
+            assert_eq!(function.output_type.to_string(), "Pair$u8$field");
+
+            // The initializer whose key is not a literal is rewritten to the only specialization.
+            let Statement::Definition(definition) = &scope.functions[1].1.block.statements[0] else {
+                panic!("expected a definition");
+            };
+            let Expression::Struct(entry) = &definition.value else {
+                panic!("expected a struct initializer, found `{}`", definition.value);
+            };
+            let Some(Expression::Call(call)) = &entry.members[0].expression else {
+                panic!("expected a call");
+            };
+            let Expression::Struct(pair) = &call.arguments[0] else {
+                panic!("expected a struct initializer, found `{}`", call.arguments[0]);
+            };
+            assert_eq!(pair.name.to_string(), "Pair$u8$field");
+        })
+    }
+
+    #[test]
+    fn test_reports_ambiguous_references() {
+        create_session_if_not_set_then(|_| {
+            let errors = specialization_errors(
+                "struct Container<T> { value: T }
+                function get(c: Container) -> u8 {
+                    return 0u8;
+                }
+                transition main() -> u8 {
+                    let a: Container = Container { value: 1u8 };
+                    let b: Container = Container { value: 2field };
+                    let c: u8 = (a : Container).value;
+                    return a.value;
+                }",
+            );
+
+            // The input of `get` and the ascription are reported, while the annotated definitions are resolved.
+            assert_eq!(errors, 2);
+        })
+    }
+
+    #[test]
+    fn test_reports_references_to_uninstantiated_circuit() {
+        create_session_if_not_set_then(|_| {
+            let errors = specialization_errors(
+                "struct Container<T> { value: T }
+                function get(c: Container) -> u8 {
+                    return 0u8;
+                }",
+            );
+            assert_eq!(errors, 1);
+        })
+    }
+}
@@ -52,6 +52,8 @@ impl<'a> CodeGenerator<'a> {
         match input {
             Expression::Access(expr) => self.visit_access(expr),
             Expression::Array(expr) => self.visit_array(expr),
+            // Ascriptions perform no conversion, so only the inner expression is generated.
+            Expression::Ascription(expr) => self.visit_expression(&expr.expression),
             Expression::Binary(expr) => self.visit_binary(expr),
             Expression::Call(expr) => self.visit_call(expr),
             Expression::Cast(expr) => self.visit_cast(expr),
@@ -59,6 +61,7 @@ impl<'a> CodeGenerator<'a> {
             Expression::Err(expr) => self.visit_err(expr),
             Expression::Identifier(expr) => self.visit_identifier(expr),
             Expression::Literal(expr) => self.visit_value(expr),
+            Expression::Spread(_) => unreachable!("`SpreadExpression`s should be expanded before code generation."),
             Expression::Ternary(expr) => self.visit_ternary(expr),
             Expression::Tuple(expr) => self.visit_tuple(expr),
             Expression::Unary(expr) => self.visit_unary(expr),
@@ -39,6 +39,9 @@ use std::fmt::Write as _;
 impl<'a> CodeGenerator<'a> {
     fn visit_statement(&mut self, input: &'a Statement) -> String {
         match input {
+            Statement::Annotated(_) => {
+                unreachable!("`AnnotatedStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
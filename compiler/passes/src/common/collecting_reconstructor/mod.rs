@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::DiagnosticSink;
+
+use leo_ast::{
+    ErrExpression,
+    Expression,
+    ExpressionReconstructor,
+    Node,
+    Program,
+    ProgramReconstructor,
+    StatementReconstructor,
+};
+use leo_errors::Result;
+
+/// A `CollectingReconstructor` applies a fallible `reduce` to all `Expression`s in an AST.
+/// If `reduce` fails on an expression, the error is recorded in the `DiagnosticSink`,
+/// the expression is replaced with an `ErrExpression`, and the traversal continues.
+/// Expressions are reduced before their subexpressions.
+pub struct CollectingReconstructor<'a, F>
+where
+    F: FnMut(Expression) -> Result<Expression>,
+{
+    /// The fallible reduction applied to each expression.
+    reduce: F,
+    /// The sink in which errors are recorded.
+    sink: &'a DiagnosticSink,
+}
+
+impl<'a, F> CollectingReconstructor<'a, F>
+where
+    F: FnMut(Expression) -> Result<Expression>,
+{
+    pub fn new(reduce: F, sink: &'a DiagnosticSink) -> Self {
+        Self { reduce, sink }
+    }
+}
+
+impl<F> ExpressionReconstructor for CollectingReconstructor<'_, F>
+where
+    F: FnMut(Expression) -> Result<Expression>,
+{
+    type AdditionalOutput = ();
+
+    fn reconstruct_expression(&mut self, input: Expression) -> (Expression, Self::AdditionalOutput) {
+        let (span, id) = (input.span(), input.id());
+        match (self.reduce)(input) {
+            Ok(Expression::Access(access)) => self.reconstruct_access(access),
+            Ok(Expression::Array(array)) => self.reconstruct_array(array),
+            Ok(Expression::Ascription(ascription)) => self.reconstruct_ascription(ascription),
+            Ok(Expression::Binary(binary)) => self.reconstruct_binary(binary),
+            Ok(Expression::Call(call)) => self.reconstruct_call(call),
+            Ok(Expression::Cast(cast)) => self.reconstruct_cast(cast),
+            Ok(Expression::Struct(struct_)) => self.reconstruct_struct_init(struct_),
+            // Placeholders are left as is, since they have already been reported.
+            Ok(Expression::Err(err)) => (Expression::Err(err), Default::default()),
+            Ok(Expression::Identifier(identifier)) => self.reconstruct_identifier(identifier),
+            Ok(Expression::Literal(value)) => self.reconstruct_literal(value),
+            Ok(Expression::Spread(spread)) => self.reconstruct_spread(spread),
+            Ok(Expression::Ternary(ternary)) => self.reconstruct_ternary(ternary),
+            Ok(Expression::Tuple(tuple)) => self.reconstruct_tuple(tuple),
+            Ok(Expression::Unary(unary)) => self.reconstruct_unary(unary),
+            Ok(Expression::Unit(unit)) => self.reconstruct_unit(unit),
+            Err(err) => {
+                self.sink.push(err);
+                (Expression::Err(ErrExpression { span, id }), Default::default())
+            }
+        }
+    }
+}
+
+impl<F> StatementReconstructor for CollectingReconstructor<'_, F> where F: FnMut(Expression) -> Result<Expression> {}
+
+impl<F> ProgramReconstructor for CollectingReconstructor<'_, F> where F: FnMut(Expression) -> Result<Expression> {}
+
+/// Applies `reduce` to every expression in `program`, collecting errors instead of aborting on the first.
+/// Returns the reconstructed program, in which each failing expression is replaced by an `ErrExpression`,
+/// along with all of the diagnostics reported during the traversal.
+pub fn reconstruct_collecting<F>(program: Program, reduce: F) -> (Program, DiagnosticSink)
+where
+    F: FnMut(Expression) -> Result<Expression>,
+{
+    let sink = DiagnosticSink::default();
+    let program = CollectingReconstructor::new(reduce, &sink).reconstruct_program(program);
+    (program, sink)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{BinaryExpression, Literal, NodeBuilder};
+    use leo_errors::{emitter::Handler, TypeCheckerError};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Rejects binary expressions over integer literals of different types.
+    fn check_literal_types(expression: Expression) -> Result<Expression> {
+        if let Expression::Binary(BinaryExpression { left, right, span, .. }) = &expression {
+            if let (Expression::Literal(Literal::Integer(left, ..)), Expression::Literal(Literal::Integer(right, ..))) =
+                (&**left, &**right)
+            {
+                if left != right {
+                    return Err(TypeCheckerError::type_should_be(right, left, *span).into());
+                }
+            }
+        }
+        Ok(expression)
+    }
+
+    #[test]
+    fn test_collects_independent_errors() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let ast = parse_program(
+                &handler,
+                &NodeBuilder::default(),
+                "program test.aleo {
+                    transition main(a: u8) -> u8 {
+                        let b: u8 = 1u8 + 2u16;
+                        let c: u8 = 3u8 * 4u8;
+                        let d: u8 = 5u32 - 6u8;
+                        return (7u8 / 8i8) + c;
+                    }
+                }",
+            );
+
+            let (program, sink) = reconstruct_collecting(ast.into_repr(), check_literal_types);
+
+            assert_eq!(sink.len(), 3);
+            let function = &program.program_scopes.values().next().unwrap().functions[0].1;
+            let placeholders = function.block.to_string().matches("error").count();
+            assert_eq!(placeholders, 3);
+        })
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::{emitter::Handler, LeoError};
+
+use std::cell::RefCell;
+
+/// A sink that records the diagnostics produced by a pass, instead of aborting on the first error.
+#[derive(Debug, Default)]
+pub struct DiagnosticSink {
+    /// The recorded diagnostics, in the order they were reported.
+    /// `RefCell` is used here to avoid `&mut` all over the compiler.
+    inner: RefCell<Vec<LeoError>>,
+}
+
+impl DiagnosticSink {
+    /// Records a diagnostic.
+    pub fn push<E: Into<LeoError>>(&self, err: E) {
+        self.inner.borrow_mut().push(err.into());
+    }
+
+    /// Returns the number of recorded diagnostics.
+    pub fn len(&self) -> usize {
+        self.inner.borrow().len()
+    }
+
+    /// Returns `true` if no diagnostics have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().is_empty()
+    }
+
+    /// Consumes the sink, returning the recorded diagnostics.
+    pub fn into_inner(self) -> Vec<LeoError> {
+        self.inner.into_inner()
+    }
+
+    /// Consumes the sink, emitting each of the recorded diagnostics to the handler.
+    pub fn emit(self, handler: &Handler) {
+        self.into_inner().into_iter().for_each(|err| handler.emit_err(err));
+    }
+}
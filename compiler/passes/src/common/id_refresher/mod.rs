@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+
+/// An `IdRefresher` gives a fresh node ID to every expression, statement, and block of a copied AST node, and to the
+/// identifiers it declares, so that the copy can be placed next to the original.
+pub struct IdRefresher<'a> {
+    /// A counter used to generate unique node IDs.
+    node_builder: &'a NodeBuilder,
+}
+
+impl<'a> IdRefresher<'a> {
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Returns `identifier` with a fresh node ID.
+    fn identifier(&self, identifier: Identifier) -> Identifier {
+        Identifier { id: self.node_builder.next_id(), ..identifier }
+    }
+}
+
+impl ExpressionReconstructor for IdRefresher<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_expression(&mut self, input: Expression) -> (Expression, Self::AdditionalOutput) {
+        let (mut expression, _) = match input {
+            Expression::Access(access) => self.reconstruct_access(access),
+            Expression::Array(array) => self.reconstruct_array(array),
+            Expression::Ascription(ascription) => self.reconstruct_ascription(ascription),
+            Expression::Binary(binary) => self.reconstruct_binary(binary),
+            Expression::Call(call) => self.reconstruct_call(call),
+            Expression::Cast(cast) => self.reconstruct_cast(cast),
+            Expression::Struct(struct_) => self.reconstruct_struct_init(struct_),
+            Expression::Err(err) => self.reconstruct_err(err),
+            Expression::Identifier(identifier) => self.reconstruct_identifier(identifier),
+            Expression::Literal(value) => self.reconstruct_literal(value),
+            Expression::Spread(spread) => self.reconstruct_spread(spread),
+            Expression::Ternary(ternary) => self.reconstruct_ternary(ternary),
+            Expression::Tuple(tuple) => self.reconstruct_tuple(tuple),
+            Expression::Unary(unary) => self.reconstruct_unary(unary),
+            Expression::Unit(unit) => self.reconstruct_unit(unit),
+        };
+        expression.set_id(self.node_builder.next_id());
+        (expression, Default::default())
+    }
+}
+
+impl StatementReconstructor for IdRefresher<'_> {
+    fn reconstruct_statement(&mut self, input: Statement) -> (Statement, Self::AdditionalOutput) {
+        let (mut statement, _) = match input {
+            Statement::Annotated(stmt) => self.reconstruct_annotated(stmt),
+            Statement::Assert(assert) => self.reconstruct_assert(assert),
+            Statement::Assign(stmt) => self.reconstruct_assign(*stmt),
+            Statement::Block(stmt) => (Statement::Block(self.reconstruct_block(stmt).0), Default::default()),
+            Statement::Conditional(stmt) => self.reconstruct_conditional(stmt),
+            Statement::Console(stmt) => self.reconstruct_console(stmt),
+            Statement::Const(stmt) => self.reconstruct_const(stmt),
+            Statement::Definition(stmt) => self.reconstruct_definition(stmt),
+            Statement::Expression(stmt) => self.reconstruct_expression_statement(stmt),
+            Statement::Iteration(stmt) => self.reconstruct_iteration(*stmt),
+            Statement::Return(stmt) => self.reconstruct_return(stmt),
+        };
+        statement.set_id(self.node_builder.next_id());
+        (statement, Default::default())
+    }
+
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place: self.reconstruct_expression(input.place).0,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        (
+            Block {
+                statements: input.statements.into_iter().map(|s| self.reconstruct_statement(s).0).collect(),
+                span: input.span,
+                id: self.node_builder.next_id(),
+            },
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Const(ConstDeclaration {
+                place: self.identifier(input.place),
+                type_: input.type_,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: self.reconstruct_expression(input.place).0,
+                type_: input.type_,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Iteration(Box::new(IterationStatement {
+                variable: self.identifier(input.variable),
+                type_: input.type_,
+                start: self.reconstruct_expression(input.start).0,
+                start_value: input.start_value,
+                stop: self.reconstruct_expression(input.stop).0,
+                stop_value: input.stop_value,
+                block: self.reconstruct_block(input.block).0,
+                inclusive: input.inclusive,
+                step: input.step.map(|step| self.reconstruct_expression(step).0),
+                span: input.span,
+                id: self.node_builder.next_id(),
+            })),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for IdRefresher<'_> {}
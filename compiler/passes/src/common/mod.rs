@@ -17,9 +17,24 @@
 pub mod assigner;
 pub use assigner::*;
 
+pub mod collecting_reconstructor;
+pub use collecting_reconstructor::*;
+
+pub mod diagnostic_sink;
+pub use diagnostic_sink::*;
+
 pub mod graph;
 pub use graph::*;
 
+pub mod id_refresher;
+pub use id_refresher::*;
+
+pub mod pass_context;
+pub use pass_context::*;
+
+pub mod range_analyzer;
+pub use range_analyzer::*;
+
 pub mod rename_table;
 pub use rename_table::*;
 
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::{emitter::Handler, LeoError};
+
+use std::cell::{Ref, RefCell, RefMut};
+
+/// State shared by a sequence of passes.
+///
+/// A pass holds a `&'a PassContext<'a, S>` in the same way that it holds a `&'a NodeBuilder` or a `&'a TypeTable`.
+/// This allows several passes to report diagnostics to the same handler and to accumulate a user-supplied state `S`,
+/// e.g. counters or analysis results, without storing it in each pass.
+/// Errors are emitted to the handler, so a pass can report every error it encounters instead of aborting on the first.
+pub struct PassContext<'a, S> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The user-supplied state.
+    /// `RefCell` is used here to avoid `&mut` all over the compiler.
+    state: RefCell<S>,
+}
+
+impl<'a, S> PassContext<'a, S> {
+    /// Returns a new `PassContext` given an error handler and an initial state.
+    pub fn new(handler: &'a Handler, state: S) -> Self {
+        Self { handler, state: RefCell::new(state) }
+    }
+
+    /// Returns the error handler.
+    pub fn handler(&self) -> &'a Handler {
+        self.handler
+    }
+
+    /// Emits an error to the handler, without aborting the pass.
+    pub fn emit_err<E: Into<LeoError>>(&self, err: E) {
+        self.handler.emit_err(err);
+    }
+
+    /// Returns a reference to the state.
+    pub fn state(&self) -> Ref<'_, S> {
+        self.state.borrow()
+    }
+
+    /// Returns a mutable reference to the state.
+    pub fn state_mut(&self) -> RefMut<'_, S> {
+        self.state.borrow_mut()
+    }
+
+    /// Consumes the context, returning the state.
+    pub fn into_state(self) -> S {
+        self.state.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{BinaryExpression, ExpressionVisitor, NodeBuilder, ProgramVisitor, StatementVisitor};
+    use leo_errors::StaticAnalyzerError;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Counts the binary expressions of a program in the shared state, reporting each one as an error.
+    struct BinaryCounter<'a> {
+        context: &'a PassContext<'a, usize>,
+    }
+
+    impl<'a> ExpressionVisitor<'a> for BinaryCounter<'a> {
+        type AdditionalInput = ();
+        type Output = ();
+
+        fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+            *self.context.state_mut() += 1;
+            self.context.emit_err(StaticAnalyzerError::string_outside_console(input.span));
+            self.visit_expression(&input.left, additional);
+            self.visit_expression(&input.right, additional);
+        }
+    }
+
+    impl<'a> StatementVisitor<'a> for BinaryCounter<'a> {}
+
+    impl<'a> ProgramVisitor<'a> for BinaryCounter<'a> {}
+
+    #[test]
+    fn test_shares_context_between_passes() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let node_builder = NodeBuilder::default();
+            let first = parse_program(
+                &handler,
+                &node_builder,
+                "program first.aleo {
+                    transition main(a: u8) -> u8 {
+                        return a / 2u8;
+                    }
+                }",
+            );
+            let second = parse_program(
+                &handler,
+                &node_builder,
+                "program second.aleo {
+                    transition main(a: field) -> field {
+                        let b: field = a * a;
+                        return b + a;
+                    }
+                }",
+            );
+
+            let context = PassContext::new(&handler, 0usize);
+            for ast in [&first, &second] {
+                BinaryCounter { context: &context }.visit_program(ast.as_repr());
+            }
+
+            assert_eq!(handler.err_count(), 3);
+            assert_eq!(context.into_state(), 3);
+        })
+    }
+}
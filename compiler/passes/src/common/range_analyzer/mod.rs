@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+
+/// An interval analysis of integer expressions, based on the types recorded by the type checker.
+pub struct RangeAnalyzer<'a> {
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+}
+
+impl<'a> RangeAnalyzer<'a> {
+    /// Returns a new `RangeAnalyzer` over the given type table.
+    pub fn new(type_table: &'a TypeTable) -> Self {
+        Self { type_table }
+    }
+
+    /// Returns the range of values of an integer type, if it is representable.
+    /// The range of `u128` is not representable, so `u128` arithmetic is never proven safe.
+    pub fn type_range(integer_type: &IntegerType) -> Option<(i128, i128)> {
+        match integer_type {
+            IntegerType::U8 => Some((u8::MIN.into(), u8::MAX.into())),
+            IntegerType::U16 => Some((u16::MIN.into(), u16::MAX.into())),
+            IntegerType::U32 => Some((u32::MIN.into(), u32::MAX.into())),
+            IntegerType::U64 => Some((u64::MIN.into(), u64::MAX.into())),
+            IntegerType::U128 => None,
+            IntegerType::I8 => Some((i8::MIN.into(), i8::MAX.into())),
+            IntegerType::I16 => Some((i16::MIN.into(), i16::MAX.into())),
+            IntegerType::I32 => Some((i32::MIN.into(), i32::MAX.into())),
+            IntegerType::I64 => Some((i64::MIN.into(), i64::MAX.into())),
+            IntegerType::I128 => Some((i128::MIN, i128::MAX)),
+        }
+    }
+
+    /// Returns the integer type of the node `id`, if any.
+    fn integer_type(&self, id: &NodeID) -> Option<IntegerType> {
+        match self.type_table.get(id) {
+            Some(Type::Integer(integer_type)) => Some(integer_type),
+            _ => None,
+        }
+    }
+
+    /// Returns the range of `left op right`, if the ranges of both operands are known and the result is representable.
+    fn apply(op: BinaryOperation, (ll, lh): (i128, i128), (rl, rh): (i128, i128)) -> Option<(i128, i128)> {
+        match op {
+            BinaryOperation::Add => Some((ll.checked_add(rl)?, lh.checked_add(rh)?)),
+            BinaryOperation::Sub => Some((ll.checked_sub(rh)?, lh.checked_sub(rl)?)),
+            BinaryOperation::Mul => {
+                let products = [ll.checked_mul(rl)?, ll.checked_mul(rh)?, lh.checked_mul(rl)?, lh.checked_mul(rh)?];
+                Some((*products.iter().min()?, *products.iter().max()?))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns an interval containing every value that `input` may evaluate to, if one is known.
+    /// Literals are exact, casts preserve the range of their operand, and checked arithmetic is bounded by the
+    /// ranges of its operands; any other integer expression is bounded by the range of its type.
+    pub fn range_of(&self, input: &Expression) -> Option<(i128, i128)> {
+        let type_range = self.integer_type(&input.id()).and_then(|integer_type| Self::type_range(&integer_type));
+        let range = match input {
+            Expression::Literal(Literal::Integer(_, string, ..)) => {
+                string.replace('_', "").parse::<i128>().ok().map(|value| (value, value))
+            }
+            Expression::Ascription(ascription) => self.range_of(&ascription.expression),
+            Expression::Cast(cast) => self.range_of(&cast.expression),
+            Expression::Unary(unary) if unary.op == UnaryOperation::Negate => {
+                let (low, high) = self.range_of(&unary.receiver)?;
+                Some((high.checked_neg()?, low.checked_neg()?))
+            }
+            Expression::Binary(binary) => {
+                Self::apply(binary.op, self.range_of(&binary.left)?, self.range_of(&binary.right)?)
+            }
+            _ => None,
+        };
+        // The result of an expression is always within the range of its type.
+        match (range, type_range) {
+            (Some((low, high)), Some((min, max))) => Some((low.max(min), high.min(max))),
+            (range, type_range) => range.or(type_range),
+        }
+    }
+
+    /// Returns `true` if `left op right` provably stays within the range of `integer_type`.
+    pub fn is_safe(
+        &self,
+        op: BinaryOperation,
+        left: &Expression,
+        right: &Expression,
+        integer_type: &IntegerType,
+    ) -> bool {
+        match (Self::type_range(integer_type), self.range_of(left), self.range_of(right)) {
+            (Some((min, max)), Some(left), Some(right)) => {
+                matches!(Self::apply(op, left, right), Some((low, high)) if min <= low && high <= max)
+            }
+            _ => false,
+        }
+    }
+}
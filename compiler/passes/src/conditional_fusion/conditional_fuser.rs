@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NameCollector;
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+pub struct ConditionalFuser<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> ConditionalFuser<'a> {
+    /// Initializes a new `ConditionalFuser`.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Returns `true` if the expressions are structurally equal, ignoring spans and node IDs, and free of calls.
+    /// Expressions that may have side effects, e.g. calls, are never considered equal.
+    pub(crate) fn same_pure_expression(left: &Expression, right: &Expression) -> bool {
+        match (left, right) {
+            (Expression::Access(AccessExpression::Array(left)), Expression::Access(AccessExpression::Array(right))) => {
+                Self::same_pure_expression(&left.array, &right.array)
+                    && Self::same_pure_expression(&left.index, &right.index)
+            }
+            (
+                Expression::Access(AccessExpression::AssociatedConstant(left)),
+                Expression::Access(AccessExpression::AssociatedConstant(right)),
+            ) => left.ty.eq_flat(&right.ty) && left.name.name == right.name.name,
+            (
+                Expression::Access(AccessExpression::Member(left)),
+                Expression::Access(AccessExpression::Member(right)),
+            ) => left.name.name == right.name.name && Self::same_pure_expression(&left.inner, &right.inner),
+            (Expression::Access(AccessExpression::Tuple(left)), Expression::Access(AccessExpression::Tuple(right))) => {
+                left.index == right.index && Self::same_pure_expression(&left.tuple, &right.tuple)
+            }
+            (Expression::Binary(left), Expression::Binary(right)) => {
+                left.op == right.op
+                    && Self::same_pure_expression(&left.left, &right.left)
+                    && Self::same_pure_expression(&left.right, &right.right)
+            }
+            (Expression::Cast(left), Expression::Cast(right)) => {
+                left.type_.eq_flat(&right.type_) && Self::same_pure_expression(&left.expression, &right.expression)
+            }
+            (Expression::Identifier(left), Expression::Identifier(right)) => left.name == right.name,
+            (Expression::Literal(left), Expression::Literal(right)) => Self::same_literal(left, right),
+            (Expression::Ternary(left), Expression::Ternary(right)) => {
+                Self::same_pure_expression(&left.condition, &right.condition)
+                    && Self::same_pure_expression(&left.if_true, &right.if_true)
+                    && Self::same_pure_expression(&left.if_false, &right.if_false)
+            }
+            (Expression::Unary(left), Expression::Unary(right)) => {
+                left.op == right.op && Self::same_pure_expression(&left.receiver, &right.receiver)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the literals have the same type and value.
+    fn same_literal(left: &Literal, right: &Literal) -> bool {
+        match (left, right) {
+            (Literal::Address(left, ..), Literal::Address(right, ..))
+            | (Literal::Field(left, ..), Literal::Field(right, ..))
+            | (Literal::Scalar(left, ..), Literal::Scalar(right, ..))
+            | (Literal::String(left, ..), Literal::String(right, ..)) => left == right,
+            (Literal::Boolean(left, ..), Literal::Boolean(right, ..)) => left == right,
+            (Literal::Group(left), Literal::Group(right)) => match (left.as_ref(), right.as_ref()) {
+                (GroupLiteral::Single(left, ..), GroupLiteral::Single(right, ..)) => left == right,
+                (GroupLiteral::Tuple(left), GroupLiteral::Tuple(right)) => {
+                    left.x.to_string() == right.x.to_string() && left.y.to_string() == right.y.to_string()
+                }
+                _ => false,
+            },
+            (Literal::Integer(left_type, left, ..), Literal::Integer(right_type, right, ..)) => {
+                left_type == right_type && left == right
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the statement is or contains a return statement.
+    fn returns(statement: &Statement) -> bool {
+        match statement {
+            Statement::Return(_) => true,
+            Statement::Block(block) => block.statements.iter().any(Self::returns),
+            Statement::Conditional(conditional) => {
+                conditional.then.statements.iter().any(Self::returns)
+                    || conditional.otherwise.as_deref().map_or(false, Self::returns)
+            }
+            Statement::Iteration(iteration) => iteration.block.statements.iter().any(Self::returns),
+            _ => false,
+        }
+    }
+
+    /// Returns the statements at the top level of a branch, which is either a block or an `else if` conditional.
+    fn branch_statements(branch: &Statement) -> &[Statement] {
+        match branch {
+            Statement::Block(block) => &block.statements,
+            statement => std::slice::from_ref(statement),
+        }
+    }
+
+    /// Returns `true` if the statements can be placed in the same block, since no variable defined at the top level
+    /// of `first` is mentioned in `second`.
+    fn can_merge(first: &[Statement], second: &[Statement]) -> bool {
+        let mentioned: IndexSet<Symbol> =
+            second.iter().flat_map(|statement| NameCollector::of_statement(statement).names).collect();
+        first.iter().all(|statement| match statement {
+            Statement::Const(declaration) => !mentioned.contains(&declaration.place.name),
+            Statement::Definition(definition) => {
+                NameCollector::of_expression(&definition.place).names.iter().all(|name| !mentioned.contains(name))
+            }
+            _ => true,
+        })
+    }
+
+    /// Returns `true` if `second` can be fused into `first`.
+    /// The conditions must be the same pure expression, `first` must neither return nor write a variable that
+    /// the condition reads, and the corresponding branches must be mergeable.
+    pub(crate) fn can_fuse(first: &ConditionalStatement, second: &ConditionalStatement) -> bool {
+        let branches = || first.then.statements.iter().chain(first.otherwise.as_deref());
+        let read = NameCollector::of_expression(&first.condition).names;
+        let written: IndexSet<Symbol> = branches().flat_map(NameCollector::written_by).collect();
+
+        Self::same_pure_expression(&first.condition, &second.condition)
+            && !branches().any(Self::returns)
+            && read.iter().all(|name| !written.contains(name))
+            && Self::can_merge(&first.then.statements, &second.then.statements)
+            && match (first.otherwise.as_deref(), second.otherwise.as_deref()) {
+                (Some(first), Some(second)) => {
+                    Self::can_merge(Self::branch_statements(first), Self::branch_statements(second))
+                }
+                _ => true,
+            }
+    }
+
+    /// Returns the statements of a branch, which is either a block or an `else if` conditional.
+    fn into_statements(branch: Statement) -> Vec<Statement> {
+        match branch {
+            Statement::Block(block) => block.statements,
+            statement => vec![statement],
+        }
+    }
+
+    /// Fuses `second` into `first`, concatenating their `then` blocks and their `else` branches.
+    pub(crate) fn fuse(&self, first: ConditionalStatement, second: ConditionalStatement) -> ConditionalStatement {
+        let mut then = first.then;
+        then.statements.extend(second.then.statements);
+        then.span = then.span + second.then.span;
+
+        let otherwise = match (first.otherwise, second.otherwise) {
+            (Some(first), Some(second)) => {
+                let span = first.span() + second.span();
+                let mut statements = Self::into_statements(*first);
+                statements.extend(Self::into_statements(*second));
+                Some(Box::new(Statement::Block(Block { statements, span, id: self.node_builder.next_id() })))
+            }
+            (first, second) => first.or(second),
+        };
+
+        ConditionalStatement {
+            condition: first.condition,
+            then,
+            otherwise,
+            span: first.span + second.span,
+            id: first.id,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConditionalFuser;
+
+use leo_ast::{Block, ExpressionReconstructor, ProgramReconstructor, Statement, StatementReconstructor};
+
+impl ExpressionReconstructor for ConditionalFuser<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for ConditionalFuser<'_> {
+    /// Reconstructs the statements in the block, fusing each conditional into the preceding one if possible.
+    fn reconstruct_block(&mut self, block: Block) -> (Block, Self::AdditionalOutput) {
+        let mut statements: Vec<Statement> = Vec::with_capacity(block.statements.len());
+
+        for statement in block.statements {
+            match (statements.pop(), self.reconstruct_statement(statement).0) {
+                (Some(Statement::Conditional(first)), Statement::Conditional(second))
+                    if Self::can_fuse(&first, &second) =>
+                {
+                    statements.push(Statement::Conditional(self.fuse(first, second)))
+                }
+                (previous, statement) => {
+                    statements.extend(previous);
+                    statements.push(statement);
+                }
+            }
+        }
+
+        (Block { statements, span: block.span, id: block.id }, Default::default())
+    }
+}
+
+impl ProgramReconstructor for ConditionalFuser<'_> {}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Conditional Fusion pass traverses the AST and fuses adjacent conditional statements with the same condition
+//! into a single conditional statement, concatenating their `then` blocks and their `else` branches.
+//! Conditions are compared structurally, and only conditions without calls are fused.
+//! A conditional is not fused into the preceding one if the preceding one returns, writes a variable that the
+//! condition reads, or defines a variable that is mentioned in the corresponding branch.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: u8) -> u8 {
+//!     let x: u8 = 0u8;
+//!     let y: u8 = 0u8;
+//!     if a > 1u8 { x = 1u8; }
+//!     if a > 1u8 { y = 2u8; }
+//!     return x + y;
+//! }
+//! ```
+//!
+//! The conditional fusion pass produces the following code.
+//! ```leo
+//! function main(a: u8) -> u8 {
+//!     let x: u8 = 0u8;
+//!     let y: u8 = 0u8;
+//!     if a > 1u8 { x = 1u8; y = 2u8; }
+//!     return x + y;
+//! }
+//! ```
+
+pub mod conditional_fuser;
+pub use conditional_fuser::*;
+
+mod fuse_statement;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for ConditionalFuser<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = ConditionalFuser::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::Statement;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and fuses the given function body, returning its statements.
+    fn fuse_body(body: &str) -> Vec<Statement> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8) -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast = ConditionalFuser::do_pass((parse_program(&handler, &node_builder, &source), &node_builder)).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1.block.statements
+    }
+
+    #[test]
+    fn test_fuses_adjacent_conditionals() {
+        create_session_if_not_set_then(|_| {
+            let statements = fuse_body(
+                "let x: u8 = 0u8;
+                let y: u8 = 0u8;
+                if a > 1u8 { x = 1u8; } else { y = 1u8; }
+                if a > 1u8 { y = 2u8; } else { x = 2u8; }
+                return x + y;",
+            );
+
+            assert_eq!(statements.len(), 4);
+            let Statement::Conditional(conditional) = &statements[2] else {
+                panic!("expected a conditional, found `{}`", statements[2]);
+            };
+            assert_eq!(conditional.then.statements.len(), 2);
+            assert!(
+                matches!(conditional.otherwise.as_deref(), Some(Statement::Block(block)) if block.statements.len() == 2)
+            );
+        })
+    }
+
+    #[test]
+    fn test_preserves_conditionals_after_write_to_condition() {
+        create_session_if_not_set_then(|_| {
+            let statements = fuse_body(
+                "let b: u8 = a;
+                let y: u8 = 0u8;
+                if b > 1u8 { b = 0u8; }
+                if b > 1u8 { y = 2u8; }
+                return y;",
+            );
+
+            assert_eq!(statements.len(), 5);
+            assert!(matches!(statements[2], Statement::Conditional(_)));
+            assert!(matches!(statements[3], Statement::Conditional(_)));
+        })
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+
+pub struct ConsoleAssertLowerer<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> ConsoleAssertLowerer<'a> {
+    /// Initializes a new `ConsoleAssertLowerer` reducer.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Returns the comparison `left op right`, spanning both operands.
+    fn compare(&self, left: Expression, right: Expression, op: BinaryOperation) -> Expression {
+        Expression::Binary(BinaryExpression {
+            span: left.span() + right.span(),
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            id: self.node_builder.next_id(),
+        })
+    }
+}
+
+impl ExpressionReconstructor for ConsoleAssertLowerer<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for ConsoleAssertLowerer<'_> {
+    fn reconstruct_console(&mut self, input: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
+        let condition = match input.function {
+            ConsoleFunction::Assert(condition) => self.reconstruct_expression(condition).0,
+            ConsoleFunction::AssertEq(left, right) => {
+                let (left, right) = (self.reconstruct_expression(left).0, self.reconstruct_expression(right).0);
+                self.compare(left, right, BinaryOperation::Eq)
+            }
+            ConsoleFunction::AssertNeq(left, right) => {
+                let (left, right) = (self.reconstruct_expression(left).0, self.reconstruct_expression(right).0);
+                self.compare(left, right, BinaryOperation::Neq)
+            }
+        };
+        (
+            Statement::Assert(AssertStatement {
+                variant: AssertVariant::Assert(condition),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for ConsoleAssertLowerer<'_> {}
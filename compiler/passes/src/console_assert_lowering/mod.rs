@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Console Assert Lowering pass traverses the AST and rewrites each console assertion into an assert statement
+//! of a single condition, so that later passes only handle one kind of assertion.
+//! `console.assert_eq(a, b)` and `console.assert_neq(a, b)` are lowered to assertions of `a == b` and `a != b`.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u8 {
+//!     console.assert(a > 0u8);
+//!     console.assert_eq(a, b);
+//!     return a;
+//! }
+//! ```
+//!
+//! The console assert lowering pass produces the following code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u8 {
+//!     assert(a > 0u8);
+//!     assert(a == b);
+//!     return a;
+//! }
+//! ```
+
+pub mod console_assert_lowerer;
+pub use console_assert_lowerer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for ConsoleAssertLowerer<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = ConsoleAssertLowerer::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::{console_from_asserts, parse_program};
+
+    use leo_ast::{AssertVariant, BinaryOperation, Expression, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and lowers a transition whose body starts with the console statement `statement`,
+    /// written without the `console.` prefix, returning the condition it asserts.
+    fn lower_condition(statement: &str) -> Expression {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8, b: u8) -> u8 {{
+                    {statement}
+                    return a;
+                }}
+            }}"
+        );
+        let ast = ConsoleAssertLowerer::do_pass((
+            console_from_asserts(parse_program(&handler, &node_builder, &source)),
+            &node_builder,
+        ))
+        .unwrap();
+        let mut function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        match function.block.statements.swap_remove(0) {
+            Statement::Assert(assert) => match assert.variant {
+                AssertVariant::Assert(condition) => condition,
+                variant => panic!("expected a plain assertion, found `{variant:?}`"),
+            },
+            statement => panic!("expected an assert statement, found `{statement}`"),
+        }
+    }
+
+    #[test]
+    fn test_lowers_assert() {
+        create_session_if_not_set_then(|_| {
+            let condition = lower_condition("assert(a > b);");
+            assert!(matches!(condition, Expression::Binary(binary) if binary.op == BinaryOperation::Gt));
+        })
+    }
+
+    #[test]
+    fn test_lowers_assert_eq_to_equality() {
+        create_session_if_not_set_then(|_| {
+            let condition = lower_condition("assert_eq(a, b);");
+            let Expression::Binary(binary) = condition else {
+                panic!("expected a comparison, found `{condition}`");
+            };
+            assert_eq!(binary.op, BinaryOperation::Eq);
+            assert_eq!((binary.left.to_string(), binary.right.to_string()), ("a".to_string(), "b".to_string()));
+        })
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstDefinitionChecker;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// A compiler pass that checks that each argument for a constant parameter of a function is a constant expression.
+pub struct ConstArgChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// Tracks the constants in scope and decides whether an expression is constant.
+    constness: ConstDefinitionChecker<'a>,
+    /// A mapping from each function in the current program scope to the names of its parameters and whether or not
+    /// each of them is constant.
+    parameters: IndexMap<Symbol, Vec<(Symbol, bool)>>,
+}
+
+impl<'a> ConstArgChecker<'a> {
+    /// Returns a new `ConstArgChecker`.
+    pub fn new(handler: &'a Handler, purities: &'a IndexMap<Symbol, bool>) -> Self {
+        Self { handler, constness: ConstDefinitionChecker::new(handler, purities), parameters: IndexMap::new() }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ConstArgChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        if let (Expression::Identifier(function), None) = (&*input.function, &input.external) {
+            if let Some(parameters) = self.parameters.get(&function.name) {
+                for ((parameter, is_constant), argument) in parameters.iter().zip(input.arguments.iter()) {
+                    if *is_constant && !self.constness.is_const(argument) {
+                        self.handler.emit_err(StaticAnalyzerError::non_constant_argument(
+                            parameter,
+                            function.name,
+                            argument.span(),
+                        ));
+                    }
+                }
+            }
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+}
+
+impl<'a> StatementVisitor<'a> for ConstArgChecker<'a> {
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_expression(&input.value, &Default::default());
+        self.constness.constants.insert(input.place.name);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ConstArgChecker<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.parameters = input
+            .functions
+            .iter()
+            .map(|(name, function)| {
+                let parameters = function
+                    .input
+                    .iter()
+                    .map(|input| (input.identifier().name, input.mode() == Mode::Constant))
+                    .collect();
+                (*name, parameters)
+            })
+            .collect();
+
+        // Program-scope constants may be referenced before they are declared.
+        self.constness.globals = input.consts.iter().map(|(name, _)| *name).collect();
+
+        self.constness.constants = self.constness.globals.clone();
+        input.consts.iter().for_each(|(_, declaration)| self.visit_const(declaration));
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.constness.constants = self.constness.globals.clone();
+        self.constness.constants.extend(
+            input.input.iter().filter(|input| input.mode() == Mode::Constant).map(|input| input.identifier().name),
+        );
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.constness.constants = self.constness.globals.clone();
+            self.visit_block(&finalize.block);
+        }
+    }
+}
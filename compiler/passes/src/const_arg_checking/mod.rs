@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Const Arg Checking pass traverses the AST and checks that each argument for a `constant` parameter of a
+//! function is a constant expression, reporting other arguments at their span.
+//! A constant expression is decided as by the const definition checking pass: it may use literals, constants,
+//! constant function inputs, operators, and calls to pure functions with constant arguments.
+//! Note that this pass relies on the purity analysis to determine which functions are pure.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function scale(constant k: u8, a: u8) -> u8 {
+//!     return a * k;
+//! }
+//!
+//! transition main(a: u8) -> u8 {
+//!     const c: u8 = 2u8;
+//!     return scale(c, a) + scale(a, a);
+//! }
+//! ```
+//!
+//! The const arg checking pass reports an error for the argument `a` of the second call, which is not constant.
+
+pub mod const_arg_checker;
+pub use const_arg_checker::*;
+
+use crate::{Pass, PurityAnalyzer};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ConstArgChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let purities = PurityAnalyzer::do_pass(ast);
+        let mut visitor = ConstArgChecker::new(handler, &purities);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Checks a program with a function taking a constant parameter and a transition with the given body.
+    fn check_body(body: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                function scale(constant k: u8, a: u8) -> u8 {{
+                    return a * k;
+                }}
+                transition main(a: u8) -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        ConstArgChecker::do_pass((&ast, &handler))
+    }
+
+    #[test]
+    fn test_accepts_literal_argument() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("return scale(2u8, a);").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_accepts_constant_argument() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("const c: u8 = 2u8; return scale(c, a);").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_runtime_argument() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("return scale(a, a);").is_err());
+        })
+    }
+}
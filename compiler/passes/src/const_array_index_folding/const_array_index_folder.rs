@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+pub struct ConstArrayIndexFolder<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// A mapping from the names of the program-scope constants to their values.
+    pub(crate) globals: IndexMap<Symbol, Expression>,
+}
+
+impl<'a> ConstArrayIndexFolder<'a> {
+    /// Initializes a new `ConstArrayIndexFolder` reducer.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, globals: IndexMap::new() }
+    }
+
+    /// Returns the value of `index`, if it is an integer literal or a program-scope constant bound to one.
+    fn constant_index(&self, index: &Expression) -> Option<i128> {
+        match index {
+            Expression::Literal(Literal::Integer(_, value, ..)) => value.replace('_', "").parse().ok(),
+            Expression::Identifier(identifier) => match self.globals.get(&identifier.name) {
+                Some(value @ Expression::Literal(_)) => self.constant_index(value),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the array literal that `array` evaluates to, if it is a program-scope constant array.
+    fn constant_array(&self, array: &Expression) -> Option<ArrayExpression> {
+        match array {
+            Expression::Identifier(identifier) => match self.globals.get(&identifier.name) {
+                Some(Expression::Array(array)) => Some(array.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl ExpressionReconstructor for ConstArrayIndexFolder<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        // An access into a folded access, e.g. `A[0u32]` in `A[0u32][1u32]`, is folded as well.
+        let is_nested = matches!(*input.array, Expression::Access(AccessExpression::Array(_)));
+        let array = self.reconstruct_expression(*input.array).0;
+        let index = self.reconstruct_expression(*input.index).0;
+
+        let elements = match (&array, self.constant_array(&array)) {
+            (_, Some(constant)) => Some(constant.elements),
+            (Expression::Array(folded), None) if is_nested => Some(folded.elements.clone()),
+            _ => None,
+        };
+        if let (Some(mut elements), Some(value)) = (elements, self.constant_index(&index)) {
+            match usize::try_from(value) {
+                Ok(position) if position < elements.len() => {
+                    return (elements.swap_remove(position), Default::default())
+                }
+                _ => self.handler.emit_err(StaticAnalyzerError::array_index_out_of_range(
+                    value,
+                    elements.len(),
+                    input.span,
+                )),
+            }
+        }
+
+        (
+            Expression::Access(AccessExpression::Array(ArrayAccess {
+                array: Box::new(array),
+                index: Box::new(index),
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for ConstArrayIndexFolder<'_> {}
+
+impl ProgramReconstructor for ConstArrayIndexFolder<'_> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        self.globals =
+            input.consts.iter().map(|(_, declaration)| (declaration.place.name, declaration.value.clone())).collect();
+
+        ProgramScope {
+            program_id: input.program_id,
+            structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+}
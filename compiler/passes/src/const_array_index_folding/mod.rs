@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Const Array Index Folding pass traverses the AST and folds accesses into program-scope constant arrays
+//! whose index is constant, i.e. an integer literal or a program-scope constant bound to one.
+//! Each such access is replaced by the corresponding element of the array literal, and nested accesses are folded
+//! one dimension at a time.
+//! Constant indices that are out of range are reported at the span of the access.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! const TABLE: [u8; 3] = [1u8, 2u8, 3u8];
+//!
+//! transition main(a: u8) -> u8 {
+//!     return a + TABLE[2u32];
+//! }
+//! ```
+//!
+//! The const array index folding pass produces the following code.
+//! ```leo
+//! const TABLE: [u8; 3] = [1u8, 2u8, 3u8];
+//!
+//! transition main(a: u8) -> u8 {
+//!     return a + 3u8;
+//! }
+//! ```
+
+pub mod const_array_index_folder;
+pub use const_array_index_folder::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ConstArrayIndexFolder<'a> {
+    type Input = (Ast, &'a Handler);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut reconstructor = ConstArrayIndexFolder::new(handler);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{NodeBuilder, Statement};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and folds a program with a constant array and a transition returning `expression`.
+    fn fold_return(expression: &str) -> Result<Ast> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                const TABLE: [[u8; 2]; 2] = [[1u8, 2u8], [3u8, 4u8]];
+                const ONE: u32 = 1u32;
+                transition main() -> u8 {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        ConstArrayIndexFolder::do_pass((ast, &handler))
+    }
+
+    #[test]
+    fn test_folds_constant_index() {
+        create_session_if_not_set_then(|_| {
+            let ast = fold_return("TABLE[ONE][0u32]").unwrap();
+            let function = &ast.as_repr().program_scopes[0].functions[0].1;
+            let Statement::Return(return_) = &function.block.statements[0] else {
+                panic!("expected a return, found `{}`", function.block.statements[0]);
+            };
+            assert_eq!(return_.expression.to_string(), "3u8");
+        })
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_index() {
+        create_session_if_not_set_then(|_| {
+            assert!(fold_return("TABLE[2u32][0u32]").is_err());
+        })
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::{sym, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A compiler pass that checks that each constant is initialized with a constant expression.
+pub struct ConstDefinitionChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping from each function to whether or not it is pure, as computed by the purity analysis.
+    purities: &'a IndexMap<Symbol, bool>,
+    /// The program-scope constants.
+    pub(crate) globals: IndexSet<Symbol>,
+    /// The constants in scope, including the program-scope constants and the constant inputs of the current function.
+    pub(crate) constants: IndexSet<Symbol>,
+}
+
+impl<'a> ConstDefinitionChecker<'a> {
+    /// Returns a new `ConstDefinitionChecker`.
+    pub fn new(handler: &'a Handler, purities: &'a IndexMap<Symbol, bool>) -> Self {
+        Self { handler, purities, globals: IndexSet::new(), constants: IndexSet::new() }
+    }
+
+    /// Returns `true` if the expression is constant, i.e. it only depends on literals and constants,
+    /// and only calls pure functions and core functions without side effects.
+    pub(crate) fn is_const(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Access(AccessExpression::Array(access)) => {
+                self.is_const(&access.array) && self.is_const(&access.index)
+            }
+            Expression::Access(AccessExpression::AssociatedConstant(_)) => true,
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => {
+                !matches!(function.ty, Type::Identifier(Identifier { name: sym::Mapping | sym::ChaCha, .. }))
+                    && function.arguments.iter().all(|argument| self.is_const(argument))
+            }
+            Expression::Access(AccessExpression::Member(access)) => self.is_const(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => self.is_const(&access.tuple),
+            Expression::Array(array) => array.elements.iter().all(|element| self.is_const(element)),
+            Expression::Ascription(ascription) => self.is_const(&ascription.expression),
+            Expression::Binary(binary) => self.is_const(&binary.left) && self.is_const(&binary.right),
+            Expression::Call(call) => match (&*call.function, &call.external) {
+                (Expression::Identifier(function), None) => {
+                    self.purities.get(&function.name).copied().unwrap_or(false)
+                        && call.arguments.iter().all(|argument| self.is_const(argument))
+                }
+                _ => false,
+            },
+            Expression::Cast(cast) => self.is_const(&cast.expression),
+            Expression::Identifier(identifier) => self.constants.contains(&identifier.name),
+            Expression::Literal(_) | Expression::Unit(_) => true,
+            Expression::Struct(struct_) => struct_.members.iter().all(|member| match &member.expression {
+                Some(expression) => self.is_const(expression),
+                None => self.constants.contains(&member.identifier.name),
+            }),
+            Expression::Ternary(ternary) => {
+                self.is_const(&ternary.condition) && self.is_const(&ternary.if_true) && self.is_const(&ternary.if_false)
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().all(|element| self.is_const(element)),
+            Expression::Unary(unary) => self.is_const(&unary.receiver),
+            Expression::Err(_) | Expression::Spread(_) => false,
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ConstDefinitionChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for ConstDefinitionChecker<'a> {
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        if !self.is_const(&input.value) {
+            self.handler.emit_err(StaticAnalyzerError::non_constant_initializer(input.place, input.span));
+        }
+        self.constants.insert(input.place.name);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ConstDefinitionChecker<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        // Program-scope constants may be referenced before they are declared.
+        self.globals = input.consts.iter().map(|(name, _)| *name).collect();
+
+        self.constants = self.globals.clone();
+        input.consts.iter().for_each(|(_, declaration)| self.visit_const(declaration));
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.constants = self.globals.clone();
+        self.constants.extend(
+            input.input.iter().filter(|input| input.mode() == Mode::Constant).map(|input| input.identifier().name),
+        );
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.constants = self.globals.clone();
+            self.visit_block(&finalize.block);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Const Definition Checking pass traverses the AST and checks that each constant is initialized with a constant
+//! expression, reporting non-constant initializers at the span of the declaration.
+//! A constant expression may use literals, other constants, constant function inputs, operators, and calls to pure
+//! functions with constant arguments.
+//! Note that this pass relies on the purity analysis to determine which functions are pure.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function side_effecting() -> u8 {
+//!     assert(true);
+//!     return 1u8;
+//! }
+//!
+//! transition main() -> u8 {
+//!     const a: u8 = side_effecting();
+//!     return a;
+//! }
+//! ```
+//!
+//! The const definition checking pass reports an error for `a`, since `side_effecting` is impure.
+
+pub mod const_definition_checker;
+pub use const_definition_checker::*;
+
+use crate::{Pass, PurityAnalyzer};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ConstDefinitionChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let purities = PurityAnalyzer::do_pass(ast);
+        let mut visitor = ConstDefinitionChecker::new(handler, &purities);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Checks a program with an impure function and a transition with the given body.
+    fn check_body(body: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                function side_effecting() -> u8 {{
+                    assert(true);
+                    return 1u8;
+                }}
+                transition main() -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        ConstDefinitionChecker::do_pass((&ast, &handler))
+    }
+
+    #[test]
+    fn test_accepts_literal_initializer() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("const a: u8 = 1u8; return a;").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_accepts_reference_to_constant() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("const a: u8 = 1u8; const b: u8 = a + 1u8; return b;").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_call_to_impure_function() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("const a: u8 = side_effecting(); return a;").is_err());
+        })
+    }
+}
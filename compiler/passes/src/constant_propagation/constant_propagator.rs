@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NameCollector;
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+pub struct ConstantPropagator<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The variables declared in each enclosing block, innermost last.
+    /// A variable maps to the literal it is bound to, or `None` if its value is unknown or it has been reassigned.
+    pub(crate) scopes: Vec<IndexMap<Symbol, Option<Literal>>>,
+}
+
+impl<'a> ConstantPropagator<'a> {
+    /// Initializes a new `ConstantPropagator`.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder, scopes: Vec::new() }
+    }
+
+    /// Returns the literal bound to the innermost declaration of `name`, if it is known.
+    fn lookup(&self, name: Symbol) -> Option<&Literal> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(&name)).and_then(|value| value.as_ref())
+    }
+
+    /// Returns the literal that a variable defined as `value` is bound to, if `value` is a literal.
+    fn bound_literal(value: &Expression) -> Option<Literal> {
+        match value {
+            Expression::Literal(literal) => Some(literal.clone()),
+            _ => None,
+        }
+    }
+
+    /// Declares `name` in the current block, bound to `value` if it is known.
+    fn declare(&mut self, name: Symbol, value: Option<Literal>) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, value);
+        }
+    }
+
+    /// Stops propagating the innermost declaration of each of `names`.
+    fn invalidate(&mut self, names: impl IntoIterator<Item = Symbol>) {
+        for name in names {
+            if let Some(value) = self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(&name)) {
+                *value = None;
+            }
+        }
+    }
+}
+
+impl ExpressionReconstructor for ConstantPropagator<'_> {
+    type AdditionalOutput = ();
+
+    /// Leaves the callee intact, since it names a function rather than a variable.
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Call(CallExpression {
+                function: input.function,
+                arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                external: input.external,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Replaces a variable bound to a literal with a copy of the literal.
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        match self.lookup(input.name).cloned() {
+            Some(mut literal) => {
+                literal.set_span(input.span);
+                literal.set_id(self.node_builder.next_id());
+                (Expression::Literal(literal), Default::default())
+            }
+            None => (Expression::Identifier(input), Default::default()),
+        }
+    }
+}
+
+impl StatementReconstructor for ConstantPropagator<'_> {
+    /// Stops propagating the variables written by the assignment.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+        self.invalidate(NameCollector::of_expression(&input.place).names);
+        (
+            Statement::Assign(Box::new(AssignStatement { place: input.place, value, span: input.span, id: input.id })),
+            Default::default(),
+        )
+    }
+
+    /// Opens a new scope for the declarations in the block.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        self.scopes.push(IndexMap::new());
+        let statements =
+            input.statements.into_iter().map(|statement| self.reconstruct_statement(statement).0).collect();
+        self.scopes.pop();
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+        self.declare(input.place.name, Self::bound_literal(&value));
+        (
+            Statement::Const(ConstDeclaration {
+                place: input.place,
+                type_: input.type_,
+                value,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Declares the defined variables after reconstructing the value, so that the value refers to earlier bindings.
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+        match &input.place {
+            Expression::Identifier(place) => self.declare(place.name, Self::bound_literal(&value)),
+            place => NameCollector::of_expression(place).names.into_iter().for_each(|name| self.declare(name, None)),
+        }
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: input.place,
+                type_: input.type_,
+                value,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Stops propagating the variables written anywhere in the loop body before reconstructing it,
+    /// since a later iteration observes the writes of an earlier one.
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        let start = self.reconstruct_expression(input.start).0;
+        let stop = self.reconstruct_expression(input.stop).0;
+        let step = input.step.map(|step| self.reconstruct_expression(step).0);
+        self.invalidate(input.block.statements.iter().flat_map(NameCollector::written_by).collect::<Vec<_>>());
+
+        self.scopes.push(IndexMap::from([(input.variable.name, None)]));
+        let block = self.reconstruct_block(input.block).0;
+        self.scopes.pop();
+
+        (
+            Statement::Iteration(Box::new(IterationStatement {
+                variable: input.variable,
+                type_: input.type_,
+                start,
+                start_value: input.start_value,
+                stop,
+                stop_value: input.stop_value,
+                block,
+                inclusive: input.inclusive,
+                step,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for ConstantPropagator<'_> {}
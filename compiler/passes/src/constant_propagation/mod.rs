@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Constant Propagation pass traverses the AST and replaces uses of variables bound to literals with the literals.
+//! Each block opens a scope, so a definition in an inner block shadows an outer one only until the inner block ends.
+//! Once a variable is assigned to, its uses are no longer replaced, and the variables written anywhere in a loop body
+//! are not replaced within or after the loop, since a later iteration observes the writes of an earlier one.
+//! Definitions are kept, so that later passes can remove the ones that are no longer used.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8) -> u8 {
+//!     let x: u8 = 5u8;
+//!     let y: u8 = x + a;
+//!     x = y;
+//!     return x;
+//! }
+//! ```
+//!
+//! The constant propagation pass produces the following code.
+//! ```leo
+//! transition main(a: u8) -> u8 {
+//!     let x: u8 = 5u8;
+//!     let y: u8 = 5u8 + a;
+//!     x = y;
+//!     return x;
+//! }
+//! ```
+
+pub mod constant_propagator;
+pub use constant_propagator::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for ConstantPropagator<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = ConstantPropagator::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and propagates constants in a transition with inputs `a: u8` and `b: bool` and the given body,
+    /// returning the statements of the body as strings.
+    fn propagate(body: &str) -> Vec<String> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8, b: bool) -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast =
+            ConstantPropagator::do_pass((parse_program(&handler, &node_builder, &source), &node_builder)).unwrap();
+        let function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        function.block.statements.iter().map(|statement| statement.to_string()).collect()
+    }
+
+    #[test]
+    fn test_propagates_constant_binding() {
+        create_session_if_not_set_then(|_| {
+            let statements = propagate("let x: u8 = 5u8; let y: u8 = x + a; return x * y;");
+            assert_eq!(statements[1], "let y: u8 = 5u8 + a;");
+            assert_eq!(statements[2], "return 5u8 * y");
+        })
+    }
+
+    #[test]
+    fn test_stops_after_reassignment() {
+        create_session_if_not_set_then(|_| {
+            let statements = propagate("let x: u8 = 5u8; let y: u8 = x; x = a; return x + y;");
+            assert_eq!(statements[1], "let y: u8 = 5u8;");
+            assert_eq!(statements[2], "x = a;");
+            assert_eq!(statements[3], "return x + y");
+        })
+    }
+
+    #[test]
+    fn test_respects_shadowing_in_inner_block() {
+        create_session_if_not_set_then(|_| {
+            let statements = propagate("let x: u8 = 5u8; if b { let x: u8 = a; return x; } return x;");
+            assert!(statements[1].contains("return x"), "unexpected conditional `{}`", statements[1]);
+            assert!(!statements[1].contains("5u8"), "unexpected conditional `{}`", statements[1]);
+            assert_eq!(statements[2], "return 5u8");
+        })
+    }
+
+    #[test]
+    fn test_stops_for_variables_written_in_loop() {
+        create_session_if_not_set_then(|_| {
+            let statements = propagate("let x: u8 = 5u8; for i: u8 in 0u8..3u8 { a = a + x; x = a; } return x;");
+            assert!(statements[1].contains("a + x"), "unexpected loop `{}`", statements[1]);
+            assert_eq!(statements[2], "return x");
+        })
+    }
+}
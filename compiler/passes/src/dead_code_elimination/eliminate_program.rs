@@ -48,12 +48,15 @@ impl ProgramReconstructor for DeadCodeEliminator<'_> {
         });
 
         Function {
+            docs: input.docs,
             annotations: input.annotations,
             variant: input.variant,
             identifier: input.identifier,
+            type_params: input.type_params,
             input: input.input,
             output: input.output,
             output_type: input.output_type,
+            const_predicates: input.const_predicates,
             block,
             finalize,
             span: input.span,
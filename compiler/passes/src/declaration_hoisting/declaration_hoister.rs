@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexSet;
+
+pub struct DeclarationHoister<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> DeclarationHoister<'a> {
+    /// Initializes a new `DeclarationHoister`.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Returns a constant of the given type that can stand in for a variable until it is initialized, if one exists.
+    pub(crate) fn placeholder(&self, type_: &Type, span: Span) -> Option<Expression> {
+        let id = self.node_builder.next_id();
+        let literal = match type_ {
+            Type::Boolean => Literal::Boolean(false, span, id),
+            Type::Field => Literal::Field("0".to_string(), span, id),
+            Type::Group => Literal::Group(Box::new(GroupLiteral::Single("0".to_string(), span, id))),
+            Type::Integer(type_) => Literal::Integer(*type_, "0".to_string(), span, id),
+            Type::Scalar => Literal::Scalar("0".to_string(), span, id),
+            _ => return None,
+        };
+        Some(Expression::Literal(literal))
+    }
+}
+
+/// Collects the names of the variables used by a statement or an expression, and whether it contains any calls.
+#[derive(Default)]
+pub(crate) struct NameCollector {
+    /// The names of the variables that are read or written.
+    pub(crate) names: IndexSet<Symbol>,
+    /// Whether a function, an external function, or a core function is called.
+    pub(crate) has_calls: bool,
+}
+
+impl NameCollector {
+    /// Returns the collector for the given expression.
+    pub(crate) fn of_expression(input: &Expression) -> Self {
+        let mut collector = Self::default();
+        collector.visit_expression(input, &());
+        collector
+    }
+
+    /// Returns the collector for the given statement.
+    pub(crate) fn of_statement(input: &Statement) -> Self {
+        let mut collector = Self::default();
+        collector.visit_statement(input);
+        collector
+    }
+
+    /// Returns the names of the variables that are defined or assigned by the given statement.
+    pub(crate) fn written_by(input: &Statement) -> IndexSet<Symbol> {
+        match input {
+            Statement::Annotated(statement) => Self::written_by(&statement.statement),
+            Statement::Assign(statement) => Self::of_expression(&statement.place).names,
+            Statement::Block(block) => block.statements.iter().flat_map(Self::written_by).collect(),
+            Statement::Conditional(statement) => statement
+                .then
+                .statements
+                .iter()
+                .chain(statement.otherwise.as_deref())
+                .flat_map(Self::written_by)
+                .collect(),
+            Statement::Const(statement) => IndexSet::from([statement.place.name]),
+            Statement::Definition(statement) => Self::of_expression(&statement.place).names,
+            Statement::Iteration(statement) => std::iter::once(statement.variable.name)
+                .chain(statement.block.statements.iter().flat_map(Self::written_by))
+                .collect(),
+            Statement::Assert(_) | Statement::Console(_) | Statement::Expression(_) | Statement::Return(_) => {
+                IndexSet::new()
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for NameCollector {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                self.has_calls = true;
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.has_calls = true;
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.names.insert(input.name);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for member in input.members.iter() {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                None => self.visit_identifier(&member.identifier, additional),
+            }
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for NameCollector {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_identifier(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_identifier(&input.variable, &());
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+        self.visit_block(&input.block);
+    }
+}
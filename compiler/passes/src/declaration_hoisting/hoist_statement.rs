@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{DeclarationHoister, NameCollector};
+
+use leo_ast::{
+    AssignStatement,
+    Block,
+    DeclarationType,
+    DefinitionStatement,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    ProgramReconstructor,
+    Statement,
+    StatementReconstructor,
+};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+impl ExpressionReconstructor for DeclarationHoister<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for DeclarationHoister<'_> {
+    /// Reconstructs the statements in the block, moving its definitions to the top of the block.
+    /// A definition is moved as a whole if it can be evaluated before the statements that precede it.
+    /// Otherwise, if it is a `let` binding a single variable, it is split into a declaration, which is moved,
+    /// and an assignment, which stays in place.
+    /// All other definitions stay in place.
+    fn reconstruct_block(&mut self, block: Block) -> (Block, Self::AdditionalOutput) {
+        let mut declarations = Vec::new();
+        let mut statements = Vec::with_capacity(block.statements.len());
+        // The names used and the names written by the statements that stay in place.
+        let mut used = IndexSet::new();
+        let mut written = IndexSet::new();
+
+        for statement in block.statements {
+            let statement = match self.reconstruct_statement(statement).0 {
+                Statement::Definition(definition) => {
+                    let (declaration, statement) = self.hoist(definition, &statements, &used, &written);
+                    declarations.extend(declaration);
+                    match statement {
+                        Some(statement) => statement,
+                        None => continue,
+                    }
+                }
+                statement => statement,
+            };
+            used.extend(NameCollector::of_statement(&statement).names);
+            written.extend(NameCollector::written_by(&statement));
+            statements.push(statement);
+        }
+
+        declarations.extend(statements);
+        (Block { statements: declarations, span: block.span, id: block.id }, Default::default())
+    }
+}
+
+impl DeclarationHoister<'_> {
+    /// Hoists the definition above the given statements, whose used and written names are given.
+    /// Returns the statement, if any, to move to the top of the block, and the statement, if any, to leave in place.
+    fn hoist(
+        &self,
+        definition: DefinitionStatement,
+        statements: &[Statement],
+        used: &IndexSet<Symbol>,
+        written: &IndexSet<Symbol>,
+    ) -> (Option<Statement>, Option<Statement>) {
+        let value = NameCollector::of_expression(&definition.value);
+        let defined = NameCollector::of_expression(&definition.place).names;
+
+        // Moving a definition must not change which variable a name refers to.
+        if defined.iter().any(|name| used.contains(name)) {
+            return (None, Some(Statement::Definition(definition)));
+        }
+        // The value is unchanged if it reads no variable written before it, and its calls are not reordered with other statements.
+        if value.names.iter().all(|name| !written.contains(name)) && (!value.has_calls || statements.is_empty()) {
+            return (Some(Statement::Definition(definition)), None);
+        }
+
+        let placeholder = match (&definition.declaration_type, &definition.place) {
+            (DeclarationType::Let, Expression::Identifier(_)) => self.placeholder(&definition.type_, definition.span),
+            _ => None,
+        };
+        match (placeholder, &definition.place) {
+            (Some(placeholder), Expression::Identifier(identifier)) => {
+                let place = Expression::Identifier(Identifier {
+                    name: identifier.name,
+                    span: identifier.span,
+                    id: self.node_builder.next_id(),
+                });
+                let assignment = Statement::Assign(Box::new(AssignStatement {
+                    place,
+                    value: definition.value,
+                    span: definition.span,
+                    id: self.node_builder.next_id(),
+                }));
+                let declaration = Statement::Definition(DefinitionStatement { value: placeholder, ..definition });
+                (Some(declaration), Some(assignment))
+            }
+            _ => (None, Some(Statement::Definition(definition))),
+        }
+    }
+}
+
+impl ProgramReconstructor for DeclarationHoister<'_> {}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Declaration Hoisting pass traverses the AST and moves the definitions in each block to the top of the block,
+//! preserving their relative order.
+//! A definition whose value depends on a preceding statement, or whose value makes calls after other statements,
+//! is split into a declaration, initialized with a placeholder, and an assignment that stays in place.
+//! This preserves the order in which the variables are initialized.
+//! Definitions that cannot be split, e.g. those of a struct type, stay in place.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: u8) -> u8 {
+//!     assert_eq(a, 1u8);
+//!     let b: u8 = a + a;
+//!     let c: u8 = foo(b);
+//!     return c;
+//! }
+//! ```
+//!
+//! The declaration hoisting pass produces the following code.
+//! ```leo
+//! function main(a: u8) -> u8 {
+//!     let b: u8 = a + a;
+//!     let c: u8 = 0u8;
+//!     assert_eq(a, 1u8);
+//!     c = foo(b);
+//!     return c;
+//! }
+//! ```
+
+pub mod declaration_hoister;
+pub use declaration_hoister::*;
+
+mod hoist_statement;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for DeclarationHoister<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = DeclarationHoister::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_hoists_declarations_in_initialization_order() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let node_builder = NodeBuilder::default();
+            let source = "program test.aleo {
+                function foo(a: u8) -> u8 {
+                    assert(a > 0u8);
+                    return a;
+                }
+
+                transition main(a: u8) -> u8 {
+                    assert_eq(a, 1u8);
+                    let b: u8 = a + a;
+                    let c: u8 = foo(a);
+                    let d: u8 = foo(c);
+                    return b + c + d;
+                }
+            }";
+            let ast =
+                DeclarationHoister::do_pass((parse_program(&handler, &node_builder, source), &node_builder)).unwrap();
+            let function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+            let statements: Vec<String> = function.block.statements.iter().map(ToString::to_string).collect();
+
+            assert_eq!(statements, [
+                "let b: u8 = a + a;",
+                "let c: u8 = 0u8;",
+                "let d: u8 = 0u8;",
+                "assert_eq(a, 1u8);",
+                "c = foo(a);",
+                "d = foo(c);",
+                "return b + c + d",
+            ]);
+        })
+    }
+}
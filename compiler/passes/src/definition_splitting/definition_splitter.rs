@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{DefinitionStatement, Expression, NodeBuilder, Type};
+
+use itertools::Itertools;
+
+pub struct DefinitionSplitter<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> DefinitionSplitter<'a> {
+    /// Initializes a new `DefinitionSplitter`.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Splits a definition binding a tuple of variables to a tuple expression, e.g. `let (a, b): (u8, u8) = (1u8, 2u8);`,
+    /// into one definition per variable, e.g. `let a: u8 = 1u8; let b: u8 = 2u8;`.
+    /// Nested tuples are split recursively.
+    /// All other definitions, including destructuring definitions like `let (a, b): (u8, u8) = foo();`, are returned as is.
+    pub(crate) fn split(&self, input: DefinitionStatement) -> Vec<DefinitionStatement> {
+        match (input.place, input.type_, input.value) {
+            (Expression::Tuple(place), Type::Tuple(type_), Expression::Tuple(value))
+                if place.elements.len() == type_.length() && place.elements.len() == value.elements.len() =>
+            {
+                place
+                    .elements
+                    .into_iter()
+                    .zip_eq(type_.elements().iter().cloned())
+                    .zip_eq(value.elements)
+                    .flat_map(|((place, type_), value)| {
+                        self.split(DefinitionStatement {
+                            declaration_type: input.declaration_type,
+                            place,
+                            type_,
+                            value,
+                            span: input.span,
+                            id: self.node_builder.next_id(),
+                        })
+                    })
+                    .collect()
+            }
+            (place, type_, value) => vec![DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place,
+                type_,
+                value,
+                span: input.span,
+                id: input.id,
+            }],
+        }
+    }
+}
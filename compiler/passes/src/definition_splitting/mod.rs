@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Definition Splitting pass traverses the AST and splits each definition binding a tuple of variables
+//! to a tuple expression into one definition per variable.
+//! The declaration type and the types of the variables are preserved.
+//! Destructuring definitions, whose values are not tuple expressions, are left intact.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: u8) -> u8 {
+//!     let (b, c): (u8, bool) = (a, true);
+//!     let (d, e): (u8, u8) = foo(b);
+//!     return c ? d : e;
+//! }
+//! ```
+//!
+//! The definition splitting pass produces the following code.
+//! ```leo
+//! function main(a: u8) -> u8 {
+//!     let b: u8 = a;
+//!     let c: bool = true;
+//!     let (d, e): (u8, u8) = foo(b);
+//!     return c ? d : e;
+//! }
+//! ```
+
+pub mod definition_splitter;
+pub use definition_splitter::*;
+
+mod split_statement;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for DefinitionSplitter<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = DefinitionSplitter::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{DeclarationType, Expression, Statement, Type};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and splits the given function body, returning its statements.
+    fn split_body(body: &str) -> Vec<Statement> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                function foo(a: u8) -> (u8, u8) {{
+                    return (a, a);
+                }}
+
+                transition main(a: u8) -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast =
+            DefinitionSplitter::do_pass((parse_program(&handler, &node_builder, &source), &node_builder)).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1.block.statements
+    }
+
+    #[test]
+    fn test_splits_parallel_definition() {
+        create_session_if_not_set_then(|_| {
+            let statements = split_body("let (b, c): (u8, bool) = (a, true); return b;");
+
+            assert_eq!(statements.len(), 3);
+            for (statement, (name, type_)) in
+                statements.iter().zip([("b", Type::Integer(leo_ast::IntegerType::U8)), ("c", Type::Boolean)])
+            {
+                let Statement::Definition(definition) = statement else {
+                    panic!("expected a definition, found `{statement}`");
+                };
+                assert_eq!(definition.declaration_type, DeclarationType::Let);
+                assert!(
+                    matches!(&definition.place, Expression::Identifier(identifier) if identifier.name.to_string() == name)
+                );
+                assert_eq!(definition.type_, type_);
+            }
+        })
+    }
+
+    #[test]
+    fn test_preserves_destructuring_definition() {
+        create_session_if_not_set_then(|_| {
+            let statements = split_body("let (b, c): (u8, u8) = foo(a); return b;");
+
+            assert_eq!(statements.len(), 2);
+            let Statement::Definition(definition) = &statements[0] else {
+                panic!("expected a definition, found `{}`", statements[0]);
+            };
+            assert!(matches!(&definition.place, Expression::Tuple(tuple) if tuple.elements.len() == 2));
+            assert!(matches!(&definition.value, Expression::Call(_)));
+        })
+    }
+}
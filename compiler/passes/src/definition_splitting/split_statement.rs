@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::DefinitionSplitter;
+
+use leo_ast::{
+    Block,
+    DefinitionStatement,
+    ExpressionReconstructor,
+    ProgramReconstructor,
+    Statement,
+    StatementReconstructor,
+};
+
+impl ExpressionReconstructor for DefinitionSplitter<'_> {
+    type AdditionalOutput = Vec<Statement>;
+}
+
+impl StatementReconstructor for DefinitionSplitter<'_> {
+    /// Reconstructs the statements in the block, accumulating any additional statements.
+    fn reconstruct_block(&mut self, block: Block) -> (Block, Self::AdditionalOutput) {
+        let mut statements = Vec::with_capacity(block.statements.len());
+
+        for statement in block.statements {
+            let (reconstructed_statement, additional_statements) = self.reconstruct_statement(statement);
+            statements.extend(additional_statements);
+            statements.push(reconstructed_statement);
+        }
+
+        (Block { statements, span: block.span, id: block.id }, Default::default())
+    }
+
+    /// Splits the definition into one definition per variable, if possible.
+    /// The last definition is returned as the reconstructed statement, and the others as additional statements.
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        let mut statements: Vec<Statement> = self.split(input).into_iter().map(Statement::Definition).collect();
+        // Note that this unwrap is safe since `split` always returns at least one definition.
+        let last = statements.pop().unwrap();
+        (last, statements)
+    }
+}
+
+impl ProgramReconstructor for DefinitionSplitter<'_> {}
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, TypeTable};
+
+use leo_ast::*;
+
+pub struct DiscardNormalizer<'a> {
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+}
+
+impl<'a> DiscardNormalizer<'a> {
+    /// Initializes a new `DiscardNormalizer`.
+    pub fn new(type_table: &'a TypeTable, node_builder: &'a NodeBuilder, assigner: &'a Assigner) -> Self {
+        Self { type_table, node_builder, assigner }
+    }
+
+    /// Returns a new node ID of the given type.
+    fn next_id(&self, type_: Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        id
+    }
+}
+
+impl ExpressionReconstructor for DiscardNormalizer<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for DiscardNormalizer<'_> {
+    fn reconstruct_expression_statement(&mut self, input: ExpressionStatement) -> (Statement, Self::AdditionalOutput) {
+        let type_ = match &input.expression {
+            Expression::Call(call) => self.type_table.get(&call.id),
+            _ => None,
+        };
+
+        match type_ {
+            // The result of the call is bound to a fresh variable that is never read.
+            Some(type_) if type_ != Type::Unit => {
+                let name = self.assigner.unique_symbol("$discard", "$");
+                (
+                    Statement::Definition(DefinitionStatement {
+                        declaration_type: DeclarationType::Let,
+                        place: Expression::Identifier(Identifier {
+                            name,
+                            span: input.span,
+                            id: self.next_id(type_.clone()),
+                        }),
+                        type_,
+                        value: input.expression,
+                        span: input.span,
+                        id: input.id,
+                    }),
+                    Default::default(),
+                )
+            }
+            _ => (Statement::Expression(input), Default::default()),
+        }
+    }
+}
+
+impl ProgramReconstructor for DiscardNormalizer<'_> {}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Discard Normalization pass traverses the AST and makes the discarded results of calls explicit.
+//! Each expression statement that calls a function with a non-unit return type is replaced by a definition binding
+//! the result to a fresh variable that is never read. Calls to functions without a return value are left as is.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function checked(a: u8) -> u8 {
+//!     assert(a < 16u8);
+//!     return a;
+//! }
+//!
+//! transition main(a: u8) -> u8 {
+//!     checked(a);
+//!     return a;
+//! }
+//! ```
+//!
+//! The discard normalization pass produces the following code for `main`.
+//! ```leo
+//! transition main(a: u8) -> u8 {
+//!     let $discard$0: u8 = checked(a);
+//!     return a;
+//! }
+//! ```
+
+pub mod discard_normalizer;
+pub use discard_normalizer::*;
+
+use crate::{Assigner, Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for DiscardNormalizer<'a> {
+    type Input = (Ast, &'a TypeTable, &'a NodeBuilder, &'a Assigner);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, type_table, node_builder, assigner): Self::Input) -> Self::Output {
+        let mut reconstructor = DiscardNormalizer::new(type_table, node_builder, assigner);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and normalizes a program with the given helper function and a transition with the given body,
+    /// returning the statements of the transition as strings.
+    fn normalize(helper: &str, body: &str) -> Vec<String> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let type_table = TypeTable::default();
+        let source = format!(
+            "program test.aleo {{
+                {helper}
+                transition main(a: u8) -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+
+        let ast = DiscardNormalizer::do_pass((ast, &type_table, &node_builder, &Assigner::default())).unwrap();
+        let function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        function.block.statements.iter().map(|statement| statement.to_string()).collect()
+    }
+
+    #[test]
+    fn test_binds_discarded_result() {
+        create_session_if_not_set_then(|_| {
+            let statements =
+                normalize("function checked(a: u8) -> u8 { assert(a < 16u8); return a; }", "checked(a); return a;");
+
+            assert_eq!(statements, vec!["let $discard$0: u8 = checked(a);", "return a"]);
+        })
+    }
+
+    #[test]
+    fn test_keeps_unit_call() {
+        create_session_if_not_set_then(|_| {
+            let statements = normalize("function check(a: u8) { assert(a < 16u8); }", "check(a); return a;");
+
+            assert_eq!(statements, vec!["check(a);", "return a"]);
+        })
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+
+/// A compiler pass that removes the documentation comments from functions, structs, and their members.
+#[derive(Default)]
+pub struct DocStripper;
+
+impl ExpressionReconstructor for DocStripper {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for DocStripper {}
+
+impl ProgramReconstructor for DocStripper {
+    fn reconstruct_docs(&mut self, _input: Option<String>) -> Option<String> {
+        None
+    }
+}
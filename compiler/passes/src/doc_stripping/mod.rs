@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Doc Stripping pass traverses the AST and removes the documentation comments attached to functions, structs,
+//! and struct members, e.g. for release builds where they are not needed.
+//! Documentation comments are the `/// ...` lines and `/** ... */` blocks preceding an item, which the parser keeps
+//! in the `docs` field of the item.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! /// A point in the plane.
+//! struct Point {
+//!     /// The horizontal coordinate.
+//!     x: u32,
+//!     y: u32,
+//! }
+//! ```
+//!
+//! The doc stripping pass produces the same code, with the `docs` of `Point` and of its member `x` set to `None`.
+
+pub mod doc_stripper;
+pub use doc_stripper::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for DocStripper {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let program = DocStripper.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{ExpressionReconstructor, NodeBuilder, ProgramScope, StatementReconstructor};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// A reconstructor that leaves the program unchanged.
+    struct Identity;
+
+    impl ExpressionReconstructor for Identity {
+        type AdditionalOutput = ();
+    }
+
+    impl StatementReconstructor for Identity {}
+
+    impl ProgramReconstructor for Identity {}
+
+    /// Parses a documented program and reconstructs it with `reconstruct`, returning its program scope.
+    fn reconstruct(reconstruct: impl FnOnce(Ast) -> Ast) -> ProgramScope {
+        let handler = Handler::default();
+        let source = "program test.aleo {
+            /// A point in the plane.
+            struct Point {
+                /// The horizontal coordinate.
+                x: u32,
+                y: u32,
+            }
+
+            /**
+             * Returns the origin.
+             * Both coordinates are zero.
+             */
+            transition origin() -> Point {
+                // Not documentation.
+                return Point { x: 0u32, y: 0u32 };
+            }
+        }";
+        let ast = reconstruct(parse_program(&handler, &NodeBuilder::default(), source));
+        ast.into_repr().program_scopes.into_values().next().unwrap()
+    }
+
+    #[test]
+    fn test_docs_round_trip() {
+        create_session_if_not_set_then(|_| {
+            let scope = reconstruct(|ast| Ast::new(Identity.reconstruct_program(ast.into_repr())));
+
+            let point = &scope.structs[0].1;
+            assert_eq!(point.docs.as_deref(), Some("A point in the plane."));
+            assert_eq!(point.members[0].docs.as_deref(), Some("The horizontal coordinate."));
+            assert_eq!(point.members[1].docs, None);
+            assert_eq!(scope.functions[0].1.docs.as_deref(), Some("Returns the origin.\nBoth coordinates are zero."));
+        })
+    }
+
+    #[test]
+    fn test_strips_docs() {
+        create_session_if_not_set_then(|_| {
+            let scope = reconstruct(|ast| DocStripper::do_pass(ast).unwrap());
+
+            let point = &scope.structs[0].1;
+            assert_eq!(point.docs, None);
+            assert!(point.members.iter().all(|member| member.docs.is_none()));
+            assert_eq!(scope.functions[0].1.docs, None);
+        })
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::{sym, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The program-scope constants, mappings, and inputs that a function reads and writes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Effects {
+    /// The names of the constants, mappings, and inputs that are read.
+    pub reads: IndexSet<Symbol>,
+    /// The names of the mappings and inputs that are written.
+    pub writes: IndexSet<Symbol>,
+}
+
+/// A compiler pass that computes the effects of each function, i.e. the program-scope constants and mappings,
+/// and the inputs of the function itself, that it reads and writes.
+/// Mappings are read by `Mapping::get`, `Mapping::get_or_use`, and `Mapping::contains`,
+/// and written by `Mapping::set` and `Mapping::remove`; inputs are written by assignments.
+pub struct EffectAnalyzer<'a> {
+    /// For each function, its effects, ignoring the functions it calls.
+    pub(crate) local_effects: IndexMap<Symbol, Effects>,
+    /// For each function, the functions it calls.
+    pub(crate) callees: IndexMap<Symbol, IndexSet<Symbol>>,
+    /// The names of the constants and mappings in the program scope.
+    pub(crate) globals: IndexSet<Symbol>,
+    /// The names of the inputs of the function that we are currently traversing, including those of its finalize block.
+    pub(crate) inputs: IndexSet<Symbol>,
+    /// The function that we are currently traversing.
+    pub(crate) function: Option<&'a Function>,
+}
+
+impl<'a> EffectAnalyzer<'a> {
+    /// Returns a new `EffectAnalyzer`.
+    pub fn new() -> Self {
+        Self {
+            local_effects: Default::default(),
+            callees: Default::default(),
+            globals: Default::default(),
+            inputs: Default::default(),
+            function: None,
+        }
+    }
+
+    /// Returns the local effects of the current function, if any.
+    fn current_effects(&mut self) -> Option<&mut Effects> {
+        let function = self.function?;
+        self.local_effects.get_mut(&function.name())
+    }
+
+    /// Records a read of `name`, if it is a global or an input of the current function.
+    fn read(&mut self, name: Symbol) {
+        if self.globals.contains(&name) || self.inputs.contains(&name) {
+            if let Some(effects) = self.current_effects() {
+                effects.reads.insert(name);
+            }
+        }
+    }
+
+    /// Records a write to `name`, if it is a global or an input of the current function.
+    fn write(&mut self, name: Symbol) {
+        if self.globals.contains(&name) || self.inputs.contains(&name) {
+            if let Some(effects) = self.current_effects() {
+                effects.writes.insert(name);
+            }
+        }
+    }
+
+    /// Returns the variable that an assignment to `place` writes, e.g. `a` for `a.b.0`.
+    fn written_variable(place: &Expression) -> Option<Symbol> {
+        match place {
+            Expression::Identifier(identifier) => Some(identifier.name),
+            Expression::Access(AccessExpression::Array(access)) => Self::written_variable(&access.array),
+            Expression::Access(AccessExpression::Member(access)) => Self::written_variable(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => Self::written_variable(&access.tuple),
+            _ => None,
+        }
+    }
+
+    /// Returns a mapping from each function to its effects, including the effects on globals of the functions it calls.
+    /// The inputs of a callee are local to it, so they are not propagated to its callers.
+    /// Effects are propagated along the call graph until a fixpoint is reached, so recursive functions are supported.
+    pub fn effects(&self) -> IndexMap<Symbol, Effects> {
+        let mut effects = self.local_effects.clone();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (function, callees) in self.callees.iter() {
+                let mut reads = IndexSet::new();
+                let mut writes = IndexSet::new();
+                for callee in callees.iter().filter_map(|callee| effects.get(callee)) {
+                    reads.extend(callee.reads.iter().filter(|name| self.globals.contains(*name)));
+                    writes.extend(callee.writes.iter().filter(|name| self.globals.contains(*name)));
+                }
+                let caller = effects.entry(*function).or_default();
+                let (num_reads, num_writes) = (caller.reads.len(), caller.writes.len());
+                caller.reads.extend(reads);
+                caller.writes.extend(writes);
+                changed |= caller.reads.len() != num_reads || caller.writes.len() != num_writes;
+            }
+        }
+        effects
+    }
+}
+
+impl Default for EffectAnalyzer<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for EffectAnalyzer<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                let mut arguments = function.arguments.iter();
+                // The mapping operated on by `Mapping::set` and `Mapping::remove` is written rather than read.
+                if let (Type::Identifier(Identifier { name: sym::Mapping, .. }), sym::set | sym::remove) =
+                    (&function.ty, function.name.name)
+                {
+                    if let Some(Expression::Identifier(mapping)) = arguments.next() {
+                        self.write(mapping.name);
+                    }
+                }
+                arguments.for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Record calls to functions in the same program.
+        if let (None, Expression::Identifier(callee), Some(function)) =
+            (&input.external, &*input.function, self.function)
+        {
+            self.callees.entry(function.name()).or_default().insert(callee.name);
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.read(input.name);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, additional),
+            None => self.read(member.identifier.name),
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for EffectAnalyzer<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        if let Some(name) = Self::written_variable(&input.place) {
+            self.write(name);
+        }
+        self.visit_expression(&input.value, &Default::default());
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for EffectAnalyzer<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.globals.extend(input.consts.iter().map(|(name, _)| *name));
+        self.globals.extend(input.mappings.iter().map(|(name, _)| *name));
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.function = Some(input);
+        self.local_effects.insert(input.name(), Effects::default());
+        self.callees.entry(input.name()).or_default();
+        self.inputs = input.input.iter().map(|input| input.identifier().name).collect();
+
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.inputs.extend(finalize.input.iter().map(|input| input.identifier().name));
+            self.visit_block(&finalize.block);
+        }
+
+        self.inputs.clear();
+        self.function = None;
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Effect Analysis pass traverses the AST and computes, for each function, the program-scope constants and
+//! mappings, and its own inputs, that it reads and writes.
+//! Functions with disjoint effects may be safely reordered or parallelized by later passes.
+//!
+//! A function has the effects of its own body and finalize block, as well as the effects on constants and mappings
+//! of the functions it calls.
+//! Since calls may be recursive, the analysis iterates over the call graph until a fixpoint is reached.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! const SCALE: u8 = 2u8;
+//!
+//! function scale(a: u8) -> u8 {
+//!     a = a * SCALE;
+//!     return a;
+//! }
+//!
+//! transition main(a: u8) -> u8 {
+//!     return scale(a);
+//! }
+//! ```
+//!
+//! The effect analysis pass determines that `scale` reads `a` and `SCALE` and writes `a`,
+//! and that `main` reads `a` and `SCALE`.
+
+pub mod effect_analyzer;
+pub use effect_analyzer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+impl<'a> Pass for EffectAnalyzer<'a> {
+    type Input = &'a Ast;
+    type Output = IndexMap<Symbol, Effects>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut visitor = EffectAnalyzer::new();
+        visitor.visit_program(ast.as_repr());
+
+        visitor.effects()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    use indexmap::IndexSet;
+
+    /// Returns the names read and written by each function in the given program scope.
+    fn effects(items: &str) -> IndexMap<String, (Vec<String>, Vec<String>)> {
+        let handler = Handler::default();
+        let source = format!("program test.aleo {{ {items} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let names = |names: IndexSet<Symbol>| names.into_iter().map(|name| name.to_string()).collect::<Vec<_>>();
+        EffectAnalyzer::do_pass(&ast)
+            .into_iter()
+            .map(|(function, effects)| (function.to_string(), (names(effects.reads), names(effects.writes))))
+            .collect()
+    }
+
+    #[test]
+    fn test_reads_global() {
+        create_session_if_not_set_then(|_| {
+            let effects = effects(
+                "const SCALE: u8 = 2u8;
+                 function scale(a: u8) -> u8 { return a * SCALE; }",
+            );
+
+            assert_eq!(effects["scale"], (vec!["a".to_string(), "SCALE".to_string()], vec![]));
+        })
+    }
+
+    #[test]
+    fn test_writes_input() {
+        create_session_if_not_set_then(|_| {
+            let effects = effects("function double(a: u8) -> u8 { a = a + a; return a; }");
+
+            assert_eq!(effects["double"].1, vec!["a".to_string()]);
+        })
+    }
+
+    #[test]
+    fn test_caller_inherits_callee_effects() {
+        create_session_if_not_set_then(|_| {
+            let effects = effects(
+                "const SCALE: u8 = 2u8;
+                 function scale(a: u8) -> u8 { a = a * SCALE; return a; }
+                 transition main(b: u8) -> u8 { return scale(b); }",
+            );
+
+            assert_eq!(effects["scale"], (vec!["a".to_string(), "SCALE".to_string()], vec!["a".to_string()]));
+            // The input of the callee is local to it, so only the global is inherited.
+            assert_eq!(effects["main"], (vec!["b".to_string(), "SCALE".to_string()], vec![]));
+        })
+    }
+}
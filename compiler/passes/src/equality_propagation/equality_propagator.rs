@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+#[derive(Default)]
+pub struct EqualityPropagator {
+    /// The integer literals that variables are known to equal in the right operand of the current conjunction.
+    pub(crate) equalities: IndexMap<Symbol, Literal>,
+}
+
+impl EqualityPropagator {
+    /// Initializes a new `EqualityPropagator`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the equalities between a variable and an integer literal in the conjunction chain `input`.
+    fn collect_equalities(input: &Expression, equalities: &mut IndexMap<Symbol, Literal>) {
+        if let Expression::Binary(binary) = input {
+            match (binary.op, binary.left.as_ref(), binary.right.as_ref()) {
+                (BinaryOperation::And, left, right) => {
+                    Self::collect_equalities(left, equalities);
+                    Self::collect_equalities(right, equalities);
+                }
+                (
+                    BinaryOperation::Eq,
+                    Expression::Identifier(identifier),
+                    Expression::Literal(literal @ Literal::Integer(..)),
+                )
+                | (
+                    BinaryOperation::Eq,
+                    Expression::Literal(literal @ Literal::Integer(..)),
+                    Expression::Identifier(identifier),
+                ) => {
+                    equalities.insert(identifier.name, literal.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the type and value of `input` if it is an integer literal, or a variable known to equal one.
+    fn constant(&self, input: &Expression) -> Option<(IntegerType, i128)> {
+        let literal = match input {
+            Expression::Literal(literal) => literal,
+            Expression::Identifier(identifier) => self.equalities.get(&identifier.name)?,
+            _ => return None,
+        };
+        match literal {
+            Literal::Integer(type_, value, ..) => Some((*type_, value.replace('_', "").parse().ok()?)),
+            _ => None,
+        }
+    }
+
+    /// Folds the binary operation if both operands are constant and the result is in range.
+    /// Variables are only ever replaced by their values as part of a successful fold, so no operation that could
+    /// fail at runtime is introduced when the guarding equality does not hold.
+    fn fold(
+        &self,
+        op: BinaryOperation,
+        left: &Expression,
+        right: &Expression,
+        span: Span,
+        id: NodeID,
+    ) -> Option<Expression> {
+        let ((type_, left), (right_type, right)) = (self.constant(left)?, self.constant(right)?);
+        if type_ != right_type {
+            return None;
+        }
+
+        let value = match op {
+            BinaryOperation::Add => left.checked_add(right)?,
+            BinaryOperation::Sub => left.checked_sub(right)?,
+            BinaryOperation::Mul => left.checked_mul(right)?,
+            BinaryOperation::Eq => return Some(Expression::Literal(Literal::Boolean(left == right, span, id))),
+            BinaryOperation::Neq => return Some(Expression::Literal(Literal::Boolean(left != right, span, id))),
+            BinaryOperation::Lt => return Some(Expression::Literal(Literal::Boolean(left < right, span, id))),
+            BinaryOperation::Lte => return Some(Expression::Literal(Literal::Boolean(left <= right, span, id))),
+            BinaryOperation::Gt => return Some(Expression::Literal(Literal::Boolean(left > right, span, id))),
+            BinaryOperation::Gte => return Some(Expression::Literal(Literal::Boolean(left >= right, span, id))),
+            _ => return None,
+        };
+
+        // The result must be representable in the type of the operands.
+        let literal = Literal::Integer(type_, value.to_string(), span, id);
+        Value::try_from(&literal).is_ok().then_some(Expression::Literal(literal))
+    }
+}
+
+impl ExpressionReconstructor for EqualityPropagator {
+    type AdditionalOutput = ();
+
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let left = self.reconstruct_expression(*input.left).0;
+
+        let right = match input.op {
+            // The equalities in the left operand of a conjunction hold whenever its right operand matters.
+            BinaryOperation::And => {
+                let mut equalities = self.equalities.clone();
+                Self::collect_equalities(&left, &mut equalities);
+                let outer = std::mem::replace(&mut self.equalities, equalities);
+                let right = self.reconstruct_expression(*input.right).0;
+                self.equalities = outer;
+                right
+            }
+            _ => {
+                let right = self.reconstruct_expression(*input.right).0;
+                if let Some(folded) = self.fold(input.op, &left, &right, input.span, input.id) {
+                    return (folded, Default::default());
+                }
+                right
+            }
+        };
+
+        (
+            Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op: input.op,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for EqualityPropagator {}
+
+impl ProgramReconstructor for EqualityPropagator {}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Equality Propagation pass traverses the AST and propagates equalities between variables and integer literals
+//! through boolean conjunctions.
+//! In `a == 5u8 && e`, the right operand `e` only matters when `a` equals `5u8`, so arithmetic and comparisons in `e`
+//! that only involve `a` and literals are folded under that assumption.
+//! A variable is only replaced as part of a fold whose result is in range, so the rewritten expression never fails
+//! where the original would not.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> bool {
+//!     return a == 5u8 && a + 1u8 == b;
+//! }
+//! ```
+//!
+//! The equality propagation pass produces the following code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> bool {
+//!     return a == 5u8 && 6u8 == b;
+//! }
+//! ```
+
+pub mod equality_propagator;
+pub use equality_propagator::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for EqualityPropagator {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut reconstructor = EqualityPropagator::new();
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{NodeBuilder, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and propagates equalities in a transition with inputs `a: u8` and `b: u8` that returns `expression`,
+    /// returning the resulting expression as a string.
+    fn propagate_return(expression: &str) -> String {
+        let handler = Handler::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8, b: u8) -> bool {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = EqualityPropagator::do_pass(parse_program(&handler, &NodeBuilder::default(), &source)).unwrap();
+        let mut function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        match function.block.statements.pop() {
+            Some(Statement::Return(statement)) => statement.expression.to_string(),
+            statement => panic!("expected a return statement, found `{statement:?}`"),
+        }
+    }
+
+    #[test]
+    fn test_propagates_literal_equality() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(propagate_return("a == 5u8 && a + 1u8 == b"), "a == 5u8 && 6u8 == b");
+            assert_eq!(propagate_return("5u8 == a && b == a * 2u8 - 1u8"), "5u8 == a && b == 9u8");
+            assert_eq!(propagate_return("a == 5u8 && b == 1u8 && a < b"), "a == 5u8 && b == 1u8 && false");
+        })
+    }
+
+    #[test]
+    fn test_keeps_unguarded_and_overflowing_expressions() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(propagate_return("a + 1u8 == b && a == 5u8"), "a + 1u8 == b && a == 5u8");
+            assert_eq!(propagate_return("a == 255u8 && a + 1u8 == b"), "a == 255u8 && a + 1u8 == b");
+        })
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConditionalFuser;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+/// A compiler pass that checks that the conditional chains producing the returned value of a function cover every
+/// path, either with a final `else` branch or with a condition that is the negation of an earlier one.
+pub struct ExhaustivenessChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> ExhaustivenessChecker<'a> {
+    /// Returns a new `ExhaustivenessChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler }
+    }
+
+    /// Returns `true` if `right` is true whenever `left` is false, e.g. `!a` for `a`, or `x >= y` for `x < y`.
+    fn is_complement(left: &Expression, right: &Expression) -> bool {
+        let negates = |negation: &Expression, expression: &Expression| {
+            matches!(negation, Expression::Unary(unary) if unary.op == UnaryOperation::Not
+                && ConditionalFuser::same_pure_expression(&unary.receiver, expression))
+        };
+        if negates(left, right) || negates(right, left) {
+            return true;
+        }
+        let (Expression::Binary(left), Expression::Binary(right)) = (left, right) else {
+            return false;
+        };
+        let complement = match left.op {
+            BinaryOperation::Eq => BinaryOperation::Neq,
+            BinaryOperation::Neq => BinaryOperation::Eq,
+            BinaryOperation::Lt => BinaryOperation::Gte,
+            BinaryOperation::Gte => BinaryOperation::Lt,
+            BinaryOperation::Gt => BinaryOperation::Lte,
+            BinaryOperation::Lte => BinaryOperation::Gt,
+            _ => return false,
+        };
+        right.op == complement
+            && ConditionalFuser::same_pure_expression(&left.left, &right.left)
+            && ConditionalFuser::same_pure_expression(&left.right, &right.right)
+    }
+
+    /// Checks the conditional chain at the end of `block`, if any, since it produces the returned value.
+    fn check_tail(&self, block: &Block) {
+        match block.statements.last() {
+            Some(Statement::Block(block)) => self.check_tail(block),
+            Some(Statement::Conditional(conditional)) => self.check_chain(conditional),
+            _ => {}
+        }
+    }
+
+    /// Reports the conditional chain starting at `input` if it has no final `else` branch and none of its conditions
+    /// is the complement of an earlier one. The branches of the chain are checked in turn.
+    fn check_chain(&self, input: &ConditionalStatement) {
+        let mut conditions = Vec::new();
+        let mut conditional = input;
+        let exhaustive = loop {
+            self.check_tail(&conditional.then);
+            if conditions.iter().any(|condition| Self::is_complement(condition, &conditional.condition)) {
+                break true;
+            }
+            conditions.push(&conditional.condition);
+            match conditional.otherwise.as_deref() {
+                Some(Statement::Conditional(otherwise)) => conditional = otherwise,
+                Some(Statement::Block(otherwise)) => {
+                    self.check_tail(otherwise);
+                    break true;
+                }
+                Some(_) => break true,
+                None => break false,
+            }
+        };
+        if !exhaustive {
+            self.handler.emit_err(StaticAnalyzerError::non_exhaustive_conditional(input.span));
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ExhaustivenessChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for ExhaustivenessChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for ExhaustivenessChecker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        if input.output_type != Type::Unit {
+            self.check_tail(&input.block);
+        }
+        if let Some(finalize) = input.finalize.as_ref().filter(|finalize| finalize.output_type != Type::Unit) {
+            self.check_tail(&finalize.block);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Exhaustiveness Checking pass traverses the AST and checks that each conditional chain producing the returned
+//! value of a function, i.e. the last statement of the body of a function with an output, covers every path.
+//! A chain covers every path if it ends with an `else` branch, or if one of its conditions is the complement of an
+//! earlier one, e.g. `if a { .. } else if !a { .. }` or `if x < y { .. } else if x >= y { .. }`, since that branch is
+//! taken whenever it is reached. The chains at the end of each branch are checked in turn.
+//! A chain that may fall through is reported at the span of its first conditional.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: bool, b: bool) -> u8 {
+//!     if a {
+//!         return 1u8;
+//!     } else if !a {
+//!         if b {
+//!             return 2u8;
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The exhaustiveness checking pass accepts the outer chain, but reports the inner conditional, which may fall through
+//! when `b` is false.
+
+pub mod exhaustiveness_checker;
+pub use exhaustiveness_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ExhaustivenessChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = ExhaustivenessChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a function with inputs `a: bool` and `x: u8` and the given body.
+    fn check_body(body: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!("program test.aleo {{ function main(a: bool, x: u8) -> u8 {{ {body} }} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        ExhaustivenessChecker::do_pass((&ast, &handler))
+    }
+
+    #[test]
+    fn test_accepts_if_else() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("if a { return 1u8; } else { return 2u8; }").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_missing_else() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("if a { return 1u8; } else if x == 0u8 { return 2u8; }").is_err());
+        })
+    }
+
+    #[test]
+    fn test_accepts_complementary_conditions() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("if a { return 1u8; } else if !a { return 2u8; }").is_ok());
+            assert!(check_body(
+                "if x < 5u8 { return 1u8; } else if x == 7u8 { return 2u8; } else if x >= 5u8 { return 3u8; }"
+            )
+            .is_ok());
+        })
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::sym;
+
+/// A compiler pass that checks that `field` and `group` values are only cast to integer types
+/// in functions that opt in to truncation with the `@truncate` annotation.
+pub struct FieldCastChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+    /// Whether the function being traversed allows truncating casts.
+    allows_truncation: bool,
+}
+
+impl<'a> FieldCastChecker<'a> {
+    /// Returns a new `FieldCastChecker`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, type_table, allows_truncation: false }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for FieldCastChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        if let (Some(from @ (Type::Field | Type::Group)), Type::Integer(_)) =
+            (self.type_table.get(&input.expression.id()), &input.type_)
+        {
+            if !self.allows_truncation {
+                self.handler.emit_err(StaticAnalyzerError::truncating_cast_without_opt_in(
+                    from,
+                    &input.type_,
+                    input.span,
+                ));
+            }
+        }
+        self.visit_expression(&input.expression, additional);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for FieldCastChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for FieldCastChecker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        self.allows_truncation = input.annotations.iter().any(|annotation| annotation.identifier.name == sym::truncate);
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_block(&finalize.block);
+        }
+    }
+}
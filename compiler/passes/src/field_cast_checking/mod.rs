@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Field Cast Checking pass traverses the AST and checks that `field` and `group` values are only cast to
+//! integer types in functions annotated with `@truncate`, since such casts may lose information.
+//! Casts from integers to `field` are always allowed.
+//! Errors are reported at the span of the cast.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: field) -> u8 {
+//!     return a as u8;
+//! }
+//! ```
+//!
+//! The field cast checking pass reports an error for `a as u8`.
+//! The cast is allowed once `main` is annotated with `@truncate`.
+
+pub mod field_cast_checker;
+pub use field_cast_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for FieldCastChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut visitor = FieldCastChecker::new(handler, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks a transition with the given annotations that casts `a` to `to`, then checks the cast.
+    fn check_cast(annotations: &str, from: &str, to: &str) -> Result<()> {
+        let source = format!(
+            "program test.aleo {{
+                {annotations}
+                transition main(a: {from}) -> {to} {{
+                    return a as {to};
+                }}
+            }}"
+        );
+        // The type checker rejects unknown annotations, but still records the types of the expressions.
+        let (type_checker_handler, _) = Handler::new_with_buf();
+        let type_table = TypeTable::default();
+        let ast = parse_program(&type_checker_handler, &NodeBuilder::default(), &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &type_checker_handler)).unwrap();
+        let _ = TypeChecker::do_pass((&ast, &type_checker_handler, symbol_table, &type_table));
+
+        let (handler, _) = Handler::new_with_buf();
+        FieldCastChecker::do_pass((&ast, &handler, &type_table))
+    }
+
+    #[test]
+    fn test_rejects_field_to_integer_cast() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_cast("", "field", "u8").is_err());
+        })
+    }
+
+    #[test]
+    fn test_accepts_integer_to_field_cast() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_cast("", "u8", "field").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_accepts_opted_in_truncating_cast() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_cast("@truncate", "field", "u8").is_ok());
+        })
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+
+use snarkvm_console::{network::Testnet3, types::Field};
+use std::str::FromStr;
+
+#[derive(Default)]
+pub struct FieldLiteralCanonicalizer;
+
+impl FieldLiteralCanonicalizer {
+    /// Initializes a new `FieldLiteralCanonicalizer`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Returns the canonical representative in `[0, p)` of the field literal `value`, e.g. `-1`,
+    /// or `None` if `value` is already a non-negative reduced value or is not a valid field element.
+    pub(crate) fn canonicalize(value: &str) -> Option<String> {
+        let magnitude = value.trim_start_matches('-');
+        let is_negated = (value.len() - magnitude.len()) % 2 == 1;
+        let digits = magnitude.replace('_', "");
+
+        let field: Field<Testnet3> = Field::from_str(&format!("{digits}field")).ok()?;
+        let canonical = match is_negated {
+            true => -field,
+            false => field,
+        }
+        .to_string();
+        let canonical = canonical.strip_suffix("field").unwrap_or(&canonical);
+
+        // Literals that are already reduced are left byte-identical, even if they contain underscores or leading zeros.
+        let is_reduced = match digits.trim_start_matches('0') {
+            "" => canonical == "0",
+            trimmed => trimmed == canonical,
+        };
+        match magnitude.len() == value.len() && is_reduced {
+            true => None,
+            false => Some(canonical.to_string()),
+        }
+    }
+}
+
+impl ExpressionReconstructor for FieldLiteralCanonicalizer {
+    type AdditionalOutput = ();
+
+    fn reconstruct_literal(&mut self, input: Literal) -> (Expression, Self::AdditionalOutput) {
+        match input {
+            Literal::Field(value, span, id) => {
+                let value = Self::canonicalize(&value).unwrap_or(value);
+                (Expression::Literal(Literal::Field(value, span, id)), Default::default())
+            }
+            literal => (Expression::Literal(literal), Default::default()),
+        }
+    }
+}
+
+impl StatementReconstructor for FieldLiteralCanonicalizer {}
+
+impl ProgramReconstructor for FieldLiteralCanonicalizer {}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Field Literal Canonicalization pass traverses the AST and rewrites each field literal into its canonical
+//! representative in `[0, p)`, where `p` is the modulus of the field.
+//! Negated literals are reduced modulo `p`, so that `-0field` becomes `0field`, and literals that exceed the modulus
+//! are reduced as well.
+//! Literals that are already reduced are left byte-identical, so that literals can be compared syntactically.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: field) -> field {
+//!     return a + -1field;
+//! }
+//! ```
+//!
+//! The field literal canonicalization pass produces the following code.
+//! ```leo
+//! transition main(a: field) -> field {
+//!     return a + 8444461749428370424248824938781546531375899335154063827935233455917409239040field;
+//! }
+//! ```
+
+pub mod field_literal_canonicalizer;
+pub use field_literal_canonicalizer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for FieldLiteralCanonicalizer {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut reconstructor = FieldLiteralCanonicalizer::new();
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, Literal, NodeBuilder, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and canonicalizes a program whose transition returns `literal`, returning the value of the literal.
+    fn canonicalize_return(literal: &str) -> String {
+        let handler = Handler::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main() -> field {{
+                    return {literal};
+                }}
+            }}"
+        );
+        let ast =
+            FieldLiteralCanonicalizer::do_pass(parse_program(&handler, &NodeBuilder::default(), &source)).unwrap();
+        let mut function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        match function.block.statements.pop() {
+            Some(Statement::Return(statement)) => match statement.expression {
+                Expression::Literal(Literal::Field(value, ..)) => value,
+                expression => panic!("expected a field literal, found `{expression}`"),
+            },
+            statement => panic!("expected a return statement, found `{statement:?}`"),
+        }
+    }
+
+    #[test]
+    fn test_canonicalizes_negative_literal() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(
+                canonicalize_return("-1field"),
+                "8444461749428370424248824938781546531375899335154063827935233455917409239040"
+            );
+            assert_eq!(canonicalize_return("-0field"), "0");
+        })
+    }
+
+    #[test]
+    fn test_keeps_reduced_literal() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(canonicalize_return("1_000field"), "1_000");
+        })
+    }
+}
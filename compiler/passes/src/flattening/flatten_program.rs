@@ -54,12 +54,15 @@ impl ProgramReconstructor for Flattener<'_> {
         self.fold_returns(&mut block, returns);
 
         Function {
+            docs: function.docs,
             annotations: function.annotations,
             variant: function.variant,
             identifier: function.identifier,
+            type_params: function.type_params,
             input: function.input,
             output: function.output,
             output_type: function.output_type,
+            const_predicates: function.const_predicates,
             block,
             finalize,
             span: function.span,
@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{Result, StaticAnalyzerError};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A compiler pass that slices a function and its transitive dependencies out of a program.
+/// The dependencies are the functions it calls, and the structs, records, mappings, constants, and imports it uses.
+pub struct FunctionExtractor<'a> {
+    /// The program to extract functions from.
+    pub(crate) program: &'a Program,
+    /// The names referenced by the items visited since the last dependency was resolved.
+    pub(crate) referenced: IndexSet<Symbol>,
+    /// The external programs referenced by the items visited so far.
+    pub(crate) programs: IndexSet<Symbol>,
+}
+
+impl<'a> FunctionExtractor<'a> {
+    /// Returns a new `FunctionExtractor` for the given program.
+    pub fn new(program: &'a Program) -> Self {
+        Self { program, referenced: IndexSet::new(), programs: IndexSet::new() }
+    }
+
+    /// Records the structs and records named in the type.
+    fn visit_type(&mut self, input: &Type) {
+        match input {
+            Type::Array(array) => self.visit_type(array.element_type()),
+            Type::Identifier(identifier) => {
+                self.referenced.insert(identifier.name);
+            }
+            Type::Mapping(mapping) => {
+                self.visit_type(&mapping.key);
+                self.visit_type(&mapping.value);
+            }
+            Type::Tuple(tuple) => tuple.elements().iter().for_each(|element| self.visit_type(element)),
+            _ => {}
+        }
+    }
+
+    /// Records the types of the inputs and outputs, and the programs of the external records among them.
+    fn visit_signature(&mut self, inputs: &[Input], outputs: &[Output]) {
+        inputs.iter().for_each(|input| {
+            if let Input::External(external) = input {
+                self.programs.insert(external.program_name.name);
+            }
+            self.visit_type(&input.type_());
+        });
+        outputs.iter().for_each(|output| {
+            if let Output::External(external) = output {
+                self.programs.insert(external.program_name.name);
+            }
+            self.visit_type(&output.type_());
+        });
+    }
+
+    /// Returns the slice of the program made of the function `name` and its transitive dependencies.
+    /// Items are kept in their original order.
+    pub(crate) fn extract(&mut self, name: Symbol) -> Result<Program> {
+        let program = self.program;
+        let Some((scope_name, scope)) = program
+            .program_scopes
+            .iter()
+            .find(|(_, scope)| scope.functions.iter().any(|(function, _)| *function == name))
+        else {
+            return Err(StaticAnalyzerError::function_not_found(name).into());
+        };
+
+        // Resolve dependencies until no new item is referenced.
+        let mut included = IndexSet::new();
+        let mut pending = vec![name];
+        while let Some(item) = pending.pop() {
+            if !included.insert(item) {
+                continue;
+            }
+            scope
+                .functions
+                .iter()
+                .filter(|(name, _)| *name == item)
+                .for_each(|(_, function)| self.visit_function(function));
+            scope.structs.iter().filter(|(name, _)| *name == item).for_each(|(_, struct_)| self.visit_struct(struct_));
+            scope
+                .mappings
+                .iter()
+                .filter(|(name, _)| *name == item)
+                .for_each(|(_, mapping)| self.visit_mapping(mapping));
+            scope.consts.iter().filter(|(name, _)| *name == item).for_each(|(_, constant)| self.visit_const(constant));
+            pending.extend(self.referenced.drain(..).filter(|name| !included.contains(name)));
+        }
+
+        let program_scope = ProgramScope {
+            program_id: scope.program_id,
+            consts: scope.consts.iter().filter(|(name, _)| included.contains(name)).cloned().collect(),
+            structs: scope.structs.iter().filter(|(name, _)| included.contains(name)).cloned().collect(),
+            mappings: scope.mappings.iter().filter(|(name, _)| included.contains(name)).cloned().collect(),
+            functions: scope.functions.iter().filter(|(name, _)| included.contains(name)).cloned().collect(),
+            span: scope.span,
+        };
+
+        Ok(Program {
+            imports: program
+                .imports
+                .iter()
+                .filter(|(name, _)| self.programs.contains(*name))
+                .map(|(name, import)| (*name, import.clone()))
+                .collect(),
+            program_scopes: IndexMap::from([(*scope_name, program_scope)]),
+        })
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for FunctionExtractor<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedConstant(constant) => self.visit_type(&constant.ty),
+            AccessExpression::AssociatedFunction(function) => {
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+        }
+    }
+
+    fn visit_ascription(
+        &mut self,
+        input: &'a AscriptionExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.expression, additional);
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // A call to an external function depends on the imported program rather than on a local function.
+        match input.external.as_deref() {
+            Some(Expression::Identifier(program)) => {
+                self.programs.insert(program.name);
+            }
+            _ => self.visit_expression(&input.function, additional),
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.expression, additional);
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.referenced.insert(input.name);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.referenced.insert(input.name.name);
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, additional),
+            // A shorthand member `x` reads the variable `x`.
+            None => self.visit_identifier(&member.identifier, additional),
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for FunctionExtractor<'a> {
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.start, &Default::default());
+        self.visit_expression(&input.stop, &Default::default());
+        self.visit_block(&input.block);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for FunctionExtractor<'a> {
+    fn visit_struct(&mut self, input: &'a Struct) {
+        input.members.iter().for_each(|member| self.visit_type(&member.type_));
+    }
+
+    fn visit_mapping(&mut self, input: &'a Mapping) {
+        self.visit_type(&input.key_type);
+        self.visit_type(&input.value_type);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.visit_signature(&input.input, &input.output);
+        input.const_predicates.iter().for_each(|predicate| self.visit_function_predicate(predicate));
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_signature(&finalize.input, &finalize.output);
+            self.visit_block(&finalize.block);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Function Extraction pass slices a single function out of a program, along with everything it transitively
+//! depends on: the functions it calls, the structs, records, mappings, and constants it uses, and the imported
+//! programs it calls into. Items that the function does not reach are excluded.
+//! The resulting program is self-contained, which makes it convenient for testing the function in isolation.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     struct Point { x: u8, y: u8 }
+//!     struct Unused { a: u8 }
+//!
+//!     function norm(p: Point) -> u8 {
+//!         return p.x + p.y;
+//!     }
+//!
+//!     function unused() -> u8 {
+//!         return 0u8;
+//!     }
+//!
+//!     transition main(x: u8) -> u8 {
+//!         return norm(Point { x, y: 1u8 });
+//!     }
+//! }
+//! ```
+//!
+//! Extracting `main` produces the following program.
+//! ```leo
+//! program test.aleo {
+//!     struct Point { x: u8, y: u8 }
+//!
+//!     function norm(p: Point) -> u8 {
+//!         return p.x + p.y;
+//!     }
+//!
+//!     transition main(x: u8) -> u8 {
+//!         return norm(Point { x, y: 1u8 });
+//!     }
+//! }
+//! ```
+
+pub mod function_extractor;
+pub use function_extractor::*;
+
+use crate::Pass;
+
+use leo_ast::Program;
+use leo_errors::Result;
+use leo_span::Symbol;
+
+impl<'a> Pass for FunctionExtractor<'a> {
+    type Input = (&'a Program, Symbol);
+    type Output = Result<Program>;
+
+    fn do_pass((program, name): Self::Input) -> Self::Output {
+        FunctionExtractor::new(program).extract(name)
+    }
+}
+
+/// Returns the function `name` of `program` together with its transitive dependencies, as a standalone program.
+pub fn extract_function(program: &Program, name: Symbol) -> Result<Program> {
+    FunctionExtractor::do_pass((program, name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns the names of the items in the slice of the program that `function` depends on.
+    fn extract(function: &str) -> Result<Vec<String>> {
+        let handler = Handler::default();
+        let source = "program test.aleo {
+            struct Point { x: u8, y: u8 }
+            struct Unused { a: u8 }
+            const ONE: u8 = 1u8;
+            const TWO: u8 = 2u8;
+            mapping counts: u8 => u8;
+
+            function norm(p: Point) -> u8 {
+                return p.x + p.y + ONE;
+            }
+
+            function unused() -> u8 {
+                return TWO;
+            }
+
+            transition main(x: u8) -> u8 {
+                return norm(Point { x, y: 1u8 });
+            }
+        }";
+        let ast = parse_program(&handler, &NodeBuilder::default(), source);
+        let slice = extract_function(ast.as_repr(), Symbol::intern(function))?;
+        let scope = slice.program_scopes.into_values().next().unwrap();
+        Ok(scope
+            .structs
+            .iter()
+            .map(|(name, _)| name)
+            .chain(scope.consts.iter().map(|(name, _)| name))
+            .chain(scope.mappings.iter().map(|(name, _)| name))
+            .chain(scope.functions.iter().map(|(name, _)| name))
+            .map(|name| name.to_string())
+            .collect())
+    }
+
+    #[test]
+    fn test_extracts_function_with_dependencies() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(extract("main").unwrap(), vec!["Point", "ONE", "norm", "main"]);
+            assert_eq!(extract("unused").unwrap(), vec!["TWO", "unused"]);
+        })
+    }
+
+    #[test]
+    fn test_rejects_missing_function() {
+        create_session_if_not_set_then(|_| assert!(extract("missing").is_err()))
+    }
+}
@@ -16,18 +16,37 @@
 
 use crate::{Assigner, AssignmentRenamer, CallGraph, TypeTable};
 
-use leo_ast::{Function, NodeBuilder};
+use leo_ast::{Expression, Function, Identifier, NodeBuilder, Statement, Variant};
 use leo_span::Symbol;
 
+use indexmap::IndexSet;
+
+/// The callees that the function inliner inlines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InliningPolicy {
+    /// Inline the functions declared with the `inline` keyword.
+    #[default]
+    Inline,
+    /// Inline the functions, other than transitions, that are called exactly once in their program scope, regardless
+    /// of their size, and remove them from the program scope.
+    SingleCall,
+}
+
 pub struct FunctionInliner<'a> {
     /// A counter used to create unique NodeIDs.
     pub(crate) node_builder: &'a NodeBuilder,
     /// The call graph for the program.
     pub(crate) call_graph: &'a CallGraph,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
     /// A wrapper around an Assigner used to create unique variable assignments.
     pub(crate) assignment_renamer: AssignmentRenamer<'a>,
     /// A mapping between node IDs and their types.
     pub(crate) type_table: &'a TypeTable,
+    /// The callees that are inlined.
+    pub(crate) policy: InliningPolicy,
+    /// The names of the functions in the current program scope that are called exactly once, if they are inlined.
+    pub(crate) called_once: IndexSet<Symbol>,
     /// A map of reconstructed functions in the current program scope.
     pub(crate) reconstructed_functions: Vec<(Symbol, Function)>,
 }
@@ -39,13 +58,43 @@ impl<'a> FunctionInliner<'a> {
         call_graph: &'a CallGraph,
         assigner: &'a Assigner,
         type_table: &'a TypeTable,
+        policy: InliningPolicy,
     ) -> Self {
         Self {
             node_builder,
             call_graph,
+            assigner,
             assignment_renamer: AssignmentRenamer::new(assigner),
             reconstructed_functions: Default::default(),
             type_table,
+            policy,
+            called_once: Default::default(),
+        }
+    }
+
+    /// Returns `true` if the call to the function `name` is inlined.
+    pub(crate) fn inlines(&self, name: Symbol, callee: &Function) -> bool {
+        match self.policy {
+            InliningPolicy::Inline => callee.variant == Variant::Inline,
+            InliningPolicy::SingleCall => self.called_once.contains(&name),
         }
     }
+
+    /// Assigns each argument to a new variable, in order, returning the variables and the assignments.
+    pub(crate) fn bind_arguments(&self, arguments: Vec<Expression>) -> (Vec<Expression>, Vec<Statement>) {
+        arguments
+            .into_iter()
+            .map(|argument| {
+                let (name, span) = (self.assigner.unique_symbol("$var", "$"), argument.span());
+                let place = Identifier { name, span, id: self.node_builder.next_id() };
+                let variable = Identifier { name, span, id: self.node_builder.next_id() };
+                if let Some(type_) = self.type_table.get(&argument.id()) {
+                    self.type_table.insert(place.id, type_.clone());
+                    self.type_table.insert(variable.id, type_);
+                }
+                let statement = self.assigner.simple_assign_statement(place, argument, self.node_builder.next_id());
+                (Expression::Identifier(variable), statement)
+            })
+            .unzip()
+    }
 }
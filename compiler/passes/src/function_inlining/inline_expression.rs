@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{FunctionInliner, Replacer};
+use crate::{FunctionInliner, InliningPolicy, Replacer};
 
 use leo_ast::{
     CallExpression,
@@ -26,7 +26,6 @@ use leo_ast::{
     StatementReconstructor,
     Type,
     UnitExpression,
-    Variant,
 };
 
 use indexmap::IndexMap;
@@ -52,57 +51,57 @@ impl ExpressionReconstructor for FunctionInliner<'_> {
         let (_, callee) = self.reconstructed_functions.iter().find(|(symbol, _)| *symbol == function_name).unwrap();
 
         // Inline the callee function, if required, otherwise, return the call expression.
-        match callee.variant {
-            Variant::Transition | Variant::Standard => (Expression::Call(input), Default::default()),
-            Variant::Inline => {
-                // Construct a mapping from input variables of the callee function to arguments passed to the callee.
-                let parameter_to_argument = callee
-                    .input
-                    .iter()
-                    .map(|input| input.identifier().name)
-                    .zip_eq(input.arguments)
-                    .collect::<IndexMap<_, _>>();
-
-                // Initializer `self.assignment_renamer` with the function parameters.
-                self.assignment_renamer.load(
-                    callee
-                        .input
-                        .iter()
-                        .map(|input| (input.identifier().name, input.identifier().name, input.identifier().id)),
-                );
-
-                // Duplicate the body of the callee and create a unique assignment statement for each assignment in the body.
-                // This is necessary to ensure the inlined variables do not conflict with variables in the caller.
-                let unique_block = self.assignment_renamer.reconstruct_block(callee.block.clone()).0;
-
-                // Reset `self.assignment_renamer`.
-                self.assignment_renamer.clear();
-
-                // Replace each input variable with the appropriate parameter.
-                let replace = |identifier: &Identifier| match parameter_to_argument.get(&identifier.name) {
-                    Some(expression) => expression.clone(),
-                    None => Expression::Identifier(*identifier),
-                };
-                let mut inlined_statements = Replacer::new(replace).reconstruct_block(unique_block).0.statements;
-
-                // If the inlined block returns a value, then use the value in place of the call expression, otherwise, use the unit expression.
-                let result = match inlined_statements.last() {
-                    Some(Statement::Return(_)) => {
-                        // Note that this unwrap is safe since we know that the last statement is a return statement.
-                        match inlined_statements.pop().unwrap() {
-                            Statement::Return(ReturnStatement { expression, .. }) => expression,
-                            _ => unreachable!("This branch checks that the last statement is a return statement."),
-                        }
-                    }
-                    _ => {
-                        let id = self.node_builder.next_id();
-                        self.type_table.insert(id, Type::Unit);
-                        Expression::Unit(UnitExpression { span: Default::default(), id })
-                    }
-                };
-
-                (result, inlined_statements)
-            }
+        if !self.inlines(function_name, callee) {
+            return (Expression::Call(input), Default::default());
         }
+
+        // The arguments of a function called exactly once are first assigned to new variables, in order, so that each
+        // argument is evaluated exactly once and before the body of the callee.
+        let (arguments, mut statements) = match self.policy {
+            InliningPolicy::Inline => (input.arguments, Vec::new()),
+            InliningPolicy::SingleCall => self.bind_arguments(input.arguments),
+        };
+
+        // Construct a mapping from input variables of the callee function to arguments passed to the callee.
+        let parameter_to_argument =
+            callee.input.iter().map(|input| input.identifier().name).zip_eq(arguments).collect::<IndexMap<_, _>>();
+
+        // Initializer `self.assignment_renamer` with the function parameters.
+        self.assignment_renamer.load(
+            callee.input.iter().map(|input| (input.identifier().name, input.identifier().name, input.identifier().id)),
+        );
+
+        // Duplicate the body of the callee and create a unique assignment statement for each assignment in the body.
+        // This is necessary to ensure the inlined variables do not conflict with variables in the caller.
+        let unique_block = self.assignment_renamer.reconstruct_block(callee.block.clone()).0;
+
+        // Reset `self.assignment_renamer`.
+        self.assignment_renamer.clear();
+
+        // Replace each input variable with the appropriate parameter.
+        let replace = |identifier: &Identifier| match parameter_to_argument.get(&identifier.name) {
+            Some(expression) => expression.clone(),
+            None => Expression::Identifier(*identifier),
+        };
+        let mut inlined_statements = Replacer::new(replace).reconstruct_block(unique_block).0.statements;
+
+        // If the inlined block returns a value, then use the value in place of the call expression, otherwise, use the unit expression.
+        let result = match inlined_statements.last() {
+            Some(Statement::Return(_)) => {
+                // Note that this unwrap is safe since we know that the last statement is a return statement.
+                match inlined_statements.pop().unwrap() {
+                    Statement::Return(ReturnStatement { expression, .. }) => expression,
+                    _ => unreachable!("This branch checks that the last statement is a return statement."),
+                }
+            }
+            _ => {
+                let id = self.node_builder.next_id();
+                self.type_table.insert(id, Type::Unit);
+                Expression::Unit(UnitExpression { span: Default::default(), id })
+            }
+        };
+        statements.extend(inlined_statements);
+
+        (result, statements)
     }
 }
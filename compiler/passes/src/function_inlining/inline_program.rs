@@ -14,15 +14,33 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::FunctionInliner;
+use crate::{CallCounter, FunctionInliner, InliningPolicy};
 
-use leo_ast::{Function, ProgramReconstructor, ProgramScope};
+use leo_ast::{Function, ProgramReconstructor, ProgramScope, ProgramVisitor, Variant};
 use leo_span::Symbol;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 impl ProgramReconstructor for FunctionInliner<'_> {
     fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        // Find the functions called exactly once, if they are inlined.
+        // Note that transitions are never inlined, and that calls to external programs are not counted.
+        self.called_once = match self.policy {
+            InliningPolicy::Inline => IndexSet::new(),
+            InliningPolicy::SingleCall => {
+                let mut counter = CallCounter::default();
+                input.functions.iter().for_each(|(_, function)| counter.visit_function(function));
+                input
+                    .functions
+                    .iter()
+                    .filter(|(name, function)| {
+                        function.variant != Variant::Transition && counter.counts.get(name) == Some(&1)
+                    })
+                    .map(|(name, _)| *name)
+                    .collect()
+            }
+        };
+
         // Get the post-order ordering of the call graph.
         // Note that the post-order always contains all nodes in the call graph.
         // Note that the unwrap is safe since type checking guarantees that the call graph is acyclic.
@@ -46,7 +64,11 @@ impl ProgramReconstructor for FunctionInliner<'_> {
         assert!(function_map.is_empty(), "All functions in the program scope should have been processed.");
 
         // Note that this intentionally clears `self.reconstructed_functions` for the next program scope.
-        let functions = core::mem::take(&mut self.reconstructed_functions).into_iter().collect();
+        // Note that the functions called exactly once are removed, since they are inlined at their only call site.
+        let functions = core::mem::take(&mut self.reconstructed_functions)
+            .into_iter()
+            .filter(|(name, _)| !self.called_once.contains(name))
+            .collect();
 
         ProgramScope {
             program_id: input.program_id,
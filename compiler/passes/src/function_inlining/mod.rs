@@ -64,7 +64,7 @@ mod inline_program;
 pub mod function_inliner;
 pub use function_inliner::*;
 
-use crate::{Assigner, CallGraph, Pass, TypeTable};
+use crate::{Assigner, CallGraph, InliningPolicy, Pass, TypeTable};
 
 use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
 use leo_errors::Result;
@@ -74,7 +74,7 @@ impl<'a> Pass for FunctionInliner<'a> {
     type Output = Result<Ast>;
 
     fn do_pass((ast, node_builder, call_graph, assigner, tt): Self::Input) -> Self::Output {
-        let mut reconstructor = FunctionInliner::new(node_builder, call_graph, assigner, tt);
+        let mut reconstructor = FunctionInliner::new(node_builder, call_graph, assigner, tt, InliningPolicy::Inline);
         let program = reconstructor.reconstruct_program(ast.into_repr());
 
         Ok(Ast::new(program))
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerWarning};
+
+/// A compiler pass that warns about functions whose size exceeds a statement budget.
+/// The size of a function is the number of statements in its body and its finalize block, counted recursively.
+/// Statements in a loop body are weighted by `loop_weight`, to approximate the size of the unrolled loop.
+pub struct FunctionSizeChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The maximum number of statements allowed in a function.
+    budget: usize,
+    /// The factor by which the size of a loop body is multiplied.
+    loop_weight: usize,
+}
+
+impl<'a> FunctionSizeChecker<'a> {
+    /// Returns a new `FunctionSizeChecker`.
+    pub fn new(handler: &'a Handler, budget: usize, loop_weight: usize) -> Self {
+        Self { handler, budget, loop_weight }
+    }
+
+    /// Returns the number of statements in `block`.
+    fn block_size(&self, block: &Block) -> usize {
+        block.statements.iter().fold(0, |size, statement| size.saturating_add(self.statement_size(statement)))
+    }
+
+    /// Returns the number of statements in `statement`, including `statement` itself.
+    /// Nested blocks are not counted as statements of their own.
+    fn statement_size(&self, statement: &Statement) -> usize {
+        match statement {
+            Statement::Block(block) => self.block_size(block),
+            Statement::Conditional(conditional) => {
+                let otherwise = conditional.otherwise.as_ref().map_or(0, |otherwise| self.statement_size(otherwise));
+                1usize.saturating_add(self.block_size(&conditional.then)).saturating_add(otherwise)
+            }
+            Statement::Iteration(iteration) => {
+                1usize.saturating_add(self.block_size(&iteration.block).saturating_mul(self.loop_weight))
+            }
+            _ => 1,
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for FunctionSizeChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for FunctionSizeChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for FunctionSizeChecker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        let finalize = input.finalize.as_ref().map_or(0, |finalize| self.block_size(&finalize.block));
+        let size = self.block_size(&input.block).saturating_add(finalize);
+        if size > self.budget {
+            self.handler.emit_warning(
+                StaticAnalyzerWarning::function_exceeds_size_budget(input.identifier, size, self.budget, input.span)
+                    .into(),
+            );
+        }
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Function Size Checking pass traverses the AST and warns about functions whose size exceeds a statement budget.
+//! Statements are counted recursively, including those in nested blocks and finalize blocks.
+//! Statements in a loop body are weighted by a configurable factor, to approximate the size of the unrolled loop.
+//! Warnings are reported at the span of the function.
+//!
+//! Consider the following Leo code, checked with a budget of 4 and a loop weight of 2.
+//! ```leo
+//! transition main(a: u8) -> u8 {
+//!     for i: u8 in 0u8..4u8 {
+//!         a = a + i;
+//!         a = a * 2u8;
+//!     }
+//!     return a;
+//! }
+//! ```
+//!
+//! The function size checking pass reports a warning, since the loop counts as 5 statements and the return as 1.
+
+pub mod function_size_checker;
+pub use function_size_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for FunctionSizeChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, usize, usize);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, budget, loop_weight): Self::Input) -> Self::Output {
+        let mut visitor = FunctionSizeChecker::new(handler, budget, loop_weight);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const SOURCE: &str = "program test.aleo {
+        transition main(a: u8) -> u8 {
+            for i: u8 in 0u8..4u8 {
+                a = a + i;
+                a = a * 2u8;
+            }
+            return a;
+        }
+    }";
+
+    /// Checks `SOURCE` with the given budget and a loop weight of 2, returning the number of warnings.
+    fn count_warnings(budget: usize) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let ast = parse_program(&handler, &NodeBuilder::default(), SOURCE);
+        FunctionSizeChecker::do_pass((&ast, &handler, budget, 2)).unwrap();
+        handler.warning_count()
+    }
+
+    #[test]
+    fn test_warns_about_function_over_budget() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(count_warnings(5), 1);
+        });
+    }
+
+    #[test]
+    fn test_accepts_function_within_budget() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(count_warnings(6), 0);
+        });
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError, StaticAnalyzerWarning};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// A compiler pass that reports local bindings that shadow a global constant of their program scope.
+/// Shadowing is reported as a warning, or as an error if `deny` is set.
+pub struct GlobalShadowChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// Whether shadowing a global constant is an error rather than a warning.
+    deny: bool,
+    /// The global constants of the current program scope, mapped to the spans of their declarations.
+    globals: IndexMap<Symbol, Span>,
+}
+
+impl<'a> GlobalShadowChecker<'a> {
+    /// Returns a new `GlobalShadowChecker`.
+    pub fn new(handler: &'a Handler, deny: bool) -> Self {
+        Self { handler, deny, globals: IndexMap::new() }
+    }
+
+    /// Reports `local` if it shadows a global constant.
+    fn check_local(&self, local: &Identifier) {
+        if let Some(global) = self.globals.get(&local.name) {
+            match self.deny {
+                true => self.handler.emit_err(StaticAnalyzerError::local_shadows_global(local, global, local.span)),
+                false => self
+                    .handler
+                    .emit_warning(StaticAnalyzerWarning::local_shadows_global(local, global, local.span).into()),
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for GlobalShadowChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for GlobalShadowChecker<'a> {
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.check_local(&input.place);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        match &input.place {
+            Expression::Identifier(local) => self.check_local(local),
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| {
+                if let Expression::Identifier(local) = element {
+                    self.check_local(local);
+                }
+            }),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for GlobalShadowChecker<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.globals = input.consts.iter().map(|(_, declaration)| (declaration.place.name, declaration.span)).collect();
+        // The global constants themselves are not visited, so that `visit_const` only sees local constants.
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+}
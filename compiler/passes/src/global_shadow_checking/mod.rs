@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Global Shadow Checking pass traverses the AST and reports local bindings, introduced by `let` or `const`
+//! definitions, whose names are also those of global constants of their program scope.
+//! Each report points at the local, and names the span of the global constant it shadows.
+//! Shadowing is reported as a warning by default, or as an error if the pass is configured to deny it.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! const LIMIT: u8 = 10u8;
+//!
+//! transition main(a: u8) -> u8 {
+//!     let LIMIT: u8 = a;
+//!     return LIMIT;
+//! }
+//! ```
+//!
+//! The global shadow checking pass reports that the local `LIMIT` shadows the global constant `LIMIT`.
+
+pub mod global_shadow_checker;
+pub use global_shadow_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for GlobalShadowChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, bool);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, deny): Self::Input) -> Self::Output {
+        let mut visitor = GlobalShadowChecker::new(handler, deny);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Checks a transition defining the local `local`, next to the global constant `LIMIT`,
+    /// returning the numbers of errors and warnings.
+    fn check(local: &str, deny: bool) -> (usize, usize) {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                const LIMIT: u8 = 10u8;
+
+                transition main(a: u8) -> u8 {{
+                    let {local}: u8 = a;
+                    return {local};
+                }}
+            }}"
+        );
+        let _ =
+            GlobalShadowChecker::do_pass((&parse_program(&handler, &NodeBuilder::default(), &source), &handler, deny));
+        (handler.err_count(), handler.warning_count())
+    }
+
+    #[test]
+    fn test_warns_on_shadowed_global() {
+        create_session_if_not_set_then(|_| assert_eq!(check("LIMIT", false), (0, 1)))
+    }
+
+    #[test]
+    fn test_denies_shadowed_global() {
+        create_session_if_not_set_then(|_| assert_eq!(check("LIMIT", true), (1, 0)))
+    }
+
+    #[test]
+    fn test_accepts_unique_local() {
+        create_session_if_not_set_then(|_| assert_eq!(check("limit", false), (0, 0)))
+    }
+}
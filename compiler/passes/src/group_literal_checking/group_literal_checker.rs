@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::sym;
+
+/// A compiler pass that checks that each group literal is written in a supported notation:
+/// a scalar multiple of the generator, e.g. `2group`, a coordinate pair, e.g. `(0, _)group`, or `group::GEN`.
+pub struct GroupLiteralChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> GroupLiteralChecker<'a> {
+    /// Returns a new `GroupLiteralChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler }
+    }
+
+    /// Returns `true` if `number` is a decimal integer, possibly negated and with `_` separators.
+    fn is_integer(number: &str) -> bool {
+        let digits = number.strip_prefix('-').unwrap_or(number);
+        digits.starts_with(|c: char| c.is_ascii_digit()) && digits.chars().all(|c| c.is_ascii_digit() || c == '_')
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for GroupLiteralChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            // The generator is the only constant associated with `group`.
+            AccessExpression::AssociatedConstant(constant)
+                if constant.ty == Type::Group && constant.name.name != sym::GEN =>
+            {
+                self.handler.emit_err(StaticAnalyzerError::malformed_group_literal(
+                    constant,
+                    "the only group constant is `group::GEN`",
+                    constant.span,
+                ));
+            }
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_literal(&mut self, input: &'a Literal, _additional: &Self::AdditionalInput) -> Self::Output {
+        let Literal::Group(group) = input else { return };
+        match group.as_ref() {
+            GroupLiteral::Single(number, span, _) if !Self::is_integer(number) => {
+                self.handler.emit_err(StaticAnalyzerError::malformed_group_literal(
+                    format!("{number}group"),
+                    "the scalar must be an integer",
+                    *span,
+                ));
+            }
+            GroupLiteral::Tuple(tuple) => {
+                // The y-coordinate may be recovered from the x-coordinate, but not the other way around.
+                let reason = match &tuple.x {
+                    GroupCoordinate::Number(x, _) if Self::is_integer(x) => return,
+                    GroupCoordinate::Number(..) => "the x-coordinate must be an integer",
+                    _ => "the x-coordinate must be a number",
+                };
+                self.handler.emit_err(StaticAnalyzerError::malformed_group_literal(
+                    format!("({}, {})group", tuple.x, tuple.y),
+                    reason,
+                    tuple.span,
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for GroupLiteralChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for GroupLiteralChecker<'a> {}
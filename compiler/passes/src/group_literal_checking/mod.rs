@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Group Literal Checking pass traverses the AST and checks that each group literal uses a supported notation.
+//! A group literal is either a scalar multiple of the generator, e.g. `2group`, a coordinate pair whose
+//! x-coordinate is a number, e.g. `(0, 1)group` or `(0, _)group`, or the generator itself, `group::GEN`.
+//! Partial coordinate pairs without an x-coordinate, e.g. `(_, 1)group`, are rejected, since the x-coordinate
+//! cannot be recovered.
+//! Errors are reported at the span of the offending literal.
+
+pub mod group_literal_checker;
+pub use group_literal_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for GroupLiteralChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = GroupLiteralChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Checks a program returning the given group literal, returning the number of errors.
+    fn check_group(literal: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                transition main() -> group {{
+                    return {literal};
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let _ = GroupLiteralChecker::do_pass((&ast, &handler));
+        handler.err_count()
+    }
+
+    #[test]
+    fn test_accepts_generator() {
+        create_session_if_not_set_then(|_| assert_eq!(check_group("group::GEN"), 0))
+    }
+
+    #[test]
+    fn test_accepts_coordinate_pair() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(check_group("(0, 1)group"), 0);
+            assert_eq!(check_group("(0, _)group"), 0);
+        })
+    }
+
+    #[test]
+    fn test_rejects_partial_coordinate_pair() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(check_group("(_, 1)group"), 1);
+            assert_eq!(check_group("(+, _)group"), 1);
+        })
+    }
+}
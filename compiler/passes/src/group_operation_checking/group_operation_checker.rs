@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+/// A compiler pass that checks that group elements are only added to or subtracted from group elements,
+/// and only multiplied by scalars.
+pub struct GroupOperationChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+}
+
+impl<'a> GroupOperationChecker<'a> {
+    /// Returns a new `GroupOperationChecker`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, type_table }
+    }
+
+    /// Returns `true` if the operands of types `left` and `right` are compatible under `operation`,
+    /// or if neither operand is a group element.
+    fn is_compatible(operation: BinaryOperation, left: &Type, right: &Type) -> bool {
+        match (operation, left, right) {
+            (BinaryOperation::Add | BinaryOperation::Sub, Type::Group, right) => matches!(right, Type::Group),
+            (BinaryOperation::Add | BinaryOperation::Sub, _, Type::Group) => false,
+            (BinaryOperation::Mul, Type::Group, Type::Scalar) | (BinaryOperation::Mul, Type::Scalar, Type::Group) => {
+                true
+            }
+            (BinaryOperation::Mul, Type::Group, _) | (BinaryOperation::Mul, _, Type::Group) => false,
+            _ => true,
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for GroupOperationChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Operands whose type could not be inferred have already been reported by the type checker.
+        if let (Some(left), Some(right)) =
+            (self.type_table.get(&input.left.id()), self.type_table.get(&input.right.id()))
+        {
+            if !Self::is_compatible(input.op, &left, &right) {
+                self.handler
+                    .emit_err(StaticAnalyzerError::incompatible_group_operand(input.op, left, right, input.span));
+            }
+        }
+        self.visit_expression(&input.left, additional);
+        self.visit_expression(&input.right, additional);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for GroupOperationChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for GroupOperationChecker<'a> {}
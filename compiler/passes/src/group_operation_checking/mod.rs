@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Group Operation Checking pass traverses the AST and checks the operands of arithmetic on group elements.
+//! A group element can only be added to or subtracted from another group element, and can only be multiplied by a
+//! scalar, on either side. Any other combination is reported at the span of the operation.
+//! The pass runs after type checking and reads the types of the operands from the type table.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: group, s: scalar, n: u8) -> group {
+//!     let b: group = s * a;
+//!     return b + n;
+//! }
+//! ```
+//!
+//! The group operation checking pass reports an error for `b + n`, since a group element cannot be added to a `u8`.
+
+pub mod group_operation_checker;
+pub use group_operation_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for GroupOperationChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut visitor = GroupOperationChecker::new(handler, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks a transition with inputs `a: group`, `b: group`, `s: scalar`, and `n: u8` that returns the given
+    /// expression, then checks its group operations.
+    /// The type checker may report errors of its own, which are ignored.
+    fn check_return(expression: &str) -> Result<()> {
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: group, b: group, s: scalar, n: u8) -> group {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let (type_checker_handler, _) = Handler::new_with_buf();
+        let type_table = TypeTable::default();
+        let ast = parse_program(&type_checker_handler, &NodeBuilder::default(), &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &type_checker_handler)).unwrap();
+        let _ = TypeChecker::do_pass((&ast, &type_checker_handler, symbol_table, &type_table));
+
+        let (handler, _) = Handler::new_with_buf();
+        GroupOperationChecker::do_pass((&ast, &handler, &type_table))
+    }
+
+    #[test]
+    fn test_accepts_group_addition() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_return("a + b").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_accepts_scalar_multiplication() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_return("s * a").is_ok());
+            assert!(check_return("a * s").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_group_plus_integer() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_return("a + n").is_err());
+        })
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+use snarkvm_console::{
+    network::Testnet3,
+    types::{Field, Group},
+};
+use std::str::FromStr;
+
+/// A compiler pass that checks that each group literal whose coordinates are both constant numbers,
+/// e.g. `(0, 1)group`, is a point on the curve.
+/// Coordinates that are recovered from a sign or inferred, e.g. `(0, +)group`, are checked when the program is run.
+pub struct GroupPointChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> GroupPointChecker<'a> {
+    /// Returns a new `GroupPointChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler }
+    }
+
+    /// Parses a coordinate of a group literal, e.g. `-42`, into a field element.
+    /// Returns `None` if the coordinate is not a valid field element, which is reported by the type checker.
+    fn coordinate(number: &str) -> Option<Field<Testnet3>> {
+        match number.strip_prefix('-') {
+            Some(magnitude) => Field::from_str(&format!("{magnitude}field")).ok().map(|field: Field<Testnet3>| -field),
+            None => Field::from_str(&format!("{number}field")).ok(),
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for GroupPointChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_literal(&mut self, input: &'a Literal, _additional: &Self::AdditionalInput) -> Self::Output {
+        if let Literal::Group(group) = input {
+            if let GroupLiteral::Tuple(GroupTuple {
+                x: GroupCoordinate::Number(x, _),
+                y: GroupCoordinate::Number(y, _),
+                span,
+                ..
+            }) = group.as_ref()
+            {
+                if let (Some(x_field), Some(y_field)) = (Self::coordinate(x), Self::coordinate(y)) {
+                    if Group::from_xy_coordinates(x_field, y_field).is_err() {
+                        self.handler.emit_err(StaticAnalyzerError::group_point_not_on_curve(x, y, *span));
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for GroupPointChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for GroupPointChecker<'a> {}
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Group Point Checking pass traverses the AST and checks that each group literal with two constant coordinates,
+//! e.g. `(0, 1)group`, is a point on the curve.
+//! Errors are reported at the span of the offending group literal.
+//! Group literals with a coordinate recovered from a sign or inferred, e.g. `(0, _)group`, are left to be checked at runtime.
+
+pub mod group_point_checker;
+pub use group_point_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for GroupPointChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = GroupPointChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Checks a program returning the given group literal, returning the number of errors.
+    fn check_group(literal: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                transition main() -> group {{
+                    return {literal};
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let _ = GroupPointChecker::do_pass((&ast, &handler));
+        handler.err_count()
+    }
+
+    #[test]
+    fn test_accepts_generator_point() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(
+                check_group(
+                    "(1540945439182663264862696551825005342995406165131907382295858612069623286213, \
+                     8003546896475222703853313610036801932325312921786952001586936882361378122196)group"
+                ),
+                0
+            )
+        })
+    }
+
+    #[test]
+    fn test_rejects_point_off_curve() {
+        create_session_if_not_set_then(|_| assert_eq!(check_group("(123, 456)group"), 1))
+    }
+}
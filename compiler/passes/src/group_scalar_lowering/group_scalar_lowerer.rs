@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::Span;
+
+/// The largest constant scalar for which a multiplication is lowered.
+pub const MAX_LOWERED_SCALAR: u128 = 64;
+
+pub struct GroupScalarLowerer<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> GroupScalarLowerer<'a> {
+    /// Initializes a new `GroupScalarLowerer`.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Returns the value of `expression`, if it is a scalar literal between 1 and `MAX_LOWERED_SCALAR`.
+    fn small_scalar(expression: &Expression) -> Option<u128> {
+        match expression {
+            Expression::Literal(Literal::Scalar(value, ..)) => {
+                value.replace('_', "").parse::<u128>().ok().filter(|value| (1..=MAX_LOWERED_SCALAR).contains(value))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a copy of `point` with a fresh node ID.
+    fn copy_point(&self, point: &Identifier) -> Expression {
+        Expression::Identifier(Identifier { name: point.name, span: point.span, id: self.node_builder.next_id() })
+    }
+
+    /// Constructs the double-and-add chain computing `scalar * point`.
+    /// The bits of `scalar` are processed from the most significant to the least significant bit.
+    /// For each bit after the leading one, the accumulator is doubled, and `point` is added if the bit is set.
+    pub(crate) fn double_and_add(&self, scalar: u128, point: &Identifier, span: Span) -> Expression {
+        let bits = u128::BITS - scalar.leading_zeros();
+        let mut result = Expression::Identifier(*point);
+        for i in (0..bits - 1).rev() {
+            result = Expression::Unary(UnaryExpression {
+                receiver: Box::new(result),
+                op: UnaryOperation::Double,
+                span,
+                id: self.node_builder.next_id(),
+            });
+            if (scalar >> i) & 1 == 1 {
+                result = Expression::Binary(BinaryExpression {
+                    left: Box::new(result),
+                    right: Box::new(self.copy_point(point)),
+                    op: BinaryOperation::Add,
+                    span,
+                    id: self.node_builder.next_id(),
+                });
+            }
+        }
+        result
+    }
+}
+
+impl ExpressionReconstructor for GroupScalarLowerer<'_> {
+    type AdditionalOutput = ();
+
+    /// Lowers `point * k` and `k * point`, where `point` is a variable and `k` is a small constant scalar.
+    /// Only variables are lowered, since the point is duplicated in the resulting chain.
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let left = self.reconstruct_expression(*input.left).0;
+        let right = self.reconstruct_expression(*input.right).0;
+
+        if input.op == BinaryOperation::Mul {
+            match (&left, &right) {
+                (Expression::Identifier(point), scalar) | (scalar, Expression::Identifier(point)) => {
+                    if let Some(scalar) = Self::small_scalar(scalar) {
+                        return (self.double_and_add(scalar, point, input.span), Default::default());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        (
+            Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op: input.op,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for GroupScalarLowerer<'_> {}
+
+impl ProgramReconstructor for GroupScalarLowerer<'_> {}
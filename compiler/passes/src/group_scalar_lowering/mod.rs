@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Group Scalar Lowering pass traverses the AST and lowers each multiplication of a group element
+//! by a small constant scalar into a chain of group doublings and additions.
+//! This is intended for backends that lack native scalar multiplication.
+//! Multiplications by large or non-constant scalars are left untouched.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(g: group) -> group {
+//!     return g * 5scalar;
+//! }
+//! ```
+//!
+//! The group scalar lowering pass produces the following code.
+//! ```leo
+//! function main(g: group) -> group {
+//!     return g.double().double() + g;
+//! }
+//! ```
+//! Note that this pass relies on the type checker to guarantee that a multiplication by a scalar is over a group element.
+
+pub mod group_scalar_lowerer;
+pub use group_scalar_lowerer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for GroupScalarLowerer<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = GroupScalarLowerer::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{BinaryExpression, BinaryOperation, Expression, Statement, UnaryExpression, UnaryOperation};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Lowers `return {expression};` in a function over `g: group` and `k: scalar`, returning the lowered expression.
+    fn lower(expression: &str) -> Expression {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(g: group, k: scalar) -> group {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let ast = GroupScalarLowerer::do_pass((ast, &node_builder)).unwrap();
+        match ast
+            .into_repr()
+            .program_scopes
+            .into_values()
+            .next()
+            .unwrap()
+            .functions
+            .pop()
+            .unwrap()
+            .1
+            .block
+            .statements
+            .pop()
+        {
+            Some(Statement::Return(return_)) => return_.expression,
+            _ => panic!("expected a return statement"),
+        }
+    }
+
+    #[test]
+    fn test_lowers_small_constant_scalar() {
+        create_session_if_not_set_then(|_| {
+            // `3scalar * g` is lowered to `g.double() + g`.
+            let Expression::Binary(BinaryExpression { left, right, op: BinaryOperation::Add, .. }) =
+                lower("3scalar * g")
+            else {
+                panic!("expected an addition");
+            };
+            assert!(matches!(*left, Expression::Unary(UnaryExpression { op: UnaryOperation::Double, receiver, .. })
+                if matches!(&*receiver, Expression::Identifier(g) if g.name.to_string() == "g")));
+            assert!(matches!(*right, Expression::Identifier(g) if g.name.to_string() == "g"));
+        })
+    }
+
+    #[test]
+    fn test_preserves_non_constant_scalar() {
+        create_session_if_not_set_then(|_| {
+            assert!(matches!(lower("g * k"), Expression::Binary(BinaryExpression { op: BinaryOperation::Mul, .. })));
+        })
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError};
+
+/// A compiler pass that checks that every array index is an unsigned integer.
+/// The type checker accepts any integer index, so negative indices are only caught when the index is evaluated.
+pub struct IndexTypeChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+}
+
+impl<'a> IndexTypeChecker<'a> {
+    /// Returns a new `IndexTypeChecker`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, type_table }
+    }
+
+    /// Reports the index if its type is known and is not an unsigned integer.
+    fn check_index(&self, index: &Expression) {
+        match self.type_table.get(&index.id()) {
+            Some(Type::Integer(integer_type)) if !integer_type.is_signed() => {}
+            Some(type_) => {
+                self.handler.emit_err(TypeCheckerError::type_should_be(type_, "unsigned integer", index.span()))
+            }
+            None => {}
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for IndexTypeChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(access) => {
+                self.check_index(&access.index);
+                self.visit_expression(&access.array, additional);
+                self.visit_expression(&access.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for IndexTypeChecker<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &Default::default());
+        self.visit_expression(&input.value, &Default::default());
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for IndexTypeChecker<'a> {}
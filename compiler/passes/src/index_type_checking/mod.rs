@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Index Type Checking pass traverses the AST and checks that the index of every array access is an unsigned
+//! integer, reporting other index types at the span of the index.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: [u8; 4], i: i32) -> u8 {
+//!     return a[i];
+//! }
+//! ```
+//!
+//! The index type checking pass reports an error for the index `i`.
+
+pub mod index_type_checker;
+pub use index_type_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for IndexTypeChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut visitor = IndexTypeChecker::new(handler, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks a transition indexing an array with an index of type `index_type`, then checks the index type.
+    fn check_index(index_type: &str) -> Result<()> {
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: [u8; 4], i: {index_type}) -> u8 {{
+                    return a[i];
+                }}
+            }}"
+        );
+        // The type checker rejects non-integer indices itself, but still records the types of the expressions.
+        let (type_checker_handler, _) = Handler::new_with_buf();
+        let type_table = TypeTable::default();
+        let ast = parse_program(&type_checker_handler, &NodeBuilder::default(), &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &type_checker_handler)).unwrap();
+        let _ = TypeChecker::do_pass((&ast, &type_checker_handler, symbol_table, &type_table));
+
+        let (handler, _) = Handler::new_with_buf();
+        IndexTypeChecker::do_pass((&ast, &handler, &type_table))
+    }
+
+    #[test]
+    fn test_accepts_unsigned_index() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_index("u32").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_signed_index() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_index("i32").is_err());
+        })
+    }
+
+    #[test]
+    fn test_rejects_field_index() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_index("field").is_err());
+        })
+    }
+}
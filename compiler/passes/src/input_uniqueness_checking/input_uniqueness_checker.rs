@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// A compiler pass that checks that the inputs of each function, and of each finalize block, have distinct names.
+/// The implicit receiver `self` counts as a name, so no input may be named `self`.
+pub struct InputUniquenessChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> InputUniquenessChecker<'a> {
+    /// Returns a new `InputUniquenessChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler }
+    }
+
+    /// Checks that the inputs of the function `function` have distinct names, other than `self`.
+    fn check_inputs(&self, function: Identifier, inputs: &[Input]) {
+        // Maps each input name to the span of its first declaration.
+        let mut declared: IndexMap<Symbol, Span> = IndexMap::new();
+        inputs.iter().for_each(|input| {
+            let name = input.identifier();
+            if name.name == sym::SelfLower {
+                self.handler.emit_err(StaticAnalyzerError::input_named_self(function, input.span()));
+            }
+            match declared.get(&name.name) {
+                Some(previous) => {
+                    self.handler.emit_err(StaticAnalyzerError::duplicate_input(function, name, previous, input.span()))
+                }
+                None => {
+                    declared.insert(name.name, input.span());
+                }
+            }
+        });
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for InputUniquenessChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for InputUniquenessChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for InputUniquenessChecker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        self.check_inputs(input.identifier, &input.input);
+        if let Some(finalize) = &input.finalize {
+            self.check_inputs(finalize.identifier, &finalize.input);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Input Uniqueness Checking pass traverses the AST and checks that no two inputs of a function, or of a
+//! finalize block, share a name. Each duplicate is reported at its own span, together with the span of the first
+//! input with that name.
+//! The name `self` is taken by the implicit receiver, e.g. in `self.caller`, so an input named `self` is an error.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, a: u16) -> u8 {
+//!     return a;
+//! }
+//! ```
+//!
+//! The input uniqueness checking pass reports an error for the second input `a`.
+
+pub mod input_uniqueness_checker;
+pub use input_uniqueness_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for InputUniquenessChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = InputUniquenessChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Input, NodeBuilder, Program};
+    use leo_span::{sym, symbol::create_session_if_not_set_then};
+
+    /// Parses a transition with the given inputs.
+    fn parse(inputs: &str) -> Program {
+        let handler = Handler::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main({inputs}) -> u8 {{
+                    return 0u8;
+                }}
+            }}"
+        );
+        parse_program(&handler, &NodeBuilder::default(), &source).into_repr()
+    }
+
+    /// Checks the program, returning the number of errors.
+    fn check(program: Program) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let _ = InputUniquenessChecker::do_pass((&Ast::new(program), &handler));
+        handler.err_count()
+    }
+
+    #[test]
+    fn test_rejects_duplicate_inputs() {
+        create_session_if_not_set_then(|_| assert_eq!(check(parse("a: u8, b: u8, a: u16")), 1))
+    }
+
+    #[test]
+    fn test_accepts_distinct_inputs() {
+        create_session_if_not_set_then(|_| assert_eq!(check(parse("a: u8, b: u8")), 0))
+    }
+
+    #[test]
+    fn test_rejects_input_named_self() {
+        create_session_if_not_set_then(|_| {
+            // `self` is a keyword, so an input named `self` is constructed directly rather than parsed.
+            let mut program = parse("a: u8");
+            let function = &mut program.program_scopes.values_mut().next().unwrap().functions[0].1;
+            if let Input::Internal(input) = &mut function.input[0] {
+                input.identifier.name = sym::SelfLower;
+            }
+            assert_eq!(check(program), 1);
+        })
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{IdRefresher, NameCollector};
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+pub struct InvariantConditionHoister<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> InvariantConditionHoister<'a> {
+    /// Initializes a new `InvariantConditionHoister`.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Returns whether the loop runs at least once, which is only known if its bounds are integer literals.
+    fn runs_at_least_once(input: &IterationStatement) -> bool {
+        let bound = |expression: &Expression| match expression {
+            Expression::Literal(Literal::Integer(_, value, ..)) => value.replace('_', "").parse::<i128>().ok(),
+            _ => None,
+        };
+        match (bound(&input.start), bound(&input.stop)) {
+            (Some(start), Some(stop)) => start < stop || (input.inclusive && start == stop),
+            _ => false,
+        }
+    }
+
+    /// Returns the index of the first conditional at the top level of the loop body whose condition is invariant.
+    /// A condition is invariant if it contains no calls and reads neither the loop variable nor any variable that the
+    /// body defines or assigns, so that it has the same value in every iteration.
+    /// Since a hoisted condition is evaluated even if the loop runs zero times, nothing is hoisted out of a loop that
+    /// may not run at all.
+    pub(crate) fn invariant_conditional(input: &IterationStatement) -> Option<usize> {
+        if !Self::runs_at_least_once(input) {
+            return None;
+        }
+        let written: IndexSet<Symbol> = std::iter::once(input.variable.name)
+            .chain(input.block.statements.iter().flat_map(NameCollector::written_by))
+            .collect();
+        input.block.statements.iter().position(|statement| match statement {
+            Statement::Conditional(conditional) => {
+                let collector = NameCollector::of_expression(&conditional.condition);
+                !collector.has_calls && collector.names.iter().all(|name| !written.contains(name))
+            }
+            _ => false,
+        })
+    }
+
+    /// Returns a copy of the loop whose body has the statement at `index` replaced by `statement`,
+    /// or removed if `statement` is `None`. Every node of the copy has a fresh node ID.
+    fn with_statement(
+        &self,
+        input: &IterationStatement,
+        index: usize,
+        statement: Option<Statement>,
+    ) -> IterationStatement {
+        let mut statements = input.block.statements.clone();
+        match statement {
+            Some(statement) => statements[index] = statement,
+            None => {
+                statements.remove(index);
+            }
+        }
+        let copy = IterationStatement { block: Block { statements, ..input.block.clone() }, ..input.clone() };
+        match IdRefresher::new(self.node_builder).reconstruct_iteration(copy).0 {
+            Statement::Iteration(copy) => *copy,
+            _ => unreachable!("`reconstruct_iteration` can only return `Statement::Iteration`"),
+        }
+    }
+}
+
+impl ExpressionReconstructor for InvariantConditionHoister<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for InvariantConditionHoister<'_> {
+    /// Hoists the first invariant conditional in the loop body out of the loop, selecting between a copy of the loop
+    /// with the `then` branch and a copy with the `else` branch, and then hoists from both copies in turn.
+    /// Inner loops are reconstructed first.
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        let input = IterationStatement { block: self.reconstruct_block(input.block).0, ..input };
+        let Some(index) = Self::invariant_conditional(&input) else {
+            return (Statement::Iteration(Box::new(input)), Default::default());
+        };
+        let Statement::Conditional(conditional) = &input.block.statements[index] else {
+            unreachable!("`invariant_conditional` only returns the index of a conditional.")
+        };
+
+        let then_loop = self.with_statement(&input, index, Some(Statement::Block(conditional.then.clone())));
+        let else_loop = self.with_statement(&input, index, conditional.otherwise.as_deref().cloned());
+        let then = self.reconstruct_iteration(then_loop).0;
+        let otherwise = self.reconstruct_iteration(else_loop).0;
+
+        (
+            Statement::Conditional(ConditionalStatement {
+                condition: conditional.condition.clone(),
+                then: Block { statements: vec![then], span: input.span, id: self.node_builder.next_id() },
+                otherwise: Some(Box::new(Statement::Block(Block {
+                    statements: vec![otherwise],
+                    span: input.span,
+                    id: self.node_builder.next_id(),
+                }))),
+                span: input.span,
+                id: self.node_builder.next_id(),
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for InvariantConditionHoister<'_> {}
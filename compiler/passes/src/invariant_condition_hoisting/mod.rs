@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Invariant Condition Hoisting pass traverses the AST and moves conditionals whose condition does not change
+//! across the iterations of a loop out of the loop, a transformation also known as loop unswitching.
+//! A conditional at the top level of a loop body is hoisted if its condition contains no calls and reads neither the
+//! loop variable nor any variable that the loop body defines or assigns, including in the conditional itself.
+//! The loop is then duplicated: one copy runs the `then` branch in place of the conditional, the other the `else`
+//! branch, and the condition selects between the two copies.
+//! Nested loops are processed from the inside out, and every invariant conditional of a loop is hoisted in turn.
+//! Each copy of a loop body is given fresh node IDs.
+//! Since the hoisted condition is evaluated even if the loop runs zero times, only loops whose bounds are integer
+//! literals describing at least one iteration are unswitched.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: bool) -> u8 {
+//!     for i: u8 in 0u8..4u8 {
+//!         if b { a = a + i; } else { a = a - i; }
+//!     }
+//!     return a;
+//! }
+//! ```
+//!
+//! The invariant condition hoisting pass produces the following code.
+//! ```leo
+//! transition main(a: u8, b: bool) -> u8 {
+//!     if b {
+//!         for i: u8 in 0u8..4u8 { { a = a + i; } }
+//!     } else {
+//!         for i: u8 in 0u8..4u8 { { a = a - i; } }
+//!     }
+//!     return a;
+//! }
+//! ```
+
+pub mod invariant_condition_hoister;
+pub use invariant_condition_hoister::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for InvariantConditionHoister<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = InvariantConditionHoister::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Node, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and hoists a transition whose body is a loop with the given body, returning the statements of the
+    /// transition body.
+    fn hoist(loop_body: &str) -> Vec<Statement> {
+        hoist_loop("0u8..4u8", loop_body)
+    }
+
+    /// Parses and hoists a transition whose body is a loop over `range` with the given body, returning the statements
+    /// of the transition body.
+    fn hoist_loop(range: &str, loop_body: &str) -> Vec<Statement> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8, b: bool) -> u8 {{
+                    for i: u8 in {range} {{
+                        {loop_body}
+                    }}
+                    return a;
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let ast = InvariantConditionHoister::do_pass((ast, &node_builder)).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1.block.statements
+    }
+
+    #[test]
+    fn test_hoists_invariant_condition() {
+        create_session_if_not_set_then(|_| {
+            let statements = hoist("if b { a = a + i; } else { a = a - i; }");
+
+            let Statement::Conditional(conditional) = &statements[0] else {
+                panic!("expected a conditional, found `{}`", statements[0]);
+            };
+            assert_eq!(conditional.condition.to_string(), "b");
+            assert!(matches!(conditional.then.statements[..], [Statement::Iteration(_)]));
+            let Some(Statement::Block(otherwise)) = conditional.otherwise.as_deref() else {
+                panic!("expected an `else` block");
+            };
+            assert!(matches!(otherwise.statements[..], [Statement::Iteration(_)]));
+        })
+    }
+
+    #[test]
+    fn test_preserves_dependent_conditions() {
+        create_session_if_not_set_then(|_| {
+            for body in ["if i > 1u8 { a = a + i; }", "if b { b = false; a = a + i; }"] {
+                let statements = hoist(body);
+
+                let Statement::Iteration(iteration) = &statements[0] else {
+                    panic!("expected a loop, found `{}`", statements[0]);
+                };
+                assert!(matches!(iteration.block.statements[..], [Statement::Conditional(_)]));
+            }
+        })
+    }
+
+    #[test]
+    fn test_gives_copies_fresh_ids() {
+        create_session_if_not_set_then(|_| {
+            let statements = hoist("if b { a = a + i; } else { a = a - i; }");
+
+            let Statement::Conditional(conditional) = &statements[0] else {
+                panic!("expected a conditional, found `{}`", statements[0]);
+            };
+            let Some(Statement::Block(otherwise)) = conditional.otherwise.as_deref() else {
+                panic!("expected an `else` block");
+            };
+            let (Statement::Iteration(then_loop), Statement::Iteration(else_loop)) =
+                (&conditional.then.statements[0], &otherwise.statements[0])
+            else {
+                panic!("expected a loop in each branch");
+            };
+            assert_ne!(then_loop.id(), else_loop.id());
+            assert_ne!(then_loop.variable.id, else_loop.variable.id);
+            assert_ne!(then_loop.start.id(), else_loop.start.id());
+            assert_ne!(then_loop.block.id, else_loop.block.id);
+        })
+    }
+
+    #[test]
+    fn test_preserves_loops_that_may_not_run() {
+        create_session_if_not_set_then(|_| {
+            let statements = hoist_loop("4u8..4u8", "if b { a = a + i; } else { a = a - i; }");
+            assert!(matches!(statements[0], Statement::Iteration(_)));
+        })
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError};
+
+/// A compiler pass that checks that the literal bounds of each loop have the type of the loop variable,
+/// and that their values fit in that type.
+/// Bounds that are not literals, e.g. constants, are checked by the type checker.
+pub struct IterationTypeChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> IterationTypeChecker<'a> {
+    /// Returns a new `IterationTypeChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler }
+    }
+
+    /// Returns `true` if `value`, the string of an integer literal, fits in `integer_type`.
+    fn fits(value: &str, integer_type: &IntegerType) -> bool {
+        let value = value.replace('_', "");
+        match integer_type {
+            IntegerType::U8 => value.parse::<u8>().is_ok(),
+            IntegerType::U16 => value.parse::<u16>().is_ok(),
+            IntegerType::U32 => value.parse::<u32>().is_ok(),
+            IntegerType::U64 => value.parse::<u64>().is_ok(),
+            IntegerType::U128 => value.parse::<u128>().is_ok(),
+            IntegerType::I8 => value.parse::<i8>().is_ok(),
+            IntegerType::I16 => value.parse::<i16>().is_ok(),
+            IntegerType::I32 => value.parse::<i32>().is_ok(),
+            IntegerType::I64 => value.parse::<i64>().is_ok(),
+            IntegerType::I128 => value.parse::<i128>().is_ok(),
+        }
+    }
+
+    /// Reports a literal bound of the loop `input` that does not have the type `integer_type` or does not fit in it.
+    /// Note that a stop bound equal to the maximum value of the type is valid, whether or not the range is inclusive.
+    fn check_bound(&self, bound: &Expression, integer_type: &IntegerType, input: &IterationStatement) {
+        match bound {
+            Expression::Literal(Literal::Integer(type_, value, ..)) if type_ == integer_type => {
+                if !Self::fits(value, integer_type) {
+                    self.handler.emit_err(TypeCheckerError::invalid_int_value(value, integer_type, input.span));
+                }
+            }
+            Expression::Literal(literal) => {
+                let type_ = match literal {
+                    Literal::Address(..) => Type::Address,
+                    Literal::Boolean(..) => Type::Boolean,
+                    Literal::Field(..) => Type::Field,
+                    Literal::Group(..) => Type::Group,
+                    Literal::Integer(type_, ..) => Type::Integer(*type_),
+                    Literal::Scalar(..) => Type::Scalar,
+                    Literal::String(..) => Type::String,
+                };
+                self.handler.emit_err(TypeCheckerError::type_should_be(type_, integer_type, input.span));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for IterationTypeChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for IterationTypeChecker<'a> {
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        match &input.type_ {
+            Type::Integer(integer_type) => {
+                self.check_bound(&input.start, integer_type, input);
+                self.check_bound(&input.stop, integer_type, input);
+            }
+            type_ => self.handler.emit_err(TypeCheckerError::type_should_be(type_, "integer", input.span)),
+        }
+        self.visit_block(&input.block);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for IterationTypeChecker<'a> {}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Iteration Type Checking pass traverses the AST and checks that the literal bounds of each loop have
+//! the type of the loop variable, and that their values fit in that type.
+//! Errors are reported at the span of the loop.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8) -> u8 {
+//!     for i: u8 in 0u8..300u8 {
+//!         a = a + 1u8;
+//!     }
+//!     return a;
+//! }
+//! ```
+//!
+//! The iteration type checking pass reports an error, since `300` does not fit in a `u8`.
+
+pub mod iteration_type_checker;
+pub use iteration_type_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for IterationTypeChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = IterationTypeChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a transition with a `u8` loop over the given range.
+    fn check_range(range: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8) -> u8 {{
+                    for i: u8 in {range} {{
+                        a = a + i;
+                    }}
+                    return a;
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        IterationTypeChecker::do_pass((&ast, &handler))
+    }
+
+    #[test]
+    fn test_accepts_bounds_of_loop_type() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_range("0u8..10u8").is_ok());
+            assert!(check_range("0u8..255u8").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_bound_out_of_range() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_range("0u8..300u8").is_err());
+        })
+    }
+
+    #[test]
+    fn test_rejects_non_integer_bound() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_range("0u8..10field").is_err());
+        })
+    }
+}
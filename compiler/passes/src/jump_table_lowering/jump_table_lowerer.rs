@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{NameCollector, TypeTable};
+
+use leo_ast::*;
+use leo_span::Span;
+
+use snarkvm_console::network::{Network, Testnet3};
+
+pub struct JumpTableLowerer<'a> {
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> JumpTableLowerer<'a> {
+    /// Initializes a new `JumpTableLowerer`.
+    pub fn new(type_table: &'a TypeTable, node_builder: &'a NodeBuilder) -> Self {
+        Self { type_table, node_builder }
+    }
+
+    /// Returns a new node ID, recording `type_` as the type of the node.
+    fn next_id(&self, type_: &Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_.clone());
+        id
+    }
+
+    /// Returns the variable, its unsigned integer type, and the key of a condition `x == k` or `k == x`.
+    fn case(condition: &Expression) -> Option<(&Identifier, IntegerType, usize)> {
+        let Expression::Binary(BinaryExpression { left, right, op: BinaryOperation::Eq, .. }) = condition else {
+            return None;
+        };
+        let (variable, type_, key) = match (&**left, &**right) {
+            (Expression::Identifier(variable), Expression::Literal(Literal::Integer(type_, key, ..)))
+            | (Expression::Literal(Literal::Integer(type_, key, ..)), Expression::Identifier(variable)) => {
+                (variable, type_, key)
+            }
+            _ => return None,
+        };
+        // The variable must be unsigned, so that every value out of the table is clamped to the default.
+        if !matches!(
+            type_,
+            IntegerType::U8 | IntegerType::U16 | IntegerType::U32 | IntegerType::U64 | IntegerType::U128
+        ) {
+            return None;
+        }
+        Some((variable, *type_, key.replace('_', "").parse().ok()?))
+    }
+
+    /// Returns the assigned variable, if any, and the value of a branch consisting of a single `return e;` or a
+    /// single assignment `x = e;`.
+    fn branch(block: &Block) -> Option<(Option<&Identifier>, &Expression)> {
+        match block.statements.as_slice() {
+            [Statement::Return(ReturnStatement { expression, finalize_arguments: None, .. })] => {
+                Some((None, expression))
+            }
+            [Statement::Assign(assign)] => match &assign.place {
+                Expression::Identifier(place) => Some((Some(place), &assign.value)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Lowers a chain `if x == 0 { .. } else if x == 1 { .. } .. else { .. }` whose keys are exactly `0..n`, into
+    /// `[e0, .., en-1, default][x < n ? x : n]`, where each branch returns or assigns the same variable its value.
+    /// Returns `None` if the chain does not have this form, if a value makes a call, or if the values do not share
+    /// a type that can be the element type of an array.
+    fn lower(&self, input: &ConditionalStatement) -> Option<Statement> {
+        let (variable, type_, _) = Self::case(&input.condition)?;
+        let (place, _) = Self::branch(&input.then)?;
+
+        let mut cases = Vec::new();
+        let mut conditional = input;
+        let default = loop {
+            let (other_variable, other_type, key) = Self::case(&conditional.condition)?;
+            let (other_place, value) = Self::branch(&conditional.then)?;
+            if other_variable.name != variable.name
+                || other_type != type_
+                || other_place.map(|place| place.name) != place.map(|place| place.name)
+            {
+                return None;
+            }
+            cases.push((key, value));
+            match conditional.otherwise.as_deref()? {
+                Statement::Conditional(otherwise) => conditional = otherwise,
+                Statement::Block(otherwise) => match Self::branch(otherwise)? {
+                    (other_place, value) if other_place.map(|place| place.name) == place.map(|place| place.name) => {
+                        break value;
+                    }
+                    _ => return None,
+                },
+                _ => return None,
+            }
+        };
+
+        // The keys must be exactly `0..n`, so that each key indexes its value in the table.
+        let length = cases.len();
+        if length >= Testnet3::MAX_ARRAY_ELEMENTS {
+            return None;
+        }
+        let mut table = vec![None; length];
+        for (key, value) in cases {
+            match table.get_mut(key) {
+                Some(entry @ None) => *entry = Some(value),
+                _ => return None,
+            }
+        }
+        let values: Vec<&Expression> =
+            table.into_iter().chain(std::iter::once(Some(default))).collect::<Option<_>>()?;
+
+        // Every value is evaluated, so none of them may have side effects.
+        if values.iter().any(|value| NameCollector::of_expression(value).has_calls) {
+            return None;
+        }
+        let element_type = self.type_table.get(&default.id())?;
+        if matches!(element_type, Type::Err | Type::Mapping(_) | Type::String | Type::Tuple(_) | Type::Unit)
+            || values
+                .iter()
+                .any(|value| !self.type_table.get(&value.id()).map_or(false, |type_| type_.eq_flat(&element_type)))
+        {
+            return None;
+        }
+
+        let span = input.span;
+        let access = self.table_access(values, element_type, variable, type_, length, span);
+        Some(match place {
+            Some(place) => Statement::Assign(Box::new(AssignStatement {
+                place: Expression::Identifier(*place),
+                value: access,
+                span,
+                id: self.node_builder.next_id(),
+            })),
+            None => Statement::Return(ReturnStatement {
+                expression: access,
+                finalize_arguments: None,
+                span,
+                id: self.node_builder.next_id(),
+            }),
+        })
+    }
+
+    /// Returns `[values..][x < n ? x : n]`, where `x` is `variable` of type `type_` and `n` is `length`.
+    fn table_access(
+        &self,
+        values: Vec<&Expression>,
+        element_type: Type,
+        variable: &Identifier,
+        type_: IntegerType,
+        length: usize,
+        span: Span,
+    ) -> Expression {
+        let index_type = Type::Integer(type_);
+        let variable = |id| Expression::Identifier(Identifier { id, ..*variable });
+        let length = |id| Expression::Literal(Literal::Integer(type_, length.to_string(), span, id));
+        let index = Expression::Ternary(TernaryExpression {
+            condition: Box::new(Expression::Binary(BinaryExpression {
+                left: Box::new(variable(self.next_id(&index_type))),
+                right: Box::new(length(self.next_id(&index_type))),
+                op: BinaryOperation::Lt,
+                span,
+                id: self.next_id(&Type::Boolean),
+            })),
+            if_true: Box::new(variable(self.next_id(&index_type))),
+            if_false: Box::new(length(self.next_id(&index_type))),
+            span,
+            id: self.next_id(&index_type),
+        });
+        let array_type = Type::Array(ArrayType::new(element_type.clone(), values.len().into()));
+        let array = Expression::Array(ArrayExpression {
+            elements: values.into_iter().cloned().collect(),
+            span,
+            id: self.next_id(&array_type),
+        });
+        Expression::Access(AccessExpression::Array(ArrayAccess {
+            array: Box::new(array),
+            index: Box::new(index),
+            span,
+            id: self.next_id(&element_type),
+        }))
+    }
+
+    /// Reconstructs a chain of conditional statements without lowering its `else if`s, so that only the head of the
+    /// chain is lowered, as a whole.
+    fn reconstruct_chain(&mut self, input: ConditionalStatement) -> ConditionalStatement {
+        ConditionalStatement {
+            condition: self.reconstruct_expression(input.condition).0,
+            then: self.reconstruct_block(input.then).0,
+            otherwise: input.otherwise.map(|otherwise| match *otherwise {
+                Statement::Conditional(otherwise) => {
+                    Box::new(Statement::Conditional(self.reconstruct_chain(otherwise)))
+                }
+                otherwise => Box::new(self.reconstruct_statement(otherwise).0),
+            }),
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
+
+impl ExpressionReconstructor for JumpTableLowerer<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for JumpTableLowerer<'_> {
+    /// Lowers the chain headed by the conditional statement, if possible.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let conditional = self.reconstruct_chain(input);
+        (self.lower(&conditional).unwrap_or(Statement::Conditional(conditional)), Default::default())
+    }
+}
+
+impl ProgramReconstructor for JumpTableLowerer<'_> {}
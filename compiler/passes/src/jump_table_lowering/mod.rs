@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Jump Table Lowering pass traverses the AST and lowers dense chains of equality comparisons on a single unsigned
+//! integer variable into a lookup table, i.e. an array of the values of the branches indexed by the variable.
+//! A chain is lowered if its conditions are `x == 0`, `x == 1`, ..., `x == n - 1` in any order, it ends with an
+//! `else` branch, and each branch consists of a single `return` or a single assignment to the same variable whose
+//! value makes no calls, since every value of the table is evaluated.
+//! The variable is clamped to `n`, the index of the value of the `else` branch.
+//! Sparse chains, and chains whose branches have side effects, are left as conditionals.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(x: u8, a: u8, b: u8) -> u8 {
+//!     if x == 0u8 {
+//!         return a;
+//!     } else if x == 1u8 {
+//!         return b;
+//!     } else if x == 2u8 {
+//!         return a + b;
+//!     } else {
+//!         return 0u8;
+//!     }
+//! }
+//! ```
+//!
+//! The jump table lowering pass produces the following code.
+//! ```leo
+//! function main(x: u8, a: u8, b: u8) -> u8 {
+//!     return [a, b, a + b, 0u8][x < 3u8 ? x : 3u8];
+//! }
+//! ```
+
+pub mod jump_table_lowerer;
+pub use jump_table_lowerer::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for JumpTableLowerer<'a> {
+    type Input = (Ast, &'a TypeTable, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, type_table, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = JumpTableLowerer::new(type_table, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::{AccessExpression, Expression, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and lowers a function with inputs `x: u8`, `a: u8` and `b: u8` and the given body, returning the
+    /// last statement of the body.
+    fn lower_body(body: &str) -> Statement {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let type_table = TypeTable::default();
+        let source = format!("program test.aleo {{ function main(x: u8, a: u8, b: u8) -> u8 {{ {body} }} }}");
+        let ast = parse_program(&handler, &node_builder, &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+
+        let ast = JumpTableLowerer::do_pass((ast, &type_table, &node_builder)).unwrap();
+        let mut function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        function.block.statements.pop().unwrap()
+    }
+
+    #[test]
+    fn test_lowers_dense_chain() {
+        create_session_if_not_set_then(|_| {
+            let statement = lower_body(
+                "if x == 0u8 { return a; } else if x == 1u8 { return b; } else if x == 2u8 { return a + b; } \
+                 else if x == 3u8 { return 7u8; } else { return 0u8; }",
+            );
+
+            let Statement::Return(statement) = statement else {
+                panic!("expected a return statement, found `{statement}`");
+            };
+            let Expression::Access(AccessExpression::Array(access)) = statement.expression else {
+                panic!("expected an array access, found `{}`", statement.expression);
+            };
+            assert!(matches!(&*access.array, Expression::Array(array) if array.elements.len() == 5));
+            assert!(matches!(&*access.index, Expression::Ternary(_)));
+        })
+    }
+
+    #[test]
+    fn test_lowers_chain_with_keys_out_of_order() {
+        create_session_if_not_set_then(|_| {
+            let statement = lower_body(
+                "if x == 2u8 { return a + b; } else if x == 0u8 { return a; } else if x == 1u8 { return b; } \
+                 else { return 0u8; }",
+            );
+
+            let Statement::Return(statement) = statement else {
+                panic!("expected a return statement, found `{statement}`");
+            };
+            let Expression::Access(AccessExpression::Array(access)) = statement.expression else {
+                panic!("expected an array access, found `{}`", statement.expression);
+            };
+            let Expression::Array(array) = &*access.array else {
+                panic!("expected an array, found `{}`", access.array);
+            };
+            let elements: Vec<String> = array.elements.iter().map(|element| element.to_string()).collect();
+            assert_eq!(elements, vec!["a", "b", "a + b", "0u8"]);
+        })
+    }
+
+    #[test]
+    fn test_preserves_sparse_chain() {
+        create_session_if_not_set_then(|_| {
+            let statement = lower_body(
+                "if x == 0u8 { return a; } else if x == 2u8 { return b; } else if x == 5u8 { return 7u8; } \
+                 else { return 0u8; }",
+            );
+            assert!(matches!(statement, Statement::Conditional(_)));
+        })
+    }
+}
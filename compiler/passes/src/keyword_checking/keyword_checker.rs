@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_parser::KEYWORD_TOKENS;
+use leo_span::{sym, Symbol};
+
+use indexmap::IndexSet;
+
+/// A compiler pass that checks that no reserved keyword, e.g. `let` or `function`, is used as an identifier.
+/// The parser already rejects such identifiers in source code, so this pass mainly catches names
+/// synthesized by buggy compiler passes.
+/// Note that `self` may be used in expressions, e.g. `self.caller`, but not as a binding.
+pub struct KeywordChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The reserved keywords.
+    keywords: IndexSet<Symbol>,
+}
+
+impl<'a> KeywordChecker<'a> {
+    /// Returns a new `KeywordChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        let keywords = KEYWORD_TOKENS.iter().filter_map(|token| token.keyword_to_symbol()).collect();
+        Self { handler, keywords }
+    }
+
+    /// Reports the identifier if it is a reserved keyword.
+    fn check_binding(&self, identifier: &Identifier) {
+        if self.keywords.contains(&identifier.name) {
+            self.handler.emit_err(StaticAnalyzerError::keyword_as_identifier(identifier.name, identifier.span));
+        }
+    }
+
+    /// Reports the name of each function input that is a reserved keyword.
+    fn check_inputs(&self, inputs: &[Input]) {
+        inputs.iter().for_each(|input| self.check_binding(&input.identifier()));
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for KeywordChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        if input.name != sym::SelfLower {
+            self.check_binding(input);
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, additional),
+            None => self.visit_identifier(&member.identifier, additional),
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for KeywordChecker<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &Default::default());
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.check_binding(&input.place);
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.place, &Default::default());
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.check_binding(&input.variable);
+        self.visit_expression(&input.start, &Default::default());
+        self.visit_expression(&input.stop, &Default::default());
+        self.visit_block(&input.block);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for KeywordChecker<'a> {
+    fn visit_struct(&mut self, input: &'a Struct) {
+        self.check_binding(&input.identifier);
+        input.members.iter().for_each(|member| self.check_binding(&member.identifier));
+    }
+
+    fn visit_mapping(&mut self, input: &'a Mapping) {
+        self.check_binding(&input.identifier);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.check_binding(&input.identifier);
+        self.check_inputs(&input.input);
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.check_binding(&finalize.identifier);
+            self.check_inputs(&finalize.input);
+            self.visit_block(&finalize.block);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Keyword Checking pass traverses the AST and checks that no reserved keyword is used as an identifier,
+//! e.g. as the name of a variable, input, function, struct, member, or mapping.
+//! Errors are reported at the span of the offending identifier.
+//! Since the parser rejects keywords as identifiers, this pass is intended to validate the output of other passes.
+
+pub mod keyword_checker;
+pub use keyword_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for KeywordChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = KeywordChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, NodeBuilder, Statement};
+    use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+    /// Parses a program whose transition defines `b`, renaming `b` to `name`.
+    fn program_binding(handler: &Handler, name: &str) -> Ast {
+        let source = "program test.aleo {
+            transition main(a: u8) -> u8 {
+                let b: u8 = a;
+                return a;
+            }
+        }";
+        let mut program = parse_program(handler, &NodeBuilder::default(), source).into_repr();
+        let function = &mut program.program_scopes.values_mut().next().unwrap().functions[0].1;
+        // The parser rejects keywords as identifiers, so the binding is renamed as a buggy pass might.
+        match &mut function.block.statements[0] {
+            Statement::Definition(definition) => match &mut definition.place {
+                Expression::Identifier(identifier) => identifier.name = Symbol::intern(name),
+                place => panic!("expected an identifier, found `{place}`"),
+            },
+            statement => panic!("expected a definition, found `{statement}`"),
+        }
+        Ast::new(program)
+    }
+
+    #[test]
+    fn test_rejects_keyword_binding() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let ast = program_binding(&handler, "function");
+
+            assert!(KeywordChecker::do_pass((&ast, &handler)).is_err());
+            assert_eq!(handler.err_count(), 1);
+        })
+    }
+
+    #[test]
+    fn test_accepts_normal_binding() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let ast = program_binding(&handler, "c");
+
+            assert!(KeywordChecker::do_pass((&ast, &handler)).is_ok());
+        })
+    }
+}
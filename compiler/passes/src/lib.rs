@@ -17,35 +17,287 @@
 #![forbid(unsafe_code)]
 #![doc = include_str!("../README.md")]
 
+pub mod access_depth_checking;
+pub use access_depth_checking::*;
+
+pub mod annotation_arg_type_checking;
+pub use annotation_arg_type_checking::*;
+
+pub mod annotation_conflict_checking;
+pub use annotation_conflict_checking::*;
+
+pub mod annotation_placement_checking;
+pub use annotation_placement_checking::*;
+
+pub mod annotation_reporting;
+pub use annotation_reporting::*;
+
+pub mod array_dimension_checking;
+pub use array_dimension_checking::*;
+
+pub mod array_homogeneity_checking;
+pub use array_homogeneity_checking::*;
+
+pub mod array_init_element_hoisting;
+pub use array_init_element_hoisting::*;
+
+pub mod array_pattern_lowering;
+pub use array_pattern_lowering::*;
+
+pub mod array_to_tuple_access_lowering;
+pub use array_to_tuple_access_lowering::*;
+
+pub mod ascription_checking;
+pub use ascription_checking::*;
+
+pub mod assign_in_condition_checking;
+pub use assign_in_condition_checking::*;
+
+pub mod assign_merging;
+pub use assign_merging::*;
+
+pub mod assignee_type_checking;
+pub use assignee_type_checking::*;
+
+pub mod bool_array_packing;
+pub use bool_array_packing::*;
+
+pub mod call_arity_checking;
+pub use call_arity_checking::*;
+
+pub mod cast_normalization;
+pub use cast_normalization::*;
+
+pub mod cfg_expansion;
+pub use cfg_expansion::*;
+
+pub mod chained_comparison_desugaring;
+pub use chained_comparison_desugaring::*;
+
+pub mod circuit_deduplication;
+pub use circuit_deduplication::*;
+
+pub mod circuit_member_uniqueness_checking;
+pub use circuit_member_uniqueness_checking::*;
+
+pub mod circuit_specialization;
+pub use circuit_specialization::*;
+
 pub mod code_generation;
 pub use code_generation::*;
 
 pub mod common;
 pub use common::*;
 
+pub mod conditional_fusion;
+pub use conditional_fusion::*;
+
+pub mod console_assert_lowering;
+pub use console_assert_lowering::*;
+
+pub mod const_arg_checking;
+pub use const_arg_checking::*;
+
+pub mod const_array_index_folding;
+pub use const_array_index_folding::*;
+
+pub mod const_definition_checking;
+pub use const_definition_checking::*;
+
+pub mod constant_propagation;
+pub use constant_propagation::*;
+
 pub mod dead_code_elimination;
 pub use dead_code_elimination::*;
 
+pub mod declaration_hoisting;
+pub use declaration_hoisting::*;
+
+pub mod definition_splitting;
+pub use definition_splitting::*;
+
 pub mod destructuring;
 pub use destructuring::*;
 
+pub mod discard_normalization;
+pub use discard_normalization::*;
+
+pub mod doc_stripping;
+pub use doc_stripping::*;
+
+pub mod effect_analysis;
+pub use effect_analysis::*;
+
+pub mod equality_propagation;
+pub use equality_propagation::*;
+
+pub mod exhaustiveness_checking;
+pub use exhaustiveness_checking::*;
+
+pub mod field_cast_checking;
+pub use field_cast_checking::*;
+
+pub mod field_literal_canonicalization;
+pub use field_literal_canonicalization::*;
+
 pub mod flattening;
 pub use flattening::*;
 
+pub mod function_extraction;
+pub use function_extraction::*;
+
 pub mod function_inlining;
 pub use function_inlining::*;
 
+pub mod function_size_checking;
+pub use function_size_checking::*;
+
+pub mod global_shadow_checking;
+pub use global_shadow_checking::*;
+
+pub mod group_literal_checking;
+pub use group_literal_checking::*;
+
+pub mod group_operation_checking;
+pub use group_operation_checking::*;
+
+pub mod group_point_checking;
+pub use group_point_checking::*;
+
+pub mod group_scalar_lowering;
+pub use group_scalar_lowering::*;
+
+pub mod index_type_checking;
+pub use index_type_checking::*;
+
+pub mod input_uniqueness_checking;
+pub use input_uniqueness_checking::*;
+
+pub mod invariant_condition_hoisting;
+pub use invariant_condition_hoisting::*;
+
+pub mod iteration_type_checking;
+pub use iteration_type_checking::*;
+
+pub mod jump_table_lowering;
+pub use jump_table_lowering::*;
+
+pub mod keyword_checking;
+pub use keyword_checking::*;
+
+pub mod liveness_analysis;
+pub use liveness_analysis::*;
+
+pub mod loop_early_return_lowering;
+pub use loop_early_return_lowering::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
+pub mod mixed_operator_checking;
+pub use mixed_operator_checking::*;
+
+pub mod mod_pow2_simplification;
+pub use mod_pow2_simplification::*;
+
+pub mod negative_index_resolution;
+pub use negative_index_resolution::*;
+
+pub mod overflow_check_insertion;
+pub use overflow_check_insertion::*;
+
 pub mod pass;
 pub use self::pass::*;
 
+pub mod pow_squaring_lowering;
+pub use pow_squaring_lowering::*;
+
+pub mod predicate_checking;
+pub use predicate_checking::*;
+
+pub mod purity_analysis;
+pub use purity_analysis::*;
+
+pub mod redundant_cast_collapsing;
+pub use redundant_cast_collapsing::*;
+
+pub mod return_ternary_folding;
+pub use return_ternary_folding::*;
+
+pub mod return_type_checking;
+pub use return_type_checking::*;
+
+pub mod select_lowering;
+pub use select_lowering::*;
+
+pub mod self_assign_elimination;
+pub use self_assign_elimination::*;
+
+pub mod self_usage_checking;
+pub use self_usage_checking::*;
+
+pub mod shorthand_expansion;
+pub use shorthand_expansion::*;
+
+pub mod single_call_inlining;
+pub use single_call_inlining::*;
+
+pub mod single_exit_transformation;
+pub use single_exit_transformation::*;
+
+pub mod soa_conversion;
+pub use soa_conversion::*;
+
+pub mod span_remapping;
+pub use span_remapping::*;
+
 pub mod static_single_assignment;
 pub use static_single_assignment::*;
 
+pub mod step_normalization;
+pub use step_normalization::*;
+
+pub mod string_context_checking;
+pub use string_context_checking::*;
+
 pub mod symbol_table_creation;
 pub use symbol_table_creation::*;
 
+pub mod tautology_checking;
+pub use tautology_checking::*;
+
+pub mod ternary_branch_type_checking;
+pub use ternary_branch_type_checking::*;
+
+pub mod test_case_reduction;
+pub use test_case_reduction::*;
+
+pub mod tuple_access_folding;
+pub use tuple_access_folding::*;
+
+pub mod tuple_arity_limiting;
+pub use tuple_arity_limiting::*;
+
+pub mod tuple_spread_expansion;
+pub use tuple_spread_expansion::*;
+
 pub mod type_checking;
 pub use type_checking::*;
+
+pub mod type_param_checking;
+pub use type_param_checking::*;
+
+pub mod typed_fingerprinting;
+pub use typed_fingerprinting::*;
+
+pub mod unroll_budget_checking;
+pub use unroll_budget_checking::*;
+
+pub mod unused_import_checking;
+pub use unused_import_checking::*;
+
+pub mod unused_result_checking;
+pub use unused_result_checking::*;
+
+#[cfg(test)]
+pub(crate) mod test_utilities;
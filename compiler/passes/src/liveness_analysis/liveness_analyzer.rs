@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NameCollector;
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The variables that are live before and after each statement and block of a program.
+/// Statements and blocks are identified by their node IDs.
+#[derive(Debug, Default)]
+pub struct Liveness {
+    /// The variables that are live before each statement or block.
+    live_in: IndexMap<NodeID, IndexSet<Symbol>>,
+    /// The variables that are live after each statement or block.
+    live_out: IndexMap<NodeID, IndexSet<Symbol>>,
+}
+
+impl Liveness {
+    /// Returns the variables that are live before the statement or block `id`, if it was analyzed.
+    pub fn live_in(&self, id: NodeID) -> Option<&IndexSet<Symbol>> {
+        self.live_in.get(&id)
+    }
+
+    /// Returns the variables that are live after the statement or block `id`, if it was analyzed.
+    pub fn live_out(&self, id: NodeID) -> Option<&IndexSet<Symbol>> {
+        self.live_out.get(&id)
+    }
+
+    /// Returns `true` if the variable `name` is live after the statement or block `id`.
+    pub fn is_live_after(&self, id: NodeID, name: Symbol) -> bool {
+        self.live_out(id).map_or(false, |live| live.contains(&name))
+    }
+}
+
+/// A compiler pass that computes which variables are live before and after each statement and block.
+/// A variable is live at a point if its current value may be read later.
+/// Constants and mappings are not variables, so they are never live.
+pub struct LivenessAnalyzer<'a> {
+    /// The liveness computed so far.
+    pub(crate) liveness: Liveness,
+    /// The program scope that we are currently traversing.
+    pub(crate) scope: Option<&'a ProgramScope>,
+}
+
+impl<'a> LivenessAnalyzer<'a> {
+    /// Returns a new `LivenessAnalyzer`.
+    pub fn new() -> Self {
+        Self { liveness: Liveness::default(), scope: None }
+    }
+
+    /// Returns the variables read by the expression.
+    fn uses(&self, input: &Expression) -> IndexSet<Symbol> {
+        self.variables(NameCollector::of_expression(input).names)
+    }
+
+    /// Filters the constants and mappings of the current program scope out of `names`.
+    fn variables(&self, mut names: IndexSet<Symbol>) -> IndexSet<Symbol> {
+        if let Some(scope) = self.scope {
+            names.retain(|name| {
+                !scope.consts.iter().any(|(constant, _)| constant == name)
+                    && !scope.mappings.iter().any(|(mapping, _)| mapping == name)
+            });
+        }
+        names
+    }
+
+    /// Returns the liveness computed so far.
+    pub fn into_liveness(self) -> Liveness {
+        self.liveness
+    }
+
+    /// Records the live variables around the node `id`, returning the variables that are live before it.
+    fn record(&mut self, id: NodeID, live_in: IndexSet<Symbol>, live_out: IndexSet<Symbol>) -> IndexSet<Symbol> {
+        self.liveness.live_in.insert(id, live_in.clone());
+        self.liveness.live_out.insert(id, live_out);
+        live_in
+    }
+
+    /// Analyzes the block, given the variables that are live after it, returning the variables that are live before it.
+    pub(crate) fn analyze_block(&mut self, input: &Block, live_out: IndexSet<Symbol>) -> IndexSet<Symbol> {
+        let live_in = input
+            .statements
+            .iter()
+            .rev()
+            .fold(live_out.clone(), |live, statement| self.analyze_statement(statement, live));
+        self.record(input.id, live_in, live_out)
+    }
+
+    /// Analyzes the statement, given the variables that are live after it, returning the variables that are live
+    /// before it.
+    fn analyze_statement(&mut self, input: &Statement, live_out: IndexSet<Symbol>) -> IndexSet<Symbol> {
+        let live_in = match input {
+            Statement::Annotated(annotated) => return self.analyze_statement(&annotated.statement, live_out),
+            // Writing to a variable kills it, unless only an element or a member of it is written.
+            Statement::Assign(assign) => {
+                let mut live = live_out.clone();
+                match &assign.place {
+                    Expression::Identifier(identifier) => {
+                        live.shift_remove(&identifier.name);
+                    }
+                    place => live.extend(self.uses(place)),
+                }
+                live.extend(self.uses(&assign.value));
+                live
+            }
+            Statement::Block(block) => return self.analyze_block(block, live_out),
+            Statement::Conditional(conditional) => {
+                let mut live = self.analyze_block(&conditional.then, live_out.clone());
+                match &conditional.otherwise {
+                    Some(otherwise) => live.extend(self.analyze_statement(otherwise, live_out.clone())),
+                    None => live.extend(live_out.iter().copied()),
+                }
+                live.extend(self.uses(&conditional.condition));
+                live
+            }
+            Statement::Const(declaration) => {
+                let mut live = live_out.clone();
+                live.shift_remove(&declaration.place.name);
+                live.extend(self.uses(&declaration.value));
+                live
+            }
+            Statement::Definition(definition) => {
+                let mut live = live_out.clone();
+                self.uses(&definition.place).iter().for_each(|name| {
+                    live.shift_remove(name);
+                });
+                live.extend(self.uses(&definition.value));
+                live
+            }
+            // The body may be executed any number of times, so the variables that are live before the body are also
+            // live after it. They are propagated along the back edge until a fixpoint is reached.
+            Statement::Iteration(iteration) => {
+                let mut body_out = live_out.clone();
+                let live = loop {
+                    let mut live = self.analyze_block(&iteration.block, body_out.clone());
+                    live.shift_remove(&iteration.variable.name);
+                    live.extend(live_out.iter().copied());
+                    if live == body_out {
+                        break live;
+                    }
+                    body_out = live;
+                };
+                let mut live = live;
+                live.extend(self.uses(&iteration.start));
+                live.extend(self.uses(&iteration.stop));
+                if let Some(step) = &iteration.step {
+                    live.extend(self.uses(step));
+                }
+                live
+            }
+            // No variable is read after a return.
+            Statement::Return(_) => self.variables(NameCollector::of_statement(input).names),
+            Statement::Assert(_) | Statement::Console(_) | Statement::Expression(_) => {
+                let mut live = live_out.clone();
+                live.extend(self.variables(NameCollector::of_statement(input).names));
+                live
+            }
+        };
+        self.record(input.id(), live_in, live_out)
+    }
+}
+
+impl<'a> Default for LivenessAnalyzer<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for LivenessAnalyzer<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for LivenessAnalyzer<'a> {}
+
+impl<'a> ProgramVisitor<'a> for LivenessAnalyzer<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.scope = Some(input);
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.analyze_block(&input.block, IndexSet::new());
+        if let Some(finalize) = &input.finalize {
+            self.analyze_block(&finalize.block, IndexSet::new());
+        }
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Liveness Analysis pass traverses the AST and computes, for each statement and block, the variables that are
+//! live before and after it. A variable is live at a point if its current value may be read later.
+//!
+//! Leo's control flow is structured, so the analysis runs backward over the AST directly rather than over an explicit
+//! control-flow graph. Each block and statement plays the role of a basic block.
+//! Conditionals join the liveness of both branches, and loops propagate liveness along their back edge until a
+//! fixpoint is reached.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: u8) -> u8 {
+//!     let s: u8 = 0u8;
+//!     for i: u8 in 0u8..4u8 {
+//!         s = s + a;
+//!     }
+//!     return s;
+//! }
+//! ```
+//!
+//! The liveness analysis pass determines that `a` and `s` are live throughout the loop, even after its last
+//! statement, since the next iteration reads them again.
+
+pub mod liveness_analyzer;
+pub use liveness_analyzer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+
+impl<'a> Pass for LivenessAnalyzer<'a> {
+    type Input = &'a Ast;
+    type Output = Liveness;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut visitor = LivenessAnalyzer::new();
+        visitor.visit_program(ast.as_repr());
+
+        visitor.into_liveness()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Function, Node, NodeBuilder, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+    /// Returns the liveness of the given program, along with its `main` function.
+    fn analyze(function: &str) -> (Liveness, Function) {
+        let handler = Handler::default();
+        let source = format!("program test.aleo {{ {function} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let liveness = LivenessAnalyzer::do_pass(&ast);
+        let main = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        (liveness, main)
+    }
+
+    #[test]
+    fn test_variable_dead_after_last_use() {
+        create_session_if_not_set_then(|_| {
+            let (liveness, main) =
+                analyze("function main(a: u8) -> u8 { let b: u8 = a + 1u8; let c: u8 = b * 2u8; return c; }");
+            let first = main.block.statements[0].id();
+
+            assert!(!liveness.is_live_after(first, Symbol::intern("a")));
+            assert!(liveness.is_live_after(first, Symbol::intern("b")));
+            assert!(liveness.live_in(first).unwrap().contains(&Symbol::intern("a")));
+        })
+    }
+
+    #[test]
+    fn test_variable_live_across_loop_body() {
+        create_session_if_not_set_then(|_| {
+            let (liveness, main) = analyze(
+                "function main(a: u8) -> u8 { let s: u8 = 0u8; for i: u8 in 0u8..4u8 { s = s + a; } return s; }",
+            );
+            let Statement::Iteration(iteration) = &main.block.statements[1] else { panic!("expected a loop") };
+            let assignment = iteration.block.statements[0].id();
+
+            assert!(liveness.is_live_after(assignment, Symbol::intern("a")));
+            assert!(liveness.is_live_after(assignment, Symbol::intern("s")));
+            assert!(!liveness.is_live_after(main.block.statements[1].id(), Symbol::intern("a")));
+        })
+    }
+
+    #[test]
+    fn test_step_is_live_before_loop() {
+        create_session_if_not_set_then(|_| {
+            let (liveness, main) = analyze(
+                "function main(a: u8) -> u8 { let k: u8 = a; for i: u8 in 0u8..8u8 step k { a = a + i; } return a; }",
+            );
+            let definition = main.block.statements[0].id();
+
+            assert!(liveness.is_live_after(definition, Symbol::intern("k")));
+            assert!(liveness.live_in(main.block.statements[1].id()).unwrap().contains(&Symbol::intern("k")));
+        })
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, SingleExitTransform};
+
+use leo_ast::*;
+
+pub struct LoopEarlyReturnLowerer<'a> {
+    /// The transform used to rewrite the bodies containing a loop that may return early.
+    pub(crate) single_exit: SingleExitTransform<'a>,
+}
+
+impl<'a> LoopEarlyReturnLowerer<'a> {
+    /// Initializes a new `LoopEarlyReturnLowerer`.
+    pub fn new(node_builder: &'a NodeBuilder, assigner: &'a Assigner) -> Self {
+        let single_exit =
+            SingleExitTransform { returned_prefix: "$found", ..SingleExitTransform::new(node_builder, assigner) };
+        Self { single_exit }
+    }
+
+    /// Returns `true` if a return statement in `statements` is nested in a loop, or `in_loop` is set and
+    /// `statements` contains any return statement.
+    fn returns_in_loop(statements: &[Statement], in_loop: bool) -> bool {
+        statements.iter().any(|statement| match statement {
+            Statement::Return(_) => in_loop,
+            Statement::Block(block) => Self::returns_in_loop(&block.statements, in_loop),
+            Statement::Conditional(conditional) => {
+                Self::returns_in_loop(&conditional.then.statements, in_loop)
+                    || conditional
+                        .otherwise
+                        .as_ref()
+                        .map_or(false, |otherwise| Self::returns_in_loop(std::slice::from_ref(otherwise), in_loop))
+            }
+            Statement::Iteration(iteration) => Self::returns_in_loop(&iteration.block.statements, true),
+            _ => false,
+        })
+    }
+
+    /// Lowers the early returns in the loops of a body with the output type `output_type`.
+    /// Bodies without a loop that may return early, and bodies whose return statements pass arguments to a finalize
+    /// block, are left intact.
+    fn lower_body(&mut self, block: Block, output_type: &Type) -> Block {
+        if !Self::returns_in_loop(&block.statements, false)
+            || SingleExitTransform::has_finalize_arguments(&block.statements)
+        {
+            return block;
+        }
+        self.single_exit.transform_body(block.clone(), output_type).unwrap_or(block)
+    }
+}
+
+impl ExpressionReconstructor for LoopEarlyReturnLowerer<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for LoopEarlyReturnLowerer<'_> {}
+
+impl ProgramReconstructor for LoopEarlyReturnLowerer<'_> {
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        let block = self.lower_body(input.block, &input.output_type);
+        let finalize = input.finalize.map(|finalize| Finalize {
+            block: self.lower_body(finalize.block, &finalize.output_type),
+            identifier: finalize.identifier,
+            input: finalize.input,
+            output: finalize.output,
+            output_type: finalize.output_type,
+            span: finalize.span,
+            id: finalize.id,
+        });
+
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            const_predicates: input.const_predicates,
+            block,
+            finalize,
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
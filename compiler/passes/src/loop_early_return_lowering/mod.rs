@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Loop Early Return Lowering pass traverses the AST and rewrites functions that may return from within a loop,
+//! for backends that cannot exit a loop early.
+//! The loop runs all of its iterations, but the first return statement executed assigns the returned value to a
+//! result variable and sets a `found` flag, and the rest of the loop body, the remaining iterations, and the statements
+//! following the loop are guarded by the flag. The function returns the result at its end, so it returns the same
+//! value as the first return statement that would have been executed.
+//! The rewrite is performed by the single exit transformation, which is only applied to bodies with a loop that may
+//! return early. Functions whose return statements pass arguments to a finalize block are left intact.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function find(a: u8) -> u8 {
+//!     for i: u8 in 0u8..4u8 {
+//!         if i == a {
+//!             return i;
+//!         }
+//!     }
+//!     return 4u8;
+//! }
+//! ```
+//!
+//! The loop early return lowering pass produces the following code.
+//! ```leo
+//! function find(a: u8) -> u8 {
+//!     let $result$0: u8 = 0u8;
+//!     let $found$1: bool = false;
+//!     for i: u8 in 0u8..4u8 {
+//!         if !$found$1 {
+//!             if i == a {
+//!                 $result$0 = i;
+//!                 $found$1 = true;
+//!             }
+//!         }
+//!     }
+//!     if !$found$1 {
+//!         $result$0 = 4u8;
+//!         $found$1 = true;
+//!     }
+//!     return $result$0;
+//! }
+//! ```
+
+pub mod loop_early_return_lowerer;
+pub use loop_early_return_lowerer::*;
+
+use crate::{Assigner, Pass};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for LoopEarlyReturnLowerer<'a> {
+    type Input = (Ast, &'a NodeBuilder, &'a Assigner);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder, assigner): Self::Input) -> Self::Output {
+        let mut reconstructor = LoopEarlyReturnLowerer::new(node_builder, assigner);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and lowers a function with the given body, returning its statements before and after.
+    fn lower_body(body: &str) -> (Vec<Statement>, Vec<Statement>) {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                function find(a: u8) -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let statements = |ast: Ast| {
+            ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1.block.statements
+        };
+
+        let original = statements(ast.clone());
+        let ast = LoopEarlyReturnLowerer::do_pass((ast, &node_builder, &Assigner::default())).unwrap();
+        (original, statements(ast))
+    }
+
+    #[test]
+    fn test_lowers_return_in_loop() {
+        create_session_if_not_set_then(|_| {
+            let (_, statements) = lower_body("for i: u8 in 0u8..4u8 { if i == a { return i; } } return 4u8;");
+
+            assert_eq!(statements.len(), 5);
+            assert_eq!(statements[0].to_string(), "let $result$0: u8 = 0u8;");
+            assert_eq!(statements[1].to_string(), "let $found$1: bool = false;");
+            let Statement::Iteration(iteration) = &statements[2] else {
+                panic!("expected a loop, found `{}`", statements[2]);
+            };
+            // The whole loop body is skipped once a result is found, so the first match is kept.
+            assert_eq!(iteration.block.statements.len(), 1);
+            let Statement::Conditional(guard) = &iteration.block.statements[0] else {
+                panic!("expected a conditional, found `{}`", iteration.block.statements[0]);
+            };
+            assert!(
+                matches!(&guard.condition, Expression::Unary(condition) if condition.receiver.to_string() == "$found$1")
+            );
+            let Statement::Conditional(conditional) = &guard.then.statements[0] else {
+                panic!("expected a conditional, found `{}`", guard.then.statements[0]);
+            };
+            assert_eq!(conditional.then.statements[0].to_string(), "$result$0 = i;");
+            assert_eq!(conditional.then.statements[1].to_string(), "$found$1 = true;");
+            assert_eq!(statements[4].to_string(), "return $result$0");
+        })
+    }
+
+    #[test]
+    fn test_preserves_loop_without_return() {
+        create_session_if_not_set_then(|_| {
+            let (original, statements) = lower_body(
+                "let b: u8 = 0u8; for i: u8 in 0u8..4u8 { b = b + i; } if a == 0u8 { return 0u8; } return b;",
+            );
+
+            assert_eq!(statements, original);
+        })
+    }
+}
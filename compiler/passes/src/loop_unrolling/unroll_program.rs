@@ -68,12 +68,15 @@ impl ProgramReconstructor for Unroller<'_> {
 
         // Reconstruct the function block.
         let reconstructed_function = Function {
+            docs: function.docs,
             annotations: function.annotations,
             variant: function.variant,
             identifier: function.identifier,
+            type_params: function.type_params,
             input: function.input,
             output: function.output,
             output_type: function.output_type,
+            const_predicates: function.const_predicates,
             block,
             finalize,
             span: function.span,
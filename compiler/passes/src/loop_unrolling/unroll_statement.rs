@@ -187,6 +187,7 @@ impl StatementReconstructor for Unroller<'_> {
                 start_value: input.start_value.clone(),
                 stop_value: input.stop_value.clone(),
                 inclusive: false,
+                step: None,
                 block: input.block,
                 span: input.span,
                 id: input.id,
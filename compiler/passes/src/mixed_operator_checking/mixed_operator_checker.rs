@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError, StaticAnalyzerWarning};
+use leo_span::{span::BytePos, symbol::with_session_globals, Span};
+
+/// The precedence groups that are confusing to combine without parentheses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OperatorKind {
+    /// An arithmetic operator, e.g. `+`.
+    Arithmetic,
+    /// A bitwise operator, e.g. `&` or `<<`.
+    Bitwise,
+    /// A comparison operator, e.g. `==`.
+    Comparison,
+    /// Any other operator, e.g. `&&`.
+    Other,
+}
+
+impl OperatorKind {
+    /// Returns the kind of `operation`.
+    fn of(operation: BinaryOperation) -> Self {
+        match operation {
+            BinaryOperation::Add
+            | BinaryOperation::Sub
+            | BinaryOperation::Mul
+            | BinaryOperation::Div
+            | BinaryOperation::Rem
+            | BinaryOperation::Mod
+            | BinaryOperation::Pow => Self::Arithmetic,
+            BinaryOperation::BitwiseAnd
+            | BinaryOperation::BitwiseOr
+            | BinaryOperation::Xor
+            | BinaryOperation::Shl
+            | BinaryOperation::Shr => Self::Bitwise,
+            BinaryOperation::Eq
+            | BinaryOperation::Neq
+            | BinaryOperation::Lt
+            | BinaryOperation::Lte
+            | BinaryOperation::Gt
+            | BinaryOperation::Gte => Self::Comparison,
+            _ => Self::Other,
+        }
+    }
+
+    /// Returns `true` if combining operators of kinds `self` and `other` requires parentheses.
+    fn is_mixed_with(self, other: Self) -> bool {
+        matches!(
+            (self, other),
+            (Self::Bitwise, Self::Arithmetic | Self::Comparison) | (Self::Arithmetic | Self::Comparison, Self::Bitwise)
+        )
+    }
+}
+
+/// A compiler pass that checks that bitwise operators are not combined with arithmetic or comparison operators
+/// without parentheses. Since the AST does not record parentheses, they are recovered from the source text between
+/// the operands of each binary expression.
+pub struct MixedOperatorChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// Whether to report errors instead of warnings.
+    strict: bool,
+}
+
+impl<'a> MixedOperatorChecker<'a> {
+    /// Returns a new `MixedOperatorChecker`, which reports errors instead of warnings if `strict` is set.
+    pub fn new(handler: &'a Handler, strict: bool) -> Self {
+        Self { handler, strict }
+    }
+
+    /// Returns the source text between `lo` and `hi`, if it is available.
+    fn source_between(lo: BytePos, hi: BytePos) -> Option<String> {
+        if lo > hi {
+            return None;
+        }
+        with_session_globals(|s| s.source_map.contents_of_span(Span::new(lo, hi)))
+    }
+
+    /// Returns the span of the operator between the operands of `input`, if it is written infix, e.g. `a + b`,
+    /// rather than as a method call, e.g. `a.add(b)`.
+    fn infix_operator_span(input: &BinaryExpression) -> Option<Span> {
+        let (lo, hi) = (input.left.span().hi, input.right.span().lo);
+        let between = Self::source_between(lo, hi)?;
+        if between.contains('.') {
+            return None;
+        }
+        // Skip the whitespace and parentheses around the operator.
+        let is_padding = |c: char| c.is_whitespace() || c == '(' || c == ')';
+        let start = between.len() - between.trim_start_matches(is_padding).len();
+        let end = between.trim_end_matches(is_padding).len();
+        Some(Span::new(BytePos(lo.0 + start as u32), BytePos(lo.0 + end as u32)))
+    }
+
+    /// Reports `operand` if it is an infix binary expression whose operator is confusing to combine with the infix
+    /// operator of `input` without parentheses, and it is not parenthesized.
+    fn check_operand(&self, input: &BinaryExpression, operand: &Expression, is_left: bool) {
+        let Expression::Binary(operand) = operand else {
+            return;
+        };
+        if !OperatorKind::of(input.op).is_mixed_with(OperatorKind::of(operand.op)) {
+            return;
+        }
+        let (Some(operator_span), Some(_)) = (Self::infix_operator_span(input), Self::infix_operator_span(operand))
+        else {
+            return;
+        };
+        // The parentheses around an operand lie between it and the operator of `input`.
+        let between = if is_left {
+            Self::source_between(operand.span.hi, operator_span.lo)
+        } else {
+            Self::source_between(operator_span.hi, operand.span.lo)
+        };
+        if between.map_or(true, |between| between.contains(|c: char| c == '(' || c == ')')) {
+            return;
+        }
+        if self.strict {
+            self.handler.emit_err(StaticAnalyzerError::unparenthesized_mixed_operators(
+                input.op,
+                operand.op,
+                operator_span,
+            ));
+        } else {
+            self.handler.emit_warning(
+                StaticAnalyzerWarning::unparenthesized_mixed_operators(input.op, operand.op, operator_span).into(),
+            );
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for MixedOperatorChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_operand(input, &input.left, true);
+        self.check_operand(input, &input.right, false);
+        self.visit_expression(&input.left, additional);
+        self.visit_expression(&input.right, additional);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for MixedOperatorChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for MixedOperatorChecker<'a> {}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Mixed Operator Checking pass traverses the AST and reports bitwise operators that are combined with arithmetic
+//! or comparison operators without parentheses, since their relative precedence is easy to misremember.
+//! Each such combination is reported at the span of the outer operator, as a warning, or as an error in strict mode.
+//! Operators written as method calls, e.g. `a.add(b)`, make the grouping explicit and are not reported.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8, c: u8) -> u8 {
+//!     let d: u8 = a & b + c;
+//!     return (a & b) + c;
+//! }
+//! ```
+//!
+//! The mixed operator checking pass reports the `&` in the definition of `d`, which is parsed as `a & (b + c)`.
+
+pub mod mixed_operator_checker;
+pub use mixed_operator_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for MixedOperatorChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, bool);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, strict): Self::Input) -> Self::Output {
+        let mut visitor = MixedOperatorChecker::new(handler, strict);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a transition with inputs `a`, `b`, and `c` that returns `expression`,
+    /// returning the result of the pass and the number of warnings.
+    fn check_return(expression: &str, strict: bool) -> (Result<()>, usize) {
+        let (handler, _) = Handler::new_with_buf();
+        let source =
+            format!("program test.aleo {{ transition main(a: u8, b: u8, c: u8) -> u8 {{ return {expression}; }} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let result = MixedOperatorChecker::do_pass((&ast, &handler, strict));
+        (result, handler.warning_count())
+    }
+
+    #[test]
+    fn test_warns_on_unparenthesized_mix() {
+        create_session_if_not_set_then(|_| {
+            let (result, warnings) = check_return("a & b + c", false);
+            assert!(result.is_ok());
+            assert_eq!(warnings, 1);
+        })
+    }
+
+    #[test]
+    fn test_rejects_unparenthesized_mix_in_strict_mode() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_return("a & b + c", true).0.is_err());
+        })
+    }
+
+    #[test]
+    fn test_accepts_parenthesized_mix() {
+        create_session_if_not_set_then(|_| {
+            let (result, warnings) = check_return("(a & b) + c", true);
+            assert!(result.is_ok());
+            assert_eq!(warnings, 0);
+        })
+    }
+
+    #[test]
+    fn test_accepts_arithmetic_chain() {
+        create_session_if_not_set_then(|_| {
+            let (result, warnings) = check_return("a + b * c - a", false);
+            assert!(result.is_ok());
+            assert_eq!(warnings, 0);
+        })
+    }
+}
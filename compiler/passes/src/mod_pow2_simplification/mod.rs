@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Mod Pow2 Simplification pass traverses the AST and rewrites remainders by a power of two into bitwise ands,
+//! which are cheaper to constrain, e.g. `x % 8u32` into `x & 7u32`.
+//! Only unsigned operands are rewritten, since the remainder of a negative dividend is negative.
+//! The divisor must be an integer literal; its type determines the type of both operands.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u32, b: i32) -> (u32, i32) {
+//!     return (a % 8u32, b % 8i32);
+//! }
+//! ```
+//!
+//! The mod pow2 simplification pass produces the following code.
+//! ```leo
+//! transition main(a: u32, b: i32) -> (u32, i32) {
+//!     return (a & 7u32, b % 8i32);
+//! }
+//! ```
+
+pub mod mod_pow2_simplifier;
+pub use mod_pow2_simplifier::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for ModPow2Simplifier {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut reconstructor = ModPow2Simplifier::new();
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, NodeBuilder, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and simplifies a transition with inputs `a: u32` and `b: i32` that returns `expression`,
+    /// returning the simplified expression as a string.
+    fn simplify_return(expression: &str, output: &str) -> String {
+        let handler = Handler::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u32, b: i32) -> {output} {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = ModPow2Simplifier::do_pass(parse_program(&handler, &NodeBuilder::default(), &source)).unwrap();
+        let mut function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        match function.block.statements.pop() {
+            Some(Statement::Return(statement)) => match statement.expression {
+                expression @ Expression::Binary(_) => expression.to_string(),
+                expression => panic!("expected a binary expression, found `{expression}`"),
+            },
+            statement => panic!("expected a return statement, found `{statement:?}`"),
+        }
+    }
+
+    #[test]
+    fn test_rewrites_unsigned_power_of_two_remainder() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(simplify_return("a % 8u32", "u32"), "a & 7u32");
+        })
+    }
+
+    #[test]
+    fn test_keeps_other_remainders() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(simplify_return("a % 6u32", "u32"), "a % 6u32");
+            assert_eq!(simplify_return("b % 8i32", "i32"), "b % 8i32");
+        })
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+
+#[derive(Default)]
+pub struct ModPow2Simplifier;
+
+impl ModPow2Simplifier {
+    /// Initializes a new `ModPow2Simplifier`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Returns the mask `divisor - 1` if `divisor` is an unsigned integer literal that is a power of two.
+    fn mask(divisor: &Expression) -> Option<Expression> {
+        match divisor {
+            Expression::Literal(Literal::Integer(type_, value, span, id)) if !type_.is_signed() => {
+                let value = value.replace('_', "").parse::<u128>().ok()?;
+                value
+                    .is_power_of_two()
+                    .then(|| Expression::Literal(Literal::Integer(*type_, (value - 1).to_string(), *span, *id)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ExpressionReconstructor for ModPow2Simplifier {
+    type AdditionalOutput = ();
+
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let left = self.reconstruct_expression(*input.left).0;
+        let right = self.reconstruct_expression(*input.right).0;
+
+        // Since both operands have the same type, an unsigned divisor implies an unsigned dividend.
+        let (op, right) = match input.op {
+            BinaryOperation::Rem | BinaryOperation::RemWrapped | BinaryOperation::Mod => match Self::mask(&right) {
+                Some(mask) => (BinaryOperation::BitwiseAnd, mask),
+                None => (input.op, right),
+            },
+            op => (op, right),
+        };
+
+        (
+            Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for ModPow2Simplifier {}
+
+impl ProgramReconstructor for ModPow2Simplifier {}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Negative Index Resolution pass traverses the AST and resolves negative constant array indices, which count
+//! back from the end of the array: `a[-1i32]` is the last element of `a`.
+//! Each negative integer literal index is replaced by the `u32` index `length + index`, using the length of the
+//! array's type. Negative indices beyond the start of the array are reported at the span of the access.
+//! Indices that are not constant are left as is, so negative values remain an error for the index type checker.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: [u8; 4]) -> u8 {
+//!     return a[-1i32];
+//! }
+//! ```
+//!
+//! The negative index resolution pass produces the following code.
+//! ```leo
+//! transition main(a: [u8; 4]) -> u8 {
+//!     return a[3u32];
+//! }
+//! ```
+
+pub mod negative_index_resolver;
+pub use negative_index_resolver::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for NegativeIndexResolver<'a> {
+    type Input = (Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut reconstructor = NegativeIndexResolver::new(handler, type_table);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::{NodeBuilder, Statement};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and resolves a transition with input `a: [u8; 4]` returning `expression`,
+    /// returning the resolved expression as a string.
+    fn resolve_return(expression: &str) -> Result<String> {
+        let (handler, _) = Handler::new_with_buf();
+        let type_table = TypeTable::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: [u8; 4]) -> u8 {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+
+        let ast = NegativeIndexResolver::do_pass((ast, &handler, &type_table))?;
+        let mut function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        match function.block.statements.pop() {
+            Some(Statement::Return(statement)) => Ok(statement.expression.to_string()),
+            statement => panic!("expected a return statement, found `{statement:?}`"),
+        }
+    }
+
+    #[test]
+    fn test_resolves_negative_index() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(resolve_return("a[-1i32]").unwrap(), "a.3u32");
+            assert_eq!(resolve_return("a[-4i8]").unwrap(), "a.0u32");
+            assert_eq!(resolve_return("a[2u32]").unwrap(), "a.2u32");
+        })
+    }
+
+    #[test]
+    fn test_rejects_negative_index_out_of_range() {
+        create_session_if_not_set_then(|_| assert!(resolve_return("a[-5i32]").is_err()))
+    }
+}
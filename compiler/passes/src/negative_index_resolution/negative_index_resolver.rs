@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+pub struct NegativeIndexResolver<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+}
+
+impl<'a> NegativeIndexResolver<'a> {
+    /// Initializes a new `NegativeIndexResolver`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, type_table }
+    }
+
+    /// Returns the value of `index` if it is a negative integer literal.
+    fn negative_index(index: &Expression) -> Option<i128> {
+        match index {
+            Expression::Literal(Literal::Integer(_, value, ..)) if value.starts_with('-') => {
+                value.replace('_', "").parse().ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ExpressionReconstructor for NegativeIndexResolver<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        let array = self.reconstruct_expression(*input.array).0;
+        let mut index = self.reconstruct_expression(*input.index).0;
+
+        // A negative index counts back from the end of the array, e.g. `-1` is the last element.
+        if let (Some(value), Some(Type::Array(array_type))) =
+            (Self::negative_index(&index), self.type_table.get(&array.id()))
+        {
+            let length = array_type.length();
+            match (length as i128).checked_add(value) {
+                Some(position) if position >= 0 => {
+                    let (span, id) = (index.span(), index.id());
+                    self.type_table.insert(id, Type::Integer(IntegerType::U32));
+                    index = Expression::Literal(Literal::Integer(IntegerType::U32, position.to_string(), span, id));
+                }
+                _ => self.handler.emit_err(StaticAnalyzerError::array_index_out_of_range(value, length, input.span)),
+            }
+        }
+
+        (
+            Expression::Access(AccessExpression::Array(ArrayAccess {
+                array: Box::new(array),
+                index: Box::new(index),
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for NegativeIndexResolver<'_> {}
+
+impl ProgramReconstructor for NegativeIndexResolver<'_> {}
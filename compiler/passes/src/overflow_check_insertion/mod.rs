@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Overflow Check Insertion pass traverses the AST and makes the overflow checks of integer arithmetic explicit.
+//! Each integer `+`, `-`, and `*` is replaced by its wrapped counterpart, whose result is bound to a new variable and
+//! followed by an assertion that it did not overflow.
+//! The operands are bound to new variables first, so that they are evaluated only once.
+//! Operations that a simple range analysis proves to stay within the range of their type are left intact,
+//! as are operations whose operands make calls, and arithmetic in constant declarations and loop bounds.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u16 {
+//!     let c: u8 = a + b;
+//!     return (a as u16) + (c as u16);
+//! }
+//! ```
+//!
+//! The overflow check insertion pass produces the following code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u16 {
+//!     let $var$0: u8 = a.add_wrapped(b);
+//!     assert($var$0 >= a);
+//!     let c: u8 = $var$0;
+//!     return (a as u16) + (c as u16);
+//! }
+//! ```
+
+pub mod overflow_check_inserter;
+pub use overflow_check_inserter::*;
+
+use crate::{Assigner, Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for OverflowCheckInserter<'a> {
+    type Input = (Ast, &'a TypeTable, &'a NodeBuilder, &'a Assigner);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, type_table, node_builder, assigner): Self::Input) -> Self::Output {
+        let mut reconstructor = OverflowCheckInserter::new(type_table, node_builder, assigner);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::{BinaryOperation, Expression, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and instruments a transition with the given signature and body, returning its statements.
+    fn insert_checks(signature: &str, body: &str) -> Vec<Statement> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let type_table = TypeTable::default();
+        let source = format!(
+            "program test.aleo {{
+                transition {signature} {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+
+        let ast = OverflowCheckInserter::do_pass((ast, &type_table, &node_builder, &Assigner::default())).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1.block.statements
+    }
+
+    #[test]
+    fn test_checks_unchecked_addition() {
+        create_session_if_not_set_then(|_| {
+            let statements = insert_checks("main(a: u8, b: u8) -> u8", "return a + b;");
+
+            assert_eq!(statements.len(), 3);
+            let Statement::Definition(definition) = &statements[0] else {
+                panic!("expected a definition, found `{}`", statements[0]);
+            };
+            assert!(matches!(&definition.value, Expression::Binary(sum) if sum.op == BinaryOperation::AddWrapped));
+            assert!(matches!(&statements[1], Statement::Assert(_)));
+            let Statement::Return(statement) = &statements[2] else {
+                panic!("expected a return statement, found `{}`", statements[2]);
+            };
+            assert!(matches!(statement.expression, Expression::Identifier(_)));
+        })
+    }
+
+    #[test]
+    fn test_preserves_provably_safe_addition() {
+        create_session_if_not_set_then(|_| {
+            let statements = insert_checks("main(a: u8, b: u8) -> u16", "return (a as u16) + (b as u16);");
+
+            assert_eq!(statements.len(), 1);
+            let Statement::Return(statement) = &statements[0] else {
+                panic!("expected a return statement, found `{}`", statements[0]);
+            };
+            assert!(matches!(&statement.expression, Expression::Binary(sum) if sum.op == BinaryOperation::Add));
+        })
+    }
+}
@@ -0,0 +1,309 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, NameCollector, RangeAnalyzer, TypeTable};
+
+use leo_ast::*;
+use leo_span::Span;
+
+pub struct OverflowCheckInserter<'a> {
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+    /// The statements that must be inserted before the statement currently being reconstructed.
+    pub(crate) statements: Vec<Statement>,
+}
+
+impl<'a> OverflowCheckInserter<'a> {
+    /// Initializes a new `OverflowCheckInserter`.
+    pub fn new(type_table: &'a TypeTable, node_builder: &'a NodeBuilder, assigner: &'a Assigner) -> Self {
+        Self { type_table, node_builder, assigner, statements: Vec::new() }
+    }
+
+    /// Returns the minimum value of a signed integer type, as a literal string.
+    fn min_value(integer_type: &IntegerType) -> String {
+        match integer_type {
+            IntegerType::I8 => i8::MIN.to_string(),
+            IntegerType::I16 => i16::MIN.to_string(),
+            IntegerType::I32 => i32::MIN.to_string(),
+            IntegerType::I64 => i64::MIN.to_string(),
+            _ => i128::MIN.to_string(),
+        }
+    }
+
+    /// Returns the integer type of the node `id`, if any.
+    fn integer_type(&self, id: &NodeID) -> Option<IntegerType> {
+        match self.type_table.get(id) {
+            Some(Type::Integer(integer_type)) => Some(integer_type),
+            _ => None,
+        }
+    }
+
+    /// Returns a new node ID, recording `type_` as the type of the node.
+    fn next_id(&self, type_: Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        id
+    }
+
+    /// Returns a copy of a variable or literal with a fresh node ID.
+    fn copy(&self, atom: &Expression, integer_type: IntegerType) -> Expression {
+        let id = self.next_id(Type::Integer(integer_type));
+        match atom {
+            Expression::Identifier(identifier) => {
+                Expression::Identifier(Identifier { name: identifier.name, span: identifier.span, id })
+            }
+            Expression::Literal(Literal::Integer(_, string, span, _)) => {
+                Expression::Literal(Literal::Integer(integer_type, string.clone(), *span, id))
+            }
+            _ => unreachable!("only variables and literals are copied"),
+        }
+    }
+
+    /// Returns the integer literal `value` of type `integer_type`.
+    fn literal(&self, value: String, integer_type: IntegerType, span: Span) -> Expression {
+        Expression::Literal(Literal::Integer(integer_type, value, span, self.next_id(Type::Integer(integer_type))))
+    }
+
+    /// Returns the binary expression `left op right` of type `type_`.
+    fn binary(&self, left: Expression, op: BinaryOperation, right: Expression, type_: Type, span: Span) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span,
+            id: self.next_id(type_),
+        })
+    }
+
+    /// Binds `value` to a new variable of type `integer_type`, returning the variable.
+    /// Variables and literals are returned as is, since evaluating them twice has no cost.
+    fn bind(&mut self, value: Expression, integer_type: IntegerType, span: Span) -> Expression {
+        if matches!(value, Expression::Identifier(_) | Expression::Literal(_)) {
+            return value;
+        }
+        let type_ = Type::Integer(integer_type);
+        let name = self.assigner.unique_symbol("$var", "$");
+        self.statements.push(Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(Identifier { name, span, id: self.next_id(type_.clone()) }),
+            type_: type_.clone(),
+            value,
+            span,
+            id: self.node_builder.next_id(),
+        }));
+        Expression::Identifier(Identifier { name, span, id: self.next_id(type_) })
+    }
+
+    /// Returns the condition under which `result`, the wrapped result of `left op right`, did not overflow.
+    fn no_overflow(
+        &self,
+        op: BinaryOperation,
+        left: &Expression,
+        right: &Expression,
+        result: &Expression,
+        integer_type: IntegerType,
+        span: Span,
+    ) -> Expression {
+        let type_ = Type::Integer(integer_type);
+        let zero = || self.literal("0".to_string(), integer_type, span);
+        let compare = |left: Expression, op, right: Expression| self.binary(left, op, right, Type::Boolean, span);
+        let non_negative = |atom: &Expression| compare(self.copy(atom, integer_type), BinaryOperation::Gte, zero());
+
+        match (op, integer_type.is_signed()) {
+            // An unsigned sum overflows if and only if it wraps below its left operand.
+            (BinaryOperation::Add, false) => {
+                compare(self.copy(result, integer_type), BinaryOperation::Gte, self.copy(left, integer_type))
+            }
+            // An unsigned difference overflows if and only if the right operand exceeds the left one.
+            (BinaryOperation::Sub, false) => {
+                compare(self.copy(left, integer_type), BinaryOperation::Gte, self.copy(right, integer_type))
+            }
+            // A signed sum overflows if and only if the operands have the same sign and the result does not.
+            // A signed difference overflows if and only if the operands have different signs and the result does not
+            // have the sign of the left one.
+            (BinaryOperation::Add | BinaryOperation::Sub, true) => {
+                let same_sign = if op == BinaryOperation::Add { BinaryOperation::Neq } else { BinaryOperation::Eq };
+                compare(
+                    compare(non_negative(left), same_sign, non_negative(right)),
+                    BinaryOperation::Or,
+                    compare(non_negative(result), BinaryOperation::Eq, non_negative(left)),
+                )
+            }
+            // A product overflows if and only if dividing it by a non-zero left operand does not yield the right one.
+            // The divisor is replaced by one when the left operand is zero, since the division is evaluated regardless.
+            (_, is_signed) => {
+                let is_zero = || compare(self.copy(left, integer_type), BinaryOperation::Eq, zero());
+                let divisor = Expression::Ternary(TernaryExpression {
+                    condition: Box::new(is_zero()),
+                    if_true: Box::new(self.literal("1".to_string(), integer_type, span)),
+                    if_false: Box::new(self.copy(left, integer_type)),
+                    span,
+                    id: self.next_id(type_.clone()),
+                });
+                let quotient =
+                    self.binary(self.copy(result, integer_type), BinaryOperation::DivWrapped, divisor, type_, span);
+                let mut exact = compare(quotient, BinaryOperation::Eq, self.copy(right, integer_type));
+                // The product of `-1` and the minimum value is the only signed overflow the division does not detect.
+                if is_signed {
+                    let is_minus_one = compare(
+                        self.copy(left, integer_type),
+                        BinaryOperation::Eq,
+                        self.literal("-1".to_string(), integer_type, span),
+                    );
+                    let is_min = compare(
+                        self.copy(right, integer_type),
+                        BinaryOperation::Eq,
+                        self.literal(Self::min_value(&integer_type), integer_type, span),
+                    );
+                    let is_min_negation = Expression::Unary(UnaryExpression {
+                        receiver: Box::new(compare(is_minus_one, BinaryOperation::And, is_min)),
+                        op: UnaryOperation::Not,
+                        span,
+                        id: self.next_id(Type::Boolean),
+                    });
+                    exact = compare(exact, BinaryOperation::And, is_min_negation);
+                }
+                compare(is_zero(), BinaryOperation::Or, exact)
+            }
+        }
+    }
+}
+
+impl ExpressionReconstructor for OverflowCheckInserter<'_> {
+    type AdditionalOutput = ();
+
+    /// Replaces integer `left + right`, `left - right`, and `left * right` with the wrapped operation, binding the result
+    /// to a new variable and asserting that it did not overflow, unless the operation is provably safe.
+    /// Operations whose operands make calls are left intact, so that calls are not reordered.
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let has_calls =
+            [&*input.left, &*input.right].into_iter().any(|operand| NameCollector::of_expression(operand).has_calls);
+        let left = self.reconstruct_expression(*input.left).0;
+        let right = self.reconstruct_expression(*input.right).0;
+
+        let wrapped = match input.op {
+            BinaryOperation::Add => Some(BinaryOperation::AddWrapped),
+            BinaryOperation::Sub => Some(BinaryOperation::SubWrapped),
+            BinaryOperation::Mul => Some(BinaryOperation::MulWrapped),
+            _ => None,
+        };
+        match (wrapped, self.integer_type(&input.id)) {
+            (Some(wrapped), Some(integer_type))
+                if !has_calls
+                    && !RangeAnalyzer::new(self.type_table).is_safe(input.op, &left, &right, &integer_type) =>
+            {
+                let left = self.bind(left, integer_type, input.span);
+                let right = self.bind(right, integer_type, input.span);
+                let operation = self.binary(
+                    self.copy(&left, integer_type),
+                    wrapped,
+                    self.copy(&right, integer_type),
+                    Type::Integer(integer_type),
+                    input.span,
+                );
+                let result = self.bind(operation, integer_type, input.span);
+                let condition = self.no_overflow(input.op, &left, &right, &result, integer_type, input.span);
+                self.statements.push(Statement::Assert(AssertStatement {
+                    variant: AssertVariant::Assert(condition),
+                    span: input.span,
+                    id: self.node_builder.next_id(),
+                }));
+                (result, Default::default())
+            }
+            _ => (
+                Expression::Binary(BinaryExpression {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    op: input.op,
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            ),
+        }
+    }
+}
+
+impl StatementReconstructor for OverflowCheckInserter<'_> {
+    /// Inserts the statements introduced by each statement of the block before it.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        let outer = std::mem::take(&mut self.statements);
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let statement = self.reconstruct_statement(statement).0;
+            statements.append(&mut self.statements);
+            statements.push(statement);
+        }
+        self.statements = outer;
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    /// Reconstructs the conditional statement, keeping the checks of an `else if` condition inside the `else` branch.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let condition = self.reconstruct_expression(input.condition).0;
+        let then = self.reconstruct_block(input.then).0;
+        let otherwise = input.otherwise.map(|otherwise| match *otherwise {
+            Statement::Block(block) => Box::new(Statement::Block(self.reconstruct_block(block).0)),
+            otherwise => {
+                let span = otherwise.span();
+                let mut block = self
+                    .reconstruct_block(Block { statements: vec![otherwise], span, id: self.node_builder.next_id() })
+                    .0;
+                match block.statements.len() {
+                    1 => Box::new(block.statements.pop().unwrap()),
+                    _ => Box::new(Statement::Block(block)),
+                }
+            }
+        });
+
+        (
+            Statement::Conditional(ConditionalStatement { condition, then, otherwise, span: input.span, id: input.id }),
+            Default::default(),
+        )
+    }
+
+    /// Constant declarations are left intact, since their values must remain constant expressions.
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Const(input), Default::default())
+    }
+
+    /// The bounds of the loop are left intact, since they must remain constant expressions.
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Iteration(Box::new(IterationStatement {
+                variable: input.variable,
+                type_: input.type_,
+                start: input.start,
+                start_value: input.start_value,
+                stop: input.stop,
+                stop_value: input.stop_value,
+                block: self.reconstruct_block(input.block).0,
+                inclusive: input.inclusive,
+                step: input.step,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for OverflowCheckInserter<'_> {}
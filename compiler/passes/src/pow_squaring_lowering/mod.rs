@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Pow Squaring Lowering pass traverses the AST and lowers powers with a constant exponent above a threshold into
+//! chains of multiplications, computed by repeated squaring.
+//! The bits of the exponent are scanned from the most significant one: each further bit squares the running power,
+//! and each set bit then multiplies it by the base, so that `x ** e` takes about `log2(e)` squarings and at most as
+//! many multiplications by `x`.
+//! Each intermediate power is bound to a new variable, so that it is computed once and shared by the next step.
+//! A base other than a variable or a literal is bound to a new variable first.
+//! Wrapping powers are lowered to wrapping multiplications. Powers at or below the threshold are left as is, e.g. for
+//! a later pass to unroll naively, as are powers in constants and in `where` clauses.
+//! Since the intermediate powers never exceed the result in magnitude, a chain overflows if and only if the power does.
+//! This pass runs after type checking, and keeps the type table up to date.
+//!
+//! Consider the following Leo code, lowered with a threshold of 4.
+//! ```leo
+//! transition main(x: u32) -> u32 {
+//!     return x ** 10u8;
+//! }
+//! ```
+//!
+//! The pow squaring lowering pass produces the following code.
+//! ```leo
+//! transition main(x: u32) -> u32 {
+//!     let $var$0: u32 = x * x;
+//!     let $var$1: u32 = $var$0 * $var$0;
+//!     let $var$2: u32 = $var$1 * x;
+//!     return $var$2 * $var$2;
+//! }
+//! ```
+
+pub mod pow_squaring_lowerer;
+pub use pow_squaring_lowerer::*;
+
+use crate::{Assigner, Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for PowSquaringLowerer<'a> {
+    type Input = (Ast, &'a TypeTable, &'a NodeBuilder, &'a Assigner, u128);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, type_table, node_builder, assigner, threshold): Self::Input) -> Self::Output {
+        let mut reconstructor = PowSquaringLowerer::new(type_table, node_builder, assigner, threshold);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::Statement;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and lowers a transition returning `x ** exponent` with a threshold of 4,
+    /// returning its statements as strings.
+    fn lower(exponent: &str) -> Vec<String> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let type_table = TypeTable::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(x: u32) -> u32 {{
+                    return x ** {exponent};
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+
+        let ast = PowSquaringLowerer::do_pass((ast, &type_table, &node_builder, &Assigner::default(), 4)).unwrap();
+        let function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        function.block.statements.iter().map(Statement::to_string).collect()
+    }
+
+    /// Returns the number of multiplications in the statements.
+    fn multiplications(statements: &[String]) -> usize {
+        statements.iter().map(|statement| statement.matches(" * ").count()).sum()
+    }
+
+    #[test]
+    fn test_lowers_power_of_two_exponent_to_squarings() {
+        create_session_if_not_set_then(|_| {
+            let statements = lower("16u8");
+            assert_eq!(statements, vec![
+                "let $var$0: u32 = x * x;",
+                "let $var$1: u32 = $var$0 * $var$0;",
+                "let $var$2: u32 = $var$1 * $var$1;",
+                "return $var$2 * $var$2",
+            ]);
+            assert_eq!(multiplications(&statements), 4);
+        })
+    }
+
+    #[test]
+    fn test_lowers_exponent_to_squarings_and_multiplications() {
+        create_session_if_not_set_then(|_| {
+            let statements = lower("10u8");
+            assert_eq!(statements[2], "let $var$2: u32 = $var$1 * x;");
+            assert_eq!(multiplications(&statements), 4);
+        })
+    }
+
+    #[test]
+    fn test_keeps_exponent_within_threshold() {
+        create_session_if_not_set_then(|_| assert_eq!(lower("3u8"), vec!["return x ** 3u8"]))
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, TypeTable};
+
+use leo_ast::*;
+use leo_span::Span;
+
+pub struct PowSquaringLowerer<'a> {
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+    /// The largest exponent that is left as is, e.g. for a later pass to unroll naively.
+    pub(crate) threshold: u128,
+    /// The statements that must be inserted before the statement currently being reconstructed.
+    pub(crate) statements: Vec<Statement>,
+}
+
+impl<'a> PowSquaringLowerer<'a> {
+    /// Initializes a new `PowSquaringLowerer`.
+    pub fn new(
+        type_table: &'a TypeTable,
+        node_builder: &'a NodeBuilder,
+        assigner: &'a Assigner,
+        threshold: u128,
+    ) -> Self {
+        Self { type_table, node_builder, assigner, threshold, statements: Vec::new() }
+    }
+
+    /// Returns a new node ID of the given type.
+    fn next_id(&self, type_: Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        id
+    }
+
+    /// Returns the exponent of `x ** e` if it is an integer literal.
+    fn constant_exponent(exponent: &Expression) -> Option<u128> {
+        match exponent {
+            Expression::Literal(Literal::Integer(_, value, ..)) => value.replace('_', "").parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Binds `value` of type `type_` to a new variable, returning the variable.
+    fn bind(&mut self, value: Expression, type_: &Type) -> Expression {
+        let name = self.assigner.unique_symbol("$var", "$");
+        let span = value.span();
+        self.statements.push(Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(Identifier { name, span, id: self.next_id(type_.clone()) }),
+            type_: type_.clone(),
+            value,
+            span,
+            id: self.node_builder.next_id(),
+        }));
+        Expression::Identifier(Identifier { name, span, id: self.next_id(type_.clone()) })
+    }
+
+    /// Returns a copy of a variable or literal with a fresh node ID.
+    fn copy(&self, operand: &Expression, type_: &Type) -> Expression {
+        let mut copy = operand.clone();
+        copy.set_id(self.next_id(type_.clone()));
+        copy
+    }
+
+    /// Lowers `base ** exponent` into a square-and-multiply chain, scanning the bits of the exponent from the most
+    /// significant one: each further bit squares the running power, and each set bit then multiplies it by the base.
+    /// Every intermediate power is bound to a new variable, and the last product takes the place of the power.
+    fn lower(
+        &mut self,
+        base: Expression,
+        exponent: u128,
+        op: BinaryOperation,
+        type_: Type,
+        span: Span,
+        id: NodeID,
+    ) -> Expression {
+        let base = match base {
+            base @ (Expression::Identifier(_) | Expression::Literal(_)) => base,
+            base => self.bind(base, &type_),
+        };
+
+        // `true` for a squaring, `false` for a multiplication by the base.
+        let mut steps = Vec::new();
+        for bit in (0..exponent.ilog2()).rev() {
+            steps.push(true);
+            if (exponent >> bit) & 1 == 1 {
+                steps.push(false);
+            }
+        }
+
+        let mut power = self.copy(&base, &type_);
+        for (index, square) in steps.iter().enumerate() {
+            let right = match square {
+                true => self.copy(&power, &type_),
+                false => self.copy(&base, &type_),
+            };
+            let product = BinaryExpression { left: Box::new(power), right: Box::new(right), op, span, id };
+            if index == steps.len() - 1 {
+                return Expression::Binary(product);
+            }
+            power =
+                self.bind(Expression::Binary(BinaryExpression { id: self.next_id(type_.clone()), ..product }), &type_);
+        }
+        unreachable!("An exponent of at least 2 takes at least one step.")
+    }
+}
+
+impl ExpressionReconstructor for PowSquaringLowerer<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let left = self.reconstruct_expression(*input.left).0;
+        let right = self.reconstruct_expression(*input.right).0;
+
+        let op = match input.op {
+            BinaryOperation::Pow => Some(BinaryOperation::Mul),
+            BinaryOperation::PowWrapping => Some(BinaryOperation::MulWrapping),
+            _ => None,
+        };
+        match (op, Self::constant_exponent(&right), self.type_table.get(&input.id)) {
+            (Some(op), Some(exponent), Some(type_)) if exponent >= 2 && exponent > self.threshold => {
+                (self.lower(left, exponent, op, type_, input.span, input.id), Default::default())
+            }
+            _ => (
+                Expression::Binary(BinaryExpression {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    op: input.op,
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            ),
+        }
+    }
+}
+
+impl StatementReconstructor for PowSquaringLowerer<'_> {
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        let outer = std::mem::take(&mut self.statements);
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let statement = self.reconstruct_statement(statement).0;
+            statements.append(&mut self.statements);
+            statements.push(statement);
+        }
+        self.statements = outer;
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    /// Reconstructs the conditional statement, keeping the definitions of an `else if` condition inside the `else`
+    /// branch.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let condition = self.reconstruct_expression(input.condition).0;
+        let then = self.reconstruct_block(input.then).0;
+        let otherwise = input.otherwise.map(|otherwise| match *otherwise {
+            Statement::Block(block) => Box::new(Statement::Block(self.reconstruct_block(block).0)),
+            otherwise => {
+                let span = otherwise.span();
+                let mut block = self
+                    .reconstruct_block(Block { statements: vec![otherwise], span, id: self.node_builder.next_id() })
+                    .0;
+                match block.statements.len() {
+                    1 => Box::new(block.statements.pop().unwrap()),
+                    _ => Box::new(Statement::Block(block)),
+                }
+            }
+        });
+
+        (
+            Statement::Conditional(ConditionalStatement { condition, then, otherwise, span: input.span, id: input.id }),
+            Default::default(),
+        )
+    }
+
+    // Constants must stay compile-time values, so their powers are left as is.
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Const(input), Default::default())
+    }
+}
+
+impl ProgramReconstructor for PowSquaringLowerer<'_> {
+    // Predicates are not in a block, so there is nowhere to bind the intermediate powers.
+    fn reconstruct_function_predicate(&mut self, input: Expression) -> Expression {
+        input
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Predicate Checking pass traverses the AST and evaluates the predicates in the `where` clause of each function
+//! at compile time, reporting predicates that evaluate to false or that cannot be evaluated at the span of the
+//! predicate.
+//! Predicates may use literals, program-scope constants, integer arithmetic, comparisons, and boolean operators.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! const N: u32 = 0u32;
+//!
+//! transition main(a: u8) -> u8 where N > 0u32 {
+//!     return a;
+//! }
+//! ```
+//!
+//! The predicate checking pass reports an error, since `N > 0u32` is not satisfied.
+
+pub mod predicate_checker;
+pub use predicate_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for PredicateChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = PredicateChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a program in which `N` is 4 and a transition has the given `where` clause.
+    fn check_predicates(predicates: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                const N: u32 = 4u32;
+                transition main(a: u8) -> u8 where {predicates} {{
+                    return a;
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        PredicateChecker::do_pass((&ast, &handler))
+    }
+
+    #[test]
+    fn test_accepts_satisfied_predicates() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_predicates("N > 0u32, N * 2u32 == 8u32").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_violated_predicate() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_predicates("N < 4u32").is_err());
+        })
+    }
+
+    #[test]
+    fn test_rejects_non_constant_predicate() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_predicates("a > 0u8").is_err());
+        })
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A value that a predicate or one of its subexpressions evaluates to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Constant {
+    /// A boolean value.
+    Boolean(bool),
+    /// An integer value, of any integer type that fits in an `i128`.
+    Integer(i128),
+}
+
+/// A compiler pass that evaluates the predicates in the `where` clause of each function at compile time,
+/// and reports those that are not satisfied or cannot be evaluated.
+pub struct PredicateChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping from the names of the program-scope constants to their values.
+    globals: IndexMap<Symbol, &'a Expression>,
+    /// The constants being evaluated, used to detect cyclic definitions.
+    evaluating: IndexSet<Symbol>,
+}
+
+impl<'a> PredicateChecker<'a> {
+    /// Returns a new `PredicateChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, globals: IndexMap::new(), evaluating: IndexSet::new() }
+    }
+
+    /// Evaluates `expression`, returning `None` if it is not a constant.
+    /// Integer arithmetic that overflows an `i128` is not evaluated.
+    fn evaluate(&mut self, expression: &'a Expression) -> Option<Constant> {
+        match expression {
+            Expression::Literal(Literal::Boolean(value, ..)) => Some(Constant::Boolean(*value)),
+            Expression::Literal(Literal::Integer(_, value, ..)) => {
+                value.replace('_', "").parse().ok().map(Constant::Integer)
+            }
+            Expression::Identifier(identifier) => {
+                let value = *self.globals.get(&identifier.name)?;
+                if !self.evaluating.insert(identifier.name) {
+                    return None;
+                }
+                let constant = self.evaluate(value);
+                self.evaluating.swap_remove(&identifier.name);
+                constant
+            }
+            Expression::Unary(unary) => match (unary.op, self.evaluate(&unary.receiver)?) {
+                (UnaryOperation::Not, Constant::Boolean(value)) => Some(Constant::Boolean(!value)),
+                (UnaryOperation::Negate, Constant::Integer(value)) => value.checked_neg().map(Constant::Integer),
+                _ => None,
+            },
+            Expression::Binary(binary) => {
+                let left = self.evaluate(&binary.left)?;
+                let right = self.evaluate(&binary.right)?;
+                Self::evaluate_binary(binary.op, left, right)
+            }
+            _ => None,
+        }
+    }
+
+    /// Evaluates the binary operation `op` on two constants.
+    fn evaluate_binary(op: BinaryOperation, left: Constant, right: Constant) -> Option<Constant> {
+        use Constant::*;
+
+        match (op, left, right) {
+            (BinaryOperation::And, Boolean(left), Boolean(right)) => Some(Boolean(left && right)),
+            (BinaryOperation::Or, Boolean(left), Boolean(right)) => Some(Boolean(left || right)),
+            (BinaryOperation::Eq, left, right) => Some(Boolean(left == right)),
+            (BinaryOperation::Neq, left, right) => Some(Boolean(left != right)),
+            (BinaryOperation::Lt, Integer(left), Integer(right)) => Some(Boolean(left < right)),
+            (BinaryOperation::Lte, Integer(left), Integer(right)) => Some(Boolean(left <= right)),
+            (BinaryOperation::Gt, Integer(left), Integer(right)) => Some(Boolean(left > right)),
+            (BinaryOperation::Gte, Integer(left), Integer(right)) => Some(Boolean(left >= right)),
+            (BinaryOperation::Add, Integer(left), Integer(right)) => left.checked_add(right).map(Integer),
+            (BinaryOperation::Sub, Integer(left), Integer(right)) => left.checked_sub(right).map(Integer),
+            (BinaryOperation::Mul, Integer(left), Integer(right)) => left.checked_mul(right).map(Integer),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for PredicateChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for PredicateChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for PredicateChecker<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.globals =
+            input.consts.iter().map(|(_, declaration)| (declaration.place.name, &declaration.value)).collect();
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        input.const_predicates.iter().for_each(|predicate| self.visit_function_predicate(predicate));
+    }
+
+    fn visit_function_predicate(&mut self, input: &'a Expression) {
+        match self.evaluate(input) {
+            Some(Constant::Boolean(true)) => {}
+            Some(Constant::Boolean(false)) => {
+                self.handler.emit_err(StaticAnalyzerError::predicate_not_satisfied(input, input.span()))
+            }
+            _ => self.handler.emit_err(StaticAnalyzerError::predicate_not_evaluable(input, input.span())),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Purity Analysis pass traverses the AST and determines which functions are pure, i.e. free of side effects.
+//! Pure functions may be safely deduplicated or reordered by later passes.
+//!
+//! A function is impure if it directly performs a side effect, or if it calls an impure function.
+//! Since calls may be recursive, the analysis iterates over the call graph until a fixpoint is reached.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function square(a: u8) -> u8 {
+//!     return a * a;
+//! }
+//!
+//! function checked_square(a: u8) -> u8 {
+//!     assert(a < 16u8);
+//!     return square(a);
+//! }
+//!
+//! transition main(a: u8) -> u8 {
+//!     return checked_square(a);
+//! }
+//! ```
+//!
+//! The purity analysis pass determines that `square` is pure, and that `checked_square` and `main` are impure.
+
+pub mod purity_analyzer;
+pub use purity_analyzer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+impl<'a> Pass for PurityAnalyzer<'a> {
+    type Input = &'a Ast;
+    type Output = IndexMap<Symbol, bool>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut visitor = PurityAnalyzer::new();
+        visitor.visit_program(ast.as_repr());
+
+        visitor.purities()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns whether or not each function in the given program scope is pure.
+    fn purities(functions: &str) -> IndexMap<String, bool> {
+        let handler = Handler::default();
+        let source = format!("program test.aleo {{ {functions} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        PurityAnalyzer::do_pass(&ast).into_iter().map(|(function, is_pure)| (function.to_string(), is_pure)).collect()
+    }
+
+    #[test]
+    fn test_pure_leaf_function() {
+        create_session_if_not_set_then(|_| {
+            let purities = purities("function square(a: u8) -> u8 { return a * a; }");
+
+            assert!(purities["square"]);
+        })
+    }
+
+    #[test]
+    fn test_caller_of_impure_function_is_impure() {
+        create_session_if_not_set_then(|_| {
+            let purities = purities(
+                "function check(a: u8) -> u8 { assert(a < 16u8); return a; }
+                 function caller(a: u8) -> u8 { return check(a) + 1u8; }",
+            );
+
+            assert!(!purities["check"]);
+            assert!(!purities["caller"]);
+        })
+    }
+
+    #[test]
+    fn test_mutually_recursive_pure_functions() {
+        create_session_if_not_set_then(|_| {
+            let purities = purities(
+                "function even(a: u8) -> bool { return a == 0u8 ? true : odd(a - 1u8); }
+                 function odd(a: u8) -> bool { return a == 0u8 ? false : even(a - 1u8); }",
+            );
+
+            assert!(purities["even"]);
+            assert!(purities["odd"]);
+        })
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::{sym, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A compiler pass that determines which functions are pure, i.e. free of side effects.
+/// A function is impure if its body contains an assertion, a console statement, a mapping operation,
+/// a call to `ChaCha`, a call to an external program, or a finalize block, or if it calls an impure function.
+pub struct PurityAnalyzer<'a> {
+    /// For each function, whether or not its body is impure, ignoring the functions it calls.
+    pub(crate) is_locally_impure: IndexMap<Symbol, bool>,
+    /// For each function, the functions it calls.
+    pub(crate) callees: IndexMap<Symbol, IndexSet<Symbol>>,
+    /// The function that we are currently traversing.
+    pub(crate) function: Option<&'a Function>,
+}
+
+impl<'a> PurityAnalyzer<'a> {
+    /// Returns a new `PurityAnalyzer`.
+    pub fn new() -> Self {
+        Self { is_locally_impure: Default::default(), callees: Default::default(), function: None }
+    }
+
+    /// Marks the current function as impure.
+    fn mark_impure(&mut self) {
+        if let Some(function) = self.function {
+            self.is_locally_impure.insert(function.name(), true);
+        }
+    }
+
+    /// Returns a mapping from each function to whether or not it is pure.
+    /// Functions are initially assumed to be pure, and marked impure until a fixpoint is reached.
+    /// Consequently, a group of mutually recursive functions is pure if and only if all of its members are pure.
+    pub fn purities(&self) -> IndexMap<Symbol, bool> {
+        let mut is_impure = self.is_locally_impure.clone();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (function, callees) in self.callees.iter() {
+                if !is_impure[function] && callees.iter().any(|callee| is_impure.get(callee).copied().unwrap_or(false))
+                {
+                    is_impure.insert(*function, true);
+                    changed = true;
+                }
+            }
+        }
+        is_impure.into_iter().map(|(function, is_impure)| (function, !is_impure)).collect()
+    }
+}
+
+impl Default for PurityAnalyzer<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for PurityAnalyzer<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                // Mapping operations and random number generation are side effects.
+                if let Type::Identifier(Identifier { name: sym::Mapping | sym::ChaCha, .. }) = function.ty {
+                    self.mark_impure();
+                }
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match (&input.external, &*input.function) {
+            // Record calls to functions in the same program.
+            (None, Expression::Identifier(callee)) => {
+                if let Some(function) = self.function {
+                    self.callees.entry(function.name()).or_default().insert(callee.name);
+                }
+            }
+            // Conservatively assume that external calls are impure.
+            _ => self.mark_impure(),
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for PurityAnalyzer<'a> {
+    fn visit_assert(&mut self, _input: &'a AssertStatement) {
+        self.mark_impure();
+    }
+
+    fn visit_console(&mut self, _input: &'a ConsoleStatement) {
+        self.mark_impure();
+    }
+
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        // Invoking a finalize block is a side effect.
+        if input.finalize_arguments.is_some() {
+            self.mark_impure();
+        }
+        self.visit_expression(&input.expression, &Default::default());
+        if let Some(arguments) = &input.finalize_arguments {
+            arguments.iter().for_each(|argument| self.visit_expression(argument, &Default::default()));
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for PurityAnalyzer<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        self.function = Some(input);
+        self.is_locally_impure.insert(input.name(), input.finalize.is_some());
+        self.callees.entry(input.name()).or_default();
+
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_block(&finalize.block);
+        }
+
+        self.function = None;
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Redundant Cast Collapsing pass traverses the AST and collapses sequences of casts, which other passes may
+//! introduce, into a single cast where this does not change any result.
+//! A cast of a cast to the same type, e.g. `(x as u16) as u16`, becomes a single cast `x as u16`.
+//! A chain of widening integer casts, e.g. `((x as u16) as u32) as u64` where `x: u8`, becomes a cast to the final
+//! type `x as u64`. A cast that narrows its operand may fail, so it is never removed.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(x: u8, y: u32) -> (u64, u16) {
+//!     return (((x as u16) as u32) as u64, (y as u8) as u16);
+//! }
+//! ```
+//!
+//! The redundant cast collapsing pass produces the following code, keeping the cast of `y` to `u8`.
+//! ```leo
+//! transition main(x: u8, y: u32) -> (u64, u16) {
+//!     return (x as u64, (y as u8) as u16);
+//! }
+//! ```
+
+pub mod redundant_cast_collapser;
+pub use redundant_cast_collapser::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for RedundantCastCollapser<'a> {
+    type Input = (Ast, &'a TypeTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, type_table): Self::Input) -> Self::Output {
+        let mut reconstructor = RedundantCastCollapser::new(type_table);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::{NodeBuilder, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and collapses the casts in a transition with the given signature returning `expression`,
+    /// returning the resulting expression as a string.
+    fn collapse_return(signature: &str, expression: &str) -> String {
+        let handler = Handler::default();
+        let type_table = TypeTable::default();
+        let source = format!(
+            "program test.aleo {{
+                transition {signature} {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+
+        let ast = RedundantCastCollapser::do_pass((ast, &type_table)).unwrap();
+        let mut function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        match function.block.statements.pop() {
+            Some(Statement::Return(statement)) => statement.expression.to_string(),
+            statement => panic!("expected a return statement, found `{statement:?}`"),
+        }
+    }
+
+    #[test]
+    fn test_collapses_repeated_cast() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(collapse_return("main(x: u32) -> u16", "(x as u16) as u16"), "(x as u16)");
+        })
+    }
+
+    #[test]
+    fn test_collapses_widening_chain() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(collapse_return("main(x: u8) -> u64", "((x as u16) as u32) as u64"), "(x as u64)");
+        })
+    }
+
+    #[test]
+    fn test_preserves_narrowing_cast() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(collapse_return("main(x: u32) -> u16", "(x as u8) as u16"), "((x as u8) as u16)");
+        })
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CastNormalizer, TypeTable};
+
+use leo_ast::*;
+
+pub struct RedundantCastCollapser<'a> {
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+}
+
+impl<'a> RedundantCastCollapser<'a> {
+    /// Initializes a new `RedundantCastCollapser`.
+    pub fn new(type_table: &'a TypeTable) -> Self {
+        Self { type_table }
+    }
+
+    /// Returns the integer type of the node `id`, if any.
+    fn integer_type(&self, id: &NodeID) -> Option<IntegerType> {
+        match self.type_table.get(id) {
+            Some(Type::Integer(integer_type)) => Some(integer_type),
+            _ => None,
+        }
+    }
+}
+
+impl ExpressionReconstructor for RedundantCastCollapser<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        let expression = match self.reconstruct_expression(*input.expression).0 {
+            // `(x as T) as T` becomes `x as T`, since the outer cast leaves the value unchanged.
+            Expression::Cast(inner) if inner.type_ == input.type_ => *inner.expression,
+            // `(x as U) as V` becomes `x as V` if the inner cast widens `x`, since `x` then reaches the outer cast
+            // intact. A narrowing inner cast may fail, so it is kept.
+            Expression::Cast(inner) => match (&inner.type_, self.integer_type(&inner.expression.id())) {
+                (Type::Integer(middle), Some(source)) if CastNormalizer::is_lossless(source, *middle) => {
+                    *inner.expression
+                }
+                _ => Expression::Cast(inner),
+            },
+            expression => expression,
+        };
+
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(expression),
+                type_: input.type_,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for RedundantCastCollapser<'_> {}
+
+impl ProgramReconstructor for RedundantCastCollapser<'_> {}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Return Ternary Folding pass traverses the AST and folds conditionals whose branches only return into a single
+//! return of a ternary expression, e.g. `if c { return a; } return b;` into `return c ? a : b;`.
+//! Conditionals with an `else` branch that only returns are folded as well.
+//! Only conditions that make no calls are folded, since the ternary may be evaluated differently.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: u8, b: u8) -> u8 {
+//!     if a > b {
+//!         return a;
+//!     }
+//!     return b;
+//! }
+//! ```
+//!
+//! The return ternary folding pass produces the following code.
+//! ```leo
+//! function main(a: u8, b: u8) -> u8 {
+//!     return a > b ? a : b;
+//! }
+//! ```
+
+pub mod return_ternary_folder;
+pub use return_ternary_folder::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for ReturnTernaryFolder<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = ReturnTernaryFolder::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and folds a function with the given body, returning its statements.
+    fn fold(body: &str) -> Vec<Statement> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                function main(a: u8, b: u8) -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast =
+            ReturnTernaryFolder::do_pass((parse_program(&handler, &node_builder, &source), &node_builder)).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1.block.statements
+    }
+
+    /// Returns `true` if the statements consist of a single return of a ternary expression.
+    fn is_ternary_return(statements: &[Statement]) -> bool {
+        matches!(statements, [Statement::Return(statement)] if matches!(statement.expression, Expression::Ternary(_)))
+    }
+
+    #[test]
+    fn test_folds_conditional_followed_by_return() {
+        create_session_if_not_set_then(|_| {
+            assert!(is_ternary_return(&fold("if a > b { return a; } return b;")));
+        })
+    }
+
+    #[test]
+    fn test_folds_conditional_with_else() {
+        create_session_if_not_set_then(|_| {
+            assert!(is_ternary_return(&fold("if a > b { return a; } else { return b; }")));
+        })
+    }
+
+    #[test]
+    fn test_keeps_multi_statement_branch() {
+        create_session_if_not_set_then(|_| {
+            let statements = fold("if a > b { let c: u8 = a; return c; } return b;");
+            assert_eq!(statements.len(), 2);
+            assert!(matches!(statements[0], Statement::Conditional(_)));
+        })
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NameCollector;
+
+use leo_ast::*;
+use leo_span::Span;
+
+pub struct ReturnTernaryFolder<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> ReturnTernaryFolder<'a> {
+    /// Initializes a new `ReturnTernaryFolder`.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Returns `true` if the block consists of a single return statement without finalize arguments.
+    fn is_single_return(block: &Block) -> bool {
+        matches!(block.statements.as_slice(), [Statement::Return(ReturnStatement { finalize_arguments: None, .. })])
+    }
+
+    /// Returns `true` if `if condition { then } otherwise` can be folded into a single return statement.
+    /// Both branches must consist of a single return statement, and the condition must make no calls.
+    fn can_fold(condition: &Expression, then: &Block, otherwise: &Statement) -> bool {
+        let returns = match otherwise {
+            Statement::Return(statement) => statement.finalize_arguments.is_none(),
+            Statement::Block(block) => Self::is_single_return(block),
+            _ => false,
+        };
+        returns && Self::is_single_return(then) && !NameCollector::of_expression(condition).has_calls
+    }
+
+    /// Returns the return statement of a branch that satisfies `can_fold`.
+    fn into_return(branch: Statement) -> ReturnStatement {
+        match branch {
+            Statement::Return(statement) => statement,
+            Statement::Block(mut block) => match block.statements.pop() {
+                Some(Statement::Return(statement)) => statement,
+                _ => unreachable!("The branch consists of a single return statement."),
+            },
+            _ => unreachable!("The branch consists of a single return statement."),
+        }
+    }
+
+    /// Folds `if condition { return a; } otherwise` into `return condition ? a : b;`,
+    /// where `otherwise` returns `b`.
+    fn fold(&self, condition: Expression, then: Block, otherwise: Statement, span: Span) -> Statement {
+        let if_true = Self::into_return(Statement::Block(then));
+        let if_false = Self::into_return(otherwise);
+        Statement::Return(ReturnStatement {
+            expression: Expression::Ternary(TernaryExpression {
+                condition: Box::new(condition),
+                if_true: Box::new(if_true.expression),
+                if_false: Box::new(if_false.expression),
+                span,
+                id: self.node_builder.next_id(),
+            }),
+            finalize_arguments: None,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+}
+
+impl ExpressionReconstructor for ReturnTernaryFolder<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for ReturnTernaryFolder<'_> {
+    /// Folds each conditional without an `else` branch that is followed by a return statement.
+    /// The result may in turn be folded with a preceding conditional, e.g. `if a { .. } if b { .. } return c;`.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        let mut statements: Vec<Statement> = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let mut statement = self.reconstruct_statement(statement).0;
+            loop {
+                match (statements.pop(), statement) {
+                    (Some(Statement::Conditional(conditional)), otherwise @ Statement::Return(_))
+                        if conditional.otherwise.is_none()
+                            && Self::can_fold(&conditional.condition, &conditional.then, &otherwise) =>
+                    {
+                        let span = conditional.span + otherwise.span();
+                        statement = self.fold(conditional.condition, conditional.then, otherwise, span);
+                    }
+                    (previous, current) => {
+                        statements.extend(previous);
+                        statements.push(current);
+                        break;
+                    }
+                }
+            }
+        }
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    /// Folds a conditional whose branches both consist of a single return statement, e.g.
+    /// `if a { return b; } else { return c; }`. Since `else if` branches are reconstructed first, chains of them fold.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let condition = self.reconstruct_expression(input.condition).0;
+        let then = self.reconstruct_block(input.then).0;
+        // A folded `else if` branch is wrapped in a block, since an `else` branch is either a block or a conditional.
+        let otherwise = input.otherwise.map(|otherwise| match self.reconstruct_statement(*otherwise).0 {
+            statement @ Statement::Return(_) => Statement::Block(Block {
+                span: statement.span(),
+                statements: vec![statement],
+                id: self.node_builder.next_id(),
+            }),
+            statement => statement,
+        });
+
+        match otherwise {
+            Some(otherwise) if Self::can_fold(&condition, &then, &otherwise) => {
+                (self.fold(condition, then, otherwise, input.span), Default::default())
+            }
+            otherwise => (
+                Statement::Conditional(ConditionalStatement {
+                    condition,
+                    then,
+                    otherwise: otherwise.map(Box::new),
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            ),
+        }
+    }
+}
+
+impl ProgramReconstructor for ReturnTernaryFolder<'_> {}
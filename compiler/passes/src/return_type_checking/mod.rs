@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Return Type Checking pass traverses the AST and checks that the expression of each return statement
+//! has the output type of the enclosing function or finalize block.
+//! In particular, a function without outputs must not return a value, and a function with outputs must return one.
+//! Errors are reported at the span of the offending return statement.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: u8) -> u16 {
+//!     return a;
+//! }
+//! ```
+//!
+//! The return type checking pass reports that the return statement has type `u8` instead of `u16`.
+
+pub mod return_type_checker;
+pub use return_type_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ReturnTypeChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut visitor = ReturnTypeChecker::new(handler, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and checks a program containing the given function, returning the number of errors reported by
+    /// the return type checker.
+    fn check_function(function: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let type_table = TypeTable::default();
+        let source = format!("program test.aleo {{ {function} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        // The type checker reports the same mismatches, so its errors are discarded.
+        let (type_checker_handler, _) = Handler::new_with_buf();
+        let _ = TypeChecker::do_pass((&ast, &type_checker_handler, symbol_table, &type_table));
+
+        let _ = ReturnTypeChecker::do_pass((&ast, &handler, &type_table));
+        handler.err_count()
+    }
+
+    #[test]
+    fn test_accepts_matching_return() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(
+                check_function("function main(a: u8) -> (u8, bool) { let b: u8 = a + 1u8; return (b, a > b); }"),
+                0
+            )
+        })
+    }
+
+    #[test]
+    fn test_rejects_mismatched_return() {
+        create_session_if_not_set_then(|_| assert_eq!(check_function("function main(a: u8) -> u16 { return a; }"), 1))
+    }
+
+    #[test]
+    fn test_rejects_value_returned_from_unit_function() {
+        create_session_if_not_set_then(|_| assert_eq!(check_function("function main(a: u8) { return a; }"), 1))
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError};
+
+/// A compiler pass that checks that the expression of each return statement has the output type
+/// of the enclosing function or finalize block.
+/// The type of an expression is looked up in the type table populated by the type checker.
+/// Returns whose type is not in the type table are left to the type checker.
+pub struct ReturnTypeChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+    /// The output type of the function or finalize block that we are currently traversing.
+    output: Type,
+}
+
+impl<'a> ReturnTypeChecker<'a> {
+    /// Returns a new `ReturnTypeChecker`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, type_table, output: Type::Unit }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ReturnTypeChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for ReturnTypeChecker<'a> {
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        // A unit function must not return a value, and a function with an output must return one.
+        let type_ = match (&input.expression, &self.output) {
+            (Expression::Unit(_), Type::Unit) => return,
+            (Expression::Unit(_), _) => Some(Type::Unit),
+            (expression, _) => self.type_table.get(&expression.id()),
+        };
+        if let Some(type_) = type_ {
+            if !type_.eq_flat(&self.output) {
+                self.handler.emit_err(TypeCheckerError::type_should_be(type_, &self.output, input.span));
+            }
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ReturnTypeChecker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        self.output = input.output_type.clone();
+        self.visit_block(&input.block);
+
+        if let Some(finalize) = &input.finalize {
+            self.output = finalize.output_type.clone();
+            self.visit_block(&finalize.block);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Select Lowering pass traverses the AST and lowers ternary expressions over integers and fields
+//! in circuits into arithmetic selects, which avoid a branch in the constraint system.
+//! The boolean condition is cast to the type of the branches.
+//! Ternaries in finalize blocks, ternaries whose branches make calls, and ternaries whose conditions are not
+//! variables are left intact.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(c: bool, a: u8, b: u8) -> u8 {
+//!     return c ? a : b;
+//! }
+//! ```
+//!
+//! The select lowering pass produces the following code.
+//! ```leo
+//! transition main(c: bool, a: u8, b: u8) -> u8 {
+//!     return (c as u8) * a + (1u8 - c as u8) * b;
+//! }
+//! ```
+
+pub mod select_lowerer;
+pub use select_lowerer::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for SelectLowerer<'a> {
+    type Input = (Ast, &'a TypeTable, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, type_table, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = SelectLowerer::new(type_table, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::{BinaryOperation, Expression, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and lowers a program whose transition returns `expression`, returning the returned expression.
+    fn lower_return(expression: &str) -> Expression {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let type_table = TypeTable::default();
+        let source = format!(
+            "program test.aleo {{
+                function foo(a: u8) -> u8 {{
+                    return a;
+                }}
+
+                transition main(c: bool, a: u8, b: u8) -> u8 {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+
+        let ast = SelectLowerer::do_pass((ast, &type_table, &node_builder)).unwrap();
+        let mut function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        match function.block.statements.pop() {
+            Some(Statement::Return(statement)) => statement.expression,
+            statement => panic!("expected a return statement, found `{statement:?}`"),
+        }
+    }
+
+    #[test]
+    fn test_lowers_numeric_ternary() {
+        create_session_if_not_set_then(|_| {
+            let expression = lower_return("c ? a : b");
+
+            let Expression::Binary(sum) = expression else {
+                panic!("expected a binary expression, found `{expression}`");
+            };
+            assert_eq!(sum.op, BinaryOperation::Add);
+            assert!(matches!(&*sum.left, Expression::Binary(product) if product.op == BinaryOperation::Mul));
+
+            // The right operand is `(1u8 - c as u8) * b`.
+            let Expression::Binary(product) = &*sum.right else {
+                panic!("expected a product, found `{}`", sum.right);
+            };
+            assert_eq!(product.op, BinaryOperation::Mul);
+            assert_eq!(product.right.to_string(), "b");
+            let Expression::Binary(complement) = &*product.left else {
+                panic!("expected a difference, found `{}`", product.left);
+            };
+            assert_eq!(complement.op, BinaryOperation::Sub);
+            assert_eq!(complement.left.to_string(), "1u8");
+            let Expression::Cast(cast) = &*complement.right else {
+                panic!("expected a cast, found `{}`", complement.right);
+            };
+            assert_eq!(cast.expression.to_string(), "c");
+            assert_eq!(cast.type_.to_string(), "u8");
+        })
+    }
+
+    #[test]
+    fn test_preserves_ternary_with_calls() {
+        create_session_if_not_set_then(|_| {
+            assert!(matches!(lower_return("c ? foo(a) : b"), Expression::Ternary(_)));
+        })
+    }
+}
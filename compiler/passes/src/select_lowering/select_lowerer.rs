@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{NameCollector, TypeTable};
+
+use leo_ast::*;
+use leo_span::Span;
+
+pub struct SelectLowerer<'a> {
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// Whether or not we are currently traversing a finalize block, which is executed on-chain rather than in a circuit.
+    pub(crate) is_finalize: bool,
+}
+
+impl<'a> SelectLowerer<'a> {
+    /// Initializes a new `SelectLowerer`.
+    pub fn new(type_table: &'a TypeTable, node_builder: &'a NodeBuilder) -> Self {
+        Self { type_table, node_builder, is_finalize: false }
+    }
+
+    /// Returns `true` if the code being traversed is compiled to a circuit.
+    pub(crate) fn in_circuit(&self) -> bool {
+        !self.is_finalize
+    }
+
+    /// Returns a new node ID, recording `type_` as the type of the node.
+    fn next_id(&self, type_: &Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_.clone());
+        id
+    }
+
+    /// Returns `condition as type_`, where `condition` is a copy of the condition with a fresh node ID.
+    fn cast(&self, condition: &Identifier, type_: &Type, span: Span) -> Expression {
+        Expression::Cast(CastExpression {
+            expression: Box::new(Expression::Identifier(Identifier {
+                name: condition.name,
+                span: condition.span,
+                id: self.next_id(&Type::Boolean),
+            })),
+            type_: type_.clone(),
+            span,
+            id: self.next_id(type_),
+        })
+    }
+
+    /// Returns the binary expression `left op right` of type `type_`.
+    fn binary(&self, left: Expression, op: BinaryOperation, right: Expression, type_: &Type, span: Span) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span,
+            id: self.next_id(type_),
+        })
+    }
+
+    /// Constructs `(condition as type_) * if_true + (1 - condition as type_) * if_false`, with the node ID `id`.
+    pub(crate) fn select(
+        &self,
+        condition: &Identifier,
+        if_true: Expression,
+        if_false: Expression,
+        type_: &Type,
+        span: Span,
+        id: NodeID,
+    ) -> Expression {
+        let one = Expression::Literal(match type_ {
+            Type::Integer(integer_type) => Literal::Integer(*integer_type, "1".to_string(), span, self.next_id(type_)),
+            _ => Literal::Field("1".to_string(), span, self.next_id(type_)),
+        });
+        let if_true = self.binary(self.cast(condition, type_, span), BinaryOperation::Mul, if_true, type_, span);
+        let negation = self.binary(one, BinaryOperation::Sub, self.cast(condition, type_, span), type_, span);
+        let if_false = self.binary(negation, BinaryOperation::Mul, if_false, type_, span);
+        Expression::Binary(BinaryExpression {
+            left: Box::new(if_true),
+            right: Box::new(if_false),
+            op: BinaryOperation::Add,
+            span,
+            id,
+        })
+    }
+}
+
+impl ExpressionReconstructor for SelectLowerer<'_> {
+    type AdditionalOutput = ();
+
+    /// Lowers `condition ? if_true : if_false` to an arithmetic select, if the ternary is in a circuit,
+    /// has an integer or field type, and its branches make no calls.
+    /// Only variable conditions are lowered, since the condition is duplicated in the resulting expression.
+    fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let condition = self.reconstruct_expression(*input.condition).0;
+        let if_true = self.reconstruct_expression(*input.if_true).0;
+        let if_false = self.reconstruct_expression(*input.if_false).0;
+
+        let has_calls = [&if_true, &if_false].into_iter().any(|branch| NameCollector::of_expression(branch).has_calls);
+        match (&condition, self.type_table.get(&input.id)) {
+            (Expression::Identifier(condition), Some(type_ @ (Type::Integer(_) | Type::Field)))
+                if self.in_circuit() && !has_calls =>
+            {
+                (self.select(condition, if_true, if_false, &type_, input.span, input.id), Default::default())
+            }
+            _ => (
+                Expression::Ternary(TernaryExpression {
+                    condition: Box::new(condition),
+                    if_true: Box::new(if_true),
+                    if_false: Box::new(if_false),
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            ),
+        }
+    }
+}
+
+impl StatementReconstructor for SelectLowerer<'_> {}
+
+impl ProgramReconstructor for SelectLowerer<'_> {
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            const_predicates: input.const_predicates,
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| {
+                self.is_finalize = true;
+                let block = self.reconstruct_block(finalize.block).0;
+                self.is_finalize = false;
+                Finalize {
+                    identifier: finalize.identifier,
+                    input: finalize.input,
+                    output: finalize.output,
+                    output_type: finalize.output_type,
+                    block,
+                    span: finalize.span,
+                    id: finalize.id,
+                }
+            }),
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
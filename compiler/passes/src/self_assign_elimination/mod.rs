@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Self Assign Elimination pass traverses the AST and removes assignments of a place to itself,
+//! e.g. `a = a;` or `a[i] = a[i];`, which have no effect.
+//! The place and the value must be structurally identical, ignoring spans, and free of calls.
+//! Compound assignments are desugared by the parser, e.g. `a += a;` into `a = a + a;`, so they are never removed.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: u8, b: [u8; 4], i: u32) -> u8 {
+//!     a = a;
+//!     b[i] = b[i];
+//!     a += a;
+//!     return a;
+//! }
+//! ```
+//!
+//! The self assign elimination pass produces the following code.
+//! ```leo
+//! function main(a: u8, b: [u8; 4], i: u32) -> u8 {
+//!     a = a + a;
+//!     return a;
+//! }
+//! ```
+
+pub mod self_assign_eliminator;
+pub use self_assign_eliminator::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for SelfAssignEliminator {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut reconstructor = SelfAssignEliminator::new();
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and eliminates self assignments in a function with the given body, returning the remaining statements.
+    fn eliminate(body: &str) -> Vec<String> {
+        let handler = Handler::default();
+        let source = format!(
+            "program test.aleo {{
+                function f() -> u8 {{
+                    return 1u8;
+                }}
+
+                function main(a: u8, b: [u8; 4], i: u32) -> u8 {{
+                    {body}
+                    return a;
+                }}
+            }}"
+        );
+        let ast = SelfAssignEliminator::do_pass(parse_program(&handler, &NodeBuilder::default(), &source)).unwrap();
+        let function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        function.block.statements.iter().map(|statement| statement.to_string()).collect()
+    }
+
+    #[test]
+    fn test_removes_self_assignment() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(eliminate("a = a;"), vec!["return a"]);
+        })
+    }
+
+    #[test]
+    fn test_removes_self_assignment_of_array_element() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(eliminate("b[i] = b[i];"), vec!["return a"]);
+        })
+    }
+
+    #[test]
+    fn test_keeps_assignment_of_call() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(eliminate("a = f();").len(), 2);
+        })
+    }
+
+    #[test]
+    fn test_keeps_compound_assignment() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(eliminate("a += a;").len(), 2);
+        })
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConditionalFuser;
+
+use leo_ast::*;
+
+#[derive(Default)]
+pub struct SelfAssignEliminator;
+
+impl SelfAssignEliminator {
+    /// Initializes a new `SelfAssignEliminator`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Returns `true` if the statement assigns a place to itself, e.g. `a = a;` or `a[i] = a[i];`.
+    /// Since calls are never considered equal, assignments whose value may have side effects are kept.
+    fn is_self_assignment(statement: &Statement) -> bool {
+        match statement {
+            Statement::Assign(assign) => ConditionalFuser::same_pure_expression(&assign.place, &assign.value),
+            _ => false,
+        }
+    }
+}
+
+impl ExpressionReconstructor for SelfAssignEliminator {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for SelfAssignEliminator {
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        (
+            Block {
+                statements: input
+                    .statements
+                    .into_iter()
+                    .filter(|statement| !Self::is_self_assignment(statement))
+                    .map(|statement| self.reconstruct_statement(statement).0)
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            },
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for SelfAssignEliminator {}
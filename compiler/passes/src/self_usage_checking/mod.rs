@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Self Usage Checking pass traverses the AST and checks that `self` is only referenced in code compiled to the
+//! circuit of a function or transition, e.g. in `self.caller`.
+//! References in nested blocks, such as the branches of a conditional or the body of a loop, are allowed.
+//! References in finalize blocks, which are executed on-chain, and in program-scope constants are reported
+//! at the span of the reference.
+
+pub mod self_usage_checker;
+pub use self_usage_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for SelfUsageChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = SelfUsageChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_rejects_self_in_finalize() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let source = "program test.aleo {
+                mapping balances: address => u64;
+
+                transition main(amount: u64) {
+                    return then finalize(amount);
+                }
+
+                finalize main(amount: u64) {
+                    Mapping::set(balances, self.caller, amount);
+                }
+            }";
+            let ast = parse_program(&handler, &NodeBuilder::default(), source);
+
+            assert!(SelfUsageChecker::do_pass((&ast, &handler)).is_err());
+            assert_eq!(handler.err_count(), 1);
+        })
+    }
+
+    #[test]
+    fn test_accepts_self_in_function_body() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let source = "program test.aleo {
+                transition main(a: address) -> bool {
+                    if a == self.caller {
+                        return true;
+                    }
+                    return self.signer == a;
+                }
+            }";
+            let ast = parse_program(&handler, &NodeBuilder::default(), source);
+
+            assert!(SelfUsageChecker::do_pass((&ast, &handler)).is_ok());
+        })
+    }
+}
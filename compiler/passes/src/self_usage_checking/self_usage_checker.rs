@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::sym;
+
+/// A compiler pass that checks that `self` is only referenced in the body of a function or transition,
+/// including the blocks nested in it, and not in finalize blocks or program-scope constants.
+/// Note that Leo has no struct methods, so the execution of a function is the only context `self` may refer to.
+pub struct SelfUsageChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// Whether or not we are currently traversing a function, including its finalize block.
+    in_function: bool,
+    /// Whether or not we are currently traversing a finalize block.
+    is_finalize: bool,
+}
+
+impl<'a> SelfUsageChecker<'a> {
+    /// Returns a new `SelfUsageChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, in_function: false, is_finalize: false }
+    }
+
+    /// Returns `true` if the code being traversed is compiled to the circuit of a function.
+    pub(crate) fn in_circuit(&self) -> bool {
+        self.in_function && !self.is_finalize
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for SelfUsageChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        if input.name == sym::SelfLower && !self.in_circuit() {
+            self.handler.emit_err(StaticAnalyzerError::self_outside_function_body(input.span));
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for SelfUsageChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for SelfUsageChecker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        self.in_function = true;
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.is_finalize = true;
+            self.visit_block(&finalize.block);
+            self.is_finalize = false;
+        }
+        self.in_function = false;
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ShorthandExpander;
+
+use leo_ast::{Expression, ExpressionReconstructor, StructExpression, StructVariableInitializer};
+use leo_errors::StaticAnalyzerError;
+
+impl ExpressionReconstructor for ShorthandExpander<'_> {
+    type AdditionalOutput = ();
+
+    /// Expands each shorthand member initializer, e.g. `x` in `Point { x, y: 0u8 }`, into `x: x`.
+    /// Reports an error if there is no variable in scope with the same name as the member.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: input.name,
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: match member.expression {
+                            Some(expression) => Some(self.reconstruct_expression(expression).0),
+                            None if self.is_in_scope(member.identifier.name) => {
+                                Some(Expression::Identifier(member.identifier))
+                            }
+                            None => {
+                                self.handler.emit_err(StaticAnalyzerError::shorthand_without_variable(
+                                    member.identifier,
+                                    member.span,
+                                ));
+                                None
+                            }
+                        },
+                        span: member.span,
+                        id: member.id,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
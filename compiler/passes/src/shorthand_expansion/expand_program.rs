@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ShorthandExpander;
+
+use leo_ast::*;
+
+impl ProgramReconstructor for ShorthandExpander<'_> {
+    /// Reconstructs the program scope, with the global constants in the outermost scope.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        self.scopes.clear();
+        self.enter_scope(None);
+
+        let consts = input
+            .consts
+            .into_iter()
+            .map(|(i, c)| match self.reconstruct_const(c) {
+                (Statement::Const(declaration), _) => (i, declaration),
+                _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+            })
+            .collect();
+        let functions = input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect();
+
+        self.exit_scope();
+
+        ProgramScope {
+            program_id: input.program_id,
+            structs: input.structs,
+            mappings: input.mappings,
+            functions,
+            consts,
+            span: input.span,
+        }
+    }
+
+    /// Reconstructs the function body and finalize block, each in a scope containing its inputs.
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        self.enter_scope(Self::input_names(&input.input));
+        let block = self.reconstruct_block(input.block).0;
+        self.exit_scope();
+
+        let finalize = input.finalize.map(|finalize| {
+            self.enter_scope(Self::input_names(&finalize.input));
+            let block = self.reconstruct_block(finalize.block).0;
+            self.exit_scope();
+
+            Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: finalize.output,
+                output_type: finalize.output_type,
+                block,
+                span: finalize.span,
+                id: finalize.id,
+            }
+        });
+
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            const_predicates: input.const_predicates,
+            block,
+            finalize,
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
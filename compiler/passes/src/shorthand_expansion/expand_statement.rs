@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ShorthandExpander;
+
+use leo_ast::*;
+
+impl StatementReconstructor for ShorthandExpander<'_> {
+    /// Reconstructs the statements in the block, within a new scope.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        self.enter_scope(None);
+        let statements =
+            input.statements.into_iter().map(|statement| self.reconstruct_statement(statement).0).collect();
+        self.exit_scope();
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    /// Reconstructs the value of the declaration, before declaring the constant.
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+        self.declare(&Expression::Identifier(input.place));
+
+        (
+            Statement::Const(ConstDeclaration {
+                place: input.place,
+                type_: input.type_,
+                value,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Reconstructs the value of the definition, before declaring the variables it binds.
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+        self.declare(&input.place);
+
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: input.place,
+                type_: input.type_,
+                value,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Reconstructs the loop body in a scope containing the loop variable.
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        let start = self.reconstruct_expression(input.start).0;
+        let stop = self.reconstruct_expression(input.stop).0;
+        let step = input.step.map(|step| self.reconstruct_expression(step).0);
+
+        self.enter_scope(Some(input.variable.name));
+        let block = self.reconstruct_block(input.block).0;
+        self.exit_scope();
+
+        (
+            Statement::Iteration(Box::new(IterationStatement {
+                variable: input.variable,
+                type_: input.type_,
+                start,
+                start_value: input.start_value,
+                stop,
+                stop_value: input.stop_value,
+                block,
+                inclusive: input.inclusive,
+                step,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+}
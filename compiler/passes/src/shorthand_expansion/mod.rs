@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Shorthand Expansion pass traverses the AST and expands each shorthand struct member initializer
+//! into an explicit one, using the variable in scope with the same name as the member.
+//! An error is reported for each shorthand initializer without a variable of the same name in scope.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function origin(x: u8) -> Point {
+//!     let y: u8 = 0u8;
+//!     return Point { x, y };
+//! }
+//! ```
+//!
+//! The shorthand expansion pass produces the following code.
+//! ```leo
+//! function origin(x: u8) -> Point {
+//!     let y: u8 = 0u8;
+//!     return Point { x: x, y: y };
+//! }
+//! ```
+
+mod expand_expression;
+
+mod expand_program;
+
+mod expand_statement;
+
+pub mod shorthand_expander;
+pub use shorthand_expander::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ShorthandExpander<'a> {
+    type Input = (Ast, &'a Handler);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut reconstructor = ShorthandExpander::new(handler);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, NodeBuilder, Statement};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = "program test.aleo {
+        struct Point {
+            x: u8,
+            y: u8,
+        }
+
+        transition origin(x: u8) -> Point {
+            let y: u8 = 0u8;
+            return Point { x, y };
+        }
+    }";
+
+    #[test]
+    fn test_expands_shorthand_to_variable_in_scope() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let ast = parse_program(&handler, &NodeBuilder::default(), PROGRAM);
+
+            let ast = ShorthandExpander::do_pass((ast, &handler)).unwrap();
+
+            let function = &ast.as_repr().program_scopes.values().next().unwrap().functions[0].1;
+            let Some(Statement::Return(return_)) = function.block.statements.last() else {
+                panic!("expected a return statement");
+            };
+            let Expression::Struct(point) = &return_.expression else {
+                panic!("expected a struct expression");
+            };
+            for member in &point.members {
+                match &member.expression {
+                    Some(Expression::Identifier(identifier)) => assert_eq!(identifier.name, member.identifier.name),
+                    _ => panic!("expected `{}` to be expanded", member.identifier),
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_rejects_shorthand_without_variable() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let ast = parse_program(&handler, &NodeBuilder::default(), &PROGRAM.replace("let y", "let z"));
+
+            assert!(ShorthandExpander::do_pass((ast, &handler)).is_err());
+            assert_eq!(handler.err_count(), 1);
+        })
+    }
+}
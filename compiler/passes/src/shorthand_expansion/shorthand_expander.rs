@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Expression, Input};
+use leo_errors::emitter::Handler;
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+pub struct ShorthandExpander<'a> {
+    /// An error handler used for any errors found during expansion.
+    pub(crate) handler: &'a Handler,
+    /// The names of the variables in scope, from the outermost to the innermost scope.
+    pub(crate) scopes: Vec<IndexSet<Symbol>>,
+}
+
+impl<'a> ShorthandExpander<'a> {
+    /// Initializes a new `ShorthandExpander`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, scopes: Default::default() }
+    }
+
+    /// Enters a new scope, declaring the given names in it.
+    pub(crate) fn enter_scope(&mut self, names: impl IntoIterator<Item = Symbol>) {
+        self.scopes.push(names.into_iter().collect());
+    }
+
+    /// Exits the current scope.
+    pub(crate) fn exit_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// Declares the variables bound by `place` in the current scope.
+    pub(crate) fn declare(&mut self, place: &Expression) {
+        match place {
+            Expression::Identifier(identifier) => {
+                if let Some(scope) = self.scopes.last_mut() {
+                    scope.insert(identifier.name);
+                }
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| self.declare(element)),
+            _ => {}
+        }
+    }
+
+    /// Returns `true` if a variable with the given name is in scope.
+    pub(crate) fn is_in_scope(&self, name: Symbol) -> bool {
+        self.scopes.iter().rev().any(|scope| scope.contains(&name))
+    }
+
+    /// Returns the names of the given function inputs.
+    pub(crate) fn input_names(inputs: &[Input]) -> impl Iterator<Item = Symbol> + '_ {
+        inputs.iter().map(|input| input.identifier().name)
+    }
+}
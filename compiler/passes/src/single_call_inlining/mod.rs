@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Single Call Inlining pass traverses the AST and inlines each function that is called exactly once in its
+//! program scope, regardless of its size, and removes it from the program scope.
+//! Transitions are never inlined, and calls to external programs are not counted.
+//! The pass is the function inlining pass with the single call inlining policy, so it also runs on flattened code
+//! and processes functions in post-order of the call graph; each argument is first assigned to a new variable, so
+//! that it is evaluated exactly once and in order.
+//!
+//! Consider the following flattened Leo code.
+//! ```leo
+//! function once(a: u8) -> u8 {
+//!     $var$0 = a * a;
+//!     return $var$0;
+//! }
+//!
+//! transition main(x: u8) -> u8 {
+//!     $var$1 = once(x);
+//!     return $var$1;
+//! }
+//! ```
+//!
+//! The single call inlining pass produces the following code.
+//! ```leo
+//! transition main(x: u8) -> u8 {
+//!     $var$2 = x;
+//!     $var$0$3 = $var$2 * $var$2;
+//!     $var$1 = $var$0$3;
+//!     return $var$1;
+//! }
+//! ```
+
+pub mod single_call_inliner;
+pub use single_call_inliner::*;
+
+use crate::{Assigner, CallGraph, Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for SingleCallInliner<'a> {
+    type Input = (Ast, &'a NodeBuilder, &'a CallGraph, &'a Assigner, &'a TypeTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder, call_graph, assigner, type_table): Self::Input) -> Self::Output {
+        let mut reconstructor = SingleCallInliner::new(node_builder, call_graph, assigner, type_table);
+        let program = reconstructor.inliner.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utilities::parse_program,
+        Flattener,
+        StaticSingleAssigner,
+        SymbolTableCreator,
+        TypeChecker,
+        Unroller,
+    };
+
+    use leo_ast::ProgramScope;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Compiles the program down to flattened code and inlines the functions called once, returning its program scope.
+    fn inline(source: &str) -> ProgramScope {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let assigner = Assigner::default();
+        let type_table = TypeTable::default();
+        let ast = parse_program(&handler, &node_builder, source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        let (symbol_table, _, call_graph) = TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+        let (ast, symbol_table) = Unroller::do_pass((ast, &handler, &node_builder, symbol_table, &type_table)).unwrap();
+        let ast = StaticSingleAssigner::do_pass((ast, &node_builder, &assigner, &symbol_table, &type_table)).unwrap();
+        let ast = Flattener::do_pass((ast, &symbol_table, &type_table, &node_builder, &assigner)).unwrap();
+        let ast = SingleCallInliner::do_pass((ast, &node_builder, &call_graph, &assigner, &type_table)).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap()
+    }
+
+    #[test]
+    fn test_inlines_and_removes_function_called_once() {
+        create_session_if_not_set_then(|_| {
+            let scope = inline(
+                "program test.aleo {
+                    function once(a: u8, b: u8) -> u8 {
+                        return a - b;
+                    }
+
+                    transition main(x: u8, y: u8) -> u8 {
+                        return once(y, x);
+                    }
+                }",
+            );
+
+            assert_eq!(scope.functions.len(), 1);
+            let main = &scope.functions[0].1;
+            assert_eq!(main.identifier.to_string(), "main");
+            assert!(!main.block.to_string().contains("once("));
+        })
+    }
+
+    #[test]
+    fn test_preserves_function_called_twice() {
+        create_session_if_not_set_then(|_| {
+            let scope = inline(
+                "program test.aleo {
+                    function twice(a: u8) -> u8 {
+                        return a * 2u8;
+                    }
+
+                    transition main(x: u8) -> u8 {
+                        let y: u8 = twice(x);
+                        return twice(y);
+                    }
+                }",
+            );
+
+            assert_eq!(scope.functions.len(), 2);
+            assert_eq!(scope.functions[0].1.identifier.to_string(), "twice");
+            assert_eq!(scope.functions[1].1.block.to_string().matches("twice(").count(), 2);
+        })
+    }
+}
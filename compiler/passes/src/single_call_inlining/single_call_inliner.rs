@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, CallGraph, FunctionInliner, InliningPolicy, TypeTable};
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+pub struct SingleCallInliner<'a> {
+    /// The function inliner, which inlines the functions called exactly once.
+    pub(crate) inliner: FunctionInliner<'a>,
+}
+
+impl<'a> SingleCallInliner<'a> {
+    /// Initializes a new `SingleCallInliner`.
+    pub fn new(
+        node_builder: &'a NodeBuilder,
+        call_graph: &'a CallGraph,
+        assigner: &'a Assigner,
+        type_table: &'a TypeTable,
+    ) -> Self {
+        Self {
+            inliner: FunctionInliner::new(node_builder, call_graph, assigner, type_table, InliningPolicy::SingleCall),
+        }
+    }
+}
+
+/// Counts the calls to each function local to the program scope.
+#[derive(Default)]
+pub(crate) struct CallCounter {
+    /// The number of call sites of each function.
+    pub(crate) counts: IndexMap<Symbol, usize>,
+}
+
+impl<'a> ExpressionVisitor<'a> for CallCounter {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        if let (Expression::Identifier(function), None) = (&*input.function, &input.external) {
+            *self.counts.entry(function.name).or_default() += 1;
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+}
+
+impl<'a> StatementVisitor<'a> for CallCounter {}
+
+impl<'a> ProgramVisitor<'a> for CallCounter {}
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Single Exit Transformation pass traverses the AST and rewrites functions with several return statements
+//! to return only at the end of their body.
+//! Each return statement assigns the returned value to a result variable and sets a `returned` flag,
+//! and the statements that follow a statement that may return are guarded by the flag.
+//! The bodies of loops that may return are guarded by the flag as well, so that the remaining iterations are skipped.
+//! Functions that already have a single exit point, functions whose output type has no default value,
+//! and functions whose return statements pass arguments to a finalize block are left intact.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function foo(a: u8) -> u8 {
+//!     if a == 0u8 {
+//!         return 1u8;
+//!     }
+//!     let b: u8 = a + 1u8;
+//!     return b;
+//! }
+//! ```
+//!
+//! The single exit transformation pass produces the following code.
+//! ```leo
+//! function foo(a: u8) -> u8 {
+//!     let $result$0: u8 = 0u8;
+//!     let $returned$1: bool = false;
+//!     if a == 0u8 {
+//!         $result$0 = 1u8;
+//!         $returned$1 = true;
+//!     }
+//!     if !$returned$1 {
+//!         let b: u8 = a + 1u8;
+//!         $result$0 = b;
+//!         $returned$1 = true;
+//!     }
+//!     return $result$0;
+//! }
+//! ```
+
+pub mod single_exit_transform;
+pub use single_exit_transform::*;
+
+use crate::{Assigner, Pass};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for SingleExitTransform<'a> {
+    type Input = (Ast, &'a NodeBuilder, &'a Assigner);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder, assigner): Self::Input) -> Self::Output {
+        let mut reconstructor = SingleExitTransform::new(node_builder, assigner);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, Statement, UnaryOperation};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and transforms a function with the given body, returning its statements before and after.
+    fn transform_body(body: &str) -> (Vec<Statement>, Vec<Statement>) {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                function foo(a: u8) -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let statements = |ast: Ast| {
+            ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1.block.statements
+        };
+
+        let original = statements(ast.clone());
+        let ast = SingleExitTransform::do_pass((ast, &node_builder, &Assigner::default())).unwrap();
+        (original, statements(ast))
+    }
+
+    /// Returns the number of return statements in `statements`, including nested ones.
+    fn count_returns(statements: &[Statement]) -> usize {
+        statements
+            .iter()
+            .map(|statement| match statement {
+                Statement::Return(_) => 1,
+                Statement::Conditional(conditional) => count_returns(&conditional.then.statements),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_rewrites_early_return() {
+        create_session_if_not_set_then(|_| {
+            let (_, statements) = transform_body("if a == 0u8 { return 1u8; } return a;");
+
+            assert_eq!(statements.len(), 5);
+            assert_eq!(count_returns(&statements), 1);
+            assert_eq!(statements[0].to_string(), "let $result$0: u8 = 0u8;");
+            assert_eq!(statements[1].to_string(), "let $returned$1: bool = false;");
+            let Statement::Conditional(guard) = &statements[3] else {
+                panic!("expected a conditional, found `{}`", statements[3]);
+            };
+            let Expression::Unary(condition) = &guard.condition else {
+                panic!("expected a negation, found `{}`", guard.condition);
+            };
+            assert_eq!(condition.op, UnaryOperation::Not);
+            assert_eq!(condition.receiver.to_string(), "$returned$1");
+            assert_eq!(statements[4].to_string(), "return $result$0");
+        })
+    }
+
+    #[test]
+    fn test_preserves_single_return() {
+        create_session_if_not_set_then(|_| {
+            let (original, statements) = transform_body("let b: u8 = a; return b;");
+
+            assert_eq!(statements, original);
+        })
+    }
+}
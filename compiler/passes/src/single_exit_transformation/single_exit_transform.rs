@@ -0,0 +1,334 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, DeclarationHoister};
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+/// Whether or not executing a statement returns from the function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Exit {
+    /// The statement never returns.
+    Never,
+    /// The statement returns on some executions.
+    Maybe,
+    /// The statement always returns.
+    Always,
+}
+
+impl Exit {
+    /// Returns the exit of a statement executing one of two branches.
+    fn join(self, other: Exit) -> Exit {
+        if self == other {
+            self
+        } else {
+            Exit::Maybe
+        }
+    }
+}
+
+pub struct SingleExitTransform<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+    /// The variable holding the value returned by the current function, if it returns a value.
+    pub(crate) result: Option<Symbol>,
+    /// The variable recording whether the current function has returned.
+    pub(crate) returned: Symbol,
+    /// The prefix of the names of the variables recording whether a function has returned.
+    pub(crate) returned_prefix: &'static str,
+}
+
+impl<'a> SingleExitTransform<'a> {
+    /// Initializes a new `SingleExitTransform`.
+    pub fn new(node_builder: &'a NodeBuilder, assigner: &'a Assigner) -> Self {
+        Self { node_builder, assigner, result: None, returned: Symbol::intern(""), returned_prefix: "$returned" }
+    }
+
+    /// Returns the number of return statements in `statements`, including nested ones.
+    fn count_returns(statements: &[Statement]) -> usize {
+        statements
+            .iter()
+            .map(|statement| match statement {
+                Statement::Return(_) => 1,
+                Statement::Block(block) => Self::count_returns(&block.statements),
+                Statement::Conditional(conditional) => {
+                    Self::count_returns(&conditional.then.statements)
+                        + conditional
+                            .otherwise
+                            .as_ref()
+                            .map_or(0, |otherwise| Self::count_returns(std::slice::from_ref(otherwise)))
+                }
+                Statement::Iteration(iteration) => Self::count_returns(&iteration.block.statements),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Returns `true` if the block has no return statement other than its last statement.
+    pub(crate) fn is_single_exit(block: &Block) -> bool {
+        match Self::count_returns(&block.statements) {
+            0 => true,
+            1 => matches!(block.statements.last(), Some(Statement::Return(_))),
+            _ => false,
+        }
+    }
+
+    /// Returns the variable `name` with a fresh node ID.
+    fn variable(&self, name: Symbol, span: Span) -> Identifier {
+        Identifier { name, span, id: self.node_builder.next_id() }
+    }
+
+    /// Returns the definition `let name: type_ = value;`.
+    fn definition(&self, name: Symbol, type_: Type, value: Expression, span: Span) -> Statement {
+        Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(self.variable(name, span)),
+            type_,
+            value,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the assignment `name = value;`.
+    fn assignment(&self, name: Symbol, value: Expression, span: Span) -> Statement {
+        Statement::Assign(Box::new(AssignStatement {
+            place: Expression::Identifier(self.variable(name, span)),
+            value,
+            span,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
+    /// Returns the statement `if !returned { statements }`.
+    fn guard(&self, statements: Vec<Statement>, span: Span) -> Statement {
+        Statement::Conditional(ConditionalStatement {
+            condition: Expression::Unary(UnaryExpression {
+                receiver: Box::new(Expression::Identifier(self.variable(self.returned, span))),
+                op: UnaryOperation::Not,
+                span,
+                id: self.node_builder.next_id(),
+            }),
+            then: Block { statements, span, id: self.node_builder.next_id() },
+            otherwise: None,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Rewrites the body of a function with the output type `output_type` to return only at its end.
+    /// Returns `None` if the function has no default value of its output type to initialize the result with.
+    pub(crate) fn transform_body(&mut self, block: Block, output_type: &Type) -> Option<Block> {
+        let span = block.span;
+        let result = match output_type {
+            Type::Unit => None,
+            type_ => Some((
+                self.assigner.unique_symbol("$result", "$"),
+                DeclarationHoister::new(self.node_builder).placeholder(type_, span)?,
+            )),
+        };
+        self.result = result.as_ref().map(|(name, _)| *name);
+        self.returned = self.assigner.unique_symbol(self.returned_prefix, "$");
+
+        let mut statements = Vec::new();
+        if let Some((name, placeholder)) = result {
+            statements.push(self.definition(name, output_type.clone(), placeholder, span));
+        }
+        let returned = Expression::Literal(Literal::Boolean(false, span, self.node_builder.next_id()));
+        statements.push(self.definition(self.returned, Type::Boolean, returned, span));
+        statements.extend(self.transform_statements(block.statements).0);
+        if let Some(name) = self.result {
+            statements.push(Statement::Return(ReturnStatement {
+                expression: Expression::Identifier(self.variable(name, span)),
+                finalize_arguments: None,
+                span,
+                id: self.node_builder.next_id(),
+            }));
+        }
+
+        Some(Block { statements, span, id: block.id })
+    }
+
+    /// Rewrites a sequence of statements, guarding the statements following one that may return.
+    /// Statements following one that always returns are unreachable and removed.
+    fn transform_statements(&mut self, statements: Vec<Statement>) -> (Vec<Statement>, Exit) {
+        let mut output = Vec::with_capacity(statements.len());
+        let mut statements = statements.into_iter();
+        while let Some(statement) = statements.next() {
+            let span = statement.span();
+            let (transformed, exit) = self.transform_statement(statement);
+            output.extend(transformed);
+            match exit {
+                Exit::Never => {}
+                Exit::Maybe => {
+                    let rest = self.transform_statements(statements.collect()).0;
+                    if !rest.is_empty() {
+                        output.push(self.guard(rest, span));
+                    }
+                    return (output, Exit::Maybe);
+                }
+                Exit::Always => return (output, Exit::Always),
+            }
+        }
+        (output, Exit::Never)
+    }
+
+    /// Rewrites a block, returning whether it returns from the function.
+    fn transform_block(&mut self, block: Block) -> (Block, Exit) {
+        let (statements, exit) = self.transform_statements(block.statements);
+        (Block { statements, span: block.span, id: block.id }, exit)
+    }
+
+    /// Rewrites a statement, replacing each return statement with assignments to the result and the returned flag.
+    fn transform_statement(&mut self, statement: Statement) -> (Vec<Statement>, Exit) {
+        match statement {
+            Statement::Return(input) => {
+                let mut statements = Vec::with_capacity(2);
+                if let Some(name) = self.result {
+                    statements.push(self.assignment(name, input.expression, input.span));
+                }
+                let returned = Expression::Literal(Literal::Boolean(true, input.span, self.node_builder.next_id()));
+                statements.push(self.assignment(self.returned, returned, input.span));
+                (statements, Exit::Always)
+            }
+            Statement::Block(block) => {
+                let (block, exit) = self.transform_block(block);
+                (vec![Statement::Block(block)], exit)
+            }
+            Statement::Conditional(input) => {
+                let (then, then_exit) = self.transform_block(input.then);
+                let (otherwise, otherwise_exit) =
+                    match input.otherwise.map(|otherwise| self.transform_statement(*otherwise)) {
+                        Some((mut statements, exit)) if statements.len() == 1 => {
+                            (Some(Box::new(statements.pop().unwrap())), exit)
+                        }
+                        Some((statements, exit)) => (
+                            Some(Box::new(Statement::Block(Block {
+                                statements,
+                                span: input.span,
+                                id: self.node_builder.next_id(),
+                            }))),
+                            exit,
+                        ),
+                        None => (None, Exit::Never),
+                    };
+                let conditional = ConditionalStatement {
+                    condition: input.condition,
+                    then,
+                    otherwise,
+                    span: input.span,
+                    id: input.id,
+                };
+                (vec![Statement::Conditional(conditional)], then_exit.join(otherwise_exit))
+            }
+            // A loop whose body may return skips its remaining iterations once it has returned.
+            // Since the loop may have no iterations, it may not return.
+            Statement::Iteration(input) => {
+                let (block, exit) = self.transform_block(input.block);
+                let block = match exit {
+                    Exit::Never => block,
+                    Exit::Maybe | Exit::Always => {
+                        let span = block.span;
+                        Block { statements: vec![self.guard(block.statements, span)], span, id: block.id }
+                    }
+                };
+                let iteration = IterationStatement {
+                    variable: input.variable,
+                    type_: input.type_,
+                    start: input.start,
+                    start_value: input.start_value,
+                    stop: input.stop,
+                    stop_value: input.stop_value,
+                    block,
+                    inclusive: input.inclusive,
+                    step: input.step,
+                    span: input.span,
+                    id: input.id,
+                };
+                let exit = if exit == Exit::Never { Exit::Never } else { Exit::Maybe };
+                (vec![Statement::Iteration(Box::new(iteration))], exit)
+            }
+            statement => (vec![statement], Exit::Never),
+        }
+    }
+
+    /// Returns `true` if any return statement in the block passes arguments to a finalize block.
+    pub(crate) fn has_finalize_arguments(statements: &[Statement]) -> bool {
+        statements.iter().any(|statement| match statement {
+            Statement::Return(input) => input.finalize_arguments.is_some(),
+            Statement::Block(block) => Self::has_finalize_arguments(&block.statements),
+            Statement::Conditional(conditional) => {
+                Self::has_finalize_arguments(&conditional.then.statements)
+                    || conditional
+                        .otherwise
+                        .as_ref()
+                        .map_or(false, |otherwise| Self::has_finalize_arguments(std::slice::from_ref(otherwise)))
+            }
+            Statement::Iteration(iteration) => Self::has_finalize_arguments(&iteration.block.statements),
+            _ => false,
+        })
+    }
+}
+
+impl ExpressionReconstructor for SingleExitTransform<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for SingleExitTransform<'_> {}
+
+impl ProgramReconstructor for SingleExitTransform<'_> {
+    /// Rewrites the bodies of the function and its finalize block, unless they already have a single exit point.
+    /// Functions whose return statements pass arguments to a finalize block are left intact.
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        let block = if Self::is_single_exit(&input.block) || Self::has_finalize_arguments(&input.block.statements) {
+            input.block
+        } else {
+            self.transform_body(input.block.clone(), &input.output_type).unwrap_or(input.block)
+        };
+        let finalize = input.finalize.map(|finalize| Finalize {
+            block: if Self::is_single_exit(&finalize.block) {
+                finalize.block
+            } else {
+                self.transform_body(finalize.block.clone(), &finalize.output_type).unwrap_or(finalize.block)
+            },
+            identifier: finalize.identifier,
+            input: finalize.input,
+            output: finalize.output,
+            output_type: finalize.output_type,
+            span: finalize.span,
+            id: finalize.id,
+        });
+
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            const_predicates: input.const_predicates,
+            block,
+            finalize,
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
@@ -0,0 +1,373 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{SoaScope, SoaUsage};
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+pub struct AosToSoa<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The variables whose types are rewritten from arrays of tuples to tuples of arrays, mapped to the number of
+    /// columns of their rewritten types.
+    pub(crate) rewritten: IndexMap<(SoaScope, Symbol), usize>,
+    /// The scope being reconstructed.
+    pub(crate) scope: SoaScope,
+}
+
+impl<'a> AosToSoa<'a> {
+    /// Initializes a new `AosToSoa` reducer.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder, rewritten: IndexMap::new(), scope: None }
+    }
+
+    /// Returns `true` if `type_` can be the element type of a rewritten array.
+    /// Only primitive types are allowed, so that each rewritten array is a flat array of scalars.
+    fn is_simple(type_: &Type) -> bool {
+        matches!(
+            type_,
+            Type::Address
+                | Type::Boolean
+                | Type::Field
+                | Type::Group
+                | Type::Integer(_)
+                | Type::Scalar
+                | Type::Signature
+        )
+    }
+
+    /// Returns the tuple of arrays equivalent to `type_`, if `type_` is an array of tuples of simple types.
+    pub(crate) fn soa_type(type_: &Type) -> Option<Type> {
+        match type_ {
+            Type::Array(array) => match array.element_type() {
+                Type::Tuple(tuple) if tuple.elements().iter().all(Self::is_simple) => {
+                    Some(Type::Tuple(TupleType::new(
+                        tuple
+                            .elements()
+                            .iter()
+                            .map(|element| Type::Array(ArrayType::new(element.clone(), array.length().into())))
+                            .collect(),
+                    )))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the number of columns of the variable `name`, if it is rewritten.
+    fn columns(&self, name: Symbol) -> Option<usize> {
+        self.rewritten.get(&(self.scope, name)).or_else(|| self.rewritten.get(&(None, name))).copied()
+    }
+
+    /// Returns the number of columns of `array`, if it is a rewritten variable.
+    fn rewritten_array(&self, array: &Expression) -> Option<usize> {
+        match array {
+            Expression::Identifier(array) => self.columns(array.name),
+            _ => None,
+        }
+    }
+
+    /// Returns the type of the variable `name`, rewritten if the variable is.
+    fn rewrite_type(&self, name: Symbol, type_: Type) -> Type {
+        match self.columns(name) {
+            Some(_) => Self::soa_type(&type_).unwrap_or(type_),
+            None => type_,
+        }
+    }
+
+    /// Returns the column `k` of `a`, `a.k`.
+    fn column(&self, array: Expression, k: usize, span: Span) -> Expression {
+        Expression::Access(AccessExpression::Tuple(TupleAccess {
+            tuple: Box::new(array),
+            index: k.into(),
+            span,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
+    /// Returns the element `i` of the column `k` of `a`, `a.k[i]`.
+    fn column_element(&self, array: Expression, k: usize, index: Expression, span: Span) -> Expression {
+        Expression::Access(AccessExpression::Array(ArrayAccess {
+            array: Box::new(self.column(array, k, span)),
+            index: Box::new(index),
+            span,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
+    /// Transposes an array literal of tuple literals, `[(a0, b0), (a1, b1)]`, into a tuple of array literals, `([a0, a1], [b0, b1])`.
+    /// The branches of a ternary are transposed in turn, and any other expression is returned unchanged.
+    pub(crate) fn transpose(&self, expression: Expression) -> Expression {
+        let array = match expression {
+            Expression::Array(array) => array,
+            Expression::Ternary(ternary) => {
+                return Expression::Ternary(TernaryExpression {
+                    condition: ternary.condition,
+                    if_true: Box::new(self.transpose(*ternary.if_true)),
+                    if_false: Box::new(self.transpose(*ternary.if_false)),
+                    span: ternary.span,
+                    id: ternary.id,
+                });
+            }
+            expression => return expression,
+        };
+        let arity = match array.elements.first() {
+            Some(Expression::Tuple(tuple)) => tuple.elements.len(),
+            _ => return Expression::Array(array),
+        };
+        if !array
+            .elements
+            .iter()
+            .all(|element| matches!(element, Expression::Tuple(tuple) if tuple.elements.len() == arity))
+        {
+            return Expression::Array(array);
+        }
+
+        let mut columns = vec![Vec::with_capacity(array.elements.len()); arity];
+        for element in array.elements {
+            let Expression::Tuple(tuple) = element else { unreachable!("all elements are tuples") };
+            for (column, expression) in columns.iter_mut().zip(tuple.elements) {
+                column.push(expression);
+            }
+        }
+        Expression::Tuple(TupleExpression {
+            elements: columns
+                .into_iter()
+                .map(|elements| {
+                    Expression::Array(ArrayExpression { elements, span: array.span, id: self.node_builder.next_id() })
+                })
+                .collect(),
+            span: array.span,
+            id: array.id,
+        })
+    }
+}
+
+impl ExpressionReconstructor for AosToSoa<'_> {
+    type AdditionalOutput = ();
+
+    /// Rebuilds a whole-element read `a[i]` as `(a.0[i], a.1[i], ..)`, if `a` is a rewritten variable.
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        let index = self.reconstruct_expression(*input.index).0;
+        match self.rewritten_array(&input.array) {
+            Some(columns) => (
+                Expression::Tuple(TupleExpression {
+                    elements: (0..columns)
+                        .map(|k| self.column_element(*input.array.clone(), k, index.clone(), input.span))
+                        .collect(),
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            ),
+            None => (
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(self.reconstruct_expression(*input.array).0),
+                    index: Box::new(index),
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            ),
+        }
+    }
+
+    /// Rewires `a[i].k` to `a.k[i]`, if `a` is a rewritten variable.
+    fn reconstruct_tuple_access(&mut self, input: TupleAccess) -> (Expression, Self::AdditionalOutput) {
+        match *input.tuple {
+            Expression::Access(AccessExpression::Array(access)) if self.rewritten_array(&access.array).is_some() => {
+                let column = Expression::Access(AccessExpression::Tuple(TupleAccess {
+                    tuple: access.array,
+                    index: input.index,
+                    span: input.span,
+                    id: access.id,
+                }));
+                (
+                    Expression::Access(AccessExpression::Array(ArrayAccess {
+                        array: Box::new(column),
+                        index: Box::new(self.reconstruct_expression(*access.index).0),
+                        span: input.span,
+                        id: input.id,
+                    })),
+                    Default::default(),
+                )
+            }
+            tuple => (
+                Expression::Access(AccessExpression::Tuple(TupleAccess {
+                    tuple: Box::new(self.reconstruct_expression(tuple).0),
+                    index: input.index,
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            ),
+        }
+    }
+}
+
+impl StatementReconstructor for AosToSoa<'_> {
+    /// Transposes the values assigned to rewritten variables, and splits a write `a[i] = v;` to an element of a
+    /// rewritten variable into a write to each column, `a.0[i] = v.0; a.1[i] = v.1; ..`.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+        match input.place {
+            Expression::Identifier(place) if self.columns(place.name).is_some() => (
+                Statement::Assign(Box::new(AssignStatement {
+                    place: Expression::Identifier(place),
+                    value: self.transpose(value),
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            ),
+            Expression::Access(AccessExpression::Array(access)) if self.rewritten_array(&access.array).is_some() => {
+                let columns = self.rewritten_array(&access.array).unwrap();
+                let index = self.reconstruct_expression(*access.index).0;
+                let values: Vec<Expression> = match value {
+                    Expression::Tuple(tuple) => tuple.elements,
+                    value => (0..columns).map(|k| self.column(value.clone(), k, input.span)).collect(),
+                };
+                let statements = values
+                    .into_iter()
+                    .enumerate()
+                    .map(|(k, value)| {
+                        Statement::Assign(Box::new(AssignStatement {
+                            place: self.column_element(*access.array.clone(), k, index.clone(), access.span),
+                            value,
+                            span: input.span,
+                            id: self.node_builder.next_id(),
+                        }))
+                    })
+                    .collect();
+                (Statement::Block(Block { statements, span: input.span, id: input.id }), Default::default())
+            }
+            place => (
+                Statement::Assign(Box::new(AssignStatement {
+                    place: self.reconstruct_expression(place).0,
+                    value,
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            ),
+        }
+    }
+
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        let mut value = self.reconstruct_expression(input.value).0;
+        if self.columns(input.place.name).is_some() {
+            value = self.transpose(value);
+        }
+        (
+            Statement::Const(ConstDeclaration {
+                place: input.place,
+                type_: self.rewrite_type(input.place.name, input.type_),
+                value,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Rewrites the declared type of a rewritten variable and transposes its initializer.
+    /// The places of a tuple definition are never rewritten.
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        let mut value = self.reconstruct_expression(input.value).0;
+        let mut type_ = input.type_;
+        if let Expression::Identifier(place) = &input.place {
+            if self.columns(place.name).is_some() {
+                value = self.transpose(value);
+                type_ = self.rewrite_type(place.name, type_);
+            }
+        }
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: input.place,
+                type_,
+                value,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for AosToSoa<'_> {
+    /// Decides which variables are rewritten before reconstructing the program scope.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        self.rewritten = SoaUsage::of_program_scope(&input);
+
+        let functions = input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect();
+        self.scope = None;
+        ProgramScope {
+            program_id: input.program_id,
+            structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions,
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        let name = input.name();
+        self.scope = Some((name, false));
+        let block = self.reconstruct_block(input.block).0;
+
+        let finalize = input.finalize.map(|finalize| {
+            self.scope = Some((name, true));
+            Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: finalize.output,
+                output_type: finalize.output_type,
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }
+        });
+
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            const_predicates: input.const_predicates,
+            block,
+            finalize,
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
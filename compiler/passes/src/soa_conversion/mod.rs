@@ -0,0 +1,306 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The SoA Conversion pass traverses the AST and rewrites arrays of tuples into tuples of arrays,
+//! so that each field of the elements is stored in its own array.
+//! Only arrays whose elements are tuples of primitive types are rewritten; arrays of structs, arrays of scalars,
+//! and arrays of nested aggregates are left intact.
+//! The types of definitions and constants are rewritten,
+//! element accesses `a[i].k` on rewritten variables are rewired to `a.k[i]`,
+//! whole elements `a[i]` are read as `(a.0[i], a.1[i])` and written column by column,
+//! and array literals assigned to rewritten variables, directly or through a ternary, are transposed.
+//! A variable used in any other way, for example returned, passed to a call, or initialized by a call,
+//! is left intact, along with every variable it is copied to or from.
+//! Function inputs are always left intact, since tuples cannot be function inputs and rewriting them would change
+//! the signature of the function.
+//! Since the type checker rejects arrays of tuples, this pass runs on the parsed AST.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(i: u32) -> field {
+//!     let b: [(u8, field); 2] = [(1u8, 1field), (2u8, 2field)];
+//!     return b[i].1;
+//! }
+//! ```
+//!
+//! The SoA conversion pass produces the following code.
+//! ```leo
+//! transition main(i: u32) -> field {
+//!     let b: ([u8; 2], [field; 2]) = ([1u8, 2u8], [1field, 2field]);
+//!     return b.1[i];
+//! }
+//! ```
+
+pub mod aos_to_soa;
+pub use aos_to_soa::*;
+
+pub mod soa_usage;
+pub use soa_usage::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for AosToSoa<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = AosToSoa::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{AccessExpression, DefinitionStatement, Expression, Function, Input, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and converts a program with the given functions, returning its functions.
+    fn convert(functions: &str) -> Vec<Function> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                {functions}
+            }}"
+        );
+        let ast = AosToSoa::do_pass((parse_program(&handler, &node_builder, &source), &node_builder)).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap().functions.into_iter().map(|(_, f)| f).collect()
+    }
+
+    /// Parses and converts a program with a single transition, returning the transition.
+    fn convert_transition(signature: &str, body: &str) -> Function {
+        convert(&format!("transition {signature} {{ {body} }}")).pop().unwrap()
+    }
+
+    /// Returns the definition at `index` in the body of `function`.
+    fn definition(function: &Function, index: usize) -> &DefinitionStatement {
+        match &function.block.statements[index] {
+            Statement::Definition(definition) => definition,
+            statement => panic!("expected a definition, found `{statement}`"),
+        }
+    }
+
+    /// Returns the expression returned by the last statement of `function`, as a string.
+    fn returned(function: &Function) -> String {
+        match function.block.statements.last() {
+            Some(Statement::Return(statement)) => statement.expression.to_string(),
+            statement => panic!("expected a return statement, found `{statement:?}`"),
+        }
+    }
+
+    /// Returns the type of the first input of `function`, as a string.
+    fn first_input_type(function: &Function) -> String {
+        match &function.input[0] {
+            Input::Internal(input) => input.type_.to_string(),
+            input => panic!("expected an internal input, found `{input}`"),
+        }
+    }
+
+    #[test]
+    fn test_rewrites_array_of_tuples_type() {
+        create_session_if_not_set_then(|_| {
+            let function = convert_transition(
+                "main() -> u8",
+                "let b: [(u8, bool); 2] = [(1u8, true), (2u8, false)]; return b[0u32].0;",
+            );
+
+            let Statement::Definition(definition) = &function.block.statements[0] else {
+                panic!("expected a definition, found `{}`", function.block.statements[0]);
+            };
+            assert_eq!(definition.type_.to_string(), "([u8; 2],[bool; 2])");
+            let Expression::Tuple(value) = &definition.value else {
+                panic!("expected a tuple, found `{}`", definition.value);
+            };
+            assert_eq!(value.elements.len(), 2);
+            for (column, expected) in value.elements.iter().zip(["1u8", "true"]) {
+                assert!(
+                    matches!(column, Expression::Array(array) if array.elements.len() == 2 && array.elements[0].to_string() == expected)
+                );
+            }
+        })
+    }
+
+    #[test]
+    fn test_rewires_element_access() {
+        create_session_if_not_set_then(|_| {
+            let function = convert_transition(
+                "main(i: u32) -> field",
+                "let b: [(u8, field); 2] = [(1u8, 1field), (2u8, 2field)]; return b[i].1;",
+            );
+
+            let Statement::Return(statement) = &function.block.statements[1] else {
+                panic!("expected a return statement, found `{}`", function.block.statements[1]);
+            };
+            let Expression::Access(AccessExpression::Array(access)) = &statement.expression else {
+                panic!("expected an array access, found `{}`", statement.expression);
+            };
+            assert!(matches!(&*access.index, Expression::Identifier(index) if index.name.to_string() == "i"));
+            let Expression::Access(AccessExpression::Tuple(column)) = &*access.array else {
+                panic!("expected a tuple access, found `{}`", access.array);
+            };
+            assert_eq!(column.index.value(), 1);
+            assert!(matches!(&*column.tuple, Expression::Identifier(array) if array.name.to_string() == "b"));
+        })
+    }
+
+    #[test]
+    fn test_preserves_input_array_of_tuples() {
+        create_session_if_not_set_then(|_| {
+            let function = convert_transition("main(a: [(u8, field); 2], i: u32) -> field", "return a[i].1;");
+
+            assert_eq!(first_input_type(&function), "[(u8,field); 2]");
+            assert_eq!(returned(&function), "a.i.1");
+
+            // A copy of an input is left intact as well.
+            let function = convert_transition(
+                "main(a: [(u8, field); 2], i: u32) -> field",
+                "let b: [(u8, field); 2] = a; return b[i].1;",
+            );
+
+            assert_eq!(first_input_type(&function), "[(u8,field); 2]");
+            assert_eq!(definition(&function, 0).type_.to_string(), "[(u8,field); 2]");
+            assert_eq!(returned(&function), "b.i.1");
+        })
+    }
+
+    #[test]
+    fn test_preserves_array_of_scalars() {
+        create_session_if_not_set_then(|_| {
+            let function = convert_transition("main(a: [u8; 2]) -> u8", "return a[0u32];");
+
+            assert_eq!(first_input_type(&function), "[u8; 2]");
+            let Statement::Return(statement) = &function.block.statements[0] else {
+                panic!("expected a return statement, found `{}`", function.block.statements[0]);
+            };
+            assert!(matches!(&statement.expression, Expression::Access(AccessExpression::Array(_))));
+        })
+    }
+
+    #[test]
+    fn test_rebuilds_whole_element_read() {
+        create_session_if_not_set_then(|_| {
+            let function = convert_transition(
+                "main(i: u32) -> (u8, field)",
+                "let b: [(u8, field); 2] = [(1u8, 1field), (2u8, 2field)]; let p: (u8, field) = b[i]; return p;",
+            );
+
+            assert_eq!(definition(&function, 1).value.to_string(), "(b.0.i,b.1.i)");
+        })
+    }
+
+    #[test]
+    fn test_splits_element_write() {
+        create_session_if_not_set_then(|_| {
+            let function = convert_transition(
+                "main(i: u32) -> u8",
+                "let b: [(u8, field); 2] = [(1u8, 1field), (2u8, 2field)]; b[i] = (3u8, 3field); return b[i].0;",
+            );
+
+            let Statement::Block(block) = &function.block.statements[1] else {
+                panic!("expected a block, found `{}`", function.block.statements[1]);
+            };
+            let writes: Vec<String> = block.statements.iter().map(|statement| statement.to_string()).collect();
+            assert_eq!(writes, ["b.0.i = 3u8;", "b.1.i = 3field;"]);
+            assert_eq!(returned(&function), "b.0.i");
+        })
+    }
+
+    #[test]
+    fn test_rewires_element_access_place() {
+        create_session_if_not_set_then(|_| {
+            let function = convert_transition(
+                "main(i: u32, x: u8) -> u8",
+                "let b: [(u8, field); 2] = [(1u8, 1field), (2u8, 2field)]; b[i].0 = x; return b[i].0;",
+            );
+
+            assert_eq!(function.block.statements[1].to_string(), "b.0.i = x;");
+        })
+    }
+
+    #[test]
+    fn test_skips_call_initializer() {
+        create_session_if_not_set_then(|_| {
+            let functions = convert(
+                "function make() -> [(u8, field); 2] { return [(1u8, 1field), (2u8, 2field)]; }
+                transition main(i: u32) -> u8 { let b: [(u8, field); 2] = make(); return b[i].0; }",
+            );
+
+            let function = &functions[1];
+            assert_eq!(definition(function, 0).type_.to_string(), "[(u8,field); 2]");
+            assert_eq!(returned(function), "b.i.0");
+        })
+    }
+
+    #[test]
+    fn test_transposes_ternary_initializer() {
+        create_session_if_not_set_then(|_| {
+            let function = convert_transition(
+                "main(c: bool, i: u32) -> u8",
+                "let b: [(u8, field); 2] = c ? [(1u8, 1field), (2u8, 2field)] : [(3u8, 3field), (4u8, 4field)];
+                return b[i].0;",
+            );
+
+            let definition = definition(&function, 0);
+            assert_eq!(definition.type_.to_string(), "([u8; 2],[field; 2])");
+            assert_eq!(definition.value.to_string(), "(c ? ([1u8,2u8],[1field,2field]) : ([3u8,4u8],[3field,4field]))");
+        })
+    }
+
+    #[test]
+    fn test_rewrites_copies_together() {
+        create_session_if_not_set_then(|_| {
+            let function = convert_transition(
+                "main(i: u32) -> u8",
+                "let a: [(u8, field); 2] = [(1u8, 1field), (2u8, 2field)]; let b: [(u8, field); 2] = a; return b[i].0;",
+            );
+
+            assert_eq!(definition(&function, 0).type_.to_string(), "([u8; 2],[field; 2])");
+            assert_eq!(definition(&function, 1).type_.to_string(), "([u8; 2],[field; 2])");
+            assert_eq!(returned(&function), "b.0.i");
+
+            // The copy is returned whole, so neither variable is rewritten.
+            let function = convert_transition(
+                "main() -> [(u8, field); 2]",
+                "let a: [(u8, field); 2] = [(1u8, 1field), (2u8, 2field)]; let b: [(u8, field); 2] = a; return b;",
+            );
+
+            assert_eq!(definition(&function, 0).type_.to_string(), "[(u8,field); 2]");
+            assert_eq!(definition(&function, 1).type_.to_string(), "[(u8,field); 2]");
+        })
+    }
+
+    #[test]
+    fn test_skips_tuple_definition() {
+        create_session_if_not_set_then(|_| {
+            let function = convert_transition(
+                "main(a: [(u8, field); 2], i: u32) -> u8",
+                "let (b, n): ([(u8, field); 2], u8) = (a, 1u8); return b[i].0 + n;",
+            );
+
+            assert_eq!(first_input_type(&function), "[(u8,field); 2]");
+            assert_eq!(definition(&function, 0).type_.to_string(), "([(u8,field); 2],u8)");
+        })
+    }
+}
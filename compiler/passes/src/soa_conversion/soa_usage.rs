@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AosToSoa, NameCollector};
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The scope of a variable: `None` for the program-scope constants, and otherwise the name of the enclosing function
+/// and whether the variable is declared in its finalize block.
+pub(crate) type SoaScope = Option<(Symbol, bool)>;
+
+/// Decides which arrays of tuples in a program scope can be rewritten into tuples of arrays, mapping each of them to
+/// the number of columns of the rewritten variable.
+/// A variable can be rewritten if it is a definition or a constant, and every use of it is supported by the rewrite:
+/// - an element access `a[i].k`, which is rewired to `a.k[i]`;
+/// - a whole-element read `a[i]` with an index free of calls, which is rebuilt as `(a.0[i], a.1[i], ..)`;
+/// - an element write `a[i] = v;` with an index free of calls, whose value is a tuple literal or free of calls, which
+///   is split into writes to each column, provided neither the index nor the value reads `a`;
+/// - a definition or an assignment of the whole variable, whose value is an array literal of tuple literals, a
+///   ternary of such values, or another variable that is rewritten as well.
+#[derive(Default)]
+pub(crate) struct SoaUsage {
+    /// The scope of the variables being declared and used.
+    scope: SoaScope,
+    /// The variables whose types are arrays of tuples, mapped to the number of columns of their rewritten types.
+    candidates: IndexMap<(SoaScope, Symbol), usize>,
+    /// The variables that are used in a way the rewrite does not support.
+    rejected: IndexSet<(SoaScope, Symbol)>,
+    /// The pairs of variables where the whole value of one is assigned to the other, so that both or neither must be
+    /// rewritten.
+    links: Vec<((SoaScope, Symbol), (SoaScope, Symbol))>,
+}
+
+impl SoaUsage {
+    /// Returns the variables of `input` that can be rewritten, mapped to the number of columns of their rewritten
+    /// types.
+    pub(crate) fn of_program_scope(input: &ProgramScope) -> IndexMap<(SoaScope, Symbol), usize> {
+        let mut usage = Self::default();
+        for (_, declaration) in input.consts.iter() {
+            usage.declare(declaration.place, &declaration.type_);
+        }
+        input.consts.iter().for_each(|(_, declaration)| usage.assign(declaration.place.name, &declaration.value));
+        for (_, function) in input.functions.iter() {
+            usage.scope = Some((function.name(), false));
+            // Function inputs are never rewritten, like the variables that escape to calls, so that the signature
+            // of the function is kept.
+            for input in function.input.iter() {
+                if let Input::Internal(input) = input {
+                    usage.declare(input.identifier, &input.type_);
+                    usage.rejected.insert((usage.scope, input.identifier.name));
+                }
+            }
+            usage.visit_block(&function.block);
+            if let Some(finalize) = &function.finalize {
+                usage.scope = Some((function.name(), true));
+                usage.visit_block(&finalize.block);
+            }
+        }
+
+        // A variable whose whole value flows to or from a rejected variable cannot be rewritten either.
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (left, right) in usage.links.iter() {
+                if usage.rejected.contains(left) != usage.rejected.contains(right) {
+                    usage.rejected.insert(*left);
+                    usage.rejected.insert(*right);
+                    changed = true;
+                }
+            }
+        }
+
+        let rejected = usage.rejected;
+        usage.candidates.into_iter().filter(|(variable, _)| !rejected.contains(variable)).collect()
+    }
+
+    /// Returns `true` if the expression is free of calls, and so can be evaluated more than once.
+    fn is_pure(expression: &Expression) -> bool {
+        !NameCollector::of_expression(expression).has_calls
+    }
+
+    /// Records `place` as a candidate if `type_` is an array of tuples.
+    fn declare(&mut self, place: Identifier, type_: &Type) {
+        if let Some(Type::Tuple(columns)) = AosToSoa::soa_type(type_) {
+            self.candidates.insert((self.scope, place.name), columns.elements().len());
+        }
+    }
+
+    /// Returns the candidate referred to by `name` in the current scope, if any.
+    fn candidate(&self, name: Symbol) -> Option<(SoaScope, Symbol)> {
+        [(self.scope, name), (None, name)].into_iter().find(|variable| self.candidates.contains_key(variable))
+    }
+
+    /// Returns the candidate `a`, if `expression` is the identifier `a`.
+    fn candidate_of(&self, expression: &Expression) -> Option<(SoaScope, Symbol)> {
+        match expression {
+            Expression::Identifier(identifier) => self.candidate(identifier.name),
+            _ => None,
+        }
+    }
+
+    /// Returns the candidate accessed by `a[i]`, if `expression` is such an access of a candidate `a`.
+    fn accessed_candidate<'b>(&self, expression: &'b Expression) -> Option<((SoaScope, Symbol), &'b ArrayAccess)> {
+        match expression {
+            Expression::Access(AccessExpression::Array(access)) => match &*access.array {
+                Expression::Identifier(array) => self.candidate(array.name).map(|variable| (variable, access)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Checks a value assigned as a whole to the variable `name`, rejecting it if the value cannot be transposed.
+    fn assign(&mut self, name: Symbol, value: &Expression) {
+        let Some(place) = self.candidate(name) else {
+            self.visit_expression(value, &());
+            return;
+        };
+        match value {
+            Expression::Identifier(other) if self.candidate(other.name).is_some() => {
+                self.links.push((place, self.candidate(other.name).unwrap()));
+            }
+            Expression::Array(array)
+                if array.elements.iter().all(|element| {
+                    matches!(element, Expression::Tuple(tuple) if tuple.elements.len() == self.candidates[&place])
+                }) =>
+            {
+                self.visit_expression(value, &());
+            }
+            Expression::Ternary(ternary) => {
+                self.visit_expression(&ternary.condition, &());
+                self.assign(name, &ternary.if_true);
+                self.assign(name, &ternary.if_false);
+            }
+            _ => {
+                self.rejected.insert(place);
+                self.visit_expression(value, &());
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for SoaUsage {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            // An element access `a[i].k` is rewired.
+            AccessExpression::Tuple(access) if self.accessed_candidate(&access.tuple).is_some() => {
+                let (_, element) = self.accessed_candidate(&access.tuple).unwrap();
+                self.visit_expression(&element.index, additional);
+            }
+            // A whole-element read `a[i]` is rebuilt, which evaluates the index once per column.
+            AccessExpression::Array(access) if self.candidate_of(&access.array).is_some() => {
+                if !Self::is_pure(&access.index) {
+                    self.rejected.insert(self.candidate_of(&access.array).unwrap());
+                }
+                self.visit_expression(&access.index, additional);
+            }
+            AccessExpression::Array(access) => {
+                self.visit_expression(&access.array, additional);
+                self.visit_expression(&access.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    /// Any other use of a candidate is not supported.
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        if let Some(variable) = self.candidate(input.name) {
+            self.rejected.insert(variable);
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for member in input.members.iter() {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                None => self.visit_identifier(&member.identifier, additional),
+            }
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for SoaUsage {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        match &input.place {
+            Expression::Identifier(place) => self.assign(place.name, &input.value),
+            // An element write `a[i] = v;` is split into a write to each column, which evaluates the index and the
+            // value once per column, unless the value is a tuple literal. Neither may read `a`, since the columns
+            // written first would be read back.
+            place if self.accessed_candidate(place).is_some() => {
+                let (variable, access) = self.accessed_candidate(place).unwrap();
+                let splits = match &input.value {
+                    Expression::Tuple(tuple) => tuple.elements.len() == self.candidates[&variable],
+                    value => Self::is_pure(value),
+                };
+                let reads =
+                    |expression: &Expression| NameCollector::of_expression(expression).names.contains(&variable.1);
+                if !splits || !Self::is_pure(&access.index) || reads(&access.index) || reads(&input.value) {
+                    self.rejected.insert(variable);
+                }
+                self.visit_expression(&access.index, &());
+                self.visit_expression(&input.value, &());
+            }
+            place => {
+                self.visit_expression(place, &());
+                self.visit_expression(&input.value, &());
+            }
+        }
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.declare(input.place, &input.type_);
+        self.assign(input.place.name, &input.value);
+    }
+
+    /// Only definitions of a single variable are rewritten; the places of a tuple definition are left intact.
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        match &input.place {
+            Expression::Identifier(place) => {
+                self.declare(*place, &input.type_);
+                self.assign(place.name, &input.value);
+            }
+            _ => self.visit_expression(&input.value, &()),
+        }
+    }
+}
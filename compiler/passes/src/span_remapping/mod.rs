@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Span Remapping pass traverses the AST and applies a transformation to every span in the program,
+//! including the spans of identifiers, types, annotations, imports, and the cached bounds of loops.
+//! For example, when a parsed snippet is embedded into a larger file, its spans can be shifted by the offset
+//! at which it is embedded.
+
+pub mod span_remapper;
+pub use span_remapper::*;
+
+mod remap_expression;
+
+mod remap_program;
+
+mod remap_statement;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+use leo_span::Span;
+
+impl<F: Fn(&Span) -> Span> Pass for SpanRemapper<F> {
+    type Input = (Ast, F);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, map): Self::Input) -> Self::Output {
+        let mut reconstructor = SpanRemapper::new(map);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{NodeBuilder, Program};
+    use leo_errors::emitter::Handler;
+    use leo_span::{span::BytePos, symbol::create_session_if_not_set_then};
+
+    /// The offset by which spans are shifted, i.e. the length of ten lines of 100 bytes.
+    const OFFSET: u32 = 1000;
+
+    /// Returns the start and end positions of all spans in the program, in the order in which they are serialized.
+    fn positions(program: &Program) -> Vec<u32> {
+        // Identifiers are serialized as escaped JSON strings, so the escapes are removed.
+        let json = serde_json::to_string(program).unwrap().replace('\\', "");
+        let mut positions: Vec<(usize, u32)> = ["\"lo\":", "\"hi\":"]
+            .iter()
+            .flat_map(|key| {
+                json.match_indices(key).map(|(index, _)| {
+                    let digits: String = json[index + key.len()..].chars().take_while(|c| c.is_ascii_digit()).collect();
+                    (index, digits.parse().unwrap())
+                })
+            })
+            .collect();
+        positions.sort();
+        positions.into_iter().map(|(_, position)| position).collect()
+    }
+
+    #[test]
+    fn test_shifts_every_span() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let source = r#"program test.aleo {
+                struct Point { x: u8, y: u8 }
+                mapping balances: address => u64;
+                const FACTOR: u8 = 2u8;
+
+                @todo("remove")
+                transition main(public a: u8, b: Point) -> u8 {
+                    let p: Point = Point { x: a, y: b.y };
+                    let (c, d): (u8, u8) = (p.x * FACTOR, (a as u16) as u8);
+                    for i: u8 in 0u8..4u8 {
+                        c = c + i;
+                    }
+                    let g: group = (0, 1)group;
+                    return c + d + u8::MAX then finalize(a);
+                } finalize main(a: u8) {
+                    Mapping::set(balances, self.caller, a as u64);
+                }
+            }"#;
+            let ast = parse_program(&handler, &NodeBuilder::default(), source);
+            let original = positions(ast.as_repr());
+
+            let shift = |span: &Span| Span::new(BytePos(span.lo.0 + OFFSET), BytePos(span.hi.0 + OFFSET));
+            let shifted = positions(SpanRemapper::do_pass((ast, shift)).unwrap().as_repr());
+
+            assert!(!original.is_empty());
+            assert!(original.iter().all(|position| *position < OFFSET));
+            assert_eq!(shifted, original.iter().map(|position| position + OFFSET).collect::<Vec<_>>());
+        })
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::SpanRemapper;
+
+use leo_ast::*;
+use leo_span::Span;
+
+impl<F: Fn(&Span) -> Span> ExpressionReconstructor for SpanRemapper<F> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::Array(ArrayAccess {
+                array: Box::new(self.reconstruct_expression(*input.array).0),
+                index: Box::new(self.reconstruct_expression(*input.index).0),
+                span: self.span(input.span),
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_associated_constant(&mut self, input: AssociatedConstant) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::AssociatedConstant(AssociatedConstant {
+                ty: self.type_(input.ty),
+                name: self.identifier(input.name),
+                span: self.span(input.span),
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                ty: self.type_(input.ty),
+                name: self.identifier(input.name),
+                arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                span: self.span(input.span),
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_member_access(&mut self, input: MemberAccess) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::Member(MemberAccess {
+                inner: Box::new(self.reconstruct_expression(*input.inner).0),
+                name: self.identifier(input.name),
+                span: self.span(input.span),
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_tuple_access(&mut self, input: TupleAccess) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::Tuple(TupleAccess {
+                tuple: Box::new(self.reconstruct_expression(*input.tuple).0),
+                index: input.index,
+                span: self.span(input.span),
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_array(&mut self, input: ArrayExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Array(ArrayExpression {
+                elements: input.elements.into_iter().map(|element| self.reconstruct_expression(element).0).collect(),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_ascription(&mut self, input: AscriptionExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Ascription(AscriptionExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.type_(input.type_),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Binary(BinaryExpression {
+                left: Box::new(self.reconstruct_expression(*input.left).0),
+                right: Box::new(self.reconstruct_expression(*input.right).0),
+                op: input.op,
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Call(CallExpression {
+                function: Box::new(self.reconstruct_expression(*input.function).0),
+                arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                external: input.external.map(|external| Box::new(self.reconstruct_expression(*external).0)),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.type_(input.type_),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Reconstructs the struct expression, preserving shorthand member initializers.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: self.identifier(input.name),
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: self.identifier(member.identifier),
+                        expression: member.expression.map(|expression| self.reconstruct_expression(expression).0),
+                        span: self.span(member.span),
+                        id: member.id,
+                    })
+                    .collect(),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_err(&mut self, input: ErrExpression) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Err(ErrExpression { span: self.span(input.span), id: input.id }), Default::default())
+    }
+
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Identifier(self.identifier(input)), Default::default())
+    }
+
+    fn reconstruct_literal(&mut self, input: Literal) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Literal(self.literal(input)), Default::default())
+    }
+
+    fn reconstruct_spread(&mut self, input: SpreadExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Spread(SpreadExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Ternary(TernaryExpression {
+                condition: Box::new(self.reconstruct_expression(*input.condition).0),
+                if_true: Box::new(self.reconstruct_expression(*input.if_true).0),
+                if_false: Box::new(self.reconstruct_expression(*input.if_false).0),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_tuple(&mut self, input: TupleExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Tuple(TupleExpression {
+                elements: input.elements.into_iter().map(|element| self.reconstruct_expression(element).0).collect(),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Unary(UnaryExpression {
+                receiver: Box::new(self.reconstruct_expression(*input.receiver).0),
+                op: input.op,
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_unit(&mut self, input: UnitExpression) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Unit(UnitExpression { span: self.span(input.span), id: input.id }), Default::default())
+    }
+}
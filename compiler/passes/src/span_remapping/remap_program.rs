@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::SpanRemapper;
+
+use leo_ast::*;
+use leo_span::Span;
+
+impl<F: Fn(&Span) -> Span> ProgramReconstructor for SpanRemapper<F> {
+    fn reconstruct_program(&mut self, input: Program) -> Program {
+        Program {
+            imports: input
+                .imports
+                .into_iter()
+                .map(|(id, (import, span))| (id, (self.reconstruct_import(import), self.span(span))))
+                .collect(),
+            program_scopes: input
+                .program_scopes
+                .into_iter()
+                .map(|(id, scope)| (id, self.reconstruct_program_scope(scope)))
+                .collect(),
+        }
+    }
+
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        ProgramScope {
+            program_id: ProgramId {
+                name: self.identifier(input.program_id.name),
+                network: self.identifier(input.program_id.network),
+            },
+            structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: self.span(input.span),
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            docs: input.docs,
+            annotations: input.annotations.into_iter().map(|annotation| self.annotation(annotation)).collect(),
+            variant: input.variant,
+            identifier: self.identifier(input.identifier),
+            type_params: input.type_params.into_iter().map(|type_param| self.identifier(type_param)).collect(),
+            input: input.input.into_iter().map(|input| self.input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.output(output)).collect(),
+            output_type: self.type_(input.output_type),
+            const_predicates: input
+                .const_predicates
+                .into_iter()
+                .map(|predicate| self.reconstruct_expression(predicate).0)
+                .collect(),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: self.identifier(finalize.identifier),
+                input: finalize.input.into_iter().map(|input| self.input(input)).collect(),
+                output: finalize.output.into_iter().map(|output| self.output(output)).collect(),
+                output_type: self.type_(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                span: self.span(finalize.span),
+                id: finalize.id,
+            }),
+            span: self.span(input.span),
+            id: input.id,
+        }
+    }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            docs: input.docs,
+            annotations: input.annotations.into_iter().map(|annotation| self.annotation(annotation)).collect(),
+            identifier: self.identifier(input.identifier),
+            type_params: input.type_params.into_iter().map(|type_param| self.identifier(type_param)).collect(),
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member {
+                    docs: member.docs,
+                    mode: member.mode,
+                    identifier: self.identifier(member.identifier),
+                    type_: self.type_(member.type_),
+                    span: self.span(member.span),
+                    id: member.id,
+                })
+                .collect(),
+            is_record: input.is_record,
+            span: self.span(input.span),
+            id: input.id,
+        }
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping {
+            identifier: self.identifier(input.identifier),
+            key_type: self.type_(input.key_type),
+            value_type: self.type_(input.value_type),
+            span: self.span(input.span),
+            id: input.id,
+        }
+    }
+}
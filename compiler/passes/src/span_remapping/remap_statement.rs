@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::SpanRemapper;
+
+use leo_ast::*;
+use leo_span::Span;
+
+use std::cell::RefCell;
+
+impl<F: Fn(&Span) -> Span> StatementReconstructor for SpanRemapper<F> {
+    fn reconstruct_annotated(&mut self, input: AnnotatedStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Annotated(AnnotatedStatement {
+                annotations: input.annotations.into_iter().map(|annotation| self.annotation(annotation)).collect(),
+                statement: Box::new(self.reconstruct_statement(*input.statement).0),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_assert(&mut self, input: AssertStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Assert(AssertStatement {
+                variant: match input.variant {
+                    AssertVariant::Assert(expr) => AssertVariant::Assert(self.reconstruct_expression(expr).0),
+                    AssertVariant::AssertEq(left, right) => AssertVariant::AssertEq(
+                        self.reconstruct_expression(left).0,
+                        self.reconstruct_expression(right).0,
+                    ),
+                    AssertVariant::AssertNeq(left, right) => AssertVariant::AssertNeq(
+                        self.reconstruct_expression(left).0,
+                        self.reconstruct_expression(right).0,
+                    ),
+                },
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place: self.reconstruct_expression(input.place).0,
+                value: self.reconstruct_expression(input.value).0,
+                span: self.span(input.span),
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        (
+            Block {
+                statements: input.statements.into_iter().map(|s| self.reconstruct_statement(s).0).collect(),
+                span: self.span(input.span),
+                id: input.id,
+            },
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Conditional(ConditionalStatement {
+                condition: self.reconstruct_expression(input.condition).0,
+                then: self.reconstruct_block(input.then).0,
+                otherwise: input.otherwise.map(|n| Box::new(self.reconstruct_statement(*n).0)),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_console(&mut self, input: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Console(ConsoleStatement {
+                function: match input.function {
+                    ConsoleFunction::Assert(expr) => ConsoleFunction::Assert(self.reconstruct_expression(expr).0),
+                    ConsoleFunction::AssertEq(left, right) => ConsoleFunction::AssertEq(
+                        self.reconstruct_expression(left).0,
+                        self.reconstruct_expression(right).0,
+                    ),
+                    ConsoleFunction::AssertNeq(left, right) => ConsoleFunction::AssertNeq(
+                        self.reconstruct_expression(left).0,
+                        self.reconstruct_expression(right).0,
+                    ),
+                },
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Const(ConstDeclaration {
+                place: self.identifier(input.place),
+                type_: self.type_(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: self.reconstruct_expression(input.place).0,
+                type_: self.type_(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_expression_statement(&mut self, input: ExpressionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Expression(ExpressionStatement {
+                expression: self.reconstruct_expression(input.expression).0,
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Reconstructs the iteration statement, including the spans of its cached bounds.
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Iteration(Box::new(IterationStatement {
+                variable: self.identifier(input.variable),
+                type_: self.type_(input.type_),
+                start: self.reconstruct_expression(input.start).0,
+                start_value: RefCell::new(input.start_value.into_inner().map(|value| self.value(value))),
+                stop: self.reconstruct_expression(input.stop).0,
+                stop_value: RefCell::new(input.stop_value.into_inner().map(|value| self.value(value))),
+                block: self.reconstruct_block(input.block).0,
+                inclusive: input.inclusive,
+                step: input.step.map(|step| self.reconstruct_expression(step).0),
+                span: self.span(input.span),
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Return(ReturnStatement {
+                expression: self.reconstruct_expression(input.expression).0,
+                finalize_arguments: input.finalize_arguments.map(|arguments| {
+                    arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect()
+                }),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
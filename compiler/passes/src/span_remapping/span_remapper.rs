@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::Span;
+
+/// A compiler pass that applies a transformation to every span in the program, e.g. to shift the spans of
+/// a snippet that is embedded into a larger file.
+pub struct SpanRemapper<F: Fn(&Span) -> Span> {
+    /// The transformation applied to each span.
+    map: F,
+}
+
+impl<F: Fn(&Span) -> Span> SpanRemapper<F> {
+    /// Initializes a new `SpanRemapper` that applies `map` to each span.
+    pub fn new(map: F) -> Self {
+        Self { map }
+    }
+
+    /// Returns the transformed span.
+    pub(crate) fn span(&self, span: Span) -> Span {
+        (self.map)(&span)
+    }
+
+    /// Returns the identifier with its span transformed.
+    pub(crate) fn identifier(&self, identifier: Identifier) -> Identifier {
+        Identifier { name: identifier.name, span: self.span(identifier.span), id: identifier.id }
+    }
+
+    /// Returns the type with the spans of any struct or record names transformed.
+    pub(crate) fn type_(&self, type_: Type) -> Type {
+        match type_ {
+            Type::Array(array_type) => Type::Array(ArrayType::new(
+                self.type_(array_type.element_type().clone()),
+                NonNegativeNumber::from(array_type.length()),
+            )),
+            Type::Identifier(identifier) => Type::Identifier(self.identifier(identifier)),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.type_(*mapping_type.key)),
+                value: Box::new(self.type_(*mapping_type.value)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| self.type_(type_.clone())).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+
+    /// Returns the annotation with its spans transformed.
+    pub(crate) fn annotation(&mut self, annotation: Annotation) -> Annotation {
+        Annotation {
+            identifier: self.identifier(annotation.identifier),
+            arguments: annotation
+                .arguments
+                .into_iter()
+                .map(|argument| match argument {
+                    AnnotationArgument::Positional(value) => {
+                        AnnotationArgument::Positional(self.reconstruct_expression(value).0)
+                    }
+                    AnnotationArgument::Named(key, value) => {
+                        AnnotationArgument::Named(self.identifier(key), self.reconstruct_expression(value).0)
+                    }
+                })
+                .collect(),
+            span: self.span(annotation.span),
+            id: annotation.id,
+        }
+    }
+
+    /// Returns the group literal with its spans transformed.
+    pub(crate) fn group(&self, group: GroupLiteral) -> GroupLiteral {
+        let coordinate = |coordinate: GroupCoordinate| match coordinate {
+            GroupCoordinate::Number(number, span) => GroupCoordinate::Number(number, self.span(span)),
+            coordinate => coordinate,
+        };
+        match group {
+            GroupLiteral::Single(value, span, id) => GroupLiteral::Single(value, self.span(span), id),
+            GroupLiteral::Tuple(tuple) => GroupLiteral::Tuple(GroupTuple {
+                x: coordinate(tuple.x),
+                y: coordinate(tuple.y),
+                span: self.span(tuple.span),
+                id: tuple.id,
+            }),
+        }
+    }
+
+    /// Returns the literal with its spans transformed.
+    pub(crate) fn literal(&self, literal: Literal) -> Literal {
+        match literal {
+            Literal::Address(value, span, id) => Literal::Address(value, self.span(span), id),
+            Literal::Boolean(value, span, id) => Literal::Boolean(value, self.span(span), id),
+            Literal::Field(value, span, id) => Literal::Field(value, self.span(span), id),
+            Literal::Group(group) => Literal::Group(Box::new(self.group(*group))),
+            Literal::Integer(type_, value, span, id) => Literal::Integer(type_, value, self.span(span), id),
+            Literal::Scalar(value, span, id) => Literal::Scalar(value, self.span(span), id),
+            Literal::String(value, span, id) => Literal::String(value, self.span(span), id),
+        }
+    }
+
+    /// Returns the constant value, e.g. a cached loop bound, with its spans transformed.
+    pub(crate) fn value(&self, value: Value) -> Value {
+        match value {
+            Value::Input(type_, identifier) => Value::Input(self.type_(type_), self.identifier(identifier)),
+            Value::Address(value, span) => Value::Address(value, self.span(span)),
+            Value::Boolean(value, span) => Value::Boolean(value, self.span(span)),
+            Value::Struct(identifier, members) => Value::Struct(
+                self.identifier(identifier),
+                members.into_iter().map(|(name, value)| (name, self.value(value))).collect(),
+            ),
+            Value::Field(value, span) => Value::Field(value, self.span(span)),
+            Value::Group(group) => Value::Group(Box::new(self.group(*group))),
+            Value::I8(value, span) => Value::I8(value, self.span(span)),
+            Value::I16(value, span) => Value::I16(value, self.span(span)),
+            Value::I32(value, span) => Value::I32(value, self.span(span)),
+            Value::I64(value, span) => Value::I64(value, self.span(span)),
+            Value::I128(value, span) => Value::I128(value, self.span(span)),
+            Value::U8(value, span) => Value::U8(value, self.span(span)),
+            Value::U16(value, span) => Value::U16(value, self.span(span)),
+            Value::U32(value, span) => Value::U32(value, self.span(span)),
+            Value::U64(value, span) => Value::U64(value, self.span(span)),
+            Value::U128(value, span) => Value::U128(value, self.span(span)),
+            Value::Scalar(value, span) => Value::Scalar(value, self.span(span)),
+            Value::String(value, span) => Value::String(value, self.span(span)),
+        }
+    }
+
+    /// Returns the external record type with its spans transformed.
+    fn external(&self, external: External) -> External {
+        External {
+            identifier: self.identifier(external.identifier),
+            program_name: self.identifier(external.program_name),
+            record: self.identifier(external.record),
+            span: self.span(external.span),
+            id: external.id,
+        }
+    }
+
+    /// Returns the function input with its spans transformed.
+    pub(crate) fn input(&self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput {
+                identifier: self.identifier(input.identifier),
+                mode: input.mode,
+                type_: self.type_(input.type_),
+                span: self.span(input.span),
+                id: input.id,
+            }),
+            Input::External(external) => Input::External(self.external(external)),
+        }
+    }
+
+    /// Returns the function output with its spans transformed.
+    pub(crate) fn output(&self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => Output::Internal(FunctionOutput {
+                mode: output.mode,
+                type_: self.type_(output.type_),
+                span: self.span(output.span),
+                id: output.id,
+            }),
+            Output::External(external) => Output::External(self.external(external)),
+        }
+    }
+}
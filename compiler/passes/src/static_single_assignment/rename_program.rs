@@ -112,12 +112,15 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
         });
 
         Function {
+            docs: function.docs,
             annotations: function.annotations,
             variant: function.variant,
             identifier: function.identifier,
+            type_params: function.type_params,
             input: function.input,
             output: function.output,
             output_type: function.output_type,
+            const_predicates: function.const_predicates,
             block,
             finalize,
             span: function.span,
@@ -18,6 +18,7 @@ use crate::{RenameTable, StaticSingleAssigner};
 
 use leo_ast::{
     AccessExpression,
+    AnnotatedStatement,
     AssertStatement,
     AssertVariant,
     AssignStatement,
@@ -49,6 +50,10 @@ use itertools::Itertools;
 impl StatementConsumer for StaticSingleAssigner<'_> {
     type Output = Vec<Statement>;
 
+    fn consume_annotated(&mut self, _: AnnotatedStatement) -> Self::Output {
+        unreachable!("Annotated statements are expanded before static single assignment.")
+    }
+
     /// Consumes the expressions in an `AssertStatement`, returning the list of simplified statements.
     fn consume_assert(&mut self, input: AssertStatement) -> Self::Output {
         let (variant, mut statements) = match input.variant {
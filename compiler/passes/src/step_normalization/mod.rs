@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Step Normalization pass traverses the AST and lowers stepped loops, e.g. `for i: u8 in 0u8..10u8 step 2u8`,
+//! into unit-step loops over the number of iterations, which are the only loops that later passes can unroll.
+//! The body of the lowered loop first defines the original loop variable from the new one, so that the rest of the
+//! body is unchanged. The new loop variable is a `u32`, and the original one is computed in `i128` and cast back to
+//! the type of the loop, so that neither the count nor the offset from the start overflows the type of the loop.
+//! The bounds and the step must be integer literals; the type of the step literal is ignored, so that a descending
+//! loop over an unsigned type can be written with a negative step, e.g. `for i: u8 in 10u8..0u8 step -2i8`.
+//! A positive step counts up to the stop and a negative step counts down to it, and the last iteration may fall
+//! short of the stop when the step does not divide the range.
+//! This pass must run before type checking, which rejects any stepped loop left in the AST.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8) -> u8 {
+//!     for i: u8 in 1u8..10u8 step 3u8 {
+//!         a = a + i;
+//!     }
+//!     return a;
+//! }
+//! ```
+//!
+//! The step normalization pass produces the following code.
+//! ```leo
+//! transition main(a: u8) -> u8 {
+//!     for i$0: u32 in 0u32..3u32 {
+//!         let i: u8 = (1i128 + i$0 as i128 * 3i128) as u8;
+//!         a = a + i;
+//!     }
+//!     return a;
+//! }
+//! ```
+
+pub mod step_normalizer;
+pub use step_normalizer::*;
+
+use crate::{Assigner, Pass};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for StepNormalizer<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, &'a Assigner);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder, assigner): Self::Input) -> Self::Output {
+        let mut reconstructor = StepNormalizer::new(handler, node_builder, assigner);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{BinaryOperation, Expression, IterationStatement, Statement};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and normalizes a transition whose body is the given loop, returning the lowered loop.
+    fn normalize(iteration: &str) -> IterationStatement {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8) -> u8 {{
+                    {iteration} {{
+                        a = a + 1u8;
+                    }}
+                    return a;
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &node_builder, &source);
+        let ast = StepNormalizer::do_pass((ast, &handler, &node_builder, &Assigner::default())).unwrap();
+        let mut statements =
+            ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1.block.statements;
+        match statements.remove(0) {
+            Statement::Iteration(iteration) => *iteration,
+            statement => panic!("expected a loop, found `{statement}`"),
+        }
+    }
+
+    /// Returns the operator and the start of the definition of the original loop variable in the lowered loop.
+    fn definition(iteration: &IterationStatement) -> (BinaryOperation, String) {
+        let Statement::Definition(definition) = &iteration.block.statements[0] else {
+            panic!("expected a definition, found `{}`", iteration.block.statements[0]);
+        };
+        assert_eq!(definition.place.to_string(), "i");
+        let Expression::Cast(cast) = &definition.value else {
+            panic!("expected a cast, found `{}`", definition.value);
+        };
+        assert_eq!(cast.type_, definition.type_);
+        let Expression::Binary(value) = &*cast.expression else {
+            panic!("expected a binary expression, found `{}`", cast.expression);
+        };
+        (value.op, value.left.to_string())
+    }
+
+    #[test]
+    fn test_lowers_ascending_step() {
+        create_session_if_not_set_then(|_| {
+            let iteration = normalize("for i: u8 in 0u8..10u8 step 2u8");
+
+            assert!(iteration.step.is_none());
+            assert_eq!(iteration.type_.to_string(), "u32");
+            assert_eq!(iteration.start.to_string(), "0u32");
+            assert_eq!(iteration.stop.to_string(), "5u32");
+            assert_eq!(iteration.block.statements.len(), 2);
+            assert_eq!(definition(&iteration), (BinaryOperation::Add, "0i128".to_string()));
+        })
+    }
+
+    #[test]
+    fn test_lowers_descending_step() {
+        create_session_if_not_set_then(|_| {
+            let iteration = normalize("for i: i8 in 10i8..0i8 step -3i8");
+
+            assert_eq!(iteration.stop.to_string(), "4u32");
+            assert_eq!(definition(&iteration), (BinaryOperation::Sub, "10i128".to_string()));
+        })
+    }
+
+    #[test]
+    fn test_counts_past_the_loop_type() {
+        create_session_if_not_set_then(|_| {
+            let iteration = normalize("for i: u8 in 0u8..=255u8 step 1u8");
+
+            assert_eq!(iteration.stop.to_string(), "256u32");
+            assert_eq!(definition(&iteration), (BinaryOperation::Add, "0i128".to_string()));
+
+            let iteration = normalize("for i: i8 in -128i8..127i8 step 2i8");
+
+            assert_eq!(iteration.stop.to_string(), "128u32");
+            assert_eq!(definition(&iteration), (BinaryOperation::Add, "-128i128".to_string()));
+        })
+    }
+
+    #[test]
+    fn test_rejects_too_many_iterations() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let node_builder = NodeBuilder::default();
+            let ast = parse_program(
+                &handler,
+                &node_builder,
+                "program test.aleo {
+                    transition main(a: u64) -> u64 {
+                        for i: u64 in 0u64..8589934592u64 step 1u64 {
+                            a = a + 1u64;
+                        }
+                        return a;
+                    }
+                }",
+            );
+
+            assert!(StepNormalizer::do_pass((ast, &handler, &node_builder, &Assigner::default())).is_err());
+        })
+    }
+
+    #[test]
+    fn test_counts_iterations() {
+        assert_eq!(StepNormalizer::iteration_count(0, 10, 3, false), 4);
+        assert_eq!(StepNormalizer::iteration_count(0, 9, 3, false), 3);
+        assert_eq!(StepNormalizer::iteration_count(0, 9, 3, true), 4);
+        assert_eq!(StepNormalizer::iteration_count(10, 0, -3, false), 4);
+        assert_eq!(StepNormalizer::iteration_count(0, 10, -1, false), 0);
+        assert_eq!(StepNormalizer::iteration_count(0, 255, 1, true), 256);
+        assert_eq!(StepNormalizer::iteration_count(-128, 127, 2, false), 128);
+        assert_eq!(StepNormalizer::iteration_count(i128::MIN, i128::MAX, 1, true), i128::MAX);
+    }
+}
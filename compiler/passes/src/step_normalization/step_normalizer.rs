@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Assigner;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::Span;
+
+/// The type in which the loop variable of a lowered loop is computed.
+const WIDE: IntegerType = IntegerType::I128;
+
+pub struct StepNormalizer<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+}
+
+impl<'a> StepNormalizer<'a> {
+    /// Initializes a new `StepNormalizer`.
+    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, assigner: &'a Assigner) -> Self {
+        Self { handler, node_builder, assigner }
+    }
+
+    /// Returns the value of an integer literal, ignoring its type.
+    pub(crate) fn integer_value(expression: &Expression) -> Option<i128> {
+        match expression {
+            Expression::Literal(Literal::Integer(_, value, ..)) => value.replace('_', "").parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of iterations of a loop from `start` to `stop` by `step`, where `step` is not zero.
+    /// A positive step counts up and a negative step counts down; a range in the other direction is empty.
+    /// The count saturates at `i128::MAX` instead of overflowing.
+    pub(crate) fn iteration_count(start: i128, stop: i128, step: i128, inclusive: bool) -> i128 {
+        let (distance, step) = if step > 0 {
+            (stop.saturating_sub(start), step)
+        } else {
+            (start.saturating_sub(stop), step.saturating_neg())
+        };
+        match (inclusive, distance) {
+            (true, distance) if distance >= 0 => (distance / step).saturating_add(1),
+            (false, distance) if distance > 0 => distance / step + i128::from(distance % step != 0),
+            _ => 0,
+        }
+    }
+
+    /// Returns an integer literal of the given type.
+    fn literal(&self, type_: IntegerType, value: i128, span: Span) -> Expression {
+        Expression::Literal(Literal::Integer(type_, value.to_string(), span, self.node_builder.next_id()))
+    }
+
+    /// Returns the cast expression `expression as type_`.
+    fn cast(&self, expression: Expression, type_: Type, span: Span) -> Expression {
+        Expression::Cast(CastExpression {
+            expression: Box::new(expression),
+            type_,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the binary expression `left op right`.
+    fn binary(&self, left: Expression, op: BinaryOperation, right: Expression, span: Span) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Lowers a stepped loop into a unit-step loop over the number of iterations, whose body first defines the loop
+    /// variable as `(start + k as i128 * step) as T`, or `(start - k as i128 * -step) as T` for a negative step, where
+    /// `k` is the new `u32` loop variable and `T` is the type of the loop.
+    /// The loop variable is computed in `i128`, since `k * step` may not fit in `T` even when the loop variable does.
+    /// A loop without iterations is lowered into an empty block.
+    /// Returns `None` if the bounds or the step are not integer literals, or if the step is zero.
+    fn lower(&self, input: &IterationStatement, step: &Expression) -> Option<Statement> {
+        if !matches!(input.type_, Type::Integer(_)) {
+            return None;
+        }
+        let (start, stop, step_value) =
+            (Self::integer_value(&input.start)?, Self::integer_value(&input.stop)?, Self::integer_value(step)?);
+        if step_value == 0 {
+            self.handler.emit_err(StaticAnalyzerError::zero_loop_step(step, step.span()));
+            return Some(Statement::Iteration(Box::new(input.clone())));
+        }
+
+        let count = Self::iteration_count(start, stop, step_value, input.inclusive);
+        if count == 0 {
+            return Some(Statement::dummy(input.span, self.node_builder.next_id()));
+        }
+        if count > u32::MAX as i128 {
+            self.handler.emit_err(StaticAnalyzerError::too_many_loop_steps(count, u32::MAX, input.span));
+            return Some(Statement::Iteration(Box::new(input.clone())));
+        }
+
+        let span = input.span;
+        let counter = Identifier {
+            name: self.assigner.unique_symbol(input.variable.name, "$"),
+            span: input.variable.span,
+            id: self.node_builder.next_id(),
+        };
+        let (op, magnitude) = if step_value > 0 {
+            (BinaryOperation::Add, step_value)
+        } else {
+            (BinaryOperation::Sub, step_value.saturating_neg())
+        };
+        let offset = self.binary(
+            self.cast(
+                Expression::Identifier(Identifier { id: self.node_builder.next_id(), ..counter }),
+                Type::Integer(WIDE),
+                span,
+            ),
+            BinaryOperation::Mul,
+            self.literal(WIDE, magnitude, step.span()),
+            span,
+        );
+        let definition = Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(input.variable),
+            type_: input.type_.clone(),
+            value: self.cast(
+                self.binary(self.literal(WIDE, start, input.start.span()), op, offset, span),
+                input.type_.clone(),
+                span,
+            ),
+            span,
+            id: self.node_builder.next_id(),
+        });
+
+        let mut statements = Vec::with_capacity(input.block.statements.len() + 1);
+        statements.push(definition);
+        statements.extend(input.block.statements.iter().cloned());
+        Some(Statement::Iteration(Box::new(IterationStatement {
+            variable: counter,
+            type_: Type::Integer(IntegerType::U32),
+            start: self.literal(IntegerType::U32, 0, input.start.span()),
+            start_value: Default::default(),
+            stop: self.literal(IntegerType::U32, count, input.stop.span()),
+            stop_value: Default::default(),
+            inclusive: false,
+            step: None,
+            block: Block { statements, span: input.block.span, id: input.block.id },
+            span,
+            id: input.id,
+        })))
+    }
+}
+
+impl ExpressionReconstructor for StepNormalizer<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for StepNormalizer<'_> {
+    /// Lowers a stepped loop into a unit-step loop, after lowering the loops in its body.
+    /// A stepped loop that cannot be lowered is reported and left as is.
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        let input = IterationStatement { block: self.reconstruct_block(input.block).0, ..input };
+        let Some(step) = &input.step else {
+            return (Statement::Iteration(Box::new(input)), Default::default());
+        };
+        match self.lower(&input, step) {
+            Some(statement) => (statement, Default::default()),
+            None => {
+                self.handler.emit_err(StaticAnalyzerError::non_literal_loop_step(step, input.span));
+                (Statement::Iteration(Box::new(input)), Default::default())
+            }
+        }
+    }
+}
+
+impl ProgramReconstructor for StepNormalizer<'_> {}
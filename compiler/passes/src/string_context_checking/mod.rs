@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The String Context Checking pass traverses the AST and checks that string literals are only used as arguments
+//! of console functions, since Leo does not support strings as runtime values.
+//! Strings used elsewhere, including inside a larger expression passed to a console function,
+//! are reported at the span of the string.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8) -> u8 {
+//!     let s: string = "leo";
+//!     return a;
+//! }
+//! ```
+//!
+//! The string context checking pass reports an error for the string bound to `s`, since it is not the argument of a
+//! console function.
+
+pub mod string_context_checker;
+pub use string_context_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for StringContextChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = StringContextChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::{console_from_asserts, parse_program};
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a transition with the given body, in which assert statements stand for console statements.
+    fn check_body(body: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8) -> u8 {{
+                    {body}
+                    return a;
+                }}
+            }}"
+        );
+        let ast = console_from_asserts(parse_program(&handler, &NodeBuilder::default(), &source));
+        StringContextChecker::do_pass((&ast, &handler))
+    }
+
+    #[test]
+    fn test_accepts_string_in_console() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("assert_eq(\"leo\", \"leo\");").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_string_bound_to_variable() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("let s: string = \"leo\";").is_err());
+        })
+    }
+}
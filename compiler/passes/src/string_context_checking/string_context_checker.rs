@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+/// A compiler pass that checks that string literals are only used as arguments of console functions.
+pub struct StringContextChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> StringContextChecker<'a> {
+    /// Returns a new `StringContextChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler }
+    }
+
+    /// Visits an argument of a console function, which may be a string literal.
+    fn visit_console_argument(&mut self, argument: &'a Expression) {
+        if !matches!(argument, Expression::Literal(Literal::String(..))) {
+            self.visit_expression(argument, &Default::default());
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for StringContextChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_literal(&mut self, input: &'a Literal, _additional: &Self::AdditionalInput) -> Self::Output {
+        if let Literal::String(_, span, _) = input {
+            self.handler.emit_err(StaticAnalyzerError::string_outside_console(*span));
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for StringContextChecker<'a> {
+    fn visit_console(&mut self, input: &'a ConsoleStatement) {
+        match &input.function {
+            ConsoleFunction::Assert(argument) => self.visit_console_argument(argument),
+            ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                self.visit_console_argument(left);
+                self.visit_console_argument(right);
+            }
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for StringContextChecker<'a> {}
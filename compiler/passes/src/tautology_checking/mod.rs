@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Tautology Checking pass traverses the AST and warns about integer comparisons whose result is the same for
+//! every value their operands may take, as determined by the range analysis.
+//! The analysis is conservative: a comparison is only reported if the ranges of both operands are known and prove
+//! its result, e.g. an operand of type `u128` may take any value.
+//! Note that this pass relies on the type table populated by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(x: u8) -> bool {
+//!     return (x as u16) < 256u16;
+//! }
+//! ```
+//!
+//! The tautology checking pass reports that the comparison is always `true`, since `x` is at most `255`.
+
+pub mod tautology_checker;
+pub use tautology_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for TautologyChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut visitor = TautologyChecker::new(handler, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks a transition with input `x: u8` returning the given comparison, returning the number of warnings.
+    fn count_warnings(comparison: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let type_table = TypeTable::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(x: u8) -> bool {{
+                    return {comparison};
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+        TautologyChecker::do_pass((&ast, &handler, &type_table)).unwrap();
+        handler.warning_count()
+    }
+
+    #[test]
+    fn test_warns_about_comparison_always_true() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(count_warnings("(x as u16) < 256u16"), 1);
+            assert_eq!(count_warnings("x <= 255u8"), 1);
+        })
+    }
+
+    #[test]
+    fn test_accepts_comparison_depending_on_operand() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(count_warnings("x < 10u8"), 0);
+            assert_eq!(count_warnings("(x as u16) < 255u16"), 0);
+        })
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{RangeAnalyzer, TypeTable};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerWarning};
+
+/// A compiler pass that warns about integer comparisons whose result is determined by the ranges of their operands,
+/// e.g. `(x as u16) < 256u16` where `x: u8`.
+pub struct TautologyChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The range analysis of integer expressions.
+    ranges: RangeAnalyzer<'a>,
+}
+
+impl<'a> TautologyChecker<'a> {
+    /// Returns a new `TautologyChecker`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, ranges: RangeAnalyzer::new(type_table) }
+    }
+
+    /// Returns the value of `left op right` if it is the same for all values in the ranges of the operands.
+    fn constant_value(op: BinaryOperation, (ll, lh): (i128, i128), (rl, rh): (i128, i128)) -> Option<bool> {
+        let (always, never) = match op {
+            BinaryOperation::Lt => (lh < rl, ll >= rh),
+            BinaryOperation::Lte => (lh <= rl, ll > rh),
+            BinaryOperation::Gt => (ll > rh, lh <= rl),
+            BinaryOperation::Gte => (ll >= rh, lh < rl),
+            BinaryOperation::Eq => (ll == lh && rl == rh && ll == rl, lh < rl || rh < ll),
+            BinaryOperation::Neq => (lh < rl || rh < ll, ll == lh && rl == rh && ll == rl),
+            _ => return None,
+        };
+        match (always, never) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for TautologyChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Operands whose range is unknown, e.g. those of type `u128`, may take any value.
+        if let (Some(left), Some(right)) = (self.ranges.range_of(&input.left), self.ranges.range_of(&input.right)) {
+            if let Some(value) = Self::constant_value(input.op, left, right) {
+                self.handler.emit_warning(StaticAnalyzerWarning::constant_comparison(input, value, input.span).into());
+            }
+        }
+        self.visit_expression(&input.left, additional);
+        self.visit_expression(&input.right, additional);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for TautologyChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for TautologyChecker<'a> {}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Ternary Branch Type Checking pass traverses the AST and checks that the two branches of each ternary
+//! expression have the same type, reporting a mismatch at the span of the ternary expression.
+//! The pass runs after type checking and reads the types of the branches from the type table.
+//! Literals always carry their type, e.g. `1u8`, so a literal branch is checked like any other branch.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(c: bool, a: u8, b: u16) -> u8 {
+//!     return c ? a : b;
+//! }
+//! ```
+//!
+//! The ternary branch type checking pass reports an error, since the branches have types `u8` and `u16`.
+
+pub mod ternary_branch_type_checker;
+pub use ternary_branch_type_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for TernaryBranchTypeChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut visitor = TernaryBranchTypeChecker::new(handler, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks a transition with the input `b: {type_}` that returns the given ternary, then checks its branches.
+    /// The type checker may report errors of its own, which are ignored.
+    fn check_ternary(type_: &str, ternary: &str) -> Result<()> {
+        let source = format!(
+            "program test.aleo {{
+                transition main(c: bool, a: u8, b: {type_}) -> u8 {{
+                    return {ternary};
+                }}
+            }}"
+        );
+        let (type_checker_handler, _) = Handler::new_with_buf();
+        let type_table = TypeTable::default();
+        let ast = parse_program(&type_checker_handler, &NodeBuilder::default(), &source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &type_checker_handler)).unwrap();
+        let _ = TypeChecker::do_pass((&ast, &type_checker_handler, symbol_table, &type_table));
+
+        let (handler, _) = Handler::new_with_buf();
+        TernaryBranchTypeChecker::do_pass((&ast, &handler, &type_table))
+    }
+
+    #[test]
+    fn test_accepts_matching_branches() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_ternary("u8", "c ? a : b").is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_mismatched_branches() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_ternary("u16", "c ? a : b").is_err());
+        })
+    }
+
+    #[test]
+    fn test_accepts_literal_branch() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_ternary("u8", "c ? a : 1u8").is_ok());
+        })
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+/// A compiler pass that checks that the two branches of each ternary expression have the same type.
+pub struct TernaryBranchTypeChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+}
+
+impl<'a> TernaryBranchTypeChecker<'a> {
+    /// Returns a new `TernaryBranchTypeChecker`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, type_table }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for TernaryBranchTypeChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Branches whose type could not be inferred have already been reported by the type checker.
+        if let (Some(if_true), Some(if_false)) =
+            (self.type_table.get(&input.if_true.id()), self.type_table.get(&input.if_false.id()))
+        {
+            if !if_true.eq_flat(&if_false) {
+                self.handler.emit_err(StaticAnalyzerError::ternary_branch_type_mismatch(if_true, if_false, input.span));
+            }
+        }
+        self.visit_expression(&input.condition, additional);
+        self.visit_expression(&input.if_true, additional);
+        self.visit_expression(&input.if_false, additional);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for TernaryBranchTypeChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for TernaryBranchTypeChecker<'a> {}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Test Case Reduction pass shrinks a program to a local minimum with respect to a predicate.
+//! It repeatedly deletes functions, struct and record members, and statements, keeping each deletion after which
+//! the predicate still holds. Deletions that would leave a program scope without functions, or a struct without
+//! members, are never kept, so the result is always printable as a well-formed program.
+//! The reduction stops once no single deletion preserves the predicate.
+//!
+//! Consider the following Leo code, and a predicate that holds whenever the program contains `a * b`.
+//! ```leo
+//! program test.aleo {
+//!     function helper(a: u8) -> u8 {
+//!         return a + 1u8;
+//!     }
+//!
+//!     transition main(a: u8, b: u8) -> u8 {
+//!         let c: u8 = a * b;
+//!         return c;
+//!     }
+//! }
+//! ```
+//!
+//! Reducing it produces the following program.
+//! ```leo
+//! program test.aleo {
+//!     transition main(a: u8, b: u8) -> u8 {
+//!         let c: u8 = a * b;
+//!     }
+//! }
+//! ```
+
+pub mod test_case_reducer;
+pub use test_case_reducer::*;
+
+use crate::Pass;
+
+use leo_ast::Program;
+
+impl<'a> Pass for TestCaseReducer<'a> {
+    type Input = (Program, &'a dyn Fn(&Program) -> bool);
+    type Output = Program;
+
+    fn do_pass((program, predicate): Self::Input) -> Self::Output {
+        TestCaseReducer::new(predicate).reduce(program)
+    }
+}
+
+/// Greedily deletes statements, functions, and struct members from `program` while `predicate` continues to hold.
+pub fn reduce_to_minimal(program: Program, predicate: impl Fn(&Program) -> bool) -> Program {
+    TestCaseReducer::do_pass((program, &predicate))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_errors::emitter::Handler;
+    use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+    const SOURCE: &str = "program test.aleo {
+        struct Point { x: u8, y: u8 }
+
+        function helper(a: u8) -> u8 {
+            return a + 1u8;
+        }
+
+        transition main(a: u8, b: u8) -> u8 {
+            let p: Point = Point { x: a, y: b };
+            let c: u8 = a * b;
+            return c;
+        }
+    }";
+
+    #[test]
+    fn test_removes_unrelated_helper() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let ast = parse_program(&handler, &NodeBuilder::default(), SOURCE);
+            let program = ast.into_repr();
+            let predicate = |program: &Program| program.to_string().contains("a * b");
+            assert!(predicate(&program));
+
+            let reduced = reduce_to_minimal(program, predicate);
+            assert!(predicate(&reduced));
+            let scope = reduced.program_scopes.values().next().unwrap();
+            let functions: Vec<Symbol> = scope.functions.iter().map(|(name, _)| *name).collect();
+            assert_eq!(functions, vec![Symbol::intern("main")]);
+            assert_eq!(scope.functions[0].1.block.statements.len(), 1);
+            assert_eq!(scope.structs[0].1.members.len(), 1);
+        })
+    }
+
+    #[test]
+    fn test_keeps_program_when_nothing_can_be_deleted() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let ast = parse_program(&handler, &NodeBuilder::default(), SOURCE);
+            let program = ast.into_repr();
+            let reduced = reduce_to_minimal(program.clone(), |candidate| candidate == &program);
+            assert_eq!(reduced, program);
+        })
+    }
+}
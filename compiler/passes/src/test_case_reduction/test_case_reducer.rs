@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+
+/// A compiler pass that greedily shrinks a program while a predicate over it continues to hold.
+/// Useful for minimizing programs that trigger compiler bugs.
+pub struct TestCaseReducer<'a> {
+    /// The property that every reduced program must preserve.
+    pub(crate) predicate: &'a dyn Fn(&Program) -> bool,
+}
+
+impl<'a> TestCaseReducer<'a> {
+    /// Returns a new `TestCaseReducer` for the given predicate.
+    pub fn new(predicate: &'a dyn Fn(&Program) -> bool) -> Self {
+        Self { predicate }
+    }
+
+    /// Deletes functions, struct members, and statements one at a time, keeping each deletion that leaves the program
+    /// structurally valid and satisfying the predicate. Stops once a full sweep deletes nothing.
+    pub fn reduce(&self, mut program: Program) -> Program {
+        loop {
+            let mut reduced = false;
+            let mut index = 0;
+            while let Some(candidate) = Deleter::delete(&program, index) {
+                if is_structurally_valid(&candidate) && (self.predicate)(&candidate) {
+                    // The next candidate has shifted into `index`, so it is tried without advancing.
+                    program = candidate;
+                    reduced = true;
+                } else {
+                    index += 1;
+                }
+            }
+            if !reduced {
+                return program;
+            }
+        }
+    }
+}
+
+/// Returns `true` if the program can still be printed as a well-formed program.
+/// Every program scope must define a function, and every struct or record must have a member.
+fn is_structurally_valid(program: &Program) -> bool {
+    program.program_scopes.values().all(|scope| {
+        !scope.functions.is_empty() && scope.structs.iter().all(|(_, struct_)| !struct_.members.is_empty())
+    })
+}
+
+/// Deletes the `index`-th deletable item of a program.
+/// Items are numbered by functions, then struct members, then statements, in each program scope in order.
+struct Deleter {
+    /// The number of deletable items left to skip.
+    remaining: usize,
+    /// Whether an item has been deleted.
+    deleted: bool,
+}
+
+impl Deleter {
+    /// Returns a copy of the program without its `index`-th deletable item, or `None` if it has fewer items.
+    fn delete(program: &Program, index: usize) -> Option<Program> {
+        let mut program = program.clone();
+        let mut deleter = Self { remaining: index, deleted: false };
+        program.program_scopes.values_mut().for_each(|scope| deleter.delete_from_scope(scope));
+        deleter.deleted.then_some(program)
+    }
+
+    /// Returns `true` if the current item is the one to delete.
+    fn take(&mut self) -> bool {
+        if self.deleted {
+            return false;
+        }
+        if self.remaining == 0 {
+            self.deleted = true;
+            return true;
+        }
+        self.remaining -= 1;
+        false
+    }
+
+    fn delete_from_scope(&mut self, scope: &mut ProgramScope) {
+        scope.functions.retain(|_| !self.take());
+        scope.structs.iter_mut().for_each(|(_, struct_)| struct_.members.retain(|_| !self.take()));
+        scope.functions.iter_mut().for_each(|(_, function)| {
+            self.delete_from_block(&mut function.block);
+            if let Some(finalize) = &mut function.finalize {
+                self.delete_from_block(&mut finalize.block);
+            }
+        });
+    }
+
+    fn delete_from_block(&mut self, block: &mut Block) {
+        block.statements.retain(|_| !self.take());
+        block.statements.iter_mut().for_each(|statement| self.delete_from_statement(statement));
+    }
+
+    fn delete_from_statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Block(block) => self.delete_from_block(block),
+            Statement::Conditional(conditional) => {
+                self.delete_from_block(&mut conditional.then);
+                if let Some(otherwise) = &mut conditional.otherwise {
+                    self.delete_from_statement(otherwise);
+                }
+            }
+            Statement::Iteration(iteration) => self.delete_from_block(&mut iteration.block),
+            _ => {}
+        }
+    }
+}
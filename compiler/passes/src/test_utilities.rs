@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{AssertVariant, Ast, ConsoleFunction, ConsoleStatement, NodeBuilder, Statement};
+use leo_errors::emitter::Handler;
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+/// Parses the given Leo source into an `Ast`.
+/// Must be called within a session, e.g. inside `create_session_if_not_set_then`.
+pub(crate) fn parse_program(handler: &Handler, node_builder: &NodeBuilder, source: &str) -> Ast {
+    let source_file = with_session_globals(|s| s.source_map.new_source(source, FileName::Custom("test".into())));
+    leo_parser::parse_ast(handler, node_builder, &source_file.src, source_file.start_pos)
+        .expect("the test program should be well-formed")
+}
+
+/// Rewrites the assert statements at the top level of each function body into console statements.
+/// Since the parser does not yet accept console statements, tests write them as assert statements instead.
+pub(crate) fn console_from_asserts(ast: Ast) -> Ast {
+    let mut program = ast.into_repr();
+    for scope in program.program_scopes.values_mut() {
+        for (_, function) in scope.functions.iter_mut() {
+            for statement in function.block.statements.iter_mut() {
+                if let Statement::Assert(assert) = statement {
+                    let console_function = match assert.variant.clone() {
+                        AssertVariant::Assert(condition) => ConsoleFunction::Assert(condition),
+                        AssertVariant::AssertEq(left, right) => ConsoleFunction::AssertEq(left, right),
+                        AssertVariant::AssertNeq(left, right) => ConsoleFunction::AssertNeq(left, right),
+                    };
+                    let console = ConsoleStatement { function: console_function, span: assert.span, id: assert.id };
+                    *statement = Statement::Console(console);
+                }
+            }
+        }
+    }
+    Ast::new(program)
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Tuple Access Folding pass traverses the AST and folds each constant-index access into a tuple literal,
+//! e.g. `(a, b, c).1`, into the accessed element.
+//! The access is only folded if the discarded elements are free of calls, so that no side effect is lost.
+//! Otherwise, the access is kept as is.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: u8, b: u8, c: u8) -> u8 {
+//!     let x: u8 = (a, b + 1u8, c).1;
+//!     let y: u8 = (f(), b).1;
+//!     return x + y;
+//! }
+//! ```
+//!
+//! The tuple access folding pass produces the following code.
+//! ```leo
+//! function main(a: u8, b: u8, c: u8) -> u8 {
+//!     let x: u8 = b + 1u8;
+//!     let y: u8 = (f(), b).1;
+//!     return x + y;
+//! }
+//! ```
+
+pub mod tuple_access_folder;
+pub use tuple_access_folder::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for TupleAccessFolder {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut reconstructor = TupleAccessFolder::new();
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and folds a function returning the given expression, returning the folded return statement.
+    fn fold(expression: &str) -> String {
+        let handler = Handler::default();
+        let source = format!(
+            "program test.aleo {{
+                function f() -> u8 {{
+                    return 1u8;
+                }}
+
+                function main(a: u8, b: u8, c: u8) -> u8 {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = TupleAccessFolder::do_pass(parse_program(&handler, &NodeBuilder::default(), &source)).unwrap();
+        let function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        function.block.statements[0].to_string()
+    }
+
+    #[test]
+    fn test_folds_access_of_pure_tuple() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(fold("(a, b, c).1"), "return b");
+        })
+    }
+
+    #[test]
+    fn test_keeps_access_discarding_call() {
+        create_session_if_not_set_then(|_| {
+            assert!(fold("(f(), b, c).1").ends_with(".1"));
+        })
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConditionalFuser;
+
+use leo_ast::*;
+
+#[derive(Default)]
+pub struct TupleAccessFolder;
+
+impl TupleAccessFolder {
+    /// Initializes a new `TupleAccessFolder`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Returns `true` if the expression is free of calls, and so can be discarded without losing side effects.
+    fn is_pure(expression: &Expression) -> bool {
+        ConditionalFuser::same_pure_expression(expression, expression)
+    }
+}
+
+impl ExpressionReconstructor for TupleAccessFolder {
+    type AdditionalOutput = ();
+
+    fn reconstruct_tuple_access(&mut self, input: TupleAccess) -> (Expression, Self::AdditionalOutput) {
+        let index = input.index.value();
+        match self.reconstruct_expression(*input.tuple).0 {
+            // Fold the access if every discarded element can be dropped.
+            Expression::Tuple(tuple)
+                if index < tuple.elements.len()
+                    && tuple.elements.iter().enumerate().all(|(i, element)| i == index || Self::is_pure(element)) =>
+            {
+                (tuple.elements.into_iter().nth(index).unwrap(), Default::default())
+            }
+            tuple => (
+                Expression::Access(AccessExpression::Tuple(TupleAccess {
+                    tuple: Box::new(tuple),
+                    index: input.index,
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            ),
+        }
+    }
+}
+
+impl StatementReconstructor for TupleAccessFolder {}
+
+impl ProgramReconstructor for TupleAccessFolder {}
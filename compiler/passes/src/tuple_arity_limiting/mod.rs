@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Tuple Arity Limiting pass traverses the AST and splits tuples with more elements than a maximum arity into
+//! nested tuples, for backends that cap the arity of tuples.
+//! The elements of a large tuple are grouped, in order, into tuples of at most the maximum arity, and the groups are
+//! grouped again until at most the maximum arity remain; a trailing group with a single element is left unwrapped.
+//! Tuple types, tuple expressions, and the outputs of functions are chunked in the same way, and each tuple access is
+//! rewritten into the sequence of accesses that reaches the same element, e.g. `t.7` becomes `t.1.2` with groups of 5.
+//! Since nested tuples are rejected by type checking, this pass runs after it, and keeps the type table up to date.
+//!
+//! Consider the following Leo code, limited to tuples of at most 5 elements.
+//! ```leo
+//! function digits() -> (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) {
+//!     return (0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8);
+//! }
+//!
+//! transition main() -> u8 {
+//!     let t: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) = digits();
+//!     return t.7;
+//! }
+//! ```
+//!
+//! The tuple arity limiting pass produces the following code.
+//! ```leo
+//! function digits() -> ((u8, u8, u8, u8, u8), (u8, u8, u8, u8, u8)) {
+//!     return ((0u8, 1u8, 2u8, 3u8, 4u8), (5u8, 6u8, 7u8, 8u8, 9u8));
+//! }
+//!
+//! transition main() -> u8 {
+//!     let t: ((u8, u8, u8, u8, u8), (u8, u8, u8, u8, u8)) = digits();
+//!     return t.1.2;
+//! }
+//! ```
+
+pub mod tuple_arity_limiter;
+pub use tuple_arity_limiter::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for TupleArityLimiter<'a> {
+    type Input = (Ast, &'a Handler, &'a TypeTable, &'a NodeBuilder, usize);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, type_table, node_builder, max_arity): Self::Input) -> Self::Output {
+        let mut reconstructor = TupleArityLimiter::new(handler, type_table, node_builder, max_arity);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_program, SymbolTableCreator, TypeChecker};
+
+    use leo_ast::{Function, Statement};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Type checks and limits the program in the module documentation, returning its functions.
+    fn limit(max_arity: usize) -> Vec<Function> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let type_table = TypeTable::default();
+        let source = "program test.aleo {
+            function digits() -> (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) {
+                return (0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8);
+            }
+
+            transition main() -> u8 {
+                let t: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) = digits();
+                return t.7;
+            }
+        }";
+        let ast = parse_program(&handler, &node_builder, source);
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+        TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+
+        let ast = TupleArityLimiter::do_pass((ast, &handler, &type_table, &node_builder, max_arity)).unwrap();
+        ast.into_repr().program_scopes.into_values().next().unwrap().functions.into_iter().map(|(_, f)| f).collect()
+    }
+
+    #[test]
+    fn test_chunks_large_tuple() {
+        create_session_if_not_set_then(|_| {
+            let functions = limit(5);
+
+            let digits = &functions[0];
+            assert_eq!(digits.output.len(), 2);
+            assert_eq!(digits.output_type.to_string(), "((u8,u8,u8,u8,u8),(u8,u8,u8,u8,u8))");
+            let Statement::Return(statement) = &digits.block.statements[0] else { panic!("expected a return") };
+            assert_eq!(statement.expression.to_string(), "((0u8,1u8,2u8,3u8,4u8),(5u8,6u8,7u8,8u8,9u8))");
+
+            let main = &functions[1];
+            let Statement::Definition(definition) = &main.block.statements[0] else { panic!("expected a definition") };
+            assert_eq!(definition.type_.to_string(), "((u8,u8,u8,u8,u8),(u8,u8,u8,u8,u8))");
+            let Statement::Return(statement) = &main.block.statements[1] else { panic!("expected a return") };
+            assert_eq!(statement.expression.to_string(), "t.1.2");
+        })
+    }
+
+    #[test]
+    fn test_keeps_tuple_within_limit() {
+        create_session_if_not_set_then(|_| {
+            let functions = limit(10);
+
+            assert_eq!(functions[0].output.len(), 10);
+            let Statement::Return(statement) = &functions[1].block.statements[1] else { panic!("expected a return") };
+            assert_eq!(statement.expression.to_string(), "t.7");
+        })
+    }
+}
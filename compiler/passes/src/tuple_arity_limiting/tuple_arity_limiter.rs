@@ -0,0 +1,280 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+
+pub struct TupleArityLimiter<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The maximum number of elements in a tuple, which is at least 2.
+    pub(crate) max_arity: usize,
+}
+
+impl<'a> TupleArityLimiter<'a> {
+    /// Initializes a new `TupleArityLimiter`. A maximum arity below 2 is raised to 2.
+    pub fn new(
+        handler: &'a Handler,
+        type_table: &'a TypeTable,
+        node_builder: &'a NodeBuilder,
+        max_arity: usize,
+    ) -> Self {
+        Self { handler, type_table, node_builder, max_arity: max_arity.max(2) }
+    }
+
+    /// Returns a new node ID of the given type.
+    fn next_id(&self, type_: Option<Type>) -> NodeID {
+        let id = self.node_builder.next_id();
+        if let Some(type_) = type_ {
+            self.type_table.insert(id, type_);
+        }
+        id
+    }
+
+    /// Splits `items` into consecutive groups of `max_arity` elements, wrapping each group with `wrap`, until at most
+    /// `max_arity` items remain. A trailing group with a single element is not wrapped.
+    fn chunk<T>(&self, items: Vec<T>, wrap: &dyn Fn(Vec<T>) -> T) -> Vec<T> {
+        if items.len() <= self.max_arity {
+            return items;
+        }
+        let mut items = items.into_iter().peekable();
+        let mut groups = Vec::new();
+        while items.peek().is_some() {
+            let mut group: Vec<T> = items.by_ref().take(self.max_arity).collect();
+            groups.push(match group.len() {
+                1 => group.pop().unwrap(),
+                _ => wrap(group),
+            });
+        }
+        self.chunk(groups, wrap)
+    }
+
+    /// Returns the sequence of indices that reaches the element at `index` of a tuple of `length` elements, once the
+    /// tuple has been chunked.
+    fn path(&self, length: usize, index: usize) -> Vec<usize> {
+        if length <= self.max_arity {
+            return vec![index];
+        }
+        let (group, offset) = (index / self.max_arity, index % self.max_arity);
+        let mut path = self.path((length + self.max_arity - 1) / self.max_arity, group);
+        if self.max_arity.min(length - group * self.max_arity) > 1 {
+            path.push(offset);
+        }
+        path
+    }
+
+    /// Returns the given type with each of its tuples chunked.
+    pub(crate) fn limit_type(&self, type_: Type) -> Type {
+        match type_ {
+            Type::Tuple(tuple) => {
+                Type::Tuple(TupleType::new(self.chunk(
+                    tuple.elements().iter().map(|element| self.limit_type(element.clone())).collect(),
+                    &|group| Type::Tuple(TupleType::new(group)),
+                )))
+            }
+            type_ => type_,
+        }
+    }
+
+    /// Chunks the type of the node with the given ID in the type table.
+    fn limit_type_of(&self, id: NodeID) {
+        if let Some(type_ @ Type::Tuple(_)) = self.type_table.get(&id) {
+            self.type_table.insert(id, self.limit_type(type_));
+        }
+    }
+
+    /// Wraps a group of tuple elements in a tuple expression.
+    fn group_elements(&self, group: Vec<Expression>) -> Expression {
+        let types = group.iter().map(|element| self.type_table.get(&element.id())).collect::<Option<Vec<_>>>();
+        Expression::Tuple(TupleExpression {
+            span: group[0].span() + group[group.len() - 1].span(),
+            id: self.next_id(types.map(|types| Type::Tuple(TupleType::new(types)))),
+            elements: group,
+        })
+    }
+
+    /// Wraps a group of outputs of `function` in a single output of tuple type, with the mode shared by the group.
+    fn group_outputs(&self, function: Identifier, group: Vec<Output>) -> Output {
+        let span = group[0].span() + group[group.len() - 1].span();
+        let mode = group[0].mode();
+        if group.iter().any(|output| output.mode() != mode) {
+            self.handler.emit_err(StaticAnalyzerError::mixed_mode_output_group(function, self.max_arity, span));
+        }
+        Output::Internal(FunctionOutput {
+            mode,
+            type_: Type::Tuple(TupleType::new(group.iter().map(|output| output.type_()).collect())),
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Chunks the outputs of `function`, which together form its tuple output.
+    fn limit_outputs(&self, function: Identifier, output: Vec<Output>) -> Vec<Output> {
+        self.chunk(output, &|group| self.group_outputs(function, group))
+    }
+}
+
+impl ExpressionReconstructor for TupleArityLimiter<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        self.limit_type_of(input.id);
+        (
+            Expression::Call(CallExpression {
+                function: input.function,
+                arguments: input
+                    .arguments
+                    .into_iter()
+                    .map(|argument| self.reconstruct_expression(argument).0)
+                    .collect(),
+                external: input.external,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        self.limit_type_of(input.id);
+        (Expression::Identifier(input), Default::default())
+    }
+
+    fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
+        self.limit_type_of(input.id);
+        (
+            Expression::Ternary(TernaryExpression {
+                condition: Box::new(self.reconstruct_expression(*input.condition).0),
+                if_true: Box::new(self.reconstruct_expression(*input.if_true).0),
+                if_false: Box::new(self.reconstruct_expression(*input.if_false).0),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_tuple(&mut self, input: TupleExpression) -> (Expression, Self::AdditionalOutput) {
+        let elements = input.elements.into_iter().map(|element| self.reconstruct_expression(element).0).collect();
+        self.limit_type_of(input.id);
+        (
+            Expression::Tuple(TupleExpression {
+                elements: self.chunk(elements, &|group| self.group_elements(group)),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_tuple_access(&mut self, input: TupleAccess) -> (Expression, Self::AdditionalOutput) {
+        // The type of the accessed tuple, before it is chunked.
+        let Some(Type::Tuple(tuple_type)) = self.type_table.get(&input.tuple.id()) else {
+            return (
+                Expression::Access(AccessExpression::Tuple(TupleAccess {
+                    tuple: Box::new(self.reconstruct_expression(*input.tuple).0),
+                    index: input.index,
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            );
+        };
+        let mut path = self.path(tuple_type.length(), input.index.value());
+        let index = path.pop().unwrap();
+
+        // Access each enclosing group on the way to the element, tracking the type of each group.
+        let mut type_ = self.limit_type(Type::Tuple(tuple_type));
+        let mut tuple = self.reconstruct_expression(*input.tuple).0;
+        for step in path {
+            type_ = match type_ {
+                Type::Tuple(tuple_type) => tuple_type.elements()[step].clone(),
+                type_ => type_,
+            };
+            tuple = Expression::Access(AccessExpression::Tuple(TupleAccess {
+                tuple: Box::new(tuple),
+                index: NonNegativeNumber::from(step),
+                span: input.span,
+                id: self.next_id(Some(type_.clone())),
+            }));
+        }
+        self.limit_type_of(input.id);
+
+        (
+            Expression::Access(AccessExpression::Tuple(TupleAccess {
+                tuple: Box::new(tuple),
+                index: NonNegativeNumber::from(index),
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for TupleArityLimiter<'_> {
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: self.reconstruct_expression(input.place).0,
+                type_: self.limit_type(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for TupleArityLimiter<'_> {
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            docs: input.docs,
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            type_params: input.type_params,
+            input: input.input,
+            output: self.limit_outputs(input.identifier, input.output),
+            output_type: self.limit_type(input.output_type),
+            const_predicates: input
+                .const_predicates
+                .into_iter()
+                .map(|predicate| self.reconstruct_function_predicate(predicate))
+                .collect(),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: self.limit_outputs(finalize.identifier, finalize.output),
+                output_type: self.limit_type(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }),
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Tuple Spread Expansion pass traverses the AST and replaces each spread of a tuple expression in a tuple
+//! expression with the elements of the spread tuple.
+//! Nested spreads are flattened fully.
+//! Spreads of other expressions, e.g. variables, are left intact, and are reported by the type checker.
+//! Since spreads cannot be type checked, this pass runs on the parsed AST.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8, c: u8) -> (u8, u8, u8) {
+//!     return (a, ..(b, c));
+//! }
+//! ```
+//!
+//! The tuple spread expansion pass produces the following code.
+//! ```leo
+//! transition main(a: u8, b: u8, c: u8) -> (u8, u8, u8) {
+//!     return (a, b, c);
+//! }
+//! ```
+
+pub mod tuple_spread_expander;
+pub use tuple_spread_expander::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for TupleSpreadExpander {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut reconstructor = TupleSpreadExpander::new();
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{Expression, NodeBuilder, Statement};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and expands a program whose transition returns `expression`, returning the returned expression.
+    fn expand_return(expression: &str) -> Expression {
+        let handler = Handler::default();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8, b: u8, c: u8, t: (u8, u8)) -> (u8, u8, u8) {{
+                    return {expression};
+                }}
+            }}"
+        );
+        let ast = TupleSpreadExpander::do_pass(parse_program(&handler, &NodeBuilder::default(), &source)).unwrap();
+        let mut function = ast.into_repr().program_scopes.into_values().next().unwrap().functions.pop().unwrap().1;
+        match function.block.statements.pop() {
+            Some(Statement::Return(statement)) => statement.expression,
+            statement => panic!("expected a return statement, found `{statement:?}`"),
+        }
+    }
+
+    #[test]
+    fn test_expands_tuple_spread() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(expand_return("(a, ..(b, c))").to_string(), "(a,b,c)");
+        })
+    }
+
+    #[test]
+    fn test_expands_nested_spreads() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(expand_return("(..(a, ..(b, c)))").to_string(), "(a,b,c)");
+        })
+    }
+
+    #[test]
+    fn test_preserves_spread_of_unknown_arity() {
+        create_session_if_not_set_then(|_| {
+            let expression = expand_return("(a, ..t)");
+
+            let Expression::Tuple(tuple) = &expression else {
+                panic!("expected a tuple, found `{expression}`");
+            };
+            assert_eq!(tuple.elements.len(), 2);
+            assert!(matches!(&tuple.elements[1], Expression::Spread(spread) if spread.expression.to_string() == "t"));
+        })
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+
+#[derive(Default)]
+pub struct TupleSpreadExpander;
+
+impl TupleSpreadExpander {
+    /// Initializes a new `TupleSpreadExpander`.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ExpressionReconstructor for TupleSpreadExpander {
+    type AdditionalOutput = ();
+
+    /// Replaces each spread of a tuple expression with the elements of the tuple.
+    /// Since the spread tuple is reconstructed first, nested spreads are flattened fully.
+    /// Spreads of other expressions, whose number of elements is unknown, are left intact.
+    fn reconstruct_tuple(&mut self, input: TupleExpression) -> (Expression, Self::AdditionalOutput) {
+        let mut elements = Vec::with_capacity(input.elements.len());
+        for element in input.elements {
+            match self.reconstruct_expression(element).0 {
+                Expression::Spread(spread) => match *spread.expression {
+                    Expression::Tuple(tuple) => elements.extend(tuple.elements),
+                    expression => elements.push(Expression::Spread(SpreadExpression {
+                        expression: Box::new(expression),
+                        span: spread.span,
+                        id: spread.id,
+                    })),
+                },
+                element => elements.push(element),
+            }
+        }
+
+        (Expression::Tuple(TupleExpression { elements, span: input.span, id: input.id }), Default::default())
+    }
+}
+
+impl StatementReconstructor for TupleSpreadExpander {}
+
+impl ProgramReconstructor for TupleSpreadExpander {}
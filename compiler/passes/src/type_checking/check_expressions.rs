@@ -46,6 +46,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         let output = match input {
             Expression::Access(access) => self.visit_access(access, additional),
             Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Ascription(ascription) => self.visit_ascription(ascription, additional),
             Expression::Binary(binary) => self.visit_binary(binary, additional),
             Expression::Call(call) => self.visit_call(call, additional),
             Expression::Cast(cast) => self.visit_cast(cast, additional),
@@ -53,6 +54,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             Expression::Err(err) => self.visit_err(err, additional),
             Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
             Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Spread(spread) => self.visit_spread(spread, additional),
             Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
             Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
             Expression::Unary(unary) => self.visit_unary(unary, additional),
@@ -626,6 +628,14 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_ascription(&mut self, input: &'a AscriptionExpression, expected: &Self::AdditionalInput) -> Self::Output {
+        // Check that the inner expression has the ascribed type.
+        self.visit_expression(&input.expression, &Some(input.type_.clone()));
+
+        // Check that the expected type matches the ascribed type.
+        Some(self.assert_and_return_type(input.type_.clone(), expected, input.span()))
+    }
+
     fn visit_cast(&mut self, input: &'a CastExpression, expected: &Self::AdditionalInput) -> Self::Output {
         // Check that the target type of the cast expression is a castable type.
         self.assert_castable_type(&Some(input.type_.clone()), input.span());
@@ -684,6 +694,12 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         Default::default()
     }
 
+    fn visit_spread(&mut self, input: &'a SpreadExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        // Spreads of known arity are expanded before type checking, so the remaining ones cannot be typed.
+        self.emit_err(TypeCheckerError::spread_not_expanded(input, input.span()));
+        None
+    }
+
     fn visit_identifier(&mut self, input: &'a Identifier, expected: &Self::AdditionalInput) -> Self::Output {
         if let Some(var) = self.symbol_table.borrow().lookup_variable(input.name) {
             Some(self.assert_and_return_type(var.type_.clone(), expected, input.span()))
@@ -104,6 +104,14 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_struct(&mut self, input: &'a Struct) {
+        // The struct's type parameters are in scope for its member types.
+        // Note that type parameters are rejected, since they cannot be instantiated until generics are supported.
+        self.type_params = input.type_params.iter().map(|type_param| type_param.name).collect();
+        if let (Some(first), Some(last)) = (input.type_params.first(), input.type_params.last()) {
+            let kind = if input.is_record { "record" } else { "struct" };
+            self.emit_err(TypeCheckerError::type_parameters_not_supported(kind, input.name(), first.span + last.span));
+        }
+
         // Check for conflicting struct/record member names.
         let mut used = HashSet::new();
         // TODO: Better span to target duplicate member.
@@ -213,6 +221,17 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
         self.variant = Some(function.variant);
 
+        // The function's type parameters are in scope for its signature and body.
+        // Note that type parameters are rejected, since they cannot be instantiated until generics are supported.
+        self.type_params = function.type_params.iter().map(|type_param| type_param.name).collect();
+        if let (Some(first), Some(last)) = (function.type_params.first(), function.type_params.last()) {
+            self.emit_err(TypeCheckerError::type_parameters_not_supported(
+                "function",
+                function.name(),
+                first.span + last.span,
+            ));
+        }
+
         // Lookup function metadata in the symbol table.
         // Note that this unwrap is safe since function metadata is stored in a prior pass.
         let function_index = self.symbol_table.borrow().lookup_fn_symbol(function.identifier.name).unwrap().id;
@@ -285,7 +304,11 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                         // If the function is not a transition function, then it cannot output a record.
                         if let Type::Identifier(identifier) = function_output.type_ {
                             if !matches!(function.variant, Variant::Transition)
-                                && self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record
+                                && self
+                                    .symbol_table
+                                    .borrow()
+                                    .lookup_struct(identifier.name)
+                                    .map_or(false, |struct_| struct_.is_record)
                             {
                                 self.emit_err(TypeCheckerError::function_cannot_output_record(function_output.span));
                             }
@@ -304,6 +327,12 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             }
         });
 
+        // Type check the predicates in the function's `where` clause.
+        // Note that their values are checked at compile time by the predicate checking pass.
+        function.const_predicates.iter().for_each(|predicate| {
+            self.visit_expression(predicate, &Some(Type::Boolean));
+        });
+
         self.visit_block(&function.block);
 
         // If the function has a return type, then check that it has a return.
@@ -353,8 +382,13 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     }
                     // Check that the input parameter is not a record.
                     if let Type::Identifier(identifier) = input_var.type_() {
-                        // Note that this unwrap is safe, as the type is defined.
-                        if self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record {
+                        // Note that type parameters are abstract types and are never records.
+                        if self
+                            .symbol_table
+                            .borrow()
+                            .lookup_struct(identifier.name)
+                            .map_or(false, |struct_| struct_.is_record)
+                        {
                             self.emit_err(TypeCheckerError::finalize_cannot_take_record_as_input(input_var.span()))
                         }
                     }
@@ -394,8 +428,13 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     }
                     // Check that the output is not a record.
                     if let Type::Identifier(identifier) = output_type.type_() {
-                        // Note that this unwrap is safe, as the type is defined.
-                        if self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record {
+                        // Note that type parameters are abstract types and are never records.
+                        if self
+                            .symbol_table
+                            .borrow()
+                            .lookup_struct(identifier.name)
+                            .map_or(false, |struct_| struct_.is_record)
+                        {
                             self.emit_err(TypeCheckerError::finalize_cannot_output_record(output_type.span()))
                         }
                     }
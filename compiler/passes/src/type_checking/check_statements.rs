@@ -30,6 +30,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
 
         match input {
+            Statement::Annotated(stmt) => self.visit_annotated(stmt),
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
@@ -43,6 +44,13 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_annotated(&mut self, input: &'a AnnotatedStatement) {
+        // Annotated statements must be expanded before type checking.
+        for annotation in input.annotations.iter() {
+            self.emit_err(TypeCheckerError::annotated_statement_not_expanded(annotation, annotation.span));
+        }
+    }
+
     fn visit_assert(&mut self, input: &'a AssertStatement) {
         match &input.variant {
             AssertVariant::Assert(expr) => {
@@ -295,6 +303,11 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         let iter_type = &Some(input.type_.clone());
         self.assert_int_type(iter_type, input.variable.span);
 
+        // Stepped loops are lowered into unit-step loops before type checking, so the remaining ones cannot be checked.
+        if let Some(step) = &input.step {
+            self.emit_err(TypeCheckerError::loop_step_not_normalized(step, step.span()));
+        }
+
         // Create a new scope for the loop body.
         let scope_index = self.create_child_scope();
 
@@ -22,6 +22,7 @@ use leo_span::{Span, Symbol};
 
 use snarkvm_console::network::{Network, Testnet3};
 
+use indexmap::IndexSet;
 use itertools::Itertools;
 use std::cell::RefCell;
 
@@ -51,6 +52,8 @@ pub struct TypeChecker<'a> {
     pub(crate) is_imported: bool,
     /// Whether or not we are currently traversing a return statement.
     pub(crate) is_return: bool,
+    /// The type parameters in scope, which are treated as abstract types.
+    pub(crate) type_params: IndexSet<Symbol>,
 }
 
 const ADDRESS_TYPE: Type = Type::Address;
@@ -118,6 +121,7 @@ impl<'a> TypeChecker<'a> {
             is_finalize: false,
             is_imported: false,
             is_return: false,
+            type_params: IndexSet::new(),
         }
     }
 
@@ -1068,8 +1072,11 @@ impl<'a> TypeChecker<'a> {
                 is_valid = false;
                 self.emit_err(TypeCheckerError::strings_are_not_supported(span));
             }
-            // Check that the named composite type has been defined.
-            Type::Identifier(identifier) if self.symbol_table.borrow().lookup_struct(identifier.name).is_none() => {
+            // Check that the named composite type has been defined or is a type parameter in scope.
+            Type::Identifier(identifier)
+                if !self.type_params.contains(&identifier.name)
+                    && self.symbol_table.borrow().lookup_struct(identifier.name).is_none() =>
+            {
                 is_valid = false;
                 self.emit_err(TypeCheckerError::undefined_type(identifier.name, span));
             }
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Type Parameter Checking pass traverses the AST and checks the type parameters of functions and structs.
+//! Each named type in a definition, i.e. in its signature, its members, or the types ascribed in its body, must be
+//! either a type parameter declared by the definition or a defined struct or record; otherwise an error is reported.
+//! Each declared type parameter that is never referenced is reported with a warning.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function first<T, U>(a: T, b: T) -> V {
+//!     return a;
+//! }
+//! ```
+//!
+//! The type parameter checking pass reports an error for `V`, which is not declared, and a warning for `U`, which is
+//! never used.
+
+pub mod type_param_checker;
+pub use type_param_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for TypeParamChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = TypeParamChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_accepts_declared_type_param() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let source = "program test.aleo { function id<T>(a: T) -> T { let b: T = a; return b; } }";
+            let ast = parse_program(&handler, &NodeBuilder::default(), source);
+            assert!(TypeParamChecker::do_pass((&ast, &handler)).is_ok());
+            assert_eq!(handler.warning_count(), 0);
+        })
+    }
+
+    #[test]
+    fn test_rejects_undeclared_type_param() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let source = "program test.aleo { function id<T>(a: T) -> U { return a; } }";
+            let ast = parse_program(&handler, &NodeBuilder::default(), source);
+            assert!(TypeParamChecker::do_pass((&ast, &handler)).is_err());
+        })
+    }
+
+    #[test]
+    fn test_warns_on_unused_type_param() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let source =
+                "program test.aleo { struct Pair<T, U> { a: T, b: T } function main(a: u8) -> u8 { return a; } }";
+            let ast = parse_program(&handler, &NodeBuilder::default(), source);
+            assert!(TypeParamChecker::do_pass((&ast, &handler)).is_ok());
+            assert_eq!(handler.warning_count(), 1);
+        })
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError, StaticAnalyzerWarning};
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A compiler pass that checks that every type parameter referenced by a function or struct is declared by it, and
+/// that every declared type parameter is referenced.
+pub struct TypeParamChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The names of the structs and records defined in the program and its imports.
+    structs: IndexSet<Symbol>,
+    /// The name of the function or struct currently being checked.
+    definition: Symbol,
+    /// The type parameters declared by the current definition, mapped to whether they are referenced.
+    type_params: IndexMap<Symbol, (Identifier, bool)>,
+}
+
+impl<'a> TypeParamChecker<'a> {
+    /// Returns a new `TypeParamChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, structs: IndexSet::new(), definition: Symbol::intern(""), type_params: IndexMap::new() }
+    }
+
+    /// Records the names of the structs defined in `program` and its imports.
+    fn collect_structs(&mut self, program: &Program) {
+        program.imports.values().for_each(|(import, _)| self.collect_structs(import));
+        for scope in program.program_scopes.values() {
+            self.structs.extend(scope.structs.keys().copied());
+        }
+    }
+
+    /// Enters the definition `name` with the given type parameters.
+    fn enter(&mut self, name: Symbol, type_params: &[Identifier]) {
+        self.definition = name;
+        self.type_params = type_params.iter().map(|type_param| (type_param.name, (*type_param, false))).collect();
+    }
+
+    /// Warns about each type parameter of the current definition that is never referenced.
+    fn exit(&mut self) {
+        for (type_param, used) in std::mem::take(&mut self.type_params).into_values() {
+            if !used {
+                self.handler.emit_warning(
+                    StaticAnalyzerWarning::unused_type_parameter(type_param.name, self.definition, type_param.span)
+                        .into(),
+                );
+            }
+        }
+    }
+
+    /// Marks the type parameters referenced by `type_`, and reports each named type that is neither a declared type
+    /// parameter nor a known struct.
+    fn check_type(&mut self, type_: &Type, span: Span) {
+        match type_ {
+            Type::Identifier(identifier) => match self.type_params.get_mut(&identifier.name) {
+                Some((_, used)) => *used = true,
+                None if self.structs.contains(&identifier.name) => {}
+                None => self.handler.emit_err(StaticAnalyzerError::undeclared_type_parameter(
+                    identifier.name,
+                    self.definition,
+                    span,
+                )),
+            },
+            Type::Array(array_type) => self.check_type(array_type.element_type(), span),
+            Type::Tuple(tuple_type) => tuple_type.elements().iter().for_each(|type_| self.check_type(type_, span)),
+            Type::Mapping(mapping_type) => {
+                self.check_type(&mapping_type.key, span);
+                self.check_type(&mapping_type.value, span);
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks the types of the internal inputs and outputs of a function or finalize block.
+    fn check_signature(&mut self, input: &[Input], output: &[Output]) {
+        for input in input {
+            if let Input::Internal(input) = input {
+                self.check_type(&input.type_, input.span);
+            }
+        }
+        for output in output {
+            if let Output::Internal(output) = output {
+                self.check_type(&output.type_, output.span);
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for TypeParamChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_type(&input.type_, input.span);
+        self.visit_expression(&input.expression, additional);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for TypeParamChecker<'a> {
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.check_type(&input.type_, input.span);
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.check_type(&input.type_, input.span);
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.check_type(&input.type_, input.span);
+        self.visit_expression(&input.start, &Default::default());
+        self.visit_expression(&input.stop, &Default::default());
+        if let Some(step) = &input.step {
+            self.visit_expression(step, &Default::default());
+        }
+        self.visit_block(&input.block);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for TypeParamChecker<'a> {
+    fn visit_program(&mut self, input: &'a Program) {
+        self.collect_structs(input);
+        input.imports.values().for_each(|(import, _)| self.visit_import(import));
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+    }
+
+    fn visit_struct(&mut self, input: &'a Struct) {
+        self.enter(input.name(), &input.type_params);
+        for member in input.members.iter() {
+            self.check_type(&member.type_, member.span);
+        }
+        self.exit();
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.enter(input.name(), &input.type_params);
+        self.check_signature(&input.input, &input.output);
+        input.const_predicates.iter().for_each(|predicate| self.visit_function_predicate(predicate));
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.check_signature(&finalize.input, &finalize.output);
+            self.visit_block(&finalize.block);
+        }
+        self.exit();
+    }
+}
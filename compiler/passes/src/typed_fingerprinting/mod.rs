@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Typed fingerprinting computes a fingerprint of a function that can key a cache of type checking results.
+//! The fingerprint combines the structure of the function, i.e. its signature and body with spans and node IDs left
+//! out, with the signatures of the functions, structs, records, mappings, and constants that the function references.
+//! So the fingerprint of a function changes when the function changes, or when the signature of one of its
+//! dependencies does, e.g. when a callee takes a new input; it does not change when only the body of a callee does,
+//! since that cannot affect the type checking of the caller.
+//! Fingerprints are computed with the 64-bit FNV-1a hash, so they are stable across builds and platforms.
+
+pub mod signature_env;
+pub use signature_env::*;
+
+use crate::FunctionExtractor;
+
+use leo_ast::{Function, ProgramVisitor};
+
+/// The 64-bit FNV-1a offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The 64-bit FNV-1a prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Folds the bytes of `part`, followed by a separator, into the FNV-1a hash `hash`.
+fn fnv1a(hash: u64, part: &str) -> u64 {
+    part.bytes().chain([0xff]).fold(hash, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME))
+}
+
+/// Returns the fingerprint of `function`, combining its structure with the signatures in `env` of the items it
+/// references, in the order in which they are first referenced.
+pub fn typed_fingerprint(function: &Function, env: &SignatureEnv) -> u64 {
+    let mut references = FunctionExtractor::new(env.program);
+    references.visit_function(function);
+
+    references
+        .referenced
+        .iter()
+        .filter(|name| **name != function.identifier.name)
+        .filter_map(|name| env.signature(*name))
+        .fold(fnv1a(FNV_OFFSET_BASIS, &function.to_string()), fnv1a)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{NodeBuilder, Program};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns the fingerprint of `main` in a program where it calls the function `add` declared by `add`.
+    fn fingerprint(add: &str) -> u64 {
+        let handler = Handler::default();
+        let source = format!(
+            "program test.aleo {{
+                {add}
+
+                transition main(x: u8) -> u8 {{
+                    return add(x, 1u8);
+                }}
+            }}"
+        );
+        let program: Program = parse_program(&handler, &NodeBuilder::default(), &source).into_repr();
+        let env = SignatureEnv::new(&program);
+        let main = &program.program_scopes.values().next().unwrap().functions[1].1;
+        typed_fingerprint(main, &env)
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_callee_body() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(
+                fingerprint("function add(a: u8, b: u8) -> u8 { return a + b; }"),
+                fingerprint("function add(a: u8, b: u8) -> u8 { return b + a; }"),
+            );
+        })
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_callee_signature() {
+        create_session_if_not_set_then(|_| {
+            assert_ne!(
+                fingerprint("function add(a: u8, b: u8) -> u8 { return a + b; }"),
+                fingerprint("function add(a: u8, constant b: u8) -> u8 { return a + b; }"),
+            );
+            assert_ne!(
+                fingerprint("function add(a: u8, b: u8) -> u8 { return a + b; }"),
+                fingerprint("function add(a: u8, b: u8) -> public u8 { return a + b; }"),
+            );
+        })
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// The signatures of the items of a program that a function may depend on: the inputs and outputs of each function,
+/// the members of each struct and record, the key and value types of each mapping, and the type and value of each
+/// constant. The body of a function is not part of its signature.
+pub struct SignatureEnv<'a> {
+    /// The program whose items the signatures describe.
+    pub(crate) program: &'a Program,
+    /// A mapping from each item to its signature.
+    pub(crate) signatures: IndexMap<Symbol, String>,
+}
+
+impl<'a> SignatureEnv<'a> {
+    /// Returns the signatures of the items of the program scopes of `program`.
+    pub fn new(program: &'a Program) -> Self {
+        let mut signatures = IndexMap::new();
+        for scope in program.program_scopes.values() {
+            signatures.extend(scope.functions.iter().map(|(name, function)| (*name, Self::function(function))));
+            signatures.extend(scope.structs.iter().map(|(name, struct_)| (*name, struct_.to_string())));
+            signatures.extend(scope.mappings.iter().map(|(name, mapping)| (*name, mapping.to_string())));
+            signatures.extend(scope.consts.iter().map(|(name, constant)| (*name, constant.to_string())));
+        }
+        Self { program, signatures }
+    }
+
+    /// Returns the signature of the item `name`, if it is an item of the program.
+    pub fn signature(&self, name: Symbol) -> Option<&str> {
+        self.signatures.get(&name).map(String::as_str)
+    }
+
+    /// Returns the signature of a function: its variant, name, inputs, and outputs, and those of its finalize block.
+    fn function(function: &Function) -> String {
+        let join = |items: Vec<String>| items.join(", ");
+        let mut signature = format!(
+            "{:?} {}({}) -> ({})",
+            function.variant,
+            function.identifier,
+            join(function.input.iter().map(Input::to_string).collect()),
+            join(function.output.iter().map(Output::to_string).collect()),
+        );
+        if let Some(finalize) = &function.finalize {
+            signature.push_str(&format!(
+                " finalize({}) -> ({})",
+                join(finalize.input.iter().map(Input::to_string).collect()),
+                join(finalize.output.iter().map(Output::to_string).collect()),
+            ));
+        }
+        signature
+    }
+}
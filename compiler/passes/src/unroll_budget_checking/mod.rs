@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Unroll Budget Checking pass traverses the AST and checks that the loops of each function, which are fully
+//! unrolled, do not blow up the size of the compiled program.
+//! A loop unrolls into its trip count multiplied by the iterations of the loops nested in its body, and the
+//! iterations of the outermost loops of a function, including those of its finalize block, are summed.
+//! If the total exceeds a configurable budget, an error is reported at the outermost loop that crosses it.
+//! A loop whose bounds or step are not integer literals, or whose step is zero, is reported as unbounded.
+//! Since bounds must be literals, the pass should run after constants have been propagated.
+//!
+//! Consider the following Leo code, checked with a budget of 100.
+//! ```leo
+//! function main(a: u8, n: u8) -> u8 {
+//!     for i: u8 in 0u8..8u8 {
+//!         for j: u8 in 0u8..16u8 {
+//!             a = a + j;
+//!         }
+//!     }
+//!     for k: u8 in 0u8..n {
+//!         a = a + k;
+//!     }
+//!     return a;
+//! }
+//! ```
+//!
+//! The unroll budget checking pass reports the first loop, which unrolls into 128 iterations, and the second loop,
+//! whose stop bound is not known at compile time.
+
+pub mod unroll_budget_checker;
+pub use unroll_budget_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for UnrollBudgetChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, u128);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, budget): Self::Input) -> Self::Output {
+        let mut visitor = UnrollBudgetChecker::new(handler, budget);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Parses and checks a function with inputs `a: u8` and `n: u8` and the given body, with a budget of 100.
+    fn check_body(body: &str) -> Result<()> {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!("program test.aleo {{ function main(a: u8, n: u8) -> u8 {{ {body} return a; }} }}");
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        UnrollBudgetChecker::do_pass((&ast, &handler, 100))
+    }
+
+    #[test]
+    fn test_accepts_nested_loops_within_budget() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body(
+                "for i: u8 in 0u8..10u8 { for j: u8 in 0u8..=9u8 { a = a + j; } } for k: u8 in 5u8..0u8 {}"
+            )
+            .is_ok());
+        })
+    }
+
+    #[test]
+    fn test_rejects_nested_loops_over_budget() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("for i: u8 in 0u8..8u8 { for j: u8 in 0u8..16u8 { a = a + j; } }").is_err());
+        })
+    }
+
+    #[test]
+    fn test_rejects_unbounded_loop() {
+        create_session_if_not_set_then(|_| {
+            assert!(check_body("for i: u8 in 0u8..n { a = a + i; }").is_err());
+        })
+    }
+}
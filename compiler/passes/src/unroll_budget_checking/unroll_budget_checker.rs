@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::StepNormalizer;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerError};
+use leo_span::Span;
+
+/// A compiler pass that checks that the loops of each function unroll into at most `budget` iterations, and that the
+/// number of iterations of each loop is known at compile time.
+pub struct UnrollBudgetChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The maximum number of iterations the loops of a function may unroll into.
+    budget: u128,
+}
+
+impl<'a> UnrollBudgetChecker<'a> {
+    /// Returns a new `UnrollBudgetChecker`.
+    pub fn new(handler: &'a Handler, budget: u128) -> Self {
+        Self { handler, budget }
+    }
+
+    /// Returns the number of iterations of the loop `input`, or `None` if its bounds or step are not integer literals,
+    /// or if its step is zero.
+    fn trip_count(input: &IterationStatement) -> Option<u128> {
+        let start = StepNormalizer::integer_value(&input.start)?;
+        let stop = StepNormalizer::integer_value(&input.stop)?;
+        let step = match &input.step {
+            Some(step) => StepNormalizer::integer_value(step).filter(|step| *step != 0)?,
+            None => 1,
+        };
+        u128::try_from(StepNormalizer::iteration_count(start, stop, step, input.inclusive)).ok()
+    }
+
+    /// Appends the span and the number of unrolled iterations of each outermost loop in `block` to `nests`.
+    fn collect_nests(&self, block: &Block, nests: &mut Vec<(Span, u128)>) {
+        block.statements.iter().for_each(|statement| self.collect_statement_nests(statement, nests));
+    }
+
+    /// Appends the span and the number of unrolled iterations of each outermost loop in `statement` to `nests`.
+    fn collect_statement_nests(&self, statement: &Statement, nests: &mut Vec<(Span, u128)>) {
+        match statement {
+            Statement::Block(block) => self.collect_nests(block, nests),
+            Statement::Conditional(conditional) => {
+                self.collect_nests(&conditional.then, nests);
+                if let Some(otherwise) = &conditional.otherwise {
+                    self.collect_statement_nests(otherwise, nests);
+                }
+            }
+            Statement::Iteration(iteration) => nests.push((iteration.span, self.unrolled_count(iteration))),
+            _ => {}
+        }
+    }
+
+    /// Returns the number of iterations the loop `input` unrolls into, i.e. its trip count multiplied by the number of
+    /// iterations of the loops nested in its body, if any.
+    /// A loop whose trip count is unknown is reported, and counted as a single iteration.
+    fn unrolled_count(&self, input: &IterationStatement) -> u128 {
+        let trip_count = Self::trip_count(input).unwrap_or_else(|| {
+            self.handler.emit_err(StaticAnalyzerError::unbounded_loop(input.span));
+            1
+        });
+        let mut nests = Vec::new();
+        self.collect_nests(&input.block, &mut nests);
+        let inner = if nests.is_empty() {
+            1
+        } else {
+            nests.iter().fold(0u128, |total, (_, count)| total.saturating_add(*count))
+        };
+        trip_count.saturating_mul(inner)
+    }
+
+    /// Reports the first of the outermost loops `nests` of a function at which the total number of unrolled iterations
+    /// exceeds the budget.
+    fn check_nests(&self, nests: Vec<(Span, u128)>) {
+        let total = nests.iter().fold(0u128, |total, (_, count)| total.saturating_add(*count));
+        let mut running = 0u128;
+        for (span, count) in nests {
+            running = running.saturating_add(count);
+            if running > self.budget {
+                self.handler.emit_err(StaticAnalyzerError::loop_unroll_budget_exceeded(total, self.budget, span));
+                break;
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for UnrollBudgetChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for UnrollBudgetChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for UnrollBudgetChecker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        let mut nests = Vec::new();
+        self.collect_nests(&input.block, &mut nests);
+        if let Some(finalize) = &input.finalize {
+            self.collect_nests(&finalize.block, &mut nests);
+        }
+        self.check_nests(nests);
+    }
+}
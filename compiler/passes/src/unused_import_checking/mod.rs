@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Unused Import Checking pass traverses the AST and warns about imported programs that are never used.
+//! An import is used if the program calls one of its functions, e.g. `foo.leo/bar()`, or takes or returns one of its
+//! records. The references are collected with the same dependency analysis as the function extraction pass.
+//! Imports name whole programs, so there are no glob imports to handle.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! import foo.leo;
+//!
+//! program test.aleo {
+//!     transition main(a: u8) -> u8 {
+//!         return a;
+//!     }
+//! }
+//! ```
+//!
+//! The unused import checking pass reports a warning for the import of `foo`.
+
+pub mod unused_import_checker;
+pub use unused_import_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for UnusedImportChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = UnusedImportChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::{NodeBuilder, Program};
+    use leo_span::{symbol::create_session_if_not_set_then, Span, Symbol};
+
+    /// Checks a transition with the given body in a program importing `foo`, returning the number of warnings.
+    /// The import is added to the parsed program directly, since parsing it would read `foo.leo` from disk.
+    fn count_warnings(body: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                transition main(a: u8) -> u8 {{
+                    {body}
+                }}
+            }}"
+        );
+        let mut program = parse_program(&handler, &NodeBuilder::default(), &source).into_repr();
+        program.imports.insert(Symbol::intern("foo"), (Program::default(), Span::default()));
+        UnusedImportChecker::do_pass((&Ast::new(program), &handler)).unwrap();
+        handler.warning_count()
+    }
+
+    #[test]
+    fn test_warns_about_unused_import() {
+        create_session_if_not_set_then(|_| assert_eq!(count_warnings("return a;"), 1))
+    }
+
+    #[test]
+    fn test_accepts_used_import() {
+        create_session_if_not_set_then(|_| assert_eq!(count_warnings("return foo.leo/bar(a);"), 0))
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::FunctionExtractor;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerWarning};
+
+/// A compiler pass that warns about imported programs that are never referenced, neither by an external call nor
+/// by an external record type.
+pub struct UnusedImportChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> UnusedImportChecker<'a> {
+    /// Returns a new `UnusedImportChecker`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for UnusedImportChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for UnusedImportChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for UnusedImportChecker<'a> {
+    fn visit_program(&mut self, input: &'a Program) {
+        // The dependencies of every item of the program include the imported programs that it references.
+        let mut dependencies = FunctionExtractor::new(input);
+        input.program_scopes.values().for_each(|scope| dependencies.visit_program_scope(scope));
+
+        input.imports.iter().filter(|(name, _)| !dependencies.programs.contains(*name)).for_each(
+            |(name, (_, span))| {
+                self.handler.emit_warning(StaticAnalyzerWarning::unused_import(name, *span).into());
+            },
+        );
+    }
+}
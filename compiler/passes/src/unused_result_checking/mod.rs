@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Unused Result Checking pass traverses the AST and warns about expression statements whose expression has no
+//! side effects, since their results are discarded and the statements are dead.
+//! Calls to impure functions, external calls, and mapping operations are side effects, so statements containing them
+//! are allowed.
+//! Note that this pass relies on the purity analysis to determine which functions are pure.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u8 {
+//!     a + b;
+//!     return a;
+//! }
+//! ```
+//!
+//! The unused result checking pass reports a warning for `a + b;`.
+
+pub mod unused_result_checker;
+pub use unused_result_checker::*;
+
+use crate::{Pass, PurityAnalyzer};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for UnusedResultChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let purities = PurityAnalyzer::do_pass(ast);
+        let mut visitor = UnusedResultChecker::new(handler, &purities);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::parse_program;
+
+    use leo_ast::NodeBuilder;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Checks a program with an impure function and a transition with the given body, returning the number of warnings.
+    fn count_warnings(body: &str) -> usize {
+        let (handler, _) = Handler::new_with_buf();
+        let source = format!(
+            "program test.aleo {{
+                function side_effecting(a: u8) -> u8 {{
+                    assert(a > 0u8);
+                    return a;
+                }}
+                transition main(a: u8, b: u8) -> u8 {{
+                    {body}
+                    return a;
+                }}
+            }}"
+        );
+        let ast = parse_program(&handler, &NodeBuilder::default(), &source);
+        UnusedResultChecker::do_pass((&ast, &handler)).unwrap();
+        handler.warning_count()
+    }
+
+    #[test]
+    fn test_warns_about_pure_expression_statement() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(count_warnings("a + b;"), 1);
+        })
+    }
+
+    #[test]
+    fn test_accepts_side_effecting_call() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(count_warnings("side_effecting(a);"), 0);
+        })
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, StaticAnalyzerWarning};
+use leo_span::{sym, Symbol};
+
+use indexmap::IndexMap;
+
+/// A compiler pass that warns about expression statements whose expression has no side effects,
+/// since their results are discarded.
+pub struct UnusedResultChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A mapping from each function to whether or not it is pure, as computed by the purity analysis.
+    purities: &'a IndexMap<Symbol, bool>,
+    /// Whether the expression being traversed is free of side effects so far.
+    is_pure: bool,
+}
+
+impl<'a> UnusedResultChecker<'a> {
+    /// Returns a new `UnusedResultChecker`.
+    pub fn new(handler: &'a Handler, purities: &'a IndexMap<Symbol, bool>) -> Self {
+        Self { handler, purities, is_pure: true }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for UnusedResultChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                // Mapping operations and random number generation are side effects.
+                if let Type::Identifier(Identifier { name: sym::Mapping | sym::ChaCha, .. }) = function.ty {
+                    self.is_pure = false;
+                }
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // External calls and calls to unknown functions are conservatively assumed to be impure.
+        self.is_pure &= match (&input.external, &*input.function) {
+            (None, Expression::Identifier(callee)) => self.purities.get(&callee.name).copied().unwrap_or(false),
+            _ => false,
+        };
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for UnusedResultChecker<'a> {
+    fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) {
+        self.is_pure = true;
+        self.visit_expression(&input.expression, &Default::default());
+        if self.is_pure {
+            self.handler.emit_warning(StaticAnalyzerWarning::unused_pure_expression(input.span).into());
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for UnusedResultChecker<'a> {}
@@ -234,11 +234,13 @@ symbols! {
     assert_eq,
     assert_neq,
     caller,
+    cfg,
     console,
     Const: "const",
     constant,
     decrement,
     Else: "else",
+    feature,
     finalize,
     For: "for",
     function,
@@ -253,14 +255,19 @@ symbols! {
     main,
     mapping,
     Mut: "mut",
+    repeat,
     Return: "return",
     SelfLower: "self",
     SelfUpper: "Self",
     signer,
     Star: "*",
+    step,
+    test,
     then,
     transition,
+    truncate,
     Type: "type",
+    Where: "where",
 
     aleo,
     public,
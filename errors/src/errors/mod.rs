@@ -48,6 +48,10 @@ pub use self::package::*;
 pub mod parser;
 pub use self::parser::*;
 
+/// Contains the Static Analyzer error definitions.
+pub mod static_analyzer;
+pub use self::static_analyzer::*;
+
 /// Contains the Type Checker error definitions.
 pub mod type_checker;
 
@@ -84,6 +88,9 @@ pub enum LeoError {
     /// Represents a Flatten Error in a Leo Error.
     #[error(transparent)]
     FlattenError(#[from] FlattenError),
+    /// Represents a Static Analyzer Error in a Leo Error.
+    #[error(transparent)]
+    StaticAnalyzerError(#[from] StaticAnalyzerError),
     /// Purely for just exiting with the correct status code and
     /// not re-displaying an error.
     #[error("")]
@@ -108,6 +115,7 @@ impl LeoError {
             TypeCheckerError(error) => error.error_code(),
             LoopUnrollerError(error) => error.error_code(),
             FlattenError(error) => error.error_code(),
+            StaticAnalyzerError(error) => error.error_code(),
             LastErrorCode(_) => unreachable!(),
             Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
         }
@@ -127,6 +135,7 @@ impl LeoError {
             TypeCheckerError(error) => error.exit_code(),
             LoopUnrollerError(error) => error.exit_code(),
             FlattenError(error) => error.exit_code(),
+            StaticAnalyzerError(error) => error.exit_code(),
             LastErrorCode(code) => *code,
             Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
         }
@@ -140,6 +149,9 @@ pub enum LeoWarning {
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Static Analyzer Warning in a Leo Warning.
+    #[error(transparent)]
+    StaticAnalyzerWarning(#[from] StaticAnalyzerWarning),
 }
 
 impl LeoWarning {
@@ -149,6 +161,7 @@ impl LeoWarning {
 
         match self {
             ParserWarning(warning) => warning.warning_code(),
+            StaticAnalyzerWarning(warning) => warning.warning_code(),
         }
     }
 }
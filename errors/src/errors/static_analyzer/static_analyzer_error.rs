@@ -0,0 +1,379 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+use std::fmt::{Debug, Display};
+
+create_messages!(
+    /// StaticAnalyzerError enum that represents all the errors for the static analysis passes in the `leo-passes` crate.
+    StaticAnalyzerError,
+    code_mask: 4000i32,
+    code_prefix: "SAZ",
+
+    /// For when a shorthand struct member initializer has no variable of the same name in scope.
+    @formatted
+    shorthand_without_variable {
+        args: (member: impl Display),
+        msg: format!("The shorthand initializer for member `{member}` requires a variable named `{member}` in scope."),
+        help: Some(format!("Consider writing `{member}: <expression>` instead.")),
+    }
+
+    /// For when a group literal with constant coordinates is not a point on the curve.
+    @formatted
+    group_point_not_on_curve {
+        args: (x: impl Display, y: impl Display),
+        msg: format!("The point `({x}, {y})group` is not on the curve."),
+        help: None,
+    }
+
+    /// For when the condition of a conditional statement or ternary expression contains an assignment.
+    @formatted
+    assignment_in_condition {
+        args: (),
+        msg: format!("The condition contains an assignment."),
+        help: Some("Did you mean to compare the values with `==`?".to_string()),
+    }
+
+    /// For when a reserved keyword is used as an identifier.
+    @formatted
+    keyword_as_identifier {
+        args: (name: impl Display),
+        msg: format!("`{name}` is a reserved keyword and cannot be used as an identifier."),
+        help: None,
+    }
+
+    /// For when `self` is used outside of the body of a function.
+    @formatted
+    self_outside_function_body {
+        args: (),
+        msg: format!("`self` can only be used in the body of a function or transition."),
+        help: Some("`self` refers to the execution of a function, which is not available in finalize blocks or at program scope.".to_string()),
+    }
+
+    /// For when a `field` or `group` is cast to an integer type without opting in to truncation.
+    @formatted
+    truncating_cast_without_opt_in {
+        args: (from: impl Display, to: impl Display),
+        msg: format!("Casting a `{from}` to `{to}` may lose information."),
+        help: Some("Annotate the enclosing function with `@truncate` to allow truncating casts.".to_string()),
+    }
+
+    /// For when a constant index is out of range for a constant array.
+    @formatted
+    array_index_out_of_range {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("Array index `{index}` out of range for an array with length `{length}`."),
+        help: None,
+    }
+
+    /// For when an annotation decorates an item it cannot be applied to.
+    @formatted
+    misplaced_annotation {
+        args: (annotation: impl Display, item: impl Display),
+        msg: format!("The annotation `{annotation}` cannot be applied to a {item}."),
+        help: None,
+    }
+
+    /// For when a string is used outside of the arguments of a console function.
+    @formatted
+    string_outside_console {
+        args: (),
+        msg: format!("Strings can only be used as arguments of console functions."),
+        help: None,
+    }
+
+    /// For when a predicate in the `where` clause of a function evaluates to false.
+    @formatted
+    predicate_not_satisfied {
+        args: (predicate: impl Display),
+        msg: format!("The predicate `{predicate}` is not satisfied."),
+        help: None,
+    }
+
+    /// For when a predicate in the `where` clause of a function cannot be evaluated at compile time.
+    @formatted
+    predicate_not_evaluable {
+        args: (predicate: impl Display),
+        msg: format!("The predicate `{predicate}` cannot be evaluated to a boolean at compile time."),
+        help: Some("Predicates may only use literals, program-scope constants, arithmetic, comparisons, and boolean operators.".to_string()),
+    }
+
+    /// For when a struct or record declares more than one member with the same name.
+    @formatted
+    duplicate_member {
+        args: (struct_: impl Display, member: impl Display, previous: impl Display),
+        msg: format!("The member `{member}` of `{struct_}` is already declared at {previous}."),
+        help: None,
+    }
+
+    /// For when the type of an ascribed expression is not compatible with the ascribed type.
+    @formatted
+    incompatible_ascription {
+        args: (expression: impl Display, actual: impl Display, ascribed: impl Display),
+        msg: format!("The expression `{expression}` has type `{actual}`, which cannot be ascribed the type `{ascribed}`."),
+        help: Some("Use a cast, e.g. `expression as type`, to convert between types.".to_string()),
+    }
+
+    /// For when a function is decorated with two annotations that cannot be combined.
+    @formatted
+    conflicting_annotations {
+        args: (function: impl Display, first: impl Display, second: impl Display),
+        msg: format!("The function `{function}` cannot be annotated with both `@{first}` and `@{second}`."),
+        help: None,
+    }
+
+    /// For when a constant is initialized with an expression that is not constant.
+    @formatted
+    non_constant_initializer {
+        args: (name: impl Display),
+        msg: format!("The constant `{name}` must be initialized with a constant expression."),
+        help: Some("Constant expressions may only use literals, other constants, operators, and calls to pure functions.".to_string()),
+    }
+
+    /// For when the type of an operand shared by two chained comparisons cannot be inferred.
+    @formatted
+    chained_comparison_operand_type_unknown {
+        args: (operand: impl Display),
+        msg: format!("The type of the chained comparison operand `{operand}` cannot be inferred."),
+        help: Some("Ascribe a type to the operand, e.g. `(operand : u8)`.".to_string()),
+    }
+
+    /// For when a group literal is written in an unsupported notation.
+    @formatted
+    malformed_group_literal {
+        args: (literal: impl Display, reason: impl Display),
+        msg: format!("The group literal `{literal}` is malformed: {reason}."),
+        help: Some("Group literals are written as `<integer>group`, `(<x>, <y>)group` with a numeric x-coordinate, or `group::GEN`.".to_string()),
+    }
+
+    /// For when the function to extract from a program does not exist.
+    @backtraced
+    function_not_found {
+        args: (function: impl Display),
+        msg: format!("The function `{function}` does not exist in the program."),
+        help: None,
+    }
+
+    /// For when a function declares more than one input with the same name.
+    @formatted
+    duplicate_input {
+        args: (function: impl Display, input: impl Display, previous: impl Display),
+        msg: format!("The input `{input}` of `{function}` is already declared at {previous}."),
+        help: None,
+    }
+
+    /// For when a function declares an input named `self`, which is reserved for the implicit receiver.
+    @formatted
+    input_named_self {
+        args: (function: impl Display),
+        msg: format!("The function `{function}` declares an input named `self`, which is reserved."),
+        help: Some("`self` always refers to the implicit receiver, e.g. in `self.caller`. Rename the input.".to_string()),
+    }
+
+    /// For when the outputs grouped into a nested tuple to limit the arity of a function's output do not share a mode.
+    @formatted
+    mixed_mode_output_group {
+        args: (function: impl Display, max_arity: impl Display),
+        msg: format!(
+            "The outputs of `{function}` cannot be grouped into tuples of at most {max_arity} elements, since the grouped outputs have different modes."
+        ),
+        help: Some("Give the outputs that are grouped together the same mode.".to_string()),
+    }
+
+    /// For when a local binding shadows a global constant.
+    @formatted
+    local_shadows_global {
+        args: (local: impl Display, global: impl Display),
+        msg: format!("The local `{local}` shadows the global constant declared at {global}."),
+        help: Some("Consider renaming the local.".to_string()),
+    }
+
+    /// For when an access in the place of an assignment does not match the type of the value it accesses.
+    @formatted
+    invalid_assignee_access {
+        args: (access: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The access `{access}` in the place of an assignment expects {expected}, but found a value of type `{found}`."),
+        help: None,
+    }
+
+    /// For when a member access in the place of an assignment names a member that the struct does not have.
+    @formatted
+    unknown_assignee_member {
+        args: (member: impl Display, struct_: impl Display),
+        msg: format!("The struct `{struct_}` has no member `{member}` to assign to."),
+        help: None,
+    }
+
+    /// For when the number of variables in an array pattern differs from the length of the array it binds.
+    @formatted
+    array_pattern_length_mismatch {
+        args: (pattern: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The array pattern `{pattern}` binds {found} elements, but the array has {expected} elements."),
+        help: None,
+    }
+
+    /// For when an array pattern binds a value that is not declared as an array.
+    @formatted
+    array_pattern_type_mismatch {
+        args: (pattern: impl Display, type_: impl Display),
+        msg: format!("The array pattern `{pattern}` can only bind an array, but the value has type `{type_}`."),
+        help: None,
+    }
+
+    /// For when a `@cfg` annotation has no predicate to evaluate.
+    @formatted
+    cfg_without_predicate {
+        args: (),
+        msg: format!("The `@cfg` annotation requires a predicate, e.g. `@cfg(feature = \"name\")`."),
+        help: None,
+    }
+
+    /// For when the predicate of a `@cfg` annotation cannot be evaluated.
+    @formatted
+    unsupported_cfg_predicate {
+        args: (predicate: impl Display),
+        msg: format!("The `@cfg` predicate `{predicate}` is not supported."),
+        help: Some("Predicates are of the form `feature == \"name\"`, combined with `!`, `&&`, and `||`.".to_string()),
+    }
+
+    /// For when a chain of member, array, and tuple accesses is deeper than the allowed limit.
+    @formatted
+    access_depth_exceeded {
+        args: (access: impl Display, depth: impl Display, limit: impl Display),
+        msg: format!("The access `{access}` has a depth of {depth}, which exceeds the limit of {limit}."),
+        help: None,
+    }
+
+    /// For when the branches of a ternary expression have different types.
+    @formatted
+    ternary_branch_type_mismatch {
+        args: (if_true: impl Display, if_false: impl Display),
+        msg: format!("The branches of this ternary expression have different types `{if_true}` and `{if_false}`."),
+        help: None,
+    }
+
+    /// For when an element of an array expression has a different type than the first element.
+    @formatted
+    heterogeneous_array_element {
+        args: (element: impl Display, element_type: impl Display, first_type: impl Display),
+        msg: format!("The array element `{element}` has type `{element_type}`, but the first element has type `{first_type}`."),
+        help: None,
+    }
+
+    /// For when the step of a stepped loop is the literal zero.
+    @formatted
+    zero_loop_step {
+        args: (step: impl Display),
+        msg: format!("The loop step `{step}` is zero, so the loop would never terminate."),
+        help: None,
+    }
+
+    /// For when a stepped loop cannot be lowered into a unit-step loop.
+    @formatted
+    non_literal_loop_step {
+        args: (step: impl Display),
+        msg: format!("The loop with step `{step}` cannot be lowered, since its bounds and step must be integer literals."),
+        help: None,
+    }
+
+    /// For when a named annotation argument has a literal of the wrong type.
+    @formatted
+    annotation_argument_type_mismatch {
+        args: (annotation: impl Display, argument: impl Display, expected: impl Display),
+        msg: format!("The argument `{argument}` of the annotation `@{annotation}` must be a {expected} literal."),
+        help: None,
+    }
+
+    /// For when an annotation is given a named argument that it does not accept.
+    @formatted
+    unknown_annotation_argument {
+        args: (annotation: impl Display, argument: impl Display),
+        msg: format!("The annotation `@{annotation}` does not accept an argument named `{argument}`."),
+        help: None,
+    }
+
+    /// For when a bitwise operator is combined with an arithmetic or comparison operator without parentheses.
+    @formatted
+    unparenthesized_mixed_operators {
+        args: (operator: impl Display, other: impl Display),
+        msg: format!("The bitwise and non-bitwise operators `{operator}` and `{other}` are combined without parentheses."),
+        help: Some("Consider adding parentheses to make the grouping explicit.".to_string()),
+    }
+
+    /// For when a group element is combined with an operand of an incompatible type.
+    @formatted
+    incompatible_group_operand {
+        args: (operation: impl Display, left: impl Display, right: impl Display),
+        msg: format!("The operation `{operation}` cannot combine operands of types `{left}` and `{right}`."),
+        help: Some("Group elements can only be added to or subtracted from group elements, and multiplied by scalars.".to_string()),
+    }
+
+    /// For when a conditional chain that produces the returned value may fall through without returning.
+    @formatted
+    non_exhaustive_conditional {
+        args: (),
+        msg: format!("This conditional chain produces the returned value, but it has no `else` branch and its conditions may all be false."),
+        help: Some("Consider adding an `else` branch, or making the last condition the negation of an earlier one.".to_string()),
+    }
+
+    /// For when a type refers to a type parameter that is not declared by the enclosing definition.
+    @formatted
+    undeclared_type_parameter {
+        args: (name: impl Display, definition: impl Display),
+        msg: format!("The type `{name}` is neither a type parameter of `{definition}` nor a defined struct or record."),
+        help: Some(format!("Consider declaring it as a type parameter, e.g. `{definition}<{name}>`.")),
+    }
+
+    /// For when the loops of a function unroll into more iterations than the budget allows.
+    @formatted
+    loop_unroll_budget_exceeded {
+        args: (count: impl Display, budget: impl Display),
+        msg: format!("The loops of this function unroll into {count} iterations, which exceeds the budget of {budget}."),
+        help: Some("Consider reducing the bounds of the loops, or splitting the work across several functions.".to_string()),
+    }
+
+    /// For when the number of iterations of a loop is not known at compile time.
+    @formatted
+    unbounded_loop {
+        args: (),
+        msg: format!("The number of iterations of this loop is not known at compile time, so it cannot be unrolled."),
+        help: Some("Consider using integer literals for the bounds and step of the loop.".to_string()),
+    }
+
+    /// For when the argument for a constant parameter of a function is not a constant expression.
+    @formatted
+    non_constant_argument {
+        args: (parameter: impl Display, function: impl Display),
+        msg: format!("The argument for the constant parameter `{parameter}` of `{function}` is not a constant expression."),
+        help: Some("Consider passing a literal, a constant, or an expression of constants.".to_string()),
+    }
+
+    /// For when a stepped loop has more iterations than its lowered counter can count.
+    @formatted
+    too_many_loop_steps {
+        args: (count: impl Display, max: impl Display),
+        msg: format!("The stepped loop runs {count} times, but a lowered loop can run at most {max} times."),
+        help: None,
+    }
+
+    /// For when a generic circuit is referred to where it cannot be resolved to a single specialization.
+    @formatted
+    unresolved_generic_circuit {
+        args: (name: impl Display, count: impl Display),
+        msg: format!("The generic circuit `{name}` is instantiated with {count} sets of concrete types, so this reference to it cannot be resolved."),
+        help: Some("Consider instantiating it with a single set of concrete types, or declaring a struct for each of them.".to_string()),
+    }
+);
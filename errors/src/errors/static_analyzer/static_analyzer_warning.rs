@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+use std::fmt::Display;
+
+create_messages!(
+    /// StaticAnalyzerWarning enum that represents all the warnings for the static analysis passes in the `leo-passes` crate.
+    StaticAnalyzerWarning,
+    code_mask: 4000i32,
+    code_prefix: "SAZ",
+
+    /// For when the size of a function exceeds the statement budget.
+    @formatted
+    function_exceeds_size_budget {
+        args: (function: impl Display, size: impl Display, budget: impl Display),
+        msg: format!("The function `{function}` has a size of {size} statements, which exceeds the budget of {budget}."),
+        help: Some("Consider splitting the function into smaller functions.".to_string()),
+    }
+
+    /// For when the result of an expression statement without side effects is discarded.
+    @formatted
+    unused_pure_expression {
+        args: (),
+        msg: format!("The result of this expression is unused, and evaluating it has no side effects."),
+        help: Some("Consider removing the statement, or binding its result with `let`.".to_string()),
+    }
+
+    /// For when the ranges of the operands of a comparison determine its result.
+    @formatted
+    constant_comparison {
+        args: (comparison: impl Display, value: impl Display),
+        msg: format!("The comparison `{comparison}` is always `{value}`, given the ranges of its operands."),
+        help: Some(format!("Consider replacing the comparison with `{value}`.")),
+    }
+
+    /// For when an imported program is never referenced.
+    @formatted
+    unused_import {
+        args: (import: impl Display),
+        msg: format!("The imported program `{import}` is never used."),
+        help: Some("Consider removing the import.".to_string()),
+    }
+
+    /// For when a local binding shadows a global constant.
+    @formatted
+    local_shadows_global {
+        args: (local: impl Display, global: impl Display),
+        msg: format!("The local `{local}` shadows the global constant declared at {global}."),
+        help: Some("Consider renaming the local.".to_string()),
+    }
+
+    /// For when a bitwise operator is combined with an arithmetic or comparison operator without parentheses.
+    @formatted
+    unparenthesized_mixed_operators {
+        args: (operator: impl Display, other: impl Display),
+        msg: format!("The bitwise and non-bitwise operators `{operator}` and `{other}` are combined without parentheses."),
+        help: Some("Consider adding parentheses to make the grouping explicit.".to_string()),
+    }
+
+    /// For when a declared type parameter is not used by its definition.
+    @formatted
+    unused_type_parameter {
+        args: (name: impl Display, definition: impl Display),
+        msg: format!("The type parameter `{name}` of `{definition}` is never used."),
+        help: Some("Consider removing the type parameter.".to_string()),
+    }
+);
@@ -712,4 +712,32 @@ create_messages!(
         msg: format!("An array cannot have a record as an element type"),
         help: None,
     }
+
+    @formatted
+    spread_not_expanded {
+        args: (spread: impl Display),
+        msg: format!("The spread `{spread}` could not be expanded, since the number of elements it spreads is unknown"),
+        help: Some("Only tuple expressions, e.g. `..(a, b)`, can be spread into a tuple expression.".to_string()),
+    }
+
+    @formatted
+    loop_step_not_normalized {
+        args: (step: impl Display),
+        msg: format!("The loop step `{step}` could not be lowered into a unit-step loop"),
+        help: Some("The bounds and the step of a stepped loop must be integer literals, e.g. `for i: u8 in 0u8..10u8 step 2u8`.".to_string()),
+    }
+
+    @formatted
+    type_parameters_not_supported {
+        args: (kind: impl Display, name: impl Display),
+        msg: format!("The {kind} `{name}` declares type parameters, which are not supported yet"),
+        help: Some("Type parameters cannot be instantiated until generics are supported. Consider using concrete types instead.".to_string()),
+    }
+
+    @formatted
+    annotated_statement_not_expanded {
+        args: (annotation: impl Display),
+        msg: format!("The statement annotation `{annotation}` was not expanded"),
+        help: Some("Statement annotations, e.g. `@cfg(feature = \"x\")`, must be expanded before type checking.".to_string()),
+    }
 );